@@ -0,0 +1,16 @@
+#![doc = include_str!("../README.md")]
+use pyo3::prelude::*;
+mod py_aho_corasick;
+mod py_match;
+mod py_stream;
+
+pub use crate::py_aho_corasick::PyAhoCorasick;
+pub use crate::py_match::PyAhoCorasickMatch;
+pub use crate::py_stream::PyAhoCorasickStreamIter;
+
+pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAhoCorasick>()?;
+    m.add_class::<PyAhoCorasickMatch>()?;
+    m.add_class::<PyAhoCorasickStreamIter>()?;
+    Ok(())
+}
@@ -0,0 +1,303 @@
+#![expect(clippy::needless_pass_by_value)]
+use std::fmt::Write;
+use std::sync::Arc;
+
+use aho_corasick::{AhoCorasick, Match};
+use pyo3::IntoPyObjectExt;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+use pyo3::types::{PyBytes, PyDict, PyList, PyString, PyTuple};
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+use ryo3_macro_rules::{py_type_err, py_value_err, py_value_error};
+
+use crate::py_match::{PyAhoCorasickMatch, PyMatchKind};
+use crate::py_stream::PyAhoCorasickStreamIter;
+
+/// A pattern: a `str` (searched for as utf-8) or any bytes-like object
+pub(crate) enum PyPattern {
+    Str(PyBackedStr),
+    Buffer(RyBytes),
+}
+
+impl AsRef<[u8]> for PyPattern {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Str(s) => s.as_bytes(),
+            Self::Buffer(b) => b.as_slice(),
+        }
+    }
+}
+
+impl PyPattern {
+    fn to_pyobject<'py>(&self, py: Python<'py>) -> Bound<'py, PyAny> {
+        match self {
+            Self::Str(s) => PyString::new(py, s).into_any(),
+            Self::Buffer(b) => PyBytes::new(py, b.as_slice()).into_any(),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyPattern {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if ob.is_instance_of::<PyString>() {
+            Ok(Self::Str(ob.extract::<PyBackedStr>()?))
+        } else if let Ok(b) = ob.extract::<RyBytes>() {
+            Ok(Self::Buffer(b))
+        } else {
+            py_type_err!(
+                "pattern must be a str or bytes-like object, not '{}'",
+                ob.get_type().name()?
+            )
+        }
+    }
+}
+
+/// Haystack: a `str` (offsets are code point indices) or any bytes-like
+/// object (offsets are byte offsets)
+pub(crate) enum PyHaystack<'a, 'py> {
+    Str(&'a str),
+    Buffer(ReadableBuffer<'a, 'py>),
+}
+
+impl AsRef<[u8]> for PyHaystack<'_, '_> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Str(s) => s.as_bytes(),
+            Self::Buffer(b) => b.as_slice(),
+        }
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for PyHaystack<'a, 'py> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        if ob.is_instance_of::<PyString>() {
+            Ok(Self::Str(ob.extract::<&'a str>()?))
+        } else if let Ok(buf) = ob.extract::<ReadableBuffer<'a, 'py>>() {
+            Ok(Self::Buffer(buf))
+        } else {
+            py_type_err!(
+                "haystack must be a str or bytes-like object, not '{}'",
+                ob.get_type().name()?
+            )
+        }
+    }
+}
+
+/// Byte-offset => char-offset converter for `str` haystacks
+///
+/// Matches are converted in order so the conversion is linear in the length
+/// of the haystack; counts utf-8 leading bytes so offsets that do not fall on
+/// a char boundary (bytes patterns) are fine.
+struct CharOffsets<'h> {
+    haystack: &'h [u8],
+    ascii: bool,
+    byte: usize,
+    char: usize,
+}
+
+impl<'h> CharOffsets<'h> {
+    fn new(haystack: &'h [u8], is_str: bool) -> Self {
+        Self {
+            haystack,
+            ascii: !is_str || haystack.is_ascii(),
+            byte: 0,
+            char: 0,
+        }
+    }
+
+    fn char_offset(&mut self, byte: usize) -> usize {
+        if self.ascii {
+            return byte;
+        }
+        if byte < self.byte {
+            self.byte = 0;
+            self.char = 0;
+        }
+        self.char += self.haystack[self.byte..byte]
+            .iter()
+            .filter(|b| !is_utf8_continuation(**b))
+            .count();
+        self.byte = byte;
+        self.char
+    }
+
+    fn convert(&mut self, m: &Match) -> PyAhoCorasickMatch {
+        let start = self.char_offset(m.start());
+        let end = self.char_offset(m.end());
+        PyAhoCorasickMatch::new(m.pattern().as_usize(), start, end)
+    }
+}
+
+#[inline]
+fn is_utf8_continuation(b: u8) -> bool {
+    b & 0b1100_0000 == 0b1000_0000
+}
+
+/// Multi-pattern (Aho-Corasick) searcher
+#[pyclass(name = "AhoCorasick", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyAhoCorasick {
+    ac: Arc<AhoCorasick>,
+    patterns: Vec<PyPattern>,
+    match_kind: PyMatchKind,
+    ascii_case_insensitive: bool,
+}
+
+#[pymethods]
+impl PyAhoCorasick {
+    #[new]
+    #[pyo3(
+        signature = (patterns, *, match_kind = PyMatchKind::default(), ascii_case_insensitive = false)
+    )]
+    fn py_new(
+        patterns: Vec<PyPattern>,
+        match_kind: PyMatchKind,
+        ascii_case_insensitive: bool,
+    ) -> PyResult<Self> {
+        let ac = AhoCorasick::builder()
+            .match_kind(match_kind.0)
+            .ascii_case_insensitive(ascii_case_insensitive)
+            .build(&patterns)
+            .map_err(|e| py_value_error!("Invalid aho-corasick patterns: {e}"))?;
+        Ok(Self {
+            ac: Arc::new(ac),
+            patterns,
+            match_kind,
+            ascii_case_insensitive,
+        })
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let patterns = self
+            .patterns
+            .iter()
+            .map(|p| p.to_pyobject(py).repr().map(|r| r.to_string()))
+            .collect::<PyResult<Vec<_>>>()?;
+        let mut s = format!("AhoCorasick([{}]", patterns.join(", "));
+        if self.match_kind != PyMatchKind::default() {
+            let _ = write!(s, ", match_kind='{}'", self.match_kind.as_str());
+        }
+        if self.ascii_case_insensitive {
+            s.push_str(", ascii_case_insensitive=True");
+        }
+        s.push(')');
+        Ok(s)
+    }
+
+    fn __len__(&self) -> usize {
+        self.patterns.len()
+    }
+
+    fn __getnewargs_ex__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "match_kind"), self.match_kind.as_str())?;
+        kwargs.set_item(
+            intern!(py, "ascii_case_insensitive"),
+            self.ascii_case_insensitive,
+        )?;
+        let args = PyTuple::new(py, [PyList::new(py, self.patterns(py))?])?;
+        PyTuple::new(
+            py,
+            [args.into_bound_py_any(py)?, kwargs.into_bound_py_any(py)?],
+        )
+    }
+
+    /// The patterns the searcher was built from
+    #[getter]
+    fn patterns<'py>(&self, py: Python<'py>) -> Vec<Bound<'py, PyAny>> {
+        self.patterns.iter().map(|p| p.to_pyobject(py)).collect()
+    }
+
+    /// Number of patterns
+    #[getter]
+    fn patterns_len(&self) -> usize {
+        self.ac.patterns_len()
+    }
+
+    /// Match semantics (`'standard'`, `'leftmost-first'` or `'leftmost-longest'`)
+    #[getter]
+    fn match_kind(&self) -> &'static str {
+        self.match_kind.as_str()
+    }
+
+    #[getter]
+    fn ascii_case_insensitive(&self) -> bool {
+        self.ascii_case_insensitive
+    }
+
+    /// Return `True` if any pattern matches the haystack
+    fn is_match(&self, haystack: PyHaystack<'_, '_>) -> bool {
+        self.ac.is_match(haystack.as_ref())
+    }
+
+    /// Return the first match in the haystack (or `None`)
+    fn find(&self, haystack: PyHaystack<'_, '_>) -> Option<PyAhoCorasickMatch> {
+        let is_str = matches!(haystack, PyHaystack::Str(_));
+        let hay = haystack.as_ref();
+        self.ac
+            .find(hay)
+            .map(|m| CharOffsets::new(hay, is_str).convert(&m))
+    }
+
+    /// Return all non-overlapping matches in the haystack
+    fn find_iter(&self, haystack: PyHaystack<'_, '_>) -> Vec<PyAhoCorasickMatch> {
+        let is_str = matches!(haystack, PyHaystack::Str(_));
+        let hay = haystack.as_ref();
+        let mut offsets = CharOffsets::new(hay, is_str);
+        self.ac
+            .find_iter(hay)
+            .map(|m| offsets.convert(&m))
+            .collect()
+    }
+
+    /// Return the number of non-overlapping matches in the haystack
+    fn count(&self, haystack: PyHaystack<'_, '_>) -> usize {
+        self.ac.find_iter(haystack.as_ref()).count()
+    }
+
+    /// Replace every non-overlapping match with the replacement for the
+    /// pattern that matched (`replacements[i]` replaces `patterns[i]`)
+    ///
+    /// Returns a `str` for `str` haystacks and `Bytes` otherwise.
+    fn replace_all<'py>(
+        &self,
+        py: Python<'py>,
+        haystack: PyHaystack<'_, 'py>,
+        replacements: Vec<PyPattern>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if replacements.len() != self.ac.patterns_len() {
+            return py_value_err!(
+                "expected {} replacements (one per pattern), got {}",
+                self.ac.patterns_len(),
+                replacements.len()
+            );
+        }
+        let replaced = self.ac.replace_all_bytes(haystack.as_ref(), &replacements);
+        match haystack {
+            PyHaystack::Str(_) => {
+                let s = String::from_utf8(replaced).map_err(|_| {
+                    py_value_error!("replacing a str haystack produced invalid utf-8")
+                })?;
+                Ok(PyString::new(py, &s).into_any())
+            }
+            PyHaystack::Buffer(_) => RyBytes::from(replaced).into_bound_py_any(py),
+        }
+    }
+
+    /// Search a stream of bytes-like chunks (e.g. a `FileReadStream`) and
+    /// lazily yield matches; offsets are absolute byte offsets into the stream
+    ///
+    /// Matches spanning chunk boundaries are found; only the current chunk
+    /// and a tail of at most `max(len(pattern)) - 1` bytes are kept in memory.
+    fn stream_find_iter(&self, stream: &Bound<'_, PyAny>) -> PyResult<PyAhoCorasickStreamIter> {
+        Ok(PyAhoCorasickStreamIter::new(
+            Arc::clone(&self.ac),
+            stream.try_iter()?.unbind(),
+        ))
+    }
+}
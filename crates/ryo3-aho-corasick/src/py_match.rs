@@ -0,0 +1,116 @@
+use aho_corasick::MatchKind;
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use ryo3_macro_rules::{py_type_err, py_value_err};
+
+/// `aho_corasick::MatchKind` python string option
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PyMatchKind(pub(crate) MatchKind);
+
+impl PyMatchKind {
+    const OPTIONS: [(&'static str, MatchKind); 3] = [
+        ("standard", MatchKind::Standard),
+        ("leftmost-first", MatchKind::LeftmostFirst),
+        ("leftmost-longest", MatchKind::LeftmostLongest),
+    ];
+
+    pub(crate) fn as_str(self) -> &'static str {
+        match self.0 {
+            MatchKind::LeftmostFirst => "leftmost-first",
+            MatchKind::LeftmostLongest => "leftmost-longest",
+            _ => "standard",
+        }
+    }
+}
+
+impl Default for PyMatchKind {
+    fn default() -> Self {
+        Self(MatchKind::LeftmostFirst)
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyMatchKind {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let Ok(s) = ob.cast::<PyString>() else {
+            return py_type_err!(
+                "match_kind must be a string, not '{}'",
+                ob.get_type().name()?
+            );
+        };
+        let s = s.to_str()?;
+        let normalized = s.to_ascii_lowercase().replace('_', "-");
+        if let Some((_, kind)) = Self::OPTIONS.iter().find(|(name, _)| *name == normalized) {
+            Ok(Self(*kind))
+        } else {
+            py_value_err!(
+                "Invalid match_kind: '{s}' (options: 'standard', 'leftmost-first', 'leftmost-longest')"
+            )
+        }
+    }
+}
+
+/// A single match: the index of the pattern that matched and its span
+///
+/// Offsets are `str` (code point) indices for `str` haystacks and byte
+/// offsets for bytes-like haystacks/streams.
+#[pyclass(name = "AhoCorasickMatch", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PyAhoCorasickMatch {
+    pattern: usize,
+    start: usize,
+    end: usize,
+}
+
+impl PyAhoCorasickMatch {
+    pub(crate) fn new(pattern: usize, start: usize, end: usize) -> Self {
+        Self {
+            pattern,
+            start,
+            end,
+        }
+    }
+}
+
+#[pymethods]
+impl PyAhoCorasickMatch {
+    fn __repr__(&self) -> String {
+        format!(
+            "AhoCorasickMatch(pattern={}, start={}, end={})",
+            self.pattern, self.start, self.end
+        )
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __len__(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Index of the pattern that matched
+    #[getter]
+    fn pattern(&self) -> usize {
+        self.pattern
+    }
+
+    /// Start offset of the match
+    #[getter]
+    fn start(&self) -> usize {
+        self.start
+    }
+
+    /// End offset (exclusive) of the match
+    #[getter]
+    fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Return the `(start, end)` of the match
+    fn span(&self) -> (usize, usize) {
+        (self.start, self.end)
+    }
+}
@@ -0,0 +1,108 @@
+//! Chunked (stream) search
+//!
+//! `aho_corasick::AhoCorasick::stream_find_iter` only supports
+//! `MatchKind::Standard` and reads from a `std::io::Read`; this searches a
+//! python iterable of bytes-like chunks (e.g. `FileReadStream`) for any match
+//! kind by carrying a tail of at most `max_pattern_len - 1` bytes between
+//! chunks.
+//!
+//! A match starting before `buffer.len() - (max_pattern_len - 1)` cannot be
+//! affected by bytes not yet read, so it is reported; everything from that
+//! cutoff (or the end of the last reported match, if later) is carried over
+//! to be searched again with the next chunk.
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use aho_corasick::{AhoCorasick, Input};
+use pyo3::prelude::*;
+use pyo3::types::PyIterator;
+use ryo3_bytes::RyBytes;
+
+use crate::py_match::PyAhoCorasickMatch;
+
+/// Lazy iterator over the matches in a stream of bytes-like chunks
+#[pyclass(name = "AhoCorasickStreamIter", immutable_type)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyAhoCorasickStreamIter {
+    ac: Arc<AhoCorasick>,
+    stream: Py<PyIterator>,
+    /// unsearched tail of the previous chunk(s) + the current chunk
+    buf: Vec<u8>,
+    /// absolute (stream) offset of `buf[0]`
+    offset: usize,
+    pending: VecDeque<PyAhoCorasickMatch>,
+    done: bool,
+}
+
+impl PyAhoCorasickStreamIter {
+    pub(crate) fn new(ac: Arc<AhoCorasick>, stream: Py<PyIterator>) -> Self {
+        Self {
+            ac,
+            stream,
+            buf: Vec::new(),
+            offset: 0,
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Search the buffer, queue the matches that are final and drop the
+    /// searched prefix of the buffer
+    fn search(&mut self, eof: bool) {
+        let len = self.buf.len();
+        let cutoff = if eof {
+            len
+        } else {
+            len.saturating_sub(self.ac.max_pattern_len().saturating_sub(1))
+        };
+        let mut keep_from = cutoff;
+        for m in self.ac.find_iter(Input::new(&self.buf)) {
+            if !eof && m.start() >= cutoff {
+                break;
+            }
+            keep_from = keep_from.max(m.end());
+            self.pending.push_back(PyAhoCorasickMatch::new(
+                m.pattern().as_usize(),
+                self.offset + m.start(),
+                self.offset + m.end(),
+            ));
+        }
+        let keep_from = keep_from.min(len);
+        self.buf.drain(..keep_from);
+        self.offset += keep_from;
+    }
+
+    fn advance(&mut self, py: Python<'_>) -> PyResult<Option<PyAhoCorasickMatch>> {
+        while self.pending.is_empty() && !self.done {
+            if let Some(chunk) = self.stream.bind(py).clone().next() {
+                let chunk = chunk?.extract::<RyBytes>()?;
+                self.buf.extend_from_slice(chunk.as_slice());
+                self.search(false);
+            } else {
+                self.done = true;
+                self.search(true);
+            }
+        }
+        Ok(self.pending.pop_front())
+    }
+}
+
+#[pymethods]
+impl PyAhoCorasickStreamIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyAhoCorasickMatch>> {
+        self.advance(py)
+    }
+
+    /// Collect the remaining matches into a list
+    fn collect(&mut self, py: Python<'_>) -> PyResult<Vec<PyAhoCorasickMatch>> {
+        let mut matches = Vec::new();
+        while let Some(m) = self.advance(py)? {
+            matches.push(m);
+        }
+        Ok(matches)
+    }
+}
@@ -0,0 +1,521 @@
+//! `io.BufferedIOBase`-like reader/writer over `bytes::Bytes`/`bytes::BytesMut`
+//!
+//! `BytesReader` reads over a `Bytes` without copying (`read` returns slices of
+//! the underlying buffer) and `BytesWriter` grows a `BytesMut` that is frozen
+//! into `Bytes` when done.
+#![expect(clippy::unused_self)]
+
+use bytes::{Bytes, BytesMut};
+use pyo3::buffer::PyUntypedBuffer;
+use pyo3::exceptions::{PyMemoryError, PyOverflowError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+use crate::{PyBytes, ReadableBuffer, search};
+
+pyo3::import_exception!(io, UnsupportedOperation);
+
+fn closed_err() -> PyErr {
+    PyValueError::new_err("I/O operation on closed file.")
+}
+
+fn unsupported(op: &str) -> PyErr {
+    UnsupportedOperation::new_err(op.to_string())
+}
+
+/// `None` or negative => `None` (read everything) like python
+fn py_size(size: Option<isize>) -> Option<usize> {
+    size.and_then(|s| usize::try_from(s).ok())
+}
+
+/// Compute the new stream position for `seek(offset, whence)`
+fn seek_position(pos: usize, len: usize, offset: isize, whence: u8) -> PyResult<usize> {
+    let base = match whence {
+        0 => {
+            return usize::try_from(offset)
+                .map_err(|_| PyValueError::new_err(format!("negative seek value {offset}")));
+        }
+        1 => pos,
+        2 => len,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "invalid whence ({whence}, should be 0, 1 or 2)"
+            )));
+        }
+    };
+    // relative seeks clamp at the start of the stream (same as `io.BytesIO`)
+    Ok(base.saturating_add_signed(offset))
+}
+
+// ============================================================================
+// READER
+// ============================================================================
+
+/// Binary file-like reader over a `Bytes` buffer
+#[pyclass(name = "BytesReader", immutable_type)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyBytesReader {
+    data: Bytes,
+    pos: usize,
+    closed: bool,
+}
+
+impl PyBytesReader {
+    fn check_closed(&self) -> PyResult<()> {
+        if self.closed {
+            Err(closed_err())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Remaining (unread) bytes
+    fn remaining(&self) -> &[u8] {
+        self.data.get(self.pos..).unwrap_or_default()
+    }
+
+    /// Read up to `size` bytes (all remaining if `None`) as a zero-copy slice
+    fn read_slice(&mut self, size: Option<usize>) -> PyResult<Bytes> {
+        self.check_closed()?;
+        let start = self.pos.min(self.data.len());
+        let end = size.map_or(self.data.len(), |n| {
+            start.saturating_add(n).min(self.data.len())
+        });
+        self.pos = self.pos.max(end);
+        Ok(self.data.slice(start..end))
+    }
+
+    fn read_line(&mut self, size: Option<usize>) -> PyResult<Bytes> {
+        self.check_closed()?;
+        let remaining = self.remaining();
+        let mut n = search::memchr(b'\n', remaining).map_or(remaining.len(), |ix| ix + 1);
+        if let Some(size) = size {
+            n = n.min(size);
+        }
+        self.read_slice(Some(n))
+    }
+
+    fn read_into(&mut self, buffer: &Bound<'_, PyAny>) -> PyResult<usize> {
+        self.check_closed()?;
+        // any writable contiguous buffer (not just format 'B') like `io.BytesIO`
+        let buf = PyUntypedBuffer::get(buffer)?;
+        if buf.readonly() {
+            return Err(PyTypeError::new_err(
+                "readinto() argument must be read-write bytes-like object",
+            ));
+        }
+        if !buf.is_c_contiguous() {
+            return Err(PyValueError::new_err("Buffer is not C contiguous"));
+        }
+        let src = self.remaining();
+        let n = src.len().min(buf.len_bytes());
+        #[expect(unsafe_code)]
+        unsafe {
+            // SAFETY: the buffer is writable, contiguous and at least `n`
+            // bytes long; `copy` (not `copy_nonoverlapping`) in case the
+            // destination is the buffer being read from
+            std::ptr::copy(src.as_ptr(), buf.buf_ptr().cast::<u8>(), n);
+        }
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[pymethods]
+impl PyBytesReader {
+    #[new]
+    #[pyo3(signature = (data = None, /))]
+    fn py_new(data: Option<PyBytes>) -> Self {
+        Self {
+            data: data.map(PyBytes::into_inner).unwrap_or_default(),
+            pos: 0,
+            closed: false,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BytesReader(len={}, pos={}, closed={})",
+            self.data.len(),
+            self.pos,
+            if self.closed { "True" } else { "False" }
+        )
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.check_closed()?;
+        Ok(slf)
+    }
+
+    fn __next__(&mut self) -> PyResult<Option<PyBytes>> {
+        let line = self.read_line(None)?;
+        Ok((!line.is_empty()).then(|| line.into()))
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.check_closed()?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&mut self, _args: &Bound<'_, PyTuple>) {
+        self.close();
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.closed
+    }
+
+    #[getter]
+    fn mode(&self) -> &'static str {
+        "rb"
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        // release the underlying buffer
+        self.data = Bytes::new();
+    }
+
+    fn flush(&self) -> PyResult<()> {
+        self.check_closed()
+    }
+
+    fn readable(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| true)
+    }
+
+    fn writable(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| false)
+    }
+
+    fn seekable(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| true)
+    }
+
+    fn isatty(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| false)
+    }
+
+    fn fileno(&self) -> PyResult<()> {
+        Err(unsupported("fileno"))
+    }
+
+    fn detach(&self) -> PyResult<()> {
+        Err(unsupported("detach"))
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn write(&self, _args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        Err(unsupported("write"))
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn truncate(&self, _args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        Err(unsupported("truncate"))
+    }
+
+    fn tell(&self) -> PyResult<usize> {
+        self.check_closed()?;
+        Ok(self.pos)
+    }
+
+    #[pyo3(
+        signature = (offset, whence = 0, /),
+        text_signature = "(self, offset, whence=os.SEEK_SET, /)"
+    )]
+    fn seek(&mut self, offset: isize, whence: u8) -> PyResult<usize> {
+        self.check_closed()?;
+        self.pos = seek_position(self.pos, self.data.len(), offset, whence)?;
+        Ok(self.pos)
+    }
+
+    #[pyo3(signature = (size = None, /))]
+    fn read(&mut self, size: Option<isize>) -> PyResult<PyBytes> {
+        self.read_slice(py_size(size)).map(PyBytes::from)
+    }
+
+    #[pyo3(signature = (size = None, /))]
+    fn read1(&mut self, size: Option<isize>) -> PyResult<PyBytes> {
+        self.read_slice(py_size(size)).map(PyBytes::from)
+    }
+
+    fn readall(&mut self) -> PyResult<PyBytes> {
+        self.read_slice(None).map(PyBytes::from)
+    }
+
+    #[pyo3(signature = (buffer, /))]
+    fn readinto(&mut self, buffer: &Bound<'_, PyAny>) -> PyResult<usize> {
+        self.read_into(buffer)
+    }
+
+    #[pyo3(signature = (buffer, /))]
+    fn readinto1(&mut self, buffer: &Bound<'_, PyAny>) -> PyResult<usize> {
+        self.read_into(buffer)
+    }
+
+    #[pyo3(signature = (size = None, /))]
+    fn readline(&mut self, size: Option<isize>) -> PyResult<PyBytes> {
+        self.read_line(py_size(size)).map(PyBytes::from)
+    }
+
+    #[pyo3(signature = (hint = None, /))]
+    fn readlines(&mut self, hint: Option<isize>) -> PyResult<Vec<PyBytes>> {
+        let hint = py_size(hint).filter(|h| *h > 0);
+        let mut lines = Vec::new();
+        let mut total = 0usize;
+        loop {
+            let line = self.read_line(None)?;
+            if line.is_empty() {
+                break;
+            }
+            total += line.len();
+            lines.push(PyBytes::from(line));
+            if hint.is_some_and(|h| total >= h) {
+                break;
+            }
+        }
+        Ok(lines)
+    }
+
+    /// Return (up to `size`) bytes without advancing the position
+    #[pyo3(signature = (size = 0, /))]
+    fn peek(&self, size: isize) -> PyResult<PyBytes> {
+        self.check_closed()?;
+        let start = self.pos.min(self.data.len());
+        let end = match usize::try_from(size) {
+            Ok(n) if n > 0 => start.saturating_add(n).min(self.data.len()),
+            _ => self.data.len(),
+        };
+        Ok(self.data.slice(start..end).into())
+    }
+
+    /// Return the entire underlying buffer (zero-copy)
+    fn getvalue(&self) -> PyResult<PyBytes> {
+        self.check_closed()?;
+        Ok(self.data.clone().into())
+    }
+}
+
+// ============================================================================
+// WRITER
+// ============================================================================
+
+/// Binary file-like writer that grows a `BytesMut` buffer
+#[pyclass(name = "BytesWriter", immutable_type)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyBytesWriter {
+    buf: BytesMut,
+    pos: usize,
+    closed: bool,
+}
+
+impl PyBytesWriter {
+    fn check_closed(&self) -> PyResult<()> {
+        if self.closed {
+            Err(closed_err())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Ensure capacity for `len` bytes, raising `MemoryError` instead of
+    /// aborting if the allocation fails (`BytesMut` has no `try_reserve`)
+    fn try_grow(&mut self, len: usize) -> PyResult<()> {
+        if len <= self.buf.capacity() {
+            return Ok(());
+        }
+        let target = len.max(self.buf.capacity().saturating_mul(2));
+        let mut vec = Vec::new();
+        if vec.try_reserve_exact(target).is_err() {
+            vec.try_reserve_exact(len)
+                .map_err(|e| PyMemoryError::new_err(e.to_string()))?;
+        }
+        vec.extend_from_slice(&self.buf);
+        // unique `Bytes` -> `BytesMut` reuses the vec allocation (no copy)
+        self.buf = BytesMut::from(Bytes::from(vec));
+        Ok(())
+    }
+
+    fn write_slice(&mut self, data: &[u8]) -> PyResult<usize> {
+        self.check_closed()?;
+        if data.is_empty() {
+            return Ok(0);
+        }
+        let end = self
+            .pos
+            .checked_add(data.len())
+            .ok_or_else(|| PyOverflowError::new_err("new position too large"))?;
+        self.try_grow(end)?;
+        if self.pos > self.buf.len() {
+            // writing past the end zero-fills the gap (same as `io.BytesIO`)
+            self.buf.resize(self.pos, 0);
+        }
+        let overlap = end.min(self.buf.len());
+        let (head, tail) = data.split_at(overlap - self.pos);
+        self.buf[self.pos..overlap].copy_from_slice(head);
+        self.buf.extend_from_slice(tail);
+        self.pos = end;
+        Ok(data.len())
+    }
+}
+
+#[pymethods]
+impl PyBytesWriter {
+    #[new]
+    #[pyo3(signature = (capacity = 0))]
+    fn py_new(capacity: usize) -> PyResult<Self> {
+        let mut writer = Self {
+            buf: BytesMut::new(),
+            pos: 0,
+            closed: false,
+        };
+        writer.try_grow(capacity)?;
+        Ok(writer)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BytesWriter(len={}, pos={}, closed={})",
+            self.buf.len(),
+            self.pos,
+            if self.closed { "True" } else { "False" }
+        )
+    }
+
+    fn __len__(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.check_closed()?;
+        Ok(slf)
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn __exit__(&mut self, _args: &Bound<'_, PyTuple>) {
+        self.close();
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.closed
+    }
+
+    #[getter]
+    fn mode(&self) -> &'static str {
+        "wb"
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        self.buf = BytesMut::new();
+    }
+
+    fn flush(&self) -> PyResult<()> {
+        self.check_closed()
+    }
+
+    fn readable(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| false)
+    }
+
+    fn writable(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| true)
+    }
+
+    fn seekable(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| true)
+    }
+
+    fn isatty(&self) -> PyResult<bool> {
+        self.check_closed().map(|()| false)
+    }
+
+    fn fileno(&self) -> PyResult<()> {
+        Err(unsupported("fileno"))
+    }
+
+    fn detach(&self) -> PyResult<()> {
+        Err(unsupported("detach"))
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn read(&self, _args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        Err(unsupported("read"))
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn read1(&self, _args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        Err(unsupported("read1"))
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn readinto(&self, _args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        Err(unsupported("readinto"))
+    }
+
+    #[pyo3(signature = (*_args))]
+    fn readline(&self, _args: &Bound<'_, PyTuple>) -> PyResult<()> {
+        Err(unsupported("readline"))
+    }
+
+    fn tell(&self) -> PyResult<usize> {
+        self.check_closed()?;
+        Ok(self.pos)
+    }
+
+    #[pyo3(
+        signature = (offset, whence = 0, /),
+        text_signature = "(self, offset, whence=os.SEEK_SET, /)"
+    )]
+    fn seek(&mut self, offset: isize, whence: u8) -> PyResult<usize> {
+        self.check_closed()?;
+        self.pos = seek_position(self.pos, self.buf.len(), offset, whence)?;
+        Ok(self.pos)
+    }
+
+    /// Resize to `size` (default current position) bytes; the position is
+    /// not changed
+    #[pyo3(signature = (size = None, /))]
+    fn truncate(&mut self, size: Option<isize>) -> PyResult<usize> {
+        self.check_closed()?;
+        let size = match size {
+            None => self.pos,
+            Some(s) => usize::try_from(s)
+                .map_err(|_| PyValueError::new_err(format!("negative size value {s}")))?,
+        };
+        self.buf.truncate(size);
+        Ok(size)
+    }
+
+    #[pyo3(signature = (data, /))]
+    fn write(&mut self, data: ReadableBuffer<'_, '_>) -> PyResult<usize> {
+        self.write_slice(data.as_slice())
+    }
+
+    #[pyo3(signature = (lines, /))]
+    fn writelines(&mut self, lines: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.check_closed()?;
+        for line in lines.try_iter()? {
+            let line = line?;
+            self.write_slice(line.extract::<ReadableBuffer<'_, '_>>()?.as_slice())?;
+        }
+        Ok(())
+    }
+
+    /// Return a copy of the written bytes
+    fn getvalue(&self) -> PyResult<PyBytes> {
+        self.check_closed()?;
+        Ok(Bytes::copy_from_slice(&self.buf).into())
+    }
+
+    /// Freeze the written bytes into `Bytes` (no copy) and close the writer
+    fn freeze(&mut self) -> PyResult<PyBytes> {
+        self.check_closed()?;
+        let buf = std::mem::take(&mut self.buf);
+        self.close();
+        Ok(buf.freeze().into())
+    }
+}
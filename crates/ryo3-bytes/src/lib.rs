@@ -4,6 +4,7 @@
 #![expect(clippy::needless_pass_by_value)]
 use pyo3::prelude::*;
 pub mod bytes;
+mod bytes_io;
 mod readable_buffer;
 mod replace;
 
@@ -14,11 +15,18 @@ pub use ::bytes::Bytes;
 pub use readable_buffer::{ExactReadableBuffer, ReadableBuffer};
 
 pub use crate::bytes::PyBytes;
+pub use crate::bytes_io::{PyBytesReader, PyBytesWriter};
 // export alias `RyBytes` to avoid confusion with `pyo3::types::PyBytes`
 pub use crate::bytes::PyBytes as RyBytes;
 
 /// ryo3-bytes python module registration
 pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyBytes>()?;
+    m.add_class::<PyBytesReader>()?;
+    m.add_class::<PyBytesWriter>()?;
+    // virtual subclasses of `io.BufferedIOBase` for `isinstance` checks
+    let buffered_io_base = m.py().import("io")?.getattr("BufferedIOBase")?;
+    buffered_io_base.call_method1("register", (m.py().get_type::<PyBytesReader>(),))?;
+    buffered_io_base.call_method1("register", (m.py().get_type::<PyBytesWriter>(),))?;
     Ok(())
 }
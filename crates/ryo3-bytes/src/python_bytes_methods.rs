@@ -7,7 +7,7 @@ use std::ops::Range;
 use bytes::Bytes;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyString, PyTuple};
 use pyo3::{IntoPyObjectExt, PyClass};
 
 use crate::{ReadableBuffer, search};
@@ -406,6 +406,130 @@ pub(crate) trait PythonBytesMethods:
         self.py_rsearch::<true>(needle, start, end)
     }
 
+    fn py_count(&self, needle: BufferOrByte, start: Option<isize>, end: Option<isize>) -> usize {
+        let Some(Range { start, end }) = normalize_find_bounds(self.as_ref().len(), start, end)
+        else {
+            return 0;
+        };
+        let b = &self.as_ref()[start..end];
+        match needle {
+            BufferOrByte::Buffer(buf) => search::count_subslice(b, buf.as_slice()),
+            BufferOrByte::Byte(byte) => search::count_byte(byte, b),
+        }
+    }
+
+    /// `startswith`/`endswith` impl; `SUFFIX` selects which end to match
+    fn py_tailmatch<const SUFFIX: bool>(
+        &self,
+        affix: &PyBytesAffix,
+        start: Option<isize>,
+        end: Option<isize>,
+    ) -> bool {
+        let Some(range) = normalize_find_bounds(self.as_ref().len(), start, end) else {
+            return false;
+        };
+        let b = &self.as_ref()[range];
+        let tailmatch = |affix: &[u8]| {
+            if SUFFIX {
+                b.ends_with(affix)
+            } else {
+                b.starts_with(affix)
+            }
+        };
+        match affix {
+            PyBytesAffix::One(affix) => tailmatch(affix.as_ref()),
+            PyBytesAffix::Many(affixes) => affixes.iter().any(|affix| tailmatch(affix.as_ref())),
+        }
+    }
+
+    /// Pad to `width` with `fillbyte` returning `None` if no padding is needed
+    ///
+    /// The `center` split matches cpython's `stringlib_center` which puts the
+    /// extra byte on the left when both the margin and the width are odd.
+    fn py_pad(&self, width: isize, fillbyte: u8, align: PyBytesAlign) -> Option<Self> {
+        let b = self.as_ref();
+        let width = usize::try_from(width).ok()?;
+        if width <= b.len() {
+            return None;
+        }
+        let margin = width - b.len();
+        let (left, right) = match align {
+            PyBytesAlign::Left => (0, margin),
+            PyBytesAlign::Right => (margin, 0),
+            PyBytesAlign::Center => {
+                let left = margin / 2 + (margin & width & 1);
+                (left, margin - left)
+            }
+        };
+        let mut out = Vec::with_capacity(width);
+        out.extend(std::iter::repeat_n(fillbyte, left));
+        out.extend_from_slice(b);
+        out.extend(std::iter::repeat_n(fillbyte, right));
+        Some(Self::from(out))
+    }
+
+    /// Pad on the left with ascii `0` digits, keeping a leading sign prefix
+    fn py_zfill(&self, width: isize) -> Option<Self> {
+        let b = self.as_ref();
+        let width = usize::try_from(width).ok()?;
+        if width <= b.len() {
+            return None;
+        }
+        let fill = width - b.len();
+        let mut out = Vec::with_capacity(width);
+        let rest = match b.first() {
+            Some(&sign @ (b'+' | b'-')) => {
+                out.push(sign);
+                &b[1..]
+            }
+            _ => b,
+        };
+        out.extend(std::iter::repeat_n(b'0', fill));
+        out.extend_from_slice(rest);
+        Some(Self::from(out))
+    }
+
+    /// Translate with a 256 byte `table` and drop bytes in `delete`; returns
+    /// `None` if nothing would change
+    fn py_translate(&self, table: Option<&[u8]>, delete: &[u8]) -> PyResult<Option<Self>> {
+        let table = table
+            .map(|t| {
+                <&[u8; 256]>::try_from(t).map_err(|_| {
+                    PyValueError::new_err("translation table must be 256 characters long")
+                })
+            })
+            .transpose()?;
+        let b = self.as_ref();
+        let deleted = PythonBytesStrip::byte_lookup_table(delete);
+        let mut changed = false;
+        let mut out = Vec::with_capacity(b.len());
+        for &byte in b {
+            if deleted[byte as usize] {
+                changed = true;
+                continue;
+            }
+            let translated = table.map_or(byte, |t| t[byte as usize]);
+            changed |= translated != byte;
+            out.push(translated);
+        }
+        Ok(changed.then(|| Self::from(out)))
+    }
+
+    /// Return a 256 byte translation table mapping each byte in `frm` to the
+    /// byte at the same position in `to`
+    fn py_maketrans(frm: &[u8], to: &[u8]) -> PyResult<Self> {
+        if frm.len() != to.len() {
+            return Err(PyValueError::new_err(
+                "maketrans arguments must have same length",
+            ));
+        }
+        let mut table: Vec<u8> = (0..=u8::MAX).collect();
+        for (&f, &t) in frm.iter().zip(to) {
+            table[f as usize] = t;
+        }
+        Ok(Self::from(table))
+    }
+
     fn py_split(&self, _sep: Option<ReadableBuffer>, _maxsplit: usize) -> PyResult<Vec<Self>> {
         Err(pyo3::exceptions::PyNotImplementedError::new_err(
             "split not implemented yet",
@@ -445,6 +569,32 @@ pub(crate) fn normalize_find_bounds(
     (start <= end).then_some(start..end)
 }
 
+/// Return the ranges of the lines in `buf` split on `\n`, `\r` and `\r\n`
+/// (the only line boundaries `bytes.splitlines` recognizes)
+pub(crate) fn splitlines_ranges(buf: &[u8], keepends: bool) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut ix = 0;
+    while ix < buf.len() {
+        let eol = match buf[ix] {
+            b'\r' if buf.get(ix + 1) == Some(&b'\n') => 2,
+            b'\n' | b'\r' => 1,
+            _ => {
+                ix += 1;
+                continue;
+            }
+        };
+        let end = if keepends { ix + eol } else { ix };
+        ranges.push(start..end);
+        ix += eol;
+        start = ix;
+    }
+    if start < buf.len() {
+        ranges.push(start..buf.len());
+    }
+    ranges
+}
+
 macro_rules! rangify {
     ($start:expr, $end:expr) => {{
         let start = $start;
@@ -780,3 +930,69 @@ impl<'a, 'py> FromPyObject<'a, 'py> for BufferOrByte<'a, 'py> {
         )))
     }
 }
+
+/// `startswith`/`endswith` argument; a bytes-like or a tuple of bytes-likes
+pub(crate) enum PyBytesAffix {
+    One(Bytes),
+    Many(Vec<Bytes>),
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyBytesAffix {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(tuple) = ob.cast::<PyTuple>() {
+            let affixes = tuple
+                .iter()
+                .map(|item| match item.extract::<ReadableBuffer>() {
+                    Ok(buf) => Ok(buf.to_bytes()),
+                    Err(_) => Err(PyTypeError::new_err(format!(
+                        "a bytes-like object is required, not '{}'",
+                        item.get_type().name()?
+                    ))),
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(Self::Many(affixes));
+        }
+        if let Ok(buf) = ob.extract::<ReadableBuffer>() {
+            return Ok(Self::One(buf.to_bytes()));
+        }
+        Err(PyTypeError::new_err(format!(
+            "first arg must be bytes or a tuple of bytes, not {}",
+            ob.get_type().name()?
+        )))
+    }
+}
+
+/// Padding alignment for `ljust`/`rjust`/`center`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PyBytesAlign {
+    Left,
+    Right,
+    Center,
+}
+
+/// Single byte fill argument for `ljust`/`rjust`/`center`
+pub(crate) struct PyFillByte(pub(crate) u8);
+
+impl Default for PyFillByte {
+    fn default() -> Self {
+        Self(b' ')
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyFillByte {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(buf) = ob.extract::<ReadableBuffer>()
+            && let [byte] = buf.as_slice()
+        {
+            return Ok(Self(*byte));
+        }
+        Err(PyTypeError::new_err(format!(
+            "fillbyte must be a byte string of length 1, not {}",
+            ob.get_type().name()?
+        )))
+    }
+}
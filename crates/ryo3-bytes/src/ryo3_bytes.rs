@@ -8,14 +8,15 @@ use std::ptr::NonNull;
 
 use bytes::{Bytes, BytesMut};
 use pyo3::buffer::PyBuffer;
-use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedBytes;
 use pyo3::types::{PyDict, PySlice, PyString, PyTuple};
 use pyo3::{IntoPyObjectExt, ffi};
 
 use crate::python_bytes_methods::{
-    BufferOrByte, PyFindResult, PyHexSep, PyIndexResult, PythonBytesMethods, PythonBytesStrip,
+    BufferOrByte, PyBytesAffix, PyBytesAlign, PyFillByte, PyFindResult, PyHexSep, PyIndexResult,
+    PythonBytesMethods, PythonBytesStrip, splitlines_ranges,
 };
 use crate::replace::{ReplaceBytes, replace_bytes};
 use crate::{ReadableBuffer, search};
@@ -176,6 +177,24 @@ impl PyBytes {
     }
 }
 
+impl PyBytes {
+    /// Shared impl of `center`/`ljust`/`rjust` returning `slf` if no padding
+    /// is needed
+    fn pad(
+        slf: PyRef<'_, Self>,
+        width: isize,
+        fillbyte: PyFillByte,
+        align: PyBytesAlign,
+    ) -> PyResult<Bound<'_, Self>> {
+        if let Some(padded) = slf.py_pad(width, fillbyte.0, align) {
+            padded.into_pyobject(slf.py())
+        } else {
+            let py = slf.py();
+            slf.into_pyobject_or_pyerr(py)
+        }
+    }
+}
+
 impl From<PyBytes> for Bytes {
     #[inline]
     fn from(value: PyBytes) -> Self {
@@ -624,14 +643,22 @@ impl PyBytes {
         self.py_title()
     }
 
-    #[pyo3(signature = (prefix, /))]
-    fn startswith(&self, prefix: ReadableBuffer) -> bool {
-        self.as_slice().starts_with(prefix.as_ref())
+    /// Return True if the bytes start with the specified prefix (or any of the
+    /// prefixes if a tuple is given), False otherwise. With optional start,
+    /// test beginning at that position. With optional end, stop comparing at
+    /// that position.
+    #[pyo3(signature = (prefix, start = None, end = None, /))]
+    fn startswith(&self, prefix: PyBytesAffix, start: Option<isize>, end: Option<isize>) -> bool {
+        self.py_tailmatch::<false>(&prefix, start, end)
     }
 
-    #[pyo3(signature = (suffix, /))]
-    fn endswith(&self, suffix: ReadableBuffer) -> bool {
-        self.as_slice().ends_with(suffix.as_ref())
+    /// Return True if the bytes end with the specified suffix (or any of the
+    /// suffixes if a tuple is given), False otherwise. With optional start,
+    /// test beginning at that position. With optional end, stop comparing at
+    /// that position.
+    #[pyo3(signature = (suffix, start = None, end = None, /))]
+    fn endswith(&self, suffix: PyBytesAffix, start: Option<isize>, end: Option<isize>) -> bool {
+        self.py_tailmatch::<true>(&suffix, start, end)
     }
 
     fn capitalize(&self) -> Self {
@@ -692,6 +719,13 @@ impl PyBytes {
         }
     }
 
+    /// Return the number of non-overlapping occurrences of subsection `sub` in
+    /// bytes `B[start:end]`.
+    #[pyo3(signature = (sub, start = None, end = None, /))]
+    fn count(&self, sub: BufferOrByte, start: Option<isize>, end: Option<isize>) -> usize {
+        self.py_count(sub, start, end)
+    }
+
     #[pyo3(signature = (sub, start = None, end = None, /))]
     fn find(&self, sub: BufferOrByte, start: Option<isize>, end: Option<isize>) -> PyFindResult {
         self.py_find(sub, start, end)
@@ -780,6 +814,144 @@ impl PyBytes {
         }
     }
 
+    /// Return a list of the lines in the bytes, breaking at line boundaries
+    /// (`\n`, `\r` and `\r\n`). Line breaks are not included in the resulting
+    /// list unless `keepends` is given and true.
+    ///
+    /// The lines are zero-copy slices of the underlying buffer.
+    #[pyo3(signature = (keepends = false))]
+    fn splitlines(&self, keepends: bool) -> Vec<Self> {
+        splitlines_ranges(self.as_slice(), keepends)
+            .into_iter()
+            .map(|range| Self::new(self.0.slice(range)))
+            .collect()
+    }
+
+    /// Concatenate any number of bytes-like objects with this bytes as the
+    /// separator between them.
+    #[pyo3(signature = (iterable_of_bytes, /))]
+    fn join(&self, iterable_of_bytes: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let parts = iterable_of_bytes
+            .try_iter()?
+            .enumerate()
+            .map(|(ix, item)| {
+                let item = item?;
+                match item.extract::<Self>() {
+                    Ok(part) => Ok(part.0),
+                    Err(_) => Err(PyTypeError::new_err(format!(
+                        "sequence item {ix}: expected a bytes-like object, {} found",
+                        item.get_type().name()?
+                    ))),
+                }
+            })
+            .collect::<PyResult<Vec<Bytes>>>()?;
+        match parts.as_slice() {
+            [] => Ok(Self::new(Bytes::new())),
+            [part] => Ok(Self::new(part.clone())),
+            _ => {
+                let sep = self.as_slice();
+                let total_length =
+                    parts.iter().map(Bytes::len).sum::<usize>() + sep.len() * (parts.len() - 1);
+                let mut outbuf = BytesMut::with_capacity(total_length);
+                for (ix, part) in parts.iter().enumerate() {
+                    if ix > 0 {
+                        outbuf.extend_from_slice(sep);
+                    }
+                    outbuf.extend_from_slice(part);
+                }
+                Ok(Self::new(outbuf.freeze()))
+            }
+        }
+    }
+
+    /// Return a centered bytes of length `width` padded with `fillbyte`
+    /// (default is an ASCII space).
+    #[pyo3(
+        signature = (width, fillbyte = PyFillByte::default(), /),
+        text_signature = "(self, width, fillbyte=b' ', /)"
+    )]
+    fn center(
+        slf: PyRef<'_, Self>,
+        width: isize,
+        fillbyte: PyFillByte,
+    ) -> PyResult<Bound<'_, Self>> {
+        Self::pad(slf, width, fillbyte, PyBytesAlign::Center)
+    }
+
+    /// Return a left-justified bytes of length `width` padded with `fillbyte`
+    /// (default is an ASCII space).
+    #[pyo3(
+        signature = (width, fillbyte = PyFillByte::default(), /),
+        text_signature = "(self, width, fillbyte=b' ', /)"
+    )]
+    fn ljust(
+        slf: PyRef<'_, Self>,
+        width: isize,
+        fillbyte: PyFillByte,
+    ) -> PyResult<Bound<'_, Self>> {
+        Self::pad(slf, width, fillbyte, PyBytesAlign::Left)
+    }
+
+    /// Return a right-justified bytes of length `width` padded with `fillbyte`
+    /// (default is an ASCII space).
+    #[pyo3(
+        signature = (width, fillbyte = PyFillByte::default(), /),
+        text_signature = "(self, width, fillbyte=b' ', /)"
+    )]
+    fn rjust(
+        slf: PyRef<'_, Self>,
+        width: isize,
+        fillbyte: PyFillByte,
+    ) -> PyResult<Bound<'_, Self>> {
+        Self::pad(slf, width, fillbyte, PyBytesAlign::Right)
+    }
+
+    /// Pad a numeric bytes string with zeros on the left, to fill a field of
+    /// the given width. The bytes are never truncated.
+    #[pyo3(signature = (width, /))]
+    fn zfill(slf: PyRef<'_, Self>, width: isize) -> PyResult<Bound<'_, Self>> {
+        if let Some(padded) = slf.py_zfill(width) {
+            padded.into_pyobject(slf.py())
+        } else {
+            let py = slf.py();
+            slf.into_pyobject_or_pyerr(py)
+        }
+    }
+
+    /// Return a copy with each byte mapped through the given translation
+    /// table (or unchanged if `table` is None) and all bytes occurring in the
+    /// optional argument `delete` removed.
+    #[pyo3(
+        signature = (table, /, delete = None),
+        text_signature = "(self, table, /, delete=b'')"
+    )]
+    fn translate<'py>(
+        slf: PyRef<'py, Self>,
+        table: Option<ReadableBuffer>,
+        delete: Option<ReadableBuffer>,
+    ) -> PyResult<Bound<'py, Self>> {
+        let delete = delete.as_ref().map_or(&[][..], ReadableBuffer::as_slice);
+        if let Some(translated) =
+            slf.py_translate(table.as_ref().map(ReadableBuffer::as_slice), delete)?
+        {
+            translated.into_pyobject(slf.py())
+        } else {
+            let py = slf.py();
+            slf.into_pyobject_or_pyerr(py)
+        }
+    }
+
+    /// Return a translation table usable for `Bytes.translate`.
+    ///
+    /// The returned table will be one where each byte in `frm` is mapped to
+    /// the byte at the same position in `to`; `frm` and `to` must be
+    /// bytes-like objects and have the same length.
+    #[staticmethod]
+    #[pyo3(signature = (frm, to, /))]
+    fn maketrans(frm: ReadableBuffer, to: ReadableBuffer) -> PyResult<Self> {
+        Self::py_maketrans(frm.as_slice(), to.as_slice())
+    }
+
     #[pyo3(
         signature = (sep = None, maxsplit = PyMaxSplit::default()),
         text_signature = "(self, sep=None, maxsplit=-1, /)"
@@ -56,3 +56,42 @@ pub(crate) fn rfind_subslice(buf: &[u8], needle: &[u8]) -> Option<usize> {
             .rposition(|window| window == needle),
     }
 }
+
+#[cfg(feature = "memchr")]
+pub(crate) fn count_byte(needle: u8, haystack: &[u8]) -> usize {
+    ::memchr::memchr_iter(needle, haystack).count()
+}
+
+#[cfg(not(feature = "memchr"))]
+pub(crate) fn count_byte(needle: u8, haystack: &[u8]) -> usize {
+    haystack.iter().filter(|&&b| b == needle).count()
+}
+
+#[cfg(feature = "memchr")]
+fn count_subslice_nonoverlapping(buf: &[u8], needle: &[u8]) -> usize {
+    ::memchr::memmem::find_iter(buf, needle).count()
+}
+
+#[cfg(not(feature = "memchr"))]
+fn count_subslice_nonoverlapping(buf: &[u8], needle: &[u8]) -> usize {
+    let mut count = 0;
+    let mut pos = 0;
+    while let Some(ix) = find_subslice(&buf[pos..], needle) {
+        count += 1;
+        pos += ix + needle.len();
+    }
+    count
+}
+
+/// Count non-overlapping occurrences of `needle` in `buf` (python semantics;
+/// the empty needle matches between every byte and at both ends)
+pub(crate) fn count_subslice(buf: &[u8], needle: &[u8]) -> usize {
+    if needle.len() > buf.len() {
+        return 0;
+    }
+    match needle {
+        [] => buf.len() + 1,
+        [byte] => count_byte(*byte, buf),
+        _ => count_subslice_nonoverlapping(buf, needle),
+    }
+}
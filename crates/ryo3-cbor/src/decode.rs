@@ -0,0 +1,163 @@
+#![expect(clippy::needless_pass_by_value)]
+//! cbor => python deserialization
+use ciborium::Value;
+use pyo3::IntoPyObjectExt;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyBytes, PyDate, PyDateTime, PyDict, PyInt, PyList, PyTuple, PyType, PyTzInfo};
+use ryo3_bytes::ReadableBuffer;
+use ryo3_macro_rules::{py_type_err, py_value_err, py_value_error};
+use ryo3_serde::check_callable;
+
+use crate::tag::PyTag;
+use crate::target::tags;
+
+/// days from 0001-01-01 (ordinal 1) to 1970-01-01
+const UNIX_EPOCH_ORDINAL: i64 = 719_163;
+
+struct Decoder<'a, 'py> {
+    py: Python<'py>,
+    tag_hook: Option<&'a Bound<'py, PyAny>>,
+}
+
+impl<'py> Decoder<'_, 'py> {
+    fn value(&self, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+        let py = self.py;
+        match value {
+            Value::Null => Ok(py.None().into_bound(py)),
+            Value::Bool(b) => b.into_bound_py_any(py),
+            Value::Integer(i) => i128::from(*i).into_bound_py_any(py),
+            Value::Float(f) => f.into_bound_py_any(py),
+            Value::Text(s) => s.into_bound_py_any(py),
+            Value::Bytes(b) => Ok(PyBytes::new(py, b).into_any()),
+            Value::Array(values) => {
+                let items = values
+                    .iter()
+                    .map(|v| self.value(v))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(PyList::new(py, items)?.into_any())
+            }
+            Value::Map(entries) => {
+                let dict = PyDict::new(py);
+                for (k, v) in entries {
+                    dict.set_item(self.key(k)?, self.value(v)?)?;
+                }
+                Ok(dict.into_any())
+            }
+            Value::Tag(tag, value) => self.tag(*tag, value),
+            _ => py_value_err!("unsupported cbor value: {value:?}"),
+        }
+    }
+
+    /// Map keys; arrays become tuples so they are hashable
+    fn key(&self, key: &Value) -> PyResult<Bound<'py, PyAny>> {
+        match key {
+            Value::Array(values) => {
+                let items = values
+                    .iter()
+                    .map(|v| self.key(v))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(PyTuple::new(self.py, items)?.into_any())
+            }
+            Value::Map(_) => py_type_err!("unhashable cbor map key (map)"),
+            _ => self.value(key),
+        }
+    }
+
+    fn tag(&self, tag: u64, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+        static UUID: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+        let py = self.py;
+        match (tag, value) {
+            (tags::DATETIME_STRING, Value::Text(s)) => py
+                .get_type::<PyDateTime>()
+                .call_method1(intern!(py, "fromisoformat"), (s,)),
+            (tags::DATETIME_EPOCH, Value::Integer(_) | Value::Float(_)) => {
+                let utc = PyTzInfo::utc(py)?;
+                py.get_type::<PyDateTime>()
+                    .call_method1(intern!(py, "fromtimestamp"), (self.value(value)?, utc))
+            }
+            (tags::DATE_STRING, Value::Text(s)) => py
+                .get_type::<PyDate>()
+                .call_method1(intern!(py, "fromisoformat"), (s,)),
+            (tags::DATE_EPOCH_DAYS, Value::Integer(days)) => {
+                let days = i64::try_from(i128::from(*days))
+                    .map_err(|_| py_value_error!("cbor date out of range: {days:?}"))?;
+                py.get_type::<PyDate>()
+                    .call_method1(intern!(py, "fromordinal"), (UNIX_EPOCH_ORDINAL + days,))
+            }
+            (tags::UUID, Value::Bytes(b)) if b.len() == 16 => {
+                let kwargs = PyDict::new(py);
+                kwargs.set_item(intern!(py, "bytes"), PyBytes::new(py, b))?;
+                UUID.import(py, "uuid", "UUID")?.call((), Some(&kwargs))
+            }
+            (tags::BIGNUM_POS | tags::BIGNUM_NEG, Value::Bytes(b)) => {
+                let n = py
+                    .get_type::<PyInt>()
+                    .call_method1(intern!(py, "from_bytes"), (PyBytes::new(py, b), "big"))?;
+                if tag == tags::BIGNUM_NEG {
+                    n.call_method0(intern!(py, "__invert__"))
+                } else {
+                    Ok(n)
+                }
+            }
+            _ => {
+                let value = self.value(value)?;
+                if let Some(tag_hook) = self.tag_hook {
+                    tag_hook.call1((tag, value))
+                } else {
+                    PyTag {
+                        tag,
+                        value: value.unbind(),
+                    }
+                    .into_bound_py_any(py)
+                }
+            }
+        }
+    }
+}
+
+fn map_cbor_decode_err(e: ciborium::de::Error<std::io::Error>) -> PyErr {
+    match e {
+        ciborium::de::Error::Io(e) => {
+            py_value_error!("Failed to deserialize cbor: {e}")
+        }
+        ciborium::de::Error::Syntax(offset) => {
+            py_value_error!("Failed to deserialize cbor: syntax error at offset {offset}")
+        }
+        ciborium::de::Error::Semantic(Some(offset), msg) => {
+            py_value_error!("Failed to deserialize cbor: {msg} at offset {offset}")
+        }
+        ciborium::de::Error::Semantic(None, msg) => {
+            py_value_error!("Failed to deserialize cbor: {msg}")
+        }
+        ciborium::de::Error::RecursionLimitExceeded => {
+            py_value_error!("Failed to deserialize cbor: recursion limit exceeded")
+        }
+    }
+}
+
+/// Deserialize cbor `data` into python objects
+///
+/// Byte strings are returned as `bytes`; datetimes (tags 0/1), dates (tags
+/// 100/1004), uuids (tag 37) and bignums (tags 2/3) as their python
+/// equivalents; other tags as `Tag` objects unless a `tag_hook(tag, value)`
+/// is given in which case its return value is used.
+#[pyfunction(signature = (data, *, tag_hook = None))]
+pub fn loads<'py>(
+    py: Python<'py>,
+    data: ReadableBuffer<'_, 'py>,
+    tag_hook: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    check_callable(tag_hook)?;
+    let mut rd = data.as_slice();
+    let value: Value = ciborium::from_reader(&mut rd).map_err(map_cbor_decode_err)?;
+    if !rd.is_empty() {
+        return py_value_err!(
+            "Failed to deserialize cbor: {} bytes of trailing data",
+            rd.len()
+        );
+    }
+    Decoder { py, tag_hook }.value(&value)
+}
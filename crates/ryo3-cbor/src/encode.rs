@@ -0,0 +1,101 @@
+//! python => cbor serialization via `ryo3-serde`
+use ciborium::Value;
+use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::{PyRecursionError, PyTypeError};
+use pyo3::prelude::*;
+use ryo3_bytes::RyBytes;
+use ryo3_serde::{PyAnySerializer, PySerializeTarget, check_callable};
+
+use crate::target::CborTarget;
+
+fn map_cbor_encode_err(msg: &str) -> PyErr {
+    if msg.contains("recursion") {
+        PyRecursionError::new_err("Recursion limit reached")
+    } else {
+        PyTypeError::new_err(format!("Failed to serialize: {msg}"))
+    }
+}
+
+/// Sort map entries (recursively) by the bytewise order of their encoded
+/// keys (RFC 8949 §4.2.1 core deterministic encoding)
+///
+/// ciborium already writes the shortest form of ints/floats/lengths and
+/// `Value` only has definite lengths.
+fn canonicalize(value: &mut Value) -> PyResult<()> {
+    match value {
+        Value::Array(values) => values.iter_mut().try_for_each(canonicalize),
+        Value::Tag(_, value) => canonicalize(value),
+        Value::Map(entries) => {
+            let mut keyed = Vec::with_capacity(entries.len());
+            for (mut k, mut v) in entries.drain(..) {
+                canonicalize(&mut k)?;
+                canonicalize(&mut v)?;
+                let mut buf = Vec::new();
+                ciborium::into_writer(&k, &mut buf)
+                    .map_err(|e| map_cbor_encode_err(&e.to_string()))?;
+                keyed.push((buf, (k, v)));
+            }
+            keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+            entries.extend(keyed.into_iter().map(|(_, kv)| kv));
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn write<T: PySerializeTarget>(
+    obj: &Bound<'_, PyAny>,
+    default: Option<&Bound<'_, PyAny>>,
+    canonical: bool,
+) -> PyResult<Vec<u8>> {
+    let s = PyAnySerializer::<T>::with_target(obj.as_borrowed(), default);
+    let mut buf = Vec::with_capacity(256);
+    if canonical {
+        let mut value = Value::serialized(&s).map_err(|e| {
+            let ciborium::value::Error::Custom(msg) = e;
+            map_cbor_encode_err(&msg)
+        })?;
+        canonicalize(&mut value)?;
+        ciborium::into_writer(&value, &mut buf).map_err(|e| map_cbor_encode_err(&e.to_string()))?;
+    } else {
+        ciborium::into_writer(&s, &mut buf).map_err(|e| map_cbor_encode_err(&e.to_string()))?;
+    }
+    Ok(buf)
+}
+
+/// Serialize a python object to cbor bytes
+///
+/// `canonical` sorts map keys for deterministic (RFC 8949 §4.2.1) output and
+/// `datetime_as_timestamp` writes offset-aware datetimes as epoch seconds
+/// (tag 1) instead of RFC 3339 strings (tag 0).
+pub fn to_vec(
+    obj: &Bound<'_, PyAny>,
+    default: Option<&Bound<'_, PyAny>>,
+    canonical: bool,
+    datetime_as_timestamp: bool,
+) -> PyResult<Vec<u8>> {
+    if datetime_as_timestamp {
+        write::<CborTarget<true>>(obj, default, canonical)
+    } else {
+        write::<CborTarget<false>>(obj, default, canonical)
+    }
+}
+
+/// Serialize `obj` to cbor
+///
+/// `default` is called with objects that cannot otherwise be serialized and
+/// should return a serializable object.
+#[pyfunction(
+    signature = (obj, *, default = None, canonical = false, datetime_as_timestamp = false)
+)]
+pub fn dumps<'py>(
+    py: Python<'py>,
+    obj: &Bound<'py, PyAny>,
+    default: Option<&Bound<'py, PyAny>>,
+    canonical: bool,
+    datetime_as_timestamp: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    check_callable(default)?;
+    let buf = to_vec(obj, default, canonical, datetime_as_timestamp)?;
+    RyBytes::from(buf).into_bound_py_any(py)
+}
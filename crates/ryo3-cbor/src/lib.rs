@@ -0,0 +1,20 @@
+#![doc = include_str!("../README.md")]
+
+mod decode;
+mod encode;
+mod tag;
+mod target;
+
+use pyo3::prelude::*;
+
+pub use decode::loads;
+pub use encode::{dumps, to_vec};
+pub use tag::PyTag;
+pub use target::CborTarget;
+
+pub fn pysubmod_register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyTag>()?;
+    m.add_function(wrap_pyfunction!(encode::dumps, m)?)?;
+    m.add_function(wrap_pyfunction!(decode::loads, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use pyo3::prelude::*;
+
+/// cbor tagged value: a tag number and the (python) tagged value
+///
+/// Returned by `loads` for tags without a python equivalent and encoded as a
+/// cbor tag by `dumps`. The module is `ry.cbor` (not `ry.ryo3`) so `Tag`
+/// objects can be pickled.
+#[pyclass(name = "Tag", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.cbor"))]
+pub struct PyTag {
+    pub(crate) tag: u64,
+    pub(crate) value: Py<PyAny>,
+}
+
+#[pymethods]
+impl PyTag {
+    #[new]
+    fn py_new(tag: u64, value: Py<PyAny>) -> Self {
+        Self { tag, value }
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "Tag({}, {})",
+            self.tag,
+            self.value.bind(py).repr()?
+        ))
+    }
+
+    fn __eq__(&self, py: Python<'_>, other: &Self) -> PyResult<bool> {
+        Ok(self.tag == other.tag && self.value.bind(py).eq(other.value.bind(py))?)
+    }
+
+    fn __hash__(&self, py: Python<'_>) -> PyResult<isize> {
+        (self.tag, self.value.bind(py)).into_pyobject(py)?.hash()
+    }
+
+    fn __getnewargs__(&self, py: Python<'_>) -> (u64, Py<PyAny>) {
+        (self.tag, self.value.clone_ref(py))
+    }
+
+    /// Tag number
+    #[getter]
+    fn tag(&self) -> u64 {
+        self.tag
+    }
+
+    /// Tagged value
+    #[getter]
+    fn value(&self, py: Python<'_>) -> Py<PyAny> {
+        self.value.clone_ref(py)
+    }
+}
@@ -0,0 +1,143 @@
+use ciborium::tag::{Captured, Required};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyInt};
+use ryo3_bytes::ReadableBuffer;
+use ryo3_serde::{PyAnySerializer, PySerializeTarget, strip_tz_annotation};
+use serde::Serialize;
+use serde::ser::Serializer;
+
+use crate::tag::PyTag;
+
+/// cbor tag numbers (RFC 8949 + iana registry)
+pub(crate) mod tags {
+    pub(crate) const DATETIME_STRING: u64 = 0;
+    pub(crate) const DATETIME_EPOCH: u64 = 1;
+    pub(crate) const BIGNUM_POS: u64 = 2;
+    pub(crate) const BIGNUM_NEG: u64 = 3;
+    pub(crate) const UUID: u64 = 37;
+    pub(crate) const DATE_EPOCH_DAYS: u64 = 100;
+    pub(crate) const DATE_STRING: u64 = 1004;
+}
+
+/// `ryo3-serde` target for cbor
+///
+/// - bytes-like objects (and buffer-protocol objects) are byte strings
+/// - offset-aware datetimes are tag 0 (RFC 3339 string) or, if
+///   `EPOCH_DATETIMES`, tag 1 (epoch seconds int/float)
+/// - dates are tag 1004 (RFC 3339 full-date string)
+/// - uuids are tag 37 (16 byte string)
+/// - ints that do not fit in 64 bits are bignums (tags 2/3)
+/// - `Tag` objects are tagged values
+/// - map keys can be any serializable object
+///
+/// Naive datetimes and times have no cbor tag and are strings.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CborTarget<const EPOCH_DATETIMES: bool = false>;
+
+impl<const EPOCH_DATETIMES: bool> PySerializeTarget for CborTarget<EPOCH_DATETIMES> {
+    const KIND: &'static str = "cbor";
+    const NATIVE_BYTES: bool = true;
+    const NATIVE_DATETIMES: bool = true;
+    const NATIVE_UUIDS: bool = true;
+    const NATIVE_MAP_KEYS: bool = true;
+
+    fn serialize_ext<S: Serializer>(
+        ser: &PyAnySerializer<'_, '_, Self>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        let obj = ser.obj();
+        if let Ok(tag) = obj.cast::<PyTag>() {
+            let tag = tag.get();
+            let value = ser.nested(tag.value.bind_borrowed(obj.py()));
+            Ok(Captured(Some(tag.tag), value).serialize(serializer))
+        } else if let Ok(buf) = obj.extract::<ReadableBuffer<'_, '_>>() {
+            Ok(serializer.serialize_bytes(buf.as_slice()))
+        } else {
+            Err(serializer)
+        }
+    }
+
+    fn serialize_datetime<S: Serializer>(
+        obj: Borrowed<'_, '_, PyAny>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        let Ok(pystr) = obj.str() else {
+            return Err(serializer);
+        };
+        let Ok(s) = pystr.to_str() else {
+            return Err(serializer);
+        };
+        // python uses a space as the date/time separator
+        let s = strip_tz_annotation(s).replacen(' ', "T", 1);
+        if let Ok(ts) = s.parse::<jiff::Timestamp>() {
+            if EPOCH_DATETIMES {
+                let res = if ts.subsec_nanosecond() == 0 {
+                    Required::<_, { tags::DATETIME_EPOCH }>(ts.as_second()).serialize(serializer)
+                } else {
+                    Required::<_, { tags::DATETIME_EPOCH }>(ts.as_duration().as_secs_f64())
+                        .serialize(serializer)
+                };
+                Ok(res)
+            } else {
+                Ok(Required::<_, { tags::DATETIME_STRING }>(s.as_str()).serialize(serializer))
+            }
+        } else if !s.contains(['T', ':']) && s.parse::<jiff::civil::Date>().is_ok() {
+            Ok(Required::<_, { tags::DATE_STRING }>(s.as_str()).serialize(serializer))
+        } else {
+            Err(serializer)
+        }
+    }
+
+    fn serialize_uuid<S: Serializer>(
+        obj: Borrowed<'_, '_, PyAny>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        let Ok(bytes) = obj.getattr(intern!(obj.py(), "bytes")) else {
+            return Err(serializer);
+        };
+        let Ok(bytes) = bytes.cast::<PyBytes>() else {
+            return Err(serializer);
+        };
+        let bytes = serde_bytes::Bytes::new(bytes.as_bytes());
+        Ok(Required::<_, { tags::UUID }>(bytes).serialize(serializer))
+    }
+
+    fn serialize_bigint<S: Serializer>(
+        obj: Borrowed<'_, '_, PyInt>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        if let Ok(v) = obj.extract::<u64>() {
+            Ok(serializer.serialize_u64(v))
+        } else if let Ok(v) = obj.extract::<i128>() {
+            Ok(serializer.serialize_i128(v))
+        } else if let Ok(v) = obj.extract::<u128>() {
+            Ok(serializer.serialize_u128(v))
+        } else {
+            match bignum_bytes(obj) {
+                Ok((tag, bytes)) => {
+                    let bytes = serde_bytes::ByteBuf::from(bytes);
+                    Ok(Captured(Some(tag), bytes).serialize(serializer))
+                }
+                Err(_) => Err(serializer),
+            }
+        }
+    }
+}
+
+/// Big-endian magnitude bytes of a bignum: `n` for tag 2 and `-1 - n` for
+/// tag 3 (negative ints)
+fn bignum_bytes(obj: Borrowed<'_, '_, PyInt>) -> PyResult<(u64, Vec<u8>)> {
+    let py = obj.py();
+    let (tag, n) = if obj.lt(0)? {
+        (
+            tags::BIGNUM_NEG,
+            obj.call_method0(intern!(py, "__invert__"))?,
+        )
+    } else {
+        (tags::BIGNUM_POS, obj.to_owned().into_any())
+    };
+    let nbits: usize = n.call_method0(intern!(py, "bit_length"))?.extract()?;
+    let bytes = n.call_method1(intern!(py, "to_bytes"), (nbits.div_ceil(8), "big"))?;
+    Ok((tag, bytes.cast_into::<PyBytes>()?.as_bytes().to_vec()))
+}
@@ -0,0 +1,165 @@
+use std::sync::LazyLock;
+
+use data_encoding::{BASE32, BASE32_NOPAD, BASE32HEX, BASE32HEX_NOPAD, Encoding, Specification};
+use pyo3::prelude::*;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+
+use crate::common::{
+    DecodeInput, decode_error, extract_str_option, strip_ascii_whitespace, trim_padding,
+};
+
+// statics of the `data-encoding` consts so that they can be returned by ref
+static RFC4648: Encoding = BASE32;
+static RFC4648_NOPAD: Encoding = BASE32_NOPAD;
+static HEX: Encoding = BASE32HEX;
+static HEX_NOPAD: Encoding = BASE32HEX_NOPAD;
+
+const CROCKFORD_SYMBOLS: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Crockford base32 (RFC 4648 bit packing w/ the Crockford alphabet, no padding)
+static BASE32_CROCKFORD: LazyLock<Encoding> = LazyLock::new(|| {
+    let mut spec = Specification::new();
+    spec.symbols.push_str(CROCKFORD_SYMBOLS);
+    spec.encoding().expect("valid crockford specification")
+});
+
+/// Lenient crockford decoder; case-insensitive, `I`/`L` -> `1`, `O` -> `0`,
+/// hyphens ignored, no trailing-bit check
+static BASE32_CROCKFORD_LENIENT: LazyLock<Encoding> = LazyLock::new(|| {
+    let mut spec = Specification::new();
+    spec.symbols.push_str(CROCKFORD_SYMBOLS);
+    spec.check_trailing_bits = false;
+    spec.ignore.push('-');
+    spec.translate.from.push_str("abcdefghjkmnpqrstvwxyzIiLlOo");
+    spec.translate.to.push_str("ABCDEFGHJKMNPQRSTVWXYZ111100");
+    spec.encoding().expect("valid crockford specification")
+});
+
+/// Lenient RFC 4648 decoder; case-insensitive, no padding, no trailing-bit check
+static BASE32_LENIENT: LazyLock<Encoding> = LazyLock::new(|| lenient_spec(&BASE32_NOPAD));
+/// Lenient base32hex decoder; case-insensitive, no padding, no trailing-bit check
+static BASE32HEX_LENIENT: LazyLock<Encoding> = LazyLock::new(|| lenient_spec(&BASE32HEX_NOPAD));
+
+fn lenient_spec(base: &Encoding) -> Encoding {
+    let mut spec = base.specification();
+    spec.check_trailing_bits = false;
+    let lower = spec.symbols.to_ascii_lowercase();
+    for (lc, uc) in lower.chars().zip(spec.symbols.clone().chars()) {
+        if lc != uc {
+            spec.translate.from.push(lc);
+            spec.translate.to.push(uc);
+        }
+    }
+    spec.encoding().expect("valid base32 specification")
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum PyBase32Variant {
+    #[default]
+    Rfc4648,
+    Rfc4648NoPad,
+    Hex,
+    HexNoPad,
+    Crockford,
+}
+
+impl PyBase32Variant {
+    fn encoding(self) -> &'static Encoding {
+        match self {
+            Self::Rfc4648 => &RFC4648,
+            Self::Rfc4648NoPad => &RFC4648_NOPAD,
+            Self::Hex => &HEX,
+            Self::HexNoPad => &HEX_NOPAD,
+            Self::Crockford => &BASE32_CROCKFORD,
+        }
+    }
+
+    fn lenient_encoding(self) -> &'static Encoding {
+        match self {
+            Self::Rfc4648 | Self::Rfc4648NoPad => &BASE32_LENIENT,
+            Self::Hex | Self::HexNoPad => &BASE32HEX_LENIENT,
+            Self::Crockford => &BASE32_CROCKFORD_LENIENT,
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyBase32Variant {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        extract_str_option(
+            ob,
+            "base32 variant",
+            &[
+                ("rfc4648", Self::Rfc4648),
+                ("rfc4648-nopad", Self::Rfc4648NoPad),
+                ("hex", Self::Hex),
+                ("hex-nopad", Self::HexNoPad),
+                ("crockford", Self::Crockford),
+            ],
+        )
+    }
+}
+
+pub(crate) fn encode(data: &[u8], variant: PyBase32Variant) -> String {
+    variant.encoding().encode(data)
+}
+
+pub(crate) fn decode(data: &[u8], variant: PyBase32Variant, strict: bool) -> PyResult<Vec<u8>> {
+    if strict {
+        variant
+            .encoding()
+            .decode(data)
+            .map_err(|e| decode_error("base32", e))
+    } else {
+        let stripped = strip_ascii_whitespace(data);
+        variant
+            .lenient_encoding()
+            .decode(trim_padding(&stripped))
+            .map_err(|e| decode_error("base32", e))
+    }
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase32Variant::default()),
+    text_signature = "(data, *, variant='rfc4648')"
+)]
+pub(crate) fn base32_encode(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    variant: PyBase32Variant,
+) -> RyBytes {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, variant)).into_bytes().into()
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase32Variant::default()),
+    text_signature = "(data, *, variant='rfc4648')"
+)]
+pub(crate) fn base32_encode_str(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    variant: PyBase32Variant,
+) -> String {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, variant))
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase32Variant::default(), strict = true),
+    text_signature = "(data, *, variant='rfc4648', strict=True)"
+)]
+pub(crate) fn base32_decode(
+    py: Python<'_>,
+    data: DecodeInput<'_, '_>,
+    variant: PyBase32Variant,
+    strict: bool,
+) -> PyResult<RyBytes> {
+    let bin = data.as_ref();
+    py.detach(|| decode(bin, variant, strict))
+        .map(RyBytes::from)
+}
@@ -0,0 +1,100 @@
+use pyo3::prelude::*;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+
+use crate::common::{DecodeInput, decode_error, extract_str_option, strip_ascii_whitespace};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum PyBase58Alphabet {
+    #[default]
+    Bitcoin,
+    Ripple,
+    Flickr,
+}
+
+impl PyBase58Alphabet {
+    fn alphabet(self) -> &'static bs58::Alphabet {
+        match self {
+            Self::Bitcoin => bs58::Alphabet::BITCOIN,
+            Self::Ripple => bs58::Alphabet::RIPPLE,
+            Self::Flickr => bs58::Alphabet::FLICKR,
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyBase58Alphabet {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        extract_str_option(
+            ob,
+            "base58 alphabet",
+            &[
+                ("bitcoin", Self::Bitcoin),
+                ("ripple", Self::Ripple),
+                ("flickr", Self::Flickr),
+            ],
+        )
+    }
+}
+
+pub(crate) fn encode(data: &[u8], alphabet: PyBase58Alphabet) -> String {
+    bs58::encode(data)
+        .with_alphabet(alphabet.alphabet())
+        .into_string()
+}
+
+pub(crate) fn decode(data: &[u8], alphabet: PyBase58Alphabet, strict: bool) -> PyResult<Vec<u8>> {
+    let data = if strict {
+        std::borrow::Cow::Borrowed(data)
+    } else {
+        strip_ascii_whitespace(data)
+    };
+    bs58::decode(data.as_ref())
+        .with_alphabet(alphabet.alphabet())
+        .into_vec()
+        .map_err(|e| decode_error("base58", e))
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, alphabet = PyBase58Alphabet::default()),
+    text_signature = "(data, *, alphabet='bitcoin')"
+)]
+pub(crate) fn base58_encode(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    alphabet: PyBase58Alphabet,
+) -> RyBytes {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, alphabet)).into_bytes().into()
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, alphabet = PyBase58Alphabet::default()),
+    text_signature = "(data, *, alphabet='bitcoin')"
+)]
+pub(crate) fn base58_encode_str(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    alphabet: PyBase58Alphabet,
+) -> String {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, alphabet))
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, alphabet = PyBase58Alphabet::default(), strict = true),
+    text_signature = "(data, *, alphabet='bitcoin', strict=True)"
+)]
+pub(crate) fn base58_decode(
+    py: Python<'_>,
+    data: DecodeInput<'_, '_>,
+    alphabet: PyBase58Alphabet,
+    strict: bool,
+) -> PyResult<RyBytes> {
+    let bin = data.as_ref();
+    py.detach(|| decode(bin, alphabet, strict))
+        .map(RyBytes::from)
+}
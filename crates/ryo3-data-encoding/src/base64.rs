@@ -0,0 +1,137 @@
+use std::sync::LazyLock;
+
+use data_encoding::{BASE64, BASE64_MIME, BASE64_NOPAD, BASE64URL, BASE64URL_NOPAD, Encoding};
+use pyo3::prelude::*;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+
+use crate::common::{
+    DecodeInput, decode_error, extract_str_option, strip_ascii_whitespace, trim_padding,
+};
+
+// statics of the `data-encoding` consts so that they can be returned by ref
+static STANDARD: Encoding = BASE64;
+static STANDARD_NOPAD: Encoding = BASE64_NOPAD;
+static URLSAFE: Encoding = BASE64URL;
+static URLSAFE_NOPAD: Encoding = BASE64URL_NOPAD;
+static MIME: Encoding = BASE64_MIME;
+
+/// Lenient standard-alphabet decoder; no padding, no trailing-bit check
+static BASE64_LENIENT: LazyLock<Encoding> = LazyLock::new(|| lenient_spec(&BASE64_NOPAD));
+/// Lenient url-safe-alphabet decoder; no padding, no trailing-bit check
+static BASE64URL_LENIENT: LazyLock<Encoding> = LazyLock::new(|| lenient_spec(&BASE64URL_NOPAD));
+
+fn lenient_spec(base: &Encoding) -> Encoding {
+    let mut spec = base.specification();
+    spec.check_trailing_bits = false;
+    spec.encoding().expect("valid base64 specification")
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum PyBase64Variant {
+    #[default]
+    Standard,
+    StandardNoPad,
+    UrlSafe,
+    UrlSafeNoPad,
+    Mime,
+}
+
+impl PyBase64Variant {
+    fn encoding(self) -> &'static Encoding {
+        match self {
+            Self::Standard => &STANDARD,
+            Self::StandardNoPad => &STANDARD_NOPAD,
+            Self::UrlSafe => &URLSAFE,
+            Self::UrlSafeNoPad => &URLSAFE_NOPAD,
+            Self::Mime => &MIME,
+        }
+    }
+
+    fn lenient_encoding(self) -> &'static Encoding {
+        match self {
+            Self::Standard | Self::StandardNoPad | Self::Mime => &BASE64_LENIENT,
+            Self::UrlSafe | Self::UrlSafeNoPad => &BASE64URL_LENIENT,
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyBase64Variant {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        extract_str_option(
+            ob,
+            "base64 variant",
+            &[
+                ("standard", Self::Standard),
+                ("standard-nopad", Self::StandardNoPad),
+                ("urlsafe", Self::UrlSafe),
+                ("urlsafe-nopad", Self::UrlSafeNoPad),
+                ("mime", Self::Mime),
+            ],
+        )
+    }
+}
+
+pub(crate) fn encode(data: &[u8], variant: PyBase64Variant) -> String {
+    variant.encoding().encode(data)
+}
+
+pub(crate) fn decode(data: &[u8], variant: PyBase64Variant, strict: bool) -> PyResult<Vec<u8>> {
+    if strict {
+        variant
+            .encoding()
+            .decode(data)
+            .map_err(|e| decode_error("base64", e))
+    } else {
+        let stripped = strip_ascii_whitespace(data);
+        variant
+            .lenient_encoding()
+            .decode(trim_padding(&stripped))
+            .map_err(|e| decode_error("base64", e))
+    }
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase64Variant::default()),
+    text_signature = "(data, *, variant='standard')"
+)]
+pub(crate) fn base64_encode(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    variant: PyBase64Variant,
+) -> RyBytes {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, variant)).into_bytes().into()
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase64Variant::default()),
+    text_signature = "(data, *, variant='standard')"
+)]
+pub(crate) fn base64_encode_str(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    variant: PyBase64Variant,
+) -> String {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, variant))
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase64Variant::default(), strict = true),
+    text_signature = "(data, *, variant='standard', strict=True)"
+)]
+pub(crate) fn base64_decode(
+    py: Python<'_>,
+    data: DecodeInput<'_, '_>,
+    variant: PyBase64Variant,
+    strict: bool,
+) -> PyResult<RyBytes> {
+    let bin = data.as_ref();
+    py.detach(|| decode(bin, variant, strict))
+        .map(RyBytes::from)
+}
@@ -0,0 +1,178 @@
+//! base85 (RFC 1924 / python `base64.b85encode`) and Z85 codecs
+//!
+//! Both use the same big-endian 4-byte -> 5-symbol packing and differ only in
+//! the alphabet; partial trailing chunks are handled like python's `b85encode`
+//! (and `z85encode`), i.e. padded with zeros and truncated afterwards.
+use pyo3::prelude::*;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+use ryo3_macro_rules::py_value_error;
+
+use crate::common::{DecodeInput, extract_str_option, strip_ascii_whitespace};
+
+const RFC1924_SYMBOLS: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+const Z85_SYMBOLS: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+const INVALID: u8 = 0xff;
+
+#[expect(clippy::cast_possible_truncation, reason = "i < 85")]
+const fn decode_table(symbols: &[u8; 85]) -> [u8; 256] {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < symbols.len() {
+        table[symbols[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+static RFC1924_DECODE: [u8; 256] = decode_table(RFC1924_SYMBOLS);
+static Z85_DECODE: [u8; 256] = decode_table(Z85_SYMBOLS);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum PyBase85Variant {
+    #[default]
+    Rfc1924,
+    Z85,
+}
+
+impl PyBase85Variant {
+    fn symbols(self) -> &'static [u8; 85] {
+        match self {
+            Self::Rfc1924 => RFC1924_SYMBOLS,
+            Self::Z85 => Z85_SYMBOLS,
+        }
+    }
+
+    fn decode_table(self) -> &'static [u8; 256] {
+        match self {
+            Self::Rfc1924 => &RFC1924_DECODE,
+            Self::Z85 => &Z85_DECODE,
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyBase85Variant {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        extract_str_option(
+            ob,
+            "base85 variant",
+            &[("rfc1924", Self::Rfc1924), ("z85", Self::Z85)],
+        )
+    }
+}
+
+pub(crate) fn encode(data: &[u8], variant: PyBase85Variant, pad: bool) -> Vec<u8> {
+    let symbols = variant.symbols();
+    let mut out = Vec::with_capacity(data.len().div_ceil(4) * 5);
+    for chunk in data.chunks(4) {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(word);
+        let mut encoded = [0u8; 5];
+        for slot in encoded.iter_mut().rev() {
+            *slot = symbols[(value % 85) as usize];
+            value /= 85;
+        }
+        let n = if pad { 5 } else { chunk.len() + 1 };
+        out.extend_from_slice(&encoded[..n]);
+    }
+    out
+}
+
+pub(crate) fn decode(data: &[u8], variant: PyBase85Variant, strict: bool) -> PyResult<Vec<u8>> {
+    let data = if strict {
+        std::borrow::Cow::Borrowed(data)
+    } else {
+        strip_ascii_whitespace(data)
+    };
+    // a single trailing symbol can't encode any bytes
+    if data.len() % 5 == 1 {
+        return Err(py_value_error!(
+            "Invalid base85 data: invalid length {}",
+            data.len()
+        ));
+    }
+    let table = variant.decode_table();
+    let pad_value = 84u64;
+    let mut out = Vec::with_capacity(data.len().div_ceil(5) * 4);
+    for (chunk_ix, chunk) in data.chunks(5).enumerate() {
+        let mut value: u64 = 0;
+        for i in 0..5 {
+            let digit = match chunk.get(i) {
+                Some(&c) => {
+                    let d = table[c as usize];
+                    if d == INVALID {
+                        return Err(py_value_error!(
+                            "Invalid base85 data: invalid symbol at {}",
+                            chunk_ix * 5 + i
+                        ));
+                    }
+                    u64::from(d)
+                }
+                None => pad_value,
+            };
+            value = value * 85 + digit;
+        }
+        let Ok(word) = u32::try_from(value) else {
+            return Err(py_value_error!(
+                "Invalid base85 data: overflow in chunk starting at {}",
+                chunk_ix * 5
+            ));
+        };
+        let n = chunk.len().saturating_sub(1).min(4);
+        out.extend_from_slice(&word.to_be_bytes()[..n]);
+    }
+    Ok(out)
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase85Variant::default(), pad = false),
+    text_signature = "(data, *, variant='rfc1924', pad=False)"
+)]
+pub(crate) fn base85_encode(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    variant: PyBase85Variant,
+    pad: bool,
+) -> RyBytes {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, variant, pad)).into()
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase85Variant::default(), pad = false),
+    text_signature = "(data, *, variant='rfc1924', pad=False)"
+)]
+pub(crate) fn base85_encode_str(
+    py: Python<'_>,
+    data: ReadableBuffer<'_, '_>,
+    variant: PyBase85Variant,
+    pad: bool,
+) -> String {
+    let bin = data.as_ref();
+    let encoded = py.detach(|| encode(bin, variant, pad));
+    // every symbol in both alphabets is ascii
+    encoded.into_iter().map(char::from).collect()
+}
+
+#[pyfunction]
+#[pyo3(
+    signature = (data, *, variant = PyBase85Variant::default(), strict = true),
+    text_signature = "(data, *, variant='rfc1924', strict=True)"
+)]
+pub(crate) fn base85_decode(
+    py: Python<'_>,
+    data: DecodeInput<'_, '_>,
+    variant: PyBase85Variant,
+    strict: bool,
+) -> PyResult<RyBytes> {
+    let bin = data.as_ref();
+    py.detach(|| decode(bin, variant, strict))
+        .map(RyBytes::from)
+}
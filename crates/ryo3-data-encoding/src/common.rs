@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use ryo3_bytes::ReadableBuffer;
+use ryo3_macro_rules::{py_type_err, py_value_error};
+
+/// Input to the decoders: an ascii `str` or any bytes-like object
+pub(crate) enum DecodeInput<'a, 'py> {
+    Str(&'a str),
+    Buffer(ReadableBuffer<'a, 'py>),
+}
+
+impl AsRef<[u8]> for DecodeInput<'_, '_> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Str(s) => s.as_bytes(),
+            Self::Buffer(b) => b.as_slice(),
+        }
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for DecodeInput<'a, 'py> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        if ob.cast::<PyString>().is_ok() {
+            let s = ob.extract::<&'a str>()?;
+            if !s.is_ascii() {
+                return Err(py_value_error!(
+                    "string argument should contain only ASCII characters"
+                ));
+            }
+            Ok(Self::Str(s))
+        } else if let Ok(buf) = ob.extract::<ReadableBuffer<'a, 'py>>() {
+            Ok(Self::Buffer(buf))
+        } else {
+            py_type_err!(
+                "argument should be a bytes-like object or ASCII string, not '{}'",
+                ob.get_type().name()?
+            )
+        }
+    }
+}
+
+/// Extract a (case-insensitive, `_`/`-` agnostic) string option
+pub(crate) fn extract_str_option<T: Copy>(
+    ob: Borrowed<'_, '_, PyAny>,
+    what: &str,
+    options: &[(&str, T)],
+) -> PyResult<T> {
+    let Ok(s) = ob.extract::<&str>() else {
+        return py_type_err!(
+            "Invalid type for {what}, expected a string (options: {})",
+            options_str(options)
+        );
+    };
+    let normalized = s.to_ascii_lowercase().replace('_', "-");
+    options
+        .iter()
+        .find(|(name, _)| *name == normalized)
+        .map(|(_, v)| *v)
+        .ok_or_else(|| py_value_error!("Invalid {what}: '{s}' (options: {})", options_str(options)))
+}
+
+fn options_str<T>(options: &[(&str, T)]) -> String {
+    options
+        .iter()
+        .map(|(name, _)| format!("'{name}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Remove all ascii whitespace (only allocates if there is any)
+pub(crate) fn strip_ascii_whitespace(data: &[u8]) -> Cow<'_, [u8]> {
+    if data.iter().any(u8::is_ascii_whitespace) {
+        Cow::Owned(
+            data.iter()
+                .copied()
+                .filter(|b| !b.is_ascii_whitespace())
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(data)
+    }
+}
+
+/// Remove trailing `=` padding
+pub(crate) fn trim_padding(data: &[u8]) -> &[u8] {
+    let end = data.iter().rposition(|&b| b != b'=').map_or(0, |ix| ix + 1);
+    &data[..end]
+}
+
+pub(crate) fn decode_error(what: &str, e: impl std::fmt::Display) -> PyErr {
+    py_value_error!("Invalid {what} data: {e}")
+}
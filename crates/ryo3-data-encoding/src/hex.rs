@@ -0,0 +1,48 @@
+use data_encoding::{HEXLOWER, HEXLOWER_PERMISSIVE, HEXUPPER};
+use pyo3::prelude::*;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+
+use crate::common::{DecodeInput, decode_error, strip_ascii_whitespace};
+
+pub(crate) fn encode(data: &[u8], upper: bool) -> String {
+    if upper {
+        HEXUPPER.encode(data)
+    } else {
+        HEXLOWER.encode(data)
+    }
+}
+
+/// Decode hex (either case); lenient mode ignores ascii whitespace
+pub(crate) fn decode(data: &[u8], strict: bool) -> PyResult<Vec<u8>> {
+    if strict {
+        HEXLOWER_PERMISSIVE.decode(data)
+    } else {
+        HEXLOWER_PERMISSIVE.decode(&strip_ascii_whitespace(data))
+    }
+    .map_err(|e| decode_error("hex", e))
+}
+
+#[pyfunction]
+#[pyo3(signature = (data, *, upper = false))]
+pub(crate) fn hex_encode(py: Python<'_>, data: ReadableBuffer<'_, '_>, upper: bool) -> RyBytes {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, upper)).into_bytes().into()
+}
+
+#[pyfunction]
+#[pyo3(signature = (data, *, upper = false))]
+pub(crate) fn hex_encode_str(py: Python<'_>, data: ReadableBuffer<'_, '_>, upper: bool) -> String {
+    let bin = data.as_ref();
+    py.detach(|| encode(bin, upper))
+}
+
+#[pyfunction]
+#[pyo3(signature = (data, *, strict = true))]
+pub(crate) fn hex_decode(
+    py: Python<'_>,
+    data: DecodeInput<'_, '_>,
+    strict: bool,
+) -> PyResult<RyBytes> {
+    let bin = data.as_ref();
+    py.detach(|| decode(bin, strict)).map(RyBytes::from)
+}
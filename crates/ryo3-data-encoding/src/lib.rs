@@ -0,0 +1,33 @@
+#![doc = include_str!("../README.md")]
+#![expect(clippy::needless_pass_by_value)]
+use pyo3::prelude::*;
+mod base32;
+mod base58;
+mod base64;
+mod base85;
+mod common;
+mod hex;
+
+pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    // base64
+    m.add_function(wrap_pyfunction!(crate::base64::base64_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base64::base64_encode_str, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base64::base64_decode, m)?)?;
+    // base32
+    m.add_function(wrap_pyfunction!(crate::base32::base32_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base32::base32_encode_str, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base32::base32_decode, m)?)?;
+    // base58
+    m.add_function(wrap_pyfunction!(crate::base58::base58_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base58::base58_encode_str, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base58::base58_decode, m)?)?;
+    // base85
+    m.add_function(wrap_pyfunction!(crate::base85::base85_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base85::base85_encode_str, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::base85::base85_decode, m)?)?;
+    // hex
+    m.add_function(wrap_pyfunction!(crate::hex::hex_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::hex::hex_encode_str, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::hex::hex_decode, m)?)?;
+    Ok(())
+}
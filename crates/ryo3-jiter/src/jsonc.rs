@@ -0,0 +1,99 @@
+//! JSONC (json with comments) support
+//!
+//! Comments and trailing commas are blanked out with spaces (newlines are
+//! kept) so the result is plain json with the same byte offsets; jiter error
+//! positions still point into the original document.
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+
+use crate::error_at;
+
+/// Strip `//`/`/* */` comments and/or trailing commas from `data`
+///
+/// Returns the data unchanged (borrowed) when there is nothing to strip.
+pub fn strip_jsonc(data: &[u8], comments: bool, trailing_commas: bool) -> PyResult<Cow<'_, [u8]>> {
+    let needs_strip =
+        (comments && data.contains(&b'/')) || (trailing_commas && data.contains(&b','));
+    if !needs_strip {
+        return Ok(Cow::Borrowed(data));
+    }
+    let mut out = data.to_vec();
+    // index of the last `,` seen with only whitespace/comments after it
+    let mut pending_comma: Option<usize> = None;
+    // last significant (non-whitespace, non-comment) byte
+    let mut last = 0u8;
+    let mut i = 0;
+    while i < data.len() {
+        match data[i] {
+            b'"' => {
+                pending_comma = None;
+                last = b'"';
+                i = skip_string(data, i);
+                continue;
+            }
+            b'/' if comments && data.get(i + 1) == Some(&b'/') => {
+                while i < data.len() && data[i] != b'\n' {
+                    out[i] = b' ';
+                    i += 1;
+                }
+                continue;
+            }
+            b'/' if comments && data.get(i + 1) == Some(&b'*') => {
+                let start = i;
+                i += 2;
+                loop {
+                    match data.get(i) {
+                        Some(b'*') if data.get(i + 1) == Some(&b'/') => {
+                            i += 2;
+                            break;
+                        }
+                        Some(_) => i += 1,
+                        None => {
+                            return Err(error_at(data, start, "EOF while parsing a comment"));
+                        }
+                    }
+                }
+                for b in &mut out[start..i] {
+                    if *b != b'\n' {
+                        *b = b' ';
+                    }
+                }
+                continue;
+            }
+            b' ' | b'\t' | b'\n' | b'\r' => {}
+            b => {
+                match b {
+                    // only a comma that follows a value can be a trailing comma
+                    b',' if !matches!(last, 0 | b'[' | b'{' | b',' | b':') => {
+                        pending_comma = Some(i);
+                    }
+                    b']' | b'}' => {
+                        if trailing_commas && let Some(comma) = pending_comma {
+                            out[comma] = b' ';
+                        }
+                        pending_comma = None;
+                    }
+                    _ => pending_comma = None,
+                }
+                last = b;
+            }
+        }
+        i += 1;
+    }
+    Ok(Cow::Owned(out))
+}
+
+/// index just past the string starting at `start` (or `data.len()` if it is
+/// unterminated; the parser reports that)
+fn skip_string(data: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < data.len() {
+        match data[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    data.len()
+}
@@ -6,18 +6,74 @@
 //! of [2024-05-29])
 use std::path::PathBuf;
 
-pub use ::jiter::{FloatMode, PartialMode, PythonParse, StringCacheMode, map_json_error};
+pub use ::jiter::{
+    FloatMode, LosslessFloat, PartialMode, PythonParse, StringCacheMode, map_json_error,
+};
 use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
 use pyo3::types::PyList;
 use ryo3_bytes::RyBytes;
 
+mod jsonc;
+mod numbers;
+
+pub use jsonc::strip_jsonc;
+
+/// `ValueError` with a jiter-style position (`<msg> at line <l> column <c>`)
+fn error_at(data: &[u8], index: usize, msg: &str) -> PyErr {
+    let index = index.min(data.len());
+    let before = &data[..index];
+    let line = before.split(|b| *b == b'\n').count();
+    let column = match before.iter().rposition(|b| *b == b'\n') {
+        Some(nl) => index - nl,
+        None => index + 1,
+    };
+    PyValueError::new_err(format!("{msg} at line {line} column {column}"))
+}
+
+/// How integers that do not fit in an `i64` are returned
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BigIntMode {
+    /// python `int` (exact)
+    #[default]
+    Int,
+    /// the exact decimal `str` from the json source
+    Str,
+}
+
+impl<'py> FromPyObject<'_, 'py> for BigIntMode {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let s = obj.extract::<PyBackedStr>()?;
+        match &*s {
+            "int" => Ok(Self::Int),
+            "str" => Ok(Self::Str),
+            _ => Err(PyValueError::new_err(format!(
+                "Invalid big int mode: `{}`, expected `int` or `str`",
+                &*s
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct JiterParseOptions {
     pub allow_inf_nan: bool,
     pub cache_mode: StringCacheMode,
     pub partial_mode: PartialMode,
     pub catch_duplicate_keys: bool,
+    pub float_mode: FloatMode,
+    pub big_int_mode: BigIntMode,
+    /// return all numbers as their (unconverted) json source `str`
+    pub lazy_numbers: bool,
+    /// allow `//` and `/* */` comments (JSONC)
+    pub allow_comments: bool,
+    /// allow a trailing comma after the last array element/object member
+    pub allow_trailing_commas: bool,
 }
 
 impl JiterParseOptions {
@@ -28,6 +84,11 @@ impl JiterParseOptions {
             cache_mode: StringCacheMode::All,
             partial_mode: PartialMode::Off,
             catch_duplicate_keys: false,
+            float_mode: FloatMode::Float,
+            big_int_mode: BigIntMode::Int,
+            lazy_numbers: false,
+            allow_comments: false,
+            allow_trailing_commas: false,
         }
     }
 
@@ -54,6 +115,42 @@ impl JiterParseOptions {
         self.catch_duplicate_keys = catch_duplicate_keys;
         self
     }
+
+    #[must_use]
+    pub fn with_float_mode(mut self, float_mode: FloatMode) -> Self {
+        self.float_mode = float_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_big_int_mode(mut self, big_int_mode: BigIntMode) -> Self {
+        self.big_int_mode = big_int_mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_lazy_numbers(mut self, lazy_numbers: bool) -> Self {
+        self.lazy_numbers = lazy_numbers;
+        self
+    }
+
+    #[must_use]
+    pub fn with_allow_comments(mut self, allow_comments: bool) -> Self {
+        self.allow_comments = allow_comments;
+        self
+    }
+
+    #[must_use]
+    pub fn with_allow_trailing_commas(mut self, allow_trailing_commas: bool) -> Self {
+        self.allow_trailing_commas = allow_trailing_commas;
+        self
+    }
+
+    /// `big_int_mode="str"` and `lazy_numbers` are not supported by jiter's
+    /// python parser and use the (slower) `numbers::NumberParser`
+    fn jiter_compatible(self) -> bool {
+        self.big_int_mode == BigIntMode::Int && !self.lazy_numbers
+    }
 }
 
 impl Default for JiterParseOptions {
@@ -69,7 +166,7 @@ impl From<&JiterParseOptions> for PythonParse {
             cache_mode: options.cache_mode,
             partial_mode: options.partial_mode,
             catch_duplicate_keys: options.catch_duplicate_keys,
-            float_mode: FloatMode::Float,
+            float_mode: options.float_mode,
         }
     }
 }
@@ -81,25 +178,39 @@ impl JiterParseOptions {
     }
 
     pub fn parse<'py>(self, py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyAny>> {
-        self.parser()
-            .python_parse(py, data)
-            .map_err(|e| map_json_error(data, &e))
+        let data = strip_jsonc(data, self.allow_comments, self.allow_trailing_commas)?;
+        self.parse_stripped(py, &data)
     }
 
     pub fn parse_lines<'py>(self, py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyAny>> {
+        let jsonc = self.allow_comments || self.allow_trailing_commas;
+        // strip the whole buffer before splitting so `/* */` comments can
+        // span lines (stripping keeps newlines, so lines stay the same)
+        let data = strip_jsonc(data, self.allow_comments, self.allow_trailing_commas)?;
         let lines_iter = data.split(|b| *b == b'\n').filter(|line| !line.is_empty());
-        let parser = self.parser();
         // parse each line and collect into a Vec
         let mut parsed_lines = Vec::new();
         for line in lines_iter {
-            let parsed = parser
-                .python_parse(py, line)
-                .map_err(|e| map_json_error(line, &e))?;
-            parsed_lines.push(parsed);
+            // comment-only lines
+            if jsonc && line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            parsed_lines.push(self.parse_stripped(py, line)?);
         }
         let pylist = PyList::new(py, parsed_lines)?;
         pylist.into_bound_py_any(py)
     }
+
+    /// parse plain json (comments/trailing commas already stripped)
+    fn parse_stripped<'py>(self, py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyAny>> {
+        if self.jiter_compatible() {
+            self.parser()
+                .python_parse(py, data)
+                .map_err(|e| map_json_error(data, &e))
+        } else {
+            numbers::NumberParser::new(py, data, self).parse()
+        }
+    }
 }
 
 #[pyfunction(
@@ -111,8 +222,18 @@ impl JiterParseOptions {
         cache_mode = StringCacheMode::All,
         partial_mode = PartialMode::Off,
         catch_duplicate_keys = false,
+        float_mode = FloatMode::Float,
+        big_int_mode = BigIntMode::Int,
+        lazy_numbers = false,
+        allow_comments = false,
+        allow_trailing_commas = false,
     ),
-    text_signature = "(data, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False)"
+    text_signature = "(data, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False, allow_comments=False, allow_trailing_commas=False)"
+)]
+#[expect(
+    clippy::fn_params_excessive_bools,
+    clippy::too_many_arguments,
+    reason = "python kwargs"
 )]
 pub fn parse_json<'py>(
     py: Python<'py>,
@@ -121,12 +242,22 @@ pub fn parse_json<'py>(
     cache_mode: StringCacheMode,
     partial_mode: PartialMode,
     catch_duplicate_keys: bool,
+    float_mode: FloatMode,
+    big_int_mode: BigIntMode,
+    lazy_numbers: bool,
+    allow_comments: bool,
+    allow_trailing_commas: bool,
 ) -> PyResult<Bound<'py, PyAny>> {
     let options = JiterParseOptions::new()
         .with_allow_inf_nan(allow_inf_nan)
         .with_cache_mode(cache_mode)
         .with_partial_mode(partial_mode)
-        .with_catch_duplicate_keys(catch_duplicate_keys);
+        .with_catch_duplicate_keys(catch_duplicate_keys)
+        .with_float_mode(float_mode)
+        .with_big_int_mode(big_int_mode)
+        .with_lazy_numbers(lazy_numbers)
+        .with_allow_comments(allow_comments)
+        .with_allow_trailing_commas(allow_trailing_commas);
     if let Ok(bytes) = data.extract::<&[u8]>() {
         options.parse(py, bytes)
     } else if let Ok(s) = data.extract::<&str>() {
@@ -155,8 +286,18 @@ pub fn parse_json<'py>(
         cache_mode = StringCacheMode::All,
         partial_mode = PartialMode::Off,
         catch_duplicate_keys = false,
+        float_mode = FloatMode::Float,
+        big_int_mode = BigIntMode::Int,
+        lazy_numbers = false,
+        allow_comments = false,
+        allow_trailing_commas = false,
     ),
-    text_signature = "(data, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False)"
+    text_signature = "(data, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False, allow_comments=False, allow_trailing_commas=False)"
+)]
+#[expect(
+    clippy::fn_params_excessive_bools,
+    clippy::too_many_arguments,
+    reason = "python kwargs"
 )]
 pub fn parse_jsonl<'py>(
     py: Python<'py>,
@@ -165,12 +306,22 @@ pub fn parse_jsonl<'py>(
     cache_mode: StringCacheMode,
     partial_mode: PartialMode,
     catch_duplicate_keys: bool,
+    float_mode: FloatMode,
+    big_int_mode: BigIntMode,
+    lazy_numbers: bool,
+    allow_comments: bool,
+    allow_trailing_commas: bool,
 ) -> PyResult<Bound<'py, PyAny>> {
     let options = JiterParseOptions::new()
         .with_allow_inf_nan(allow_inf_nan)
         .with_cache_mode(cache_mode)
         .with_partial_mode(partial_mode)
-        .with_catch_duplicate_keys(catch_duplicate_keys);
+        .with_catch_duplicate_keys(catch_duplicate_keys)
+        .with_float_mode(float_mode)
+        .with_big_int_mode(big_int_mode)
+        .with_lazy_numbers(lazy_numbers)
+        .with_allow_comments(allow_comments)
+        .with_allow_trailing_commas(allow_trailing_commas);
     if let Ok(bytes) = data.extract::<&[u8]>() {
         options.parse_lines(py, bytes)
     } else if let Ok(custom) = data.cast_exact::<RyBytes>() {
@@ -194,9 +345,10 @@ pub fn parse_jsonl<'py>(
 macro_rules! py_parse_fn {
     ($name:ident) => {
         #[pyfunction(
-            signature = (data, /, *, allow_inf_nan = false, cache_mode = StringCacheMode::All, partial_mode = PartialMode::Off, catch_duplicate_keys = false),
-            text_signature = "(data, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False)"
+            signature = (data, /, *, allow_inf_nan = false, cache_mode = StringCacheMode::All, partial_mode = PartialMode::Off, catch_duplicate_keys = false, float_mode = FloatMode::Float, big_int_mode = BigIntMode::Int, lazy_numbers = false, allow_comments = false, allow_trailing_commas = false),
+            text_signature = "(data, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False, allow_comments=False, allow_trailing_commas=False)"
         )]
+        #[expect(clippy::too_many_arguments, reason = "python kwargs")]
         pub fn $name<'py>(
             py: Python<'py>,
             data: &Bound<'py, PyAny>,
@@ -204,6 +356,11 @@ macro_rules! py_parse_fn {
             cache_mode: StringCacheMode,
             partial_mode: PartialMode,
             catch_duplicate_keys: bool,
+            float_mode: FloatMode,
+            big_int_mode: BigIntMode,
+            lazy_numbers: bool,
+            allow_comments: bool,
+            allow_trailing_commas: bool,
         ) -> PyResult<Bound<'py, PyAny>> {
             parse_json(
                 py,
@@ -212,6 +369,11 @@ macro_rules! py_parse_fn {
                 cache_mode,
                 partial_mode,
                 catch_duplicate_keys,
+                float_mode,
+                big_int_mode,
+                lazy_numbers,
+                allow_comments,
+                allow_trailing_commas,
             )
         }
     };
@@ -228,9 +390,19 @@ py_parse_fn!(loads);
         cache_mode = StringCacheMode::All,
         partial_mode = PartialMode::Off,
         catch_duplicate_keys = false,
+        float_mode = FloatMode::Float,
+        big_int_mode = BigIntMode::Int,
+        lazy_numbers = false,
+        allow_comments = false,
+        allow_trailing_commas = false,
         lines = false
     ),
-    text_signature = "(p, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, lines=False)"
+    text_signature = "(p, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False, allow_comments=False, allow_trailing_commas=False, lines=False)"
+)]
+#[expect(
+    clippy::fn_params_excessive_bools,
+    clippy::too_many_arguments,
+    reason = "python kwargs"
 )]
 pub fn read_json(
     py: Python<'_>,
@@ -239,6 +411,11 @@ pub fn read_json(
     cache_mode: StringCacheMode,
     partial_mode: PartialMode,
     catch_duplicate_keys: bool,
+    float_mode: FloatMode,
+    big_int_mode: BigIntMode,
+    lazy_numbers: bool,
+    allow_comments: bool,
+    allow_trailing_commas: bool,
     lines: bool,
 ) -> PyResult<Bound<'_, PyAny>> {
     let fbytes = std::fs::read(p)?;
@@ -246,7 +423,12 @@ pub fn read_json(
         .with_allow_inf_nan(allow_inf_nan)
         .with_cache_mode(cache_mode)
         .with_partial_mode(partial_mode)
-        .with_catch_duplicate_keys(catch_duplicate_keys);
+        .with_catch_duplicate_keys(catch_duplicate_keys)
+        .with_float_mode(float_mode)
+        .with_big_int_mode(big_int_mode)
+        .with_lazy_numbers(lazy_numbers)
+        .with_allow_comments(allow_comments)
+        .with_allow_trailing_commas(allow_trailing_commas);
     if lines {
         options.parse_lines(py, &fbytes)
     } else {
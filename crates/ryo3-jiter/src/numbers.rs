@@ -0,0 +1,267 @@
+//! json -> python conversion for the number modes jiter's python parser does
+//! not support (`big_int_mode="str"` and `lazy_numbers=True`)
+//!
+//! Only used when one of those options is set; the json is still tokenized
+//! and validated by jiter (`Jiter`), only the conversion of values to python
+//! objects happens here (string cache, partial mode and duplicate key checks
+//! follow jiter's python parser).
+use std::collections::HashSet;
+
+use ::jiter::{
+    FloatMode, Jiter, JiterError, JiterErrorType, JsonErrorType, LosslessFloat, PartialMode, Peek,
+    StringCacheMode, cached_py_string,
+};
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyBytes, PyDict, PyFloat, PyList, PyString, PyType};
+
+use crate::{BigIntMode, JiterParseOptions, error_at};
+
+/// same as jiter's `DEFAULT_RECURSION_LIMIT`
+const RECURSION_LIMIT: u16 = 200;
+
+fn decimal_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    static DECIMAL: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+    DECIMAL.import(py, "decimal", "Decimal")
+}
+
+enum ParseError {
+    Json(JiterError),
+    Py(PyErr),
+}
+
+impl From<JiterError> for ParseError {
+    fn from(err: JiterError) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<PyErr> for ParseError {
+    fn from(err: PyErr) -> Self {
+        Self::Py(err)
+    }
+}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+/// `true` for errors caused by the json ending early (ignored in partial mode)
+fn is_eof(err: &ParseError) -> bool {
+    matches!(
+        err,
+        ParseError::Json(JiterError {
+            error_type: JiterErrorType::JsonError(
+                JsonErrorType::EofWhileParsingList
+                    | JsonErrorType::EofWhileParsingObject
+                    | JsonErrorType::EofWhileParsingString
+                    | JsonErrorType::EofWhileParsingValue
+            ),
+            ..
+        })
+    )
+}
+
+fn py_string<'py>(py: Python<'py>, s: &str, cache: bool) -> Bound<'py, PyString> {
+    if cache {
+        cached_py_string(py, s, false)
+    } else {
+        PyString::new(py, s)
+    }
+}
+
+pub(crate) struct NumberParser<'j, 'py> {
+    py: Python<'py>,
+    data: &'j [u8],
+    jiter: Jiter<'j>,
+    depth: u16,
+    options: JiterParseOptions,
+}
+
+impl<'j, 'py> NumberParser<'j, 'py> {
+    pub(crate) fn new(py: Python<'py>, data: &'j [u8], options: JiterParseOptions) -> Self {
+        let mut jiter = Jiter::new(data);
+        if options.allow_inf_nan {
+            jiter = jiter.with_allow_inf_nan();
+        }
+        if matches!(options.partial_mode, PartialMode::TrailingStrings) {
+            jiter = jiter.with_allow_partial_strings();
+        }
+        Self {
+            py,
+            data,
+            jiter,
+            depth: 0,
+            options,
+        }
+    }
+
+    pub(crate) fn parse(mut self) -> PyResult<Bound<'py, PyAny>> {
+        let result = self.parse_value().and_then(|value| {
+            self.jiter.finish()?;
+            Ok(value)
+        });
+        result.map_err(|err| match err {
+            ParseError::Json(err) => error_at(self.data, err.index, &err.error_type.to_string()),
+            ParseError::Py(err) => err,
+        })
+    }
+
+    fn partial(&self) -> bool {
+        !matches!(self.options.partial_mode, PartialMode::Off)
+    }
+
+    fn error(&self, msg: &str) -> ParseError {
+        ParseError::Py(error_at(self.data, self.jiter.current_index(), msg))
+    }
+
+    fn parse_value(&mut self) -> ParseResult<Bound<'py, PyAny>> {
+        let peek = self.jiter.peek()?;
+        match peek {
+            Peek::Null => {
+                self.jiter.known_null()?;
+                Ok(self.py.None().into_bound(self.py))
+            }
+            Peek::True | Peek::False => {
+                Ok(self.jiter.known_bool(peek)?.into_bound_py_any(self.py)?)
+            }
+            Peek::String => {
+                let cache = matches!(self.options.cache_mode, StringCacheMode::All);
+                let s = self.jiter.known_str()?;
+                Ok(py_string(self.py, s, cache).into_any())
+            }
+            Peek::Array => self.array(),
+            Peek::Object => self.object(),
+            _ if peek.is_num() => self.number(),
+            _ => Err(self.error("expected value")),
+        }
+    }
+
+    fn enter(&mut self) -> ParseResult<()> {
+        self.depth += 1;
+        if self.depth > RECURSION_LIMIT {
+            return Err(self.error("recursion limit exceeded"));
+        }
+        Ok(())
+    }
+
+    fn array(&mut self) -> ParseResult<Bound<'py, PyAny>> {
+        self.enter()?;
+        let list = PyList::empty(self.py);
+        let mut next = self.jiter.known_array();
+        loop {
+            match next {
+                Ok(Some(_)) => match self.parse_value() {
+                    Ok(value) => list.append(value)?,
+                    Err(err) if self.partial() && is_eof(&err) => break,
+                    Err(err) => return Err(err),
+                },
+                Ok(None) => break,
+                Err(err) => {
+                    let err = ParseError::from(err);
+                    if self.partial() && is_eof(&err) {
+                        break;
+                    }
+                    return Err(err);
+                }
+            }
+            next = self.jiter.array_step();
+        }
+        self.depth -= 1;
+        Ok(list.into_any())
+    }
+
+    fn object(&mut self) -> ParseResult<Bound<'py, PyAny>> {
+        self.enter()?;
+        let dict = PyDict::new(self.py);
+        let cache = matches!(
+            self.options.cache_mode,
+            StringCacheMode::All | StringCacheMode::Keys
+        );
+        let mut seen = self.options.catch_duplicate_keys.then(HashSet::new);
+        let mut first = true;
+        loop {
+            let key_index = self.jiter.current_index();
+            let key = if first {
+                self.jiter.known_object()
+            } else {
+                self.jiter.next_key()
+            };
+            first = false;
+            let key = match key.map(|key| key.map(str::to_owned)) {
+                Ok(Some(key)) => key,
+                Ok(None) => break,
+                Err(err) => {
+                    let err = ParseError::from(err);
+                    if self.partial() && is_eof(&err) {
+                        break;
+                    }
+                    return Err(err);
+                }
+            };
+            let value = match self.parse_value() {
+                Ok(value) => value,
+                Err(err) if self.partial() && is_eof(&err) => break,
+                Err(err) => return Err(err),
+            };
+            if let Some(seen) = seen.as_mut()
+                && !seen.insert(key.clone())
+            {
+                return Err(ParseError::Py(error_at(
+                    self.data,
+                    key_index,
+                    &format!("Detected duplicate key \"{key}\""),
+                )));
+            }
+            dict.set_item(py_string(self.py, &key, cache), value)?;
+        }
+        self.depth -= 1;
+        Ok(dict.into_any())
+    }
+
+    fn number(&mut self) -> ParseResult<Bound<'py, PyAny>> {
+        // `parse_value` peeked, so the jiter is at the start of the number
+        let data = self.data;
+        let start = self.jiter.current_index();
+        self.jiter.next_number_bytes()?;
+        let raw = &data[start..self.jiter.current_index()];
+        // only ascii digits/signs/dots/exponents/`NaN`/`Infinity`
+        let raw_str = std::str::from_utf8(raw).unwrap_or_default();
+        if self.options.lazy_numbers {
+            return Ok(PyString::new(self.py, raw_str).into_any());
+        }
+        match raw_str {
+            "NaN" => return self.inf_nan(raw_str, f64::NAN),
+            "Infinity" => return self.inf_nan(raw_str, f64::INFINITY),
+            "-Infinity" => return self.inf_nan(raw_str, f64::NEG_INFINITY),
+            _ => {}
+        }
+        if !raw_str.contains(['.', 'e', 'E']) {
+            if let Ok(n) = raw_str.parse::<i64>() {
+                return Ok(n.into_bound_py_any(self.py)?);
+            }
+            // only reachable with `big_int_mode="str"` (big ints go through
+            // jiter otherwise)
+            debug_assert!(self.options.big_int_mode == BigIntMode::Str);
+            return Ok(PyString::new(self.py, raw_str).into_any());
+        }
+        Ok(match self.options.float_mode {
+            FloatMode::Decimal => decimal_type(self.py)?.call1((raw_str,))?,
+            FloatMode::LosslessFloat => self
+                .py
+                .get_type::<LosslessFloat>()
+                .call1((PyBytes::new(self.py, raw),))?,
+            FloatMode::Float => {
+                let f: f64 = raw_str.parse().map_err(|_| self.error("invalid number"))?;
+                PyFloat::new(self.py, f).into_any()
+            }
+        })
+    }
+
+    fn inf_nan(&self, text: &str, value: f64) -> ParseResult<Bound<'py, PyAny>> {
+        if matches!(self.options.float_mode, FloatMode::Decimal) {
+            Ok(decimal_type(self.py)?.call1((text,))?)
+        } else {
+            Ok(PyFloat::new(self.py, value).into_any())
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! RFC 8785 JSON Canonicalization Scheme (JCS)
+//!
+//! - object members sorted by the UTF-16 code units of their keys
+//! - numbers formatted like ECMAScript's `Number.prototype.toString`
+//! - strings escaped minimally (only `"`, `\` and control characters)
+//! - no whitespace
+mod ser;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use ryo3_bytes::RyBytes;
+use ryo3_serde::PyAnySerializer;
+use serde::Serialize;
+
+use crate::serialize::map_serde_json_err;
+
+/// json value ready for canonical output (numbers are doubles)
+#[derive(Debug)]
+enum Canonical {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Self>),
+    Object(Vec<(String, Self)>),
+}
+
+/// `Number.prototype.toString` (ECMA-262 `Number::toString`) for a finite
+/// double
+fn write_number(out: &mut Vec<u8>, value: f64) {
+    if value == 0.0 {
+        // -0 is "0" too
+        out.push(b'0');
+        return;
+    }
+    if value.is_sign_negative() {
+        out.push(b'-');
+    }
+    // serde_json (ryu) picks the shortest round-tripping digits closest to
+    // the exact value like ECMAScript does (rust's own float formatting may
+    // pick a different last digit); only its layout differs
+    let repr = serde_json::Number::from_f64(value.abs())
+        .map(|n| n.to_string())
+        .unwrap_or_default();
+    let (mantissa, exp) = repr.split_once(['e', 'E']).unwrap_or((&repr, "0"));
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let exp: i32 = exp.parse().unwrap_or(0);
+    // value = 0.<digits> * 10^n
+    let mut n = exp + i32::try_from(int.len()).unwrap_or(i32::MAX);
+    let digits: Vec<u8> = int.bytes().chain(frac.bytes()).collect();
+    let leading_zeros = digits.iter().take_while(|d| **d == b'0').count();
+    n -= i32::try_from(leading_zeros).unwrap_or(i32::MAX);
+    let digits = &digits[leading_zeros..];
+    let trailing_zeros = digits.iter().rev().take_while(|d| **d == b'0').count();
+    let digits = &digits[..digits.len() - trailing_zeros];
+    let k = i32::try_from(digits.len()).unwrap_or(i32::MAX);
+    if k <= n && n <= 21 {
+        out.extend_from_slice(digits);
+        out.extend(std::iter::repeat_n(b'0', (n - k).unsigned_abs() as usize));
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n.unsigned_abs() as usize);
+        out.extend_from_slice(int);
+        out.push(b'.');
+        out.extend_from_slice(frac);
+    } else if -6 < n && n <= 0 {
+        out.extend_from_slice(b"0.");
+        out.extend(std::iter::repeat_n(b'0', n.unsigned_abs() as usize));
+        out.extend_from_slice(digits);
+    } else {
+        out.push(digits[0]);
+        if k > 1 {
+            out.push(b'.');
+            out.extend_from_slice(&digits[1..]);
+        }
+        let e = n - 1;
+        out.extend_from_slice(format!("e{}{}", if e < 0 { '-' } else { '+' }, e.abs()).as_bytes());
+    }
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) -> PyResult<()> {
+    // serde_json's string escaping matches JCS (short escapes for \b \f \n \r
+    // \t, lowercase `\u00xx` for other control characters, nothing else)
+    serde_json::to_writer(&mut *out, s).map_err(map_serde_json_err)
+}
+
+impl Canonical {
+    fn write(&self, out: &mut Vec<u8>) -> PyResult<()> {
+        match self {
+            Self::Null => out.extend_from_slice(b"null"),
+            Self::Bool(true) => out.extend_from_slice(b"true"),
+            Self::Bool(false) => out.extend_from_slice(b"false"),
+            Self::Number(n) => write_number(out, *n),
+            Self::String(s) => write_str(out, s)?,
+            Self::Array(items) => {
+                out.push(b'[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    item.write(out)?;
+                }
+                out.push(b']');
+            }
+            Self::Object(members) => {
+                let mut members: Vec<_> = members.iter().collect();
+                members.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+                out.push(b'{');
+                for (i, (key, value)) in members.into_iter().enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    write_str(out, key)?;
+                    out.push(b':');
+                    value.write(out)?;
+                }
+                out.push(b'}');
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serialize `obj` to RFC 8785 canonical json (JCS) bytes
+#[pyfunction(signature = (obj, /, *, default = None))]
+pub fn canonicalize<'py>(
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+) -> PyResult<RyBytes> {
+    let s = PyAnySerializer::new_json(obj.as_borrowed(), default);
+    let value = s.serialize(ser::CanonicalSerializer).map_err(|e| match e {
+        ser::Error::Invalid(msg) => PyValueError::new_err(msg),
+        ser::Error::Custom(_) => map_serde_json_err(e),
+    })?;
+    let mut out = Vec::with_capacity(4096);
+    value.write(&mut out)?;
+    Ok(RyBytes::from(out))
+}
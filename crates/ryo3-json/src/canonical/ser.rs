@@ -0,0 +1,385 @@
+//! serde `Serializer` building a [`Canonical`] value
+//!
+//! Unlike `serde_json::to_value` non-finite floats are an error (not `null`)
+//! and integers must be exactly representable as an IEEE 754 double.
+use std::fmt;
+
+use serde::ser::{self, Error as _, Serialize};
+
+use super::Canonical;
+
+#[derive(Debug)]
+pub(super) enum Error {
+    /// value that has no canonical json representation (-> `ValueError`)
+    Invalid(String),
+    /// any other serialization error (unsupported type, recursion...)
+    Custom(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(msg) | Self::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+/// 2^53; integers with a larger magnitude may not survive the trip through
+/// a double
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+pub(super) struct CanonicalSerializer;
+
+fn int_value(n: i128) -> Result<Canonical, Error> {
+    if n.unsigned_abs() <= u128::from(MAX_SAFE_INTEGER) {
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "|n| <= 2^53 is exactly representable"
+        )]
+        Ok(Canonical::Number(n as f64))
+    } else {
+        Err(Error::Invalid(format!(
+            "integer {n} cannot be represented exactly as an IEEE 754 double"
+        )))
+    }
+}
+
+fn float_value(f: f64) -> Result<Canonical, Error> {
+    if f.is_finite() {
+        Ok(Canonical::Number(f))
+    } else {
+        Err(Error::Invalid(format!("{f} is not a valid JSON number")))
+    }
+}
+
+impl ser::Serializer for CanonicalSerializer {
+    type Ok = Canonical;
+    type Error = Error;
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeVariant<SerializeArray>;
+    type SerializeMap = SerializeObject;
+    type SerializeStruct = SerializeObject;
+    type SerializeStructVariant = SerializeVariant<SerializeObject>;
+
+    fn serialize_bool(self, v: bool) -> Result<Canonical, Error> {
+        Ok(Canonical::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Canonical, Error> {
+        int_value(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Canonical, Error> {
+        int_value(i128::from(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Canonical, Error> {
+        i128::try_from(v).map_or_else(
+            |_| int_value(i128::MAX), // out of range either way
+            int_value,
+        )
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Canonical, Error> {
+        float_value(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Canonical, Error> {
+        float_value(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Canonical, Error> {
+        Ok(Canonical::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Canonical, Error> {
+        Ok(Canonical::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Canonical, Error> {
+        v.iter()
+            .map(|b| int_value(i128::from(*b)))
+            .collect::<Result<_, _>>()
+            .map(Canonical::Array)
+    }
+
+    fn serialize_none(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Canonical, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Canonical, Error> {
+        Ok(Canonical::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Canonical, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Canonical, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Canonical, Error> {
+        Ok(Canonical::Object(vec![(
+            variant.to_string(),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray, Error> {
+        Ok(SerializeArray(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeArray>, Error> {
+        Ok(SerializeVariant {
+            variant,
+            inner: SerializeArray(Vec::with_capacity(len)),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeObject, Error> {
+        Ok(SerializeObject {
+            members: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeObject, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeVariant<SerializeObject>, Error> {
+        Ok(SerializeVariant {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+pub(super) struct SerializeArray(Vec<Canonical>);
+
+impl SerializeArray {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.0.push(value.serialize(CanonicalSerializer)?);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Canonical;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Array(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = Canonical;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Array(self.0))
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = Canonical;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Array(self.0))
+    }
+}
+
+pub(super) struct SerializeObject {
+    members: Vec<(String, Canonical)>,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for SerializeObject {
+    type Ok = Canonical;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        match key.serialize(CanonicalSerializer)? {
+            Canonical::String(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            _ => Err(Error::Invalid("object keys must be strings".to_string())),
+        }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::custom("serialize_value called before serialize_key"))?;
+        self.members
+            .push((key, value.serialize(CanonicalSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Object(self.members))
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject {
+    type Ok = Canonical;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.members
+            .push((key.to_string(), value.serialize(CanonicalSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Object(self.members))
+    }
+}
+
+/// `{"variant": <inner>}` for tuple/struct enum variants
+pub(super) struct SerializeVariant<T> {
+    variant: &'static str,
+    inner: T,
+}
+
+impl ser::SerializeTupleVariant for SerializeVariant<SerializeArray> {
+    type Ok = Canonical;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Object(vec![(
+            self.variant.to_string(),
+            Canonical::Array(self.inner.0),
+        )]))
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeVariant<SerializeObject> {
+    type Ok = Canonical;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Canonical, Error> {
+        Ok(Canonical::Object(vec![(
+            self.variant.to_string(),
+            Canonical::Object(self.inner.members),
+        )]))
+    }
+}
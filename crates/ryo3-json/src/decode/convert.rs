@@ -0,0 +1,484 @@
+//! Converting parsed json (python objects) according to a [`TypeSchema`]
+use std::fmt::Write;
+
+use pyo3::PyTypeInfo;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet, PyString, PyTuple};
+use ryo3_core::PyCastExactOpt;
+
+use super::JsonValidationError;
+use super::schema::{Class, ClassKind, Schema, TypeSchema};
+
+/// json path segment of a failed value
+#[derive(Debug)]
+enum PathItem {
+    Index(usize),
+    Key(String),
+}
+
+enum DecodeError {
+    /// the value does not match the type; `path` is innermost first (it is
+    /// built while the error propagates up)
+    Invalid {
+        msg: String,
+        path: Vec<PathItem>,
+    },
+    Py(PyErr),
+}
+
+type DecodeResult<T> = Result<T, DecodeError>;
+
+impl From<PyErr> for DecodeError {
+    fn from(err: PyErr) -> Self {
+        Self::Py(err)
+    }
+}
+
+impl DecodeError {
+    fn invalid(msg: String) -> Self {
+        Self::Invalid {
+            msg,
+            path: Vec::new(),
+        }
+    }
+
+    /// `TypeError`/`ValueError` raised by a constructor (dataclass
+    /// `__init__`/`__post_init__`, enum, ry type parsing) is a validation
+    /// error, anything else is passed through
+    fn from_constructor(py: Python<'_>, err: PyErr, prefix: &str) -> Self {
+        if err.is_instance_of::<PyTypeError>(py) || err.is_instance_of::<PyValueError>(py) {
+            Self::invalid(format!("{prefix}{}", err.value(py)))
+        } else {
+            Self::Py(err)
+        }
+    }
+
+    fn at(mut self, item: PathItem) -> Self {
+        if let Self::Invalid { path, .. } = &mut self {
+            path.push(item);
+        }
+        self
+    }
+
+    fn into_pyerr(self, py: Python<'_>) -> PyErr {
+        match self {
+            Self::Invalid { msg, path } => {
+                let path = format_path(&path);
+                let err = if path == "$" {
+                    JsonValidationError::new_err(msg)
+                } else {
+                    JsonValidationError::new_err(format!("{msg} - at `{path}`"))
+                };
+                let _ = err.value(py).setattr("path", path);
+                err
+            }
+            Self::Py(err) => err,
+        }
+    }
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `JSONPath` of the failed value (`$.items[0]['first name']`)
+fn format_path(path: &[PathItem]) -> String {
+    let mut out = String::from("$");
+    for item in path.iter().rev() {
+        let _ = match item {
+            PathItem::Index(i) => write!(out, "[{i}]"),
+            PathItem::Key(key) if is_identifier(key) => write!(out, ".{key}"),
+            PathItem::Key(key) => write!(
+                out,
+                "['{}']",
+                key.replace('\\', "\\\\").replace('\'', "\\'")
+            ),
+        };
+    }
+    out
+}
+
+/// json type of a parsed value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonKind {
+    Null,
+    Bool,
+    Int,
+    Float,
+    Str,
+    Array,
+    Object,
+    /// not a json type (`convert` of arbitrary python objects)
+    Other,
+}
+
+impl JsonKind {
+    fn of(value: &Bound<'_, PyAny>) -> Self {
+        if value.is_none() {
+            Self::Null
+        } else if value.is_exact_instance_of::<PyBool>() {
+            Self::Bool
+        } else if value.is_exact_instance_of::<PyInt>() {
+            Self::Int
+        } else if value.is_exact_instance_of::<PyFloat>() {
+            Self::Float
+        } else if value.is_exact_instance_of::<PyString>() {
+            Self::Str
+        } else if value.is_exact_instance_of::<PyList>() {
+            Self::Array
+        } else if value.is_exact_instance_of::<PyDict>() {
+            Self::Object
+        } else {
+            Self::Other
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Bool => "bool",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Str => "str",
+            Self::Array => "array",
+            Self::Object | Self::Other => "object",
+        }
+    }
+}
+
+/// `got` part of a type mismatch message
+fn kind_name(value: &Bound<'_, PyAny>) -> String {
+    match JsonKind::of(value) {
+        JsonKind::Other => value
+            .get_type()
+            .name()
+            .map_or_else(|_| "object".to_string(), |name| name.to_string()),
+        kind => kind.name().to_string(),
+    }
+}
+
+impl Schema {
+    /// could a value of this json kind match (used to pick union members)
+    fn accepts(&self, py: Python<'_>, kind: JsonKind) -> bool {
+        match self {
+            Self::Any => true,
+            Self::None => kind == JsonKind::Null,
+            Self::Bool => kind == JsonKind::Bool,
+            Self::Int => kind == JsonKind::Int,
+            Self::Float => matches!(kind, JsonKind::Int | JsonKind::Float),
+            Self::Str | Self::Ry(_) => kind == JsonKind::Str,
+            Self::Literal(values) => values.iter().any(|v| JsonKind::of(v.bind(py)) == kind),
+            Self::Enum(_) => !matches!(kind, JsonKind::Array | JsonKind::Object | JsonKind::Other),
+            Self::List(_) | Self::Tuple(_) | Self::VarTuple(_) | Self::Set { .. } => {
+                kind == JsonKind::Array
+            }
+            Self::Dict { .. } | Self::Class(_) => kind == JsonKind::Object,
+            Self::Union(members) => members.iter().any(|m| m.accepts(py, kind)),
+        }
+    }
+
+    /// expected json type(s) for error messages
+    fn expected(&self, py: Python<'_>, out: &mut Vec<&'static str>) {
+        let mut push = |name| {
+            if !out.contains(&name) {
+                out.push(name);
+            }
+        };
+        match self {
+            Self::Any => push("any"),
+            Self::None => push("null"),
+            Self::Bool => push("bool"),
+            Self::Int => push("int"),
+            Self::Float => push("float"),
+            Self::Str | Self::Ry(_) => push("str"),
+            Self::Literal(values) => {
+                for v in values {
+                    push(JsonKind::of(v.bind(py)).name());
+                }
+            }
+            Self::Enum(_) => push("enum"),
+            Self::List(_) | Self::Tuple(_) | Self::VarTuple(_) | Self::Set { .. } => {
+                push("array");
+            }
+            Self::Dict { .. } | Self::Class(_) => push("object"),
+            Self::Union(members) => {
+                for m in members {
+                    m.expected(py, out);
+                }
+            }
+        }
+    }
+
+    fn mismatch(&self, value: &Bound<'_, PyAny>) -> DecodeError {
+        let mut expected = Vec::new();
+        self.expected(value.py(), &mut expected);
+        DecodeError::invalid(format!(
+            "Expected `{}`, got `{}`",
+            expected.join(" | "),
+            kind_name(value)
+        ))
+    }
+}
+
+/// int/float spelled by an object key (`"1"`, `"-2.5"`, `"Infinity"`)
+fn number_key<'py>(key: &Bound<'py, PyString>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = key.py();
+    let s = key.to_str()?;
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        // arbitrary size, like json ints
+        return PyInt::type_object(py).call1((key,)).map(Some);
+    }
+    let f = match s {
+        "NaN" => f64::NAN,
+        "Infinity" => f64::INFINITY,
+        "-Infinity" => f64::NEG_INFINITY,
+        _ if digits.starts_with(|c: char| c.is_ascii_digit()) => match s.parse::<f64>() {
+            Ok(f) => f,
+            Err(_) => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(PyFloat::new(py, f).into_any()))
+}
+
+fn array<'a, 'py>(
+    schema: &Schema,
+    value: &'a Bound<'py, PyAny>,
+) -> DecodeResult<&'a Bound<'py, PyList>> {
+    value
+        .cast_exact_opt::<PyList>()
+        .ok_or_else(|| schema.mismatch(value))
+}
+
+impl TypeSchema {
+    /// Convert a parsed json value (dicts, lists and scalars) to the type
+    pub(super) fn convert<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        self.value(&self.root, value)
+            .map_err(|e| e.into_pyerr(value.py()))
+    }
+
+    /// Convert each element of a list of parsed json values (json lines)
+    pub(super) fn convert_list<'py>(
+        &self,
+        list: &Bound<'py, PyList>,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let py = list.py();
+        let items = self.items(&self.root, list).map_err(|e| e.into_pyerr(py))?;
+        PyList::new(py, items)
+    }
+
+    fn value<'py>(
+        &self,
+        schema: &Schema,
+        value: &Bound<'py, PyAny>,
+    ) -> DecodeResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        match schema {
+            Schema::Any => Ok(value.clone()),
+            Schema::None if value.is_none() => Ok(value.clone()),
+            Schema::Bool if value.is_exact_instance_of::<PyBool>() => Ok(value.clone()),
+            Schema::Int if value.is_exact_instance_of::<PyInt>() => Ok(value.clone()),
+            Schema::Float if value.is_exact_instance_of::<PyFloat>() => Ok(value.clone()),
+            Schema::Float if value.is_exact_instance_of::<PyInt>() => {
+                Ok(PyFloat::new(py, value.extract::<f64>()?).into_any())
+            }
+            Schema::Str if value.is_exact_instance_of::<PyString>() => Ok(value.clone()),
+            Schema::Ry(ry) => match value.cast_exact_opt::<PyString>() {
+                Some(s) => ry.parse(py, s.to_str()?).map_err(|e| {
+                    DecodeError::from_constructor(py, e, &format!("Invalid `{}`: ", ry.name()))
+                }),
+                None => Err(schema.mismatch(value)),
+            },
+            Schema::Literal(values) => {
+                for literal in values {
+                    let literal = literal.bind(py);
+                    if literal.get_type().is(value.get_type()) && literal.eq(value)? {
+                        return Ok(literal.clone());
+                    }
+                }
+                Err(DecodeError::invalid(format!(
+                    "Invalid enum value {}",
+                    value.repr()?
+                )))
+            }
+            Schema::Enum(cls) => cls.bind(py).call1((value,)).map_err(|e| {
+                if e.is_instance_of::<PyValueError>(py) {
+                    value.repr().map_or_else(DecodeError::Py, |repr| {
+                        DecodeError::invalid(format!("Invalid enum value {repr}"))
+                    })
+                } else {
+                    DecodeError::Py(e)
+                }
+            }),
+            Schema::List(item) => {
+                let list = array(schema, value)?;
+                Ok(PyList::new(py, self.items(item, list)?)?.into_any())
+            }
+            Schema::VarTuple(item) => {
+                let list = array(schema, value)?;
+                Ok(PyTuple::new(py, self.items(item, list)?)?.into_any())
+            }
+            Schema::Set { item, frozen } => {
+                let list = array(schema, value)?;
+                let items = self.items(item, list)?;
+                if *frozen {
+                    Ok(PyFrozenSet::new(py, items)?.into_any())
+                } else {
+                    Ok(PySet::new(py, items)?.into_any())
+                }
+            }
+            Schema::Tuple(items) => self.tuple(items, array(schema, value)?),
+            Schema::Dict { key, value: item } => match value.cast_exact_opt::<PyDict>() {
+                Some(dict) => self.dict(key, item, dict),
+                None => Err(schema.mismatch(value)),
+            },
+            Schema::Union(members) => self.union(schema, members, value),
+            Schema::Class(index) => match value.cast_exact_opt::<PyDict>() {
+                Some(dict) => self.class(&self.classes[*index], dict),
+                None => Err(schema.mismatch(value)),
+            },
+            _ => Err(schema.mismatch(value)),
+        }
+    }
+
+    fn items<'py>(
+        &self,
+        schema: &Schema,
+        list: &Bound<'py, PyList>,
+    ) -> DecodeResult<Vec<Bound<'py, PyAny>>> {
+        list.iter()
+            .enumerate()
+            .map(|(i, item)| {
+                self.value(schema, &item)
+                    .map_err(|e| e.at(PathItem::Index(i)))
+            })
+            .collect()
+    }
+
+    /// `tuple[A, B]` (fixed length)
+    fn tuple<'py>(
+        &self,
+        items: &[Schema],
+        list: &Bound<'py, PyList>,
+    ) -> DecodeResult<Bound<'py, PyAny>> {
+        if list.len() != items.len() {
+            return Err(DecodeError::invalid(format!(
+                "Expected `array` of length {}, got {}",
+                items.len(),
+                list.len()
+            )));
+        }
+        let values = items
+            .iter()
+            .zip(list.iter())
+            .enumerate()
+            .map(|(i, (item, v))| self.value(item, &v).map_err(|e| e.at(PathItem::Index(i))))
+            .collect::<DecodeResult<Vec<_>>>()?;
+        Ok(PyTuple::new(list.py(), values)?.into_any())
+    }
+
+    fn dict<'py>(
+        &self,
+        key: &Schema,
+        value: &Schema,
+        dict: &Bound<'py, PyDict>,
+    ) -> DecodeResult<Bound<'py, PyAny>> {
+        let out = PyDict::new(dict.py());
+        for (k, v) in dict.iter() {
+            let at_key = |e: DecodeError| e.at(PathItem::Key(k.to_string()));
+            out.set_item(
+                self.key(key, &k).map_err(at_key)?,
+                self.value(value, &v).map_err(at_key)?,
+            )?;
+        }
+        Ok(out.into_any())
+    }
+
+    /// object keys are always json strings; keys that do not convert as a
+    /// string are retried as the number they spell (`dict[int, V]`,
+    /// `dict[float, V]`, int valued enums/literals)
+    fn key<'py>(
+        &self,
+        schema: &Schema,
+        key: &Bound<'py, PyAny>,
+    ) -> DecodeResult<Bound<'py, PyAny>> {
+        let converted = self.value(schema, key);
+        if !matches!(converted, Err(DecodeError::Invalid { .. })) {
+            return converted;
+        }
+        let Some(number) = key
+            .cast_exact_opt::<PyString>()
+            .map(|s| number_key(s))
+            .transpose()?
+            .flatten()
+        else {
+            return converted;
+        };
+        match self.value(schema, &number) {
+            Err(DecodeError::Invalid { .. }) => converted,
+            result => result,
+        }
+    }
+
+    /// first member (of those accepting the json type) that converts; if
+    /// they all fail the error of the first one is raised
+    fn union<'py>(
+        &self,
+        schema: &Schema,
+        members: &[Schema],
+        value: &Bound<'py, PyAny>,
+    ) -> DecodeResult<Bound<'py, PyAny>> {
+        let kind = JsonKind::of(value);
+        let mut first_err = None;
+        for member in members.iter().filter(|m| m.accepts(value.py(), kind)) {
+            match self.value(member, value) {
+                Ok(v) => return Ok(v),
+                Err(e @ DecodeError::Invalid { .. }) => {
+                    first_err.get_or_insert(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(first_err.unwrap_or_else(|| schema.mismatch(value)))
+    }
+
+    fn class<'py>(
+        &self,
+        class: &Class,
+        dict: &Bound<'py, PyDict>,
+    ) -> DecodeResult<Bound<'py, PyAny>> {
+        let py = dict.py();
+        let kwargs = PyDict::new(py);
+        for field in &class.fields {
+            let name = field.name.bind(py);
+            match dict.get_item(name)? {
+                Some(v) => {
+                    let v = self
+                        .value(&field.schema, &v)
+                        .map_err(|e| e.at(PathItem::Key(name.to_string())))?;
+                    kwargs.set_item(name, v)?;
+                }
+                None if field.required => {
+                    return Err(DecodeError::invalid(format!(
+                        "Object missing required field `{name}`"
+                    )));
+                }
+                None => {}
+            }
+        }
+        match class.kind {
+            ClassKind::TypedDict => Ok(kwargs.into_any()),
+            ClassKind::Dataclass => class
+                .cls
+                .bind(py)
+                .call((), Some(&kwargs))
+                .map_err(|e| DecodeError::from_constructor(py, e, "")),
+        }
+    }
+}
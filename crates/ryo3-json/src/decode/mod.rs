@@ -0,0 +1,150 @@
+//! Typed json decoding (`JSON.parse(data, type=...)` and `JSON.JsonDecoder`)
+//!
+//! The json is parsed by jiter and the resulting python objects are then
+//! validated/converted according to a [`TypeSchema`] compiled from the type
+//! annotation; failures raise `JsonValidationError` with the `JSONPath` of
+//! the offending value.
+mod convert;
+mod schema;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use ryo3_jiter::{BigIntMode, FloatMode, JiterParseOptions, PartialMode, StringCacheMode};
+
+use crate::select::with_json_bytes;
+use schema::TypeSchema;
+
+create_exception!(
+    ry.JSON,
+    JsonValidationError,
+    PyValueError,
+    "JSON value does not match the expected type"
+);
+
+/// Decoder for json data into a python type (dataclasses, `TypedDict`s,
+/// containers, unions, literals, enums and ry types); the type is inspected
+/// once on construction
+#[pyclass(name = "JsonDecoder", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.JSON"))]
+pub struct PyJsonDecoder {
+    tp: Py<PyAny>,
+    schema: TypeSchema,
+    options: JiterParseOptions,
+}
+
+#[pymethods]
+impl PyJsonDecoder {
+    #[new]
+    #[pyo3(
+        signature = (
+            r#type,
+            /,
+            *,
+            allow_inf_nan = false,
+            catch_duplicate_keys = false,
+            allow_comments = false,
+            allow_trailing_commas = false,
+        )
+    )]
+    #[expect(clippy::fn_params_excessive_bools, reason = "python kwargs")]
+    fn py_new(
+        r#type: &Bound<'_, PyAny>,
+        allow_inf_nan: bool,
+        catch_duplicate_keys: bool,
+        allow_comments: bool,
+        allow_trailing_commas: bool,
+    ) -> PyResult<Self> {
+        let options = JiterParseOptions::new()
+            .with_allow_inf_nan(allow_inf_nan)
+            .with_catch_duplicate_keys(catch_duplicate_keys)
+            .with_allow_comments(allow_comments)
+            .with_allow_trailing_commas(allow_trailing_commas);
+        Ok(Self {
+            tp: r#type.clone().unbind(),
+            schema: TypeSchema::compile(r#type)?,
+            options,
+        })
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!("JsonDecoder({})", self.tp.bind(py).repr()?))
+    }
+
+    /// the type values are decoded into
+    #[getter]
+    fn r#type(&self, py: Python<'_>) -> Py<PyAny> {
+        self.tp.clone_ref(py)
+    }
+
+    /// parse json (bytes-like or str) and convert it to the type
+    fn decode<'py>(
+        &self,
+        py: Python<'py>,
+        data: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let value = with_json_bytes(data, |buf| self.options.parse(py, buf))?;
+        self.schema.convert(&value)
+    }
+
+    /// parse json lines and convert each line to the type
+    fn decode_lines<'py>(
+        &self,
+        py: Python<'py>,
+        data: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let lines = with_json_bytes(data, |buf| self.options.parse_lines(py, buf))?;
+        self.schema.convert_list(&lines.cast_into::<PyList>()?)
+    }
+
+    /// convert already parsed json (dicts, lists and scalars) to the type
+    fn convert<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        self.schema.convert(obj)
+    }
+}
+
+// creates `JSON.parse`/`JSON.loads` (jiter parsing + optional `type`)
+macro_rules! py_parse_typed_fn {
+    ($name:ident) => {
+        #[pyfunction(
+            signature = (data, /, *, r#type = None, allow_inf_nan = false, cache_mode = StringCacheMode::All, partial_mode = PartialMode::Off, catch_duplicate_keys = false, float_mode = FloatMode::Float, big_int_mode = BigIntMode::Int, lazy_numbers = false, allow_comments = false, allow_trailing_commas = false),
+            text_signature = "(data, *, type=None, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False, allow_comments=False, allow_trailing_commas=False)"
+        )]
+        #[expect(clippy::too_many_arguments, reason = "python kwargs")]
+        pub(crate) fn $name<'py>(
+            py: Python<'py>,
+            data: &Bound<'py, PyAny>,
+            r#type: Option<&Bound<'py, PyAny>>,
+            allow_inf_nan: bool,
+            cache_mode: StringCacheMode,
+            partial_mode: PartialMode,
+            catch_duplicate_keys: bool,
+            float_mode: FloatMode,
+            big_int_mode: BigIntMode,
+            lazy_numbers: bool,
+            allow_comments: bool,
+            allow_trailing_commas: bool,
+        ) -> PyResult<Bound<'py, PyAny>> {
+            let value = ryo3_jiter::parse_json(
+                py,
+                data,
+                allow_inf_nan,
+                cache_mode,
+                partial_mode,
+                catch_duplicate_keys,
+                float_mode,
+                big_int_mode,
+                lazy_numbers,
+                allow_comments,
+                allow_trailing_commas,
+            )?;
+            match r#type {
+                Some(tp) => TypeSchema::compile(tp)?.convert(&value),
+                None => Ok(value),
+            }
+        }
+    };
+}
+py_parse_typed_fn!(parse);
+py_parse_typed_fn!(loads);
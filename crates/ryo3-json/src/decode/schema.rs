@@ -0,0 +1,553 @@
+//! Python type annotations compiled to a [`Schema`] tree
+//!
+//! Type hints are inspected once (with `typing.get_origin`/`get_args` and
+//! `typing.get_type_hints`) so decoding never touches `typing` again.
+//! Dataclasses and `TypedDict`s live in a side table and are referenced by
+//! index, which allows recursive types.
+use std::collections::HashMap;
+
+#[cfg(any(
+    feature = "jiff",
+    feature = "std",
+    feature = "ulid",
+    feature = "url",
+    feature = "uuid"
+))]
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use pyo3::types::{
+    PyBool, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PyNone, PySet, PyString, PyTuple, PyType,
+};
+use pyo3::{PyTypeInfo, intern};
+#[cfg(any(feature = "jiff", feature = "std", feature = "url", feature = "uuid"))]
+use ryo3_core::PyFromStr;
+use ryo3_core::macros::py_type_err;
+
+/// ry types decoded from json strings (each behind the feature of its crate)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RyType {
+    #[cfg(feature = "jiff")]
+    Date,
+    #[cfg(feature = "jiff")]
+    DateTime,
+    #[cfg(feature = "jiff")]
+    SignedDuration,
+    #[cfg(feature = "jiff")]
+    Span,
+    #[cfg(feature = "jiff")]
+    Time,
+    #[cfg(feature = "jiff")]
+    TimeZone,
+    #[cfg(feature = "jiff")]
+    Timestamp,
+    #[cfg(feature = "jiff")]
+    ZonedDateTime,
+    #[cfg(feature = "ulid")]
+    Ulid,
+    #[cfg(feature = "url")]
+    Url,
+    #[cfg(feature = "uuid")]
+    Uuid,
+    #[cfg(feature = "std")]
+    IpAddr,
+    #[cfg(feature = "std")]
+    Ipv4Addr,
+    #[cfg(feature = "std")]
+    Ipv6Addr,
+}
+
+impl RyType {
+    fn from_type(tp: &Bound<'_, PyType>) -> Option<Self> {
+        let ptr = tp.as_type_ptr();
+        let types: &[(*mut pyo3::ffi::PyTypeObject, Self)] = &[
+            #[cfg(feature = "jiff")]
+            (ryo3_jiff::RyDate::type_object_raw(tp.py()), Self::Date),
+            #[cfg(feature = "jiff")]
+            (
+                ryo3_jiff::RyDateTime::type_object_raw(tp.py()),
+                Self::DateTime,
+            ),
+            #[cfg(feature = "jiff")]
+            (
+                ryo3_jiff::RySignedDuration::type_object_raw(tp.py()),
+                Self::SignedDuration,
+            ),
+            #[cfg(feature = "jiff")]
+            (ryo3_jiff::RySpan::type_object_raw(tp.py()), Self::Span),
+            #[cfg(feature = "jiff")]
+            (ryo3_jiff::RyTime::type_object_raw(tp.py()), Self::Time),
+            #[cfg(feature = "jiff")]
+            (
+                ryo3_jiff::RyTimeZone::type_object_raw(tp.py()),
+                Self::TimeZone,
+            ),
+            #[cfg(feature = "jiff")]
+            (
+                ryo3_jiff::RyTimestamp::type_object_raw(tp.py()),
+                Self::Timestamp,
+            ),
+            #[cfg(feature = "jiff")]
+            (
+                ryo3_jiff::RyZoned::type_object_raw(tp.py()),
+                Self::ZonedDateTime,
+            ),
+            #[cfg(feature = "ulid")]
+            (ryo3_ulid::PyUlid::type_object_raw(tp.py()), Self::Ulid),
+            #[cfg(feature = "url")]
+            (ryo3_url::PyUrl::type_object_raw(tp.py()), Self::Url),
+            #[cfg(feature = "uuid")]
+            (ryo3_uuid::PyUuid::type_object_raw(tp.py()), Self::Uuid),
+            #[cfg(feature = "std")]
+            (
+                ryo3_std::net::PyIpAddr::type_object_raw(tp.py()),
+                Self::IpAddr,
+            ),
+            #[cfg(feature = "std")]
+            (
+                ryo3_std::net::PyIpv4Addr::type_object_raw(tp.py()),
+                Self::Ipv4Addr,
+            ),
+            #[cfg(feature = "std")]
+            (
+                ryo3_std::net::PyIpv6Addr::type_object_raw(tp.py()),
+                Self::Ipv6Addr,
+            ),
+        ];
+        types
+            .iter()
+            .find_map(|&(ry_ptr, ry)| (ry_ptr == ptr).then_some(ry))
+    }
+
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "jiff")]
+            Self::Date => "Date",
+            #[cfg(feature = "jiff")]
+            Self::DateTime => "DateTime",
+            #[cfg(feature = "jiff")]
+            Self::SignedDuration => "SignedDuration",
+            #[cfg(feature = "jiff")]
+            Self::Span => "TimeSpan",
+            #[cfg(feature = "jiff")]
+            Self::Time => "Time",
+            #[cfg(feature = "jiff")]
+            Self::TimeZone => "TimeZone",
+            #[cfg(feature = "jiff")]
+            Self::Timestamp => "Timestamp",
+            #[cfg(feature = "jiff")]
+            Self::ZonedDateTime => "ZonedDateTime",
+            #[cfg(feature = "ulid")]
+            Self::Ulid => "ULID",
+            #[cfg(feature = "url")]
+            Self::Url => "URL",
+            #[cfg(feature = "uuid")]
+            Self::Uuid => "UUID",
+            #[cfg(feature = "std")]
+            Self::IpAddr => "IpAddr",
+            #[cfg(feature = "std")]
+            Self::Ipv4Addr => "Ipv4Addr",
+            #[cfg(feature = "std")]
+            Self::Ipv6Addr => "Ipv6Addr",
+        }
+    }
+
+    /// parse the ry type from its string form
+    #[cfg_attr(
+        not(any(
+            feature = "jiff",
+            feature = "std",
+            feature = "ulid",
+            feature = "url",
+            feature = "uuid"
+        )),
+        expect(unused_variables)
+    )]
+    pub(super) fn parse<'py>(self, py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            #[cfg(feature = "jiff")]
+            Self::Date => ryo3_jiff::RyDate::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "jiff")]
+            Self::DateTime => ryo3_jiff::RyDateTime::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "jiff")]
+            Self::SignedDuration => {
+                ryo3_jiff::RySignedDuration::py_from_str(s)?.into_bound_py_any(py)
+            }
+            #[cfg(feature = "jiff")]
+            Self::Span => ryo3_jiff::RySpan::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "jiff")]
+            Self::Time => ryo3_jiff::RyTime::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "jiff")]
+            Self::TimeZone => ryo3_jiff::RyTimeZone::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "jiff")]
+            Self::Timestamp => ryo3_jiff::RyTimestamp::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "jiff")]
+            Self::ZonedDateTime => ryo3_jiff::RyZoned::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "ulid")]
+            Self::Ulid => ryo3_ulid::PyUlid::py_from_string(s)?.into_bound_py_any(py),
+            #[cfg(feature = "url")]
+            Self::Url => ryo3_url::PyUrl::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "uuid")]
+            Self::Uuid => ryo3_uuid::uuid::Uuid::py_from_str(s)
+                .map(ryo3_uuid::PyUuid::from)?
+                .into_bound_py_any(py),
+            #[cfg(feature = "std")]
+            Self::IpAddr => ryo3_std::net::PyIpAddr::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "std")]
+            Self::Ipv4Addr => ryo3_std::net::PyIpv4Addr::py_from_str(s)?.into_bound_py_any(py),
+            #[cfg(feature = "std")]
+            Self::Ipv6Addr => ryo3_std::net::PyIpv6Addr::py_from_str(s)?.into_bound_py_any(py),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) enum Schema {
+    Any,
+    None,
+    Bool,
+    Int,
+    Float,
+    Str,
+    Ry(RyType),
+    /// `typing.Literal[...]` (str/int/bool/None values)
+    Literal(Vec<Py<PyAny>>),
+    /// `enum.Enum` subclass (constructed from its value)
+    Enum(Py<PyType>),
+    List(Box<Self>),
+    /// `tuple[A, B]`
+    Tuple(Vec<Self>),
+    /// `tuple[A, ...]`
+    VarTuple(Box<Self>),
+    Set {
+        item: Box<Self>,
+        frozen: bool,
+    },
+    Dict {
+        key: Box<Self>,
+        value: Box<Self>,
+    },
+    Union(Vec<Self>),
+    /// dataclass or `TypedDict`; index into [`TypeSchema::classes`]
+    Class(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ClassKind {
+    Dataclass,
+    TypedDict,
+}
+
+#[derive(Debug)]
+pub(super) struct Field {
+    pub(super) name: Py<PyString>,
+    pub(super) schema: Schema,
+    pub(super) required: bool,
+}
+
+#[derive(Debug)]
+pub(super) struct Class {
+    pub(super) cls: Py<PyType>,
+    pub(super) kind: ClassKind,
+    pub(super) fields: Vec<Field>,
+}
+
+/// Compiled type: root schema + the dataclasses/`TypedDict`s it references
+#[derive(Debug)]
+pub(super) struct TypeSchema {
+    pub(super) root: Schema,
+    pub(super) classes: Vec<Class>,
+}
+
+impl TypeSchema {
+    pub(super) fn compile(tp: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut compiler = Compiler::new(tp.py())?;
+        let root = compiler.compile(tp)?;
+        Ok(Self {
+            root,
+            classes: compiler.classes,
+        })
+    }
+}
+
+struct Compiler<'py> {
+    typing: Bound<'py, PyModule>,
+    collections_abc: Bound<'py, PyModule>,
+    dataclasses: Bound<'py, PyModule>,
+    enum_type: Bound<'py, PyAny>,
+    classes: Vec<Class>,
+    /// type pointer -> index into `classes`
+    seen: HashMap<usize, usize>,
+}
+
+impl<'py> Compiler<'py> {
+    fn new(py: Python<'py>) -> PyResult<Self> {
+        Ok(Self {
+            typing: py.import(intern!(py, "typing"))?,
+            collections_abc: py.import(intern!(py, "collections.abc"))?,
+            dataclasses: py.import(intern!(py, "dataclasses"))?,
+            enum_type: py
+                .import(intern!(py, "enum"))?
+                .getattr(intern!(py, "Enum"))?,
+            classes: Vec::new(),
+            seen: HashMap::new(),
+        })
+    }
+
+    fn typing_attr(&self, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        self.typing.getattr(name)
+    }
+
+    fn abc_is(&self, origin: &Bound<'py, PyAny>, names: &[&str]) -> PyResult<bool> {
+        for name in names {
+            if origin.is(&self.collections_abc.getattr(*name)?) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn compile(&mut self, tp: &Bound<'py, PyAny>) -> PyResult<Schema> {
+        let py = tp.py();
+        if tp.is_none() || tp.is(PyNone::type_object(py)) {
+            return Ok(Schema::None);
+        }
+        if tp.is(&self.typing_attr("Any")?) || tp.is(PyAny::type_object(py)) {
+            return Ok(Schema::Any);
+        }
+        if let Ok(t) = tp.cast::<PyType>() {
+            if t.is(PyBool::type_object(py)) {
+                return Ok(Schema::Bool);
+            } else if t.is(PyInt::type_object(py)) {
+                return Ok(Schema::Int);
+            } else if t.is(PyFloat::type_object(py)) {
+                return Ok(Schema::Float);
+            } else if t.is(PyString::type_object(py)) {
+                return Ok(Schema::Str);
+            } else if let Some(ry) = RyType::from_type(t) {
+                return Ok(Schema::Ry(ry));
+            }
+        }
+        let origin = self.typing_attr("get_origin")?.call1((tp,))?;
+        if !origin.is_none() {
+            return self.compile_generic(tp, &origin);
+        }
+        if let Ok(t) = tp.cast::<PyType>()
+            && let Some(schema) = self.compile_class(t)?
+        {
+            return Ok(schema);
+        }
+        if tp.is_instance(&self.typing_attr("TypeVar")?)? {
+            return Ok(Schema::Any);
+        }
+        // `typing.NewType`
+        if let Ok(supertype) = tp.getattr(intern!(py, "__supertype__")) {
+            return self.compile(&supertype);
+        }
+        // `type Alias = ...` (python 3.12+)
+        if let Ok(alias_type) = self.typing_attr("TypeAliasType")
+            && tp.is_instance(&alias_type)?
+        {
+            return self.compile(&tp.getattr(intern!(py, "__value__"))?);
+        }
+        py_type_err!("Type `{}` is not supported", tp.repr()?)
+    }
+
+    /// parameterized generic (`list[int]`, `int | None`, `Literal["a"]`...)
+    fn compile_generic(
+        &mut self,
+        tp: &Bound<'py, PyAny>,
+        origin: &Bound<'py, PyAny>,
+    ) -> PyResult<Schema> {
+        let py = tp.py();
+        let args = self
+            .typing_attr("get_args")?
+            .call1((tp,))?
+            .cast_into::<PyTuple>()?;
+        let any = self.typing_attr("Any")?;
+        // missing type arguments (`list`, `dict[str]`...) are `Any`
+        let arg = |i: usize| -> PyResult<Bound<'py, PyAny>> {
+            if args.len() > i {
+                args.get_item(i)
+            } else {
+                Ok(any.clone())
+            }
+        };
+        let union_type = py
+            .import(intern!(py, "types"))?
+            .getattr(intern!(py, "UnionType"))?;
+        if origin.is(&self.typing_attr("Union")?) || origin.is(&union_type) {
+            let members = args
+                .iter()
+                .map(|a| self.compile(&a))
+                .collect::<PyResult<Vec<_>>>()?;
+            if members.iter().any(|m| matches!(m, Schema::Any)) {
+                return Ok(Schema::Any);
+            }
+            return Ok(Schema::Union(members));
+        }
+        if origin.is(&self.typing_attr("Literal")?) {
+            for value in args.iter() {
+                if !(value.is_none()
+                    || value.is_exact_instance_of::<PyString>()
+                    || value.is_exact_instance_of::<PyInt>()
+                    || value.is_exact_instance_of::<PyBool>())
+                {
+                    return py_type_err!(
+                        "Literal values must be str, int, bool or None, got {}",
+                        value.repr()?
+                    );
+                }
+            }
+            return Ok(Schema::Literal(args.iter().map(Bound::unbind).collect()));
+        }
+        if origin.is(&self.typing_attr("Annotated")?) {
+            return self.compile(&arg(0)?);
+        }
+        if origin.is(PyList::type_object(py))
+            || self.abc_is(
+                origin,
+                &["Sequence", "MutableSequence", "Collection", "Iterable"],
+            )?
+        {
+            return Ok(Schema::List(Box::new(self.compile(&arg(0)?)?)));
+        }
+        if origin.is(PyTuple::type_object(py)) {
+            if args.len() == 2 && args.get_item(1)?.is(py.Ellipsis().bind(py)) {
+                return Ok(Schema::VarTuple(Box::new(self.compile(&arg(0)?)?)));
+            }
+            return Ok(Schema::Tuple(
+                args.iter()
+                    .map(|a| self.compile(&a))
+                    .collect::<PyResult<_>>()?,
+            ));
+        }
+        if origin.is(PySet::type_object(py)) || self.abc_is(origin, &["Set", "MutableSet"])? {
+            return Ok(Schema::Set {
+                item: Box::new(self.compile(&arg(0)?)?),
+                frozen: false,
+            });
+        }
+        if origin.is(PyFrozenSet::type_object(py)) {
+            return Ok(Schema::Set {
+                item: Box::new(self.compile(&arg(0)?)?),
+                frozen: true,
+            });
+        }
+        if origin.is(PyDict::type_object(py))
+            || self.abc_is(origin, &["Mapping", "MutableMapping"])?
+        {
+            return Ok(Schema::Dict {
+                key: Box::new(self.compile(&arg(0)?)?),
+                value: Box::new(self.compile(&arg(1)?)?),
+            });
+        }
+        // generic dataclass/`TypedDict`/type alias (type parameters are `Any`)
+        self.compile(origin)
+    }
+
+    /// bare classes: containers, dataclasses, `TypedDict`s and enums
+    fn compile_class(&mut self, tp: &Bound<'py, PyType>) -> PyResult<Option<Schema>> {
+        let py = tp.py();
+        let any = || Box::new(Schema::Any);
+        let schema = if tp.is(PyList::type_object(py)) {
+            Schema::List(any())
+        } else if tp.is(PyTuple::type_object(py)) {
+            Schema::VarTuple(any())
+        } else if tp.is(PySet::type_object(py)) {
+            Schema::Set {
+                item: any(),
+                frozen: false,
+            }
+        } else if tp.is(PyFrozenSet::type_object(py)) {
+            Schema::Set {
+                item: any(),
+                frozen: true,
+            }
+        } else if tp.is(PyDict::type_object(py)) {
+            Schema::Dict {
+                key: any(),
+                value: any(),
+            }
+        } else if self
+            .dataclasses
+            .call_method1(intern!(py, "is_dataclass"), (tp,))?
+            .is_truthy()?
+        {
+            Schema::Class(self.compile_fields(tp, ClassKind::Dataclass)?)
+        } else if self
+            .typing_attr("is_typeddict")?
+            .call1((tp,))?
+            .is_truthy()?
+        {
+            Schema::Class(self.compile_fields(tp, ClassKind::TypedDict)?)
+        } else if tp.is_subclass(&self.enum_type)? {
+            Schema::Enum(tp.clone().unbind())
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(schema))
+    }
+
+    fn compile_fields(&mut self, tp: &Bound<'py, PyType>, kind: ClassKind) -> PyResult<usize> {
+        let key = tp.as_type_ptr() as usize;
+        if let Some(index) = self.seen.get(&key) {
+            return Ok(*index);
+        }
+        // registered before compiling the fields so recursive references
+        // resolve to this class
+        let index = self.classes.len();
+        self.seen.insert(key, index);
+        self.classes.push(Class {
+            cls: tp.clone().unbind(),
+            kind,
+            fields: Vec::new(),
+        });
+        let py = tp.py();
+        let hints = self
+            .typing_attr("get_type_hints")?
+            .call1((tp,))?
+            .cast_into::<PyDict>()?;
+        let mut fields = Vec::new();
+        match kind {
+            ClassKind::Dataclass => {
+                let missing = self.dataclasses.getattr(intern!(py, "MISSING"))?;
+                let dc_fields = self
+                    .dataclasses
+                    .call_method1(intern!(py, "fields"), (tp,))?;
+                for field in dc_fields.try_iter()? {
+                    let field = field?;
+                    if !field.getattr(intern!(py, "init"))?.is_truthy()? {
+                        continue;
+                    }
+                    let name = field
+                        .getattr(intern!(py, "name"))?
+                        .cast_into::<PyString>()?;
+                    let hint = match hints.get_item(&name)? {
+                        Some(hint) => hint,
+                        None => field.getattr(intern!(py, "type"))?,
+                    };
+                    let required = field.getattr(intern!(py, "default"))?.is(&missing)
+                        && field.getattr(intern!(py, "default_factory"))?.is(&missing);
+                    fields.push(Field {
+                        name: name.unbind(),
+                        schema: self.compile(&hint)?,
+                        required,
+                    });
+                }
+            }
+            ClassKind::TypedDict => {
+                let required_keys = tp.getattr(intern!(py, "__required_keys__"))?;
+                for (name, hint) in hints.iter() {
+                    let required = required_keys.contains(&name)?;
+                    fields.push(Field {
+                        name: name.cast_into::<PyString>()?.unbind(),
+                        schema: self.compile(&hint)?,
+                        required,
+                    });
+                }
+            }
+        }
+        self.classes[index].fields = fields;
+        Ok(index)
+    }
+}
@@ -0,0 +1,264 @@
+//! RFC 6902 (JSON Patch) and RFC 7386 (JSON Merge Patch) application
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+use super::{JsonPatchError, JsonPatchTestError, deep_copy, json_eq};
+use crate::select::pointer::{parse_index, parse_pointer};
+
+/// where an operation's target lives
+enum Location<'py> {
+    Root,
+    Key(Bound<'py, PyDict>, String),
+    Index(Bound<'py, PyList>, usize),
+    /// `-` (past the end of an array; `add` only)
+    End(Bound<'py, PyList>),
+}
+
+fn child<'py>(parent: &Bound<'py, PyAny>, token: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if let Ok(dict) = parent.cast::<PyDict>() {
+        dict.get_item(token)
+    } else if let Ok(list) = parent.cast::<PyList>() {
+        match parse_index(token) {
+            Some(i) if i < list.len() => list.get_item(i).map(Some),
+            _ => Ok(None),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+/// A single operation being applied; carries its index and the operation
+/// itself for error reporting
+struct Operation<'a, 'py> {
+    index: usize,
+    op: &'a Bound<'py, PyDict>,
+    name: String,
+}
+
+impl<'py> Operation<'_, 'py> {
+    fn error(&self, msg: &str) -> PyErr {
+        let err =
+            JsonPatchError::new_err(format!("operation {} ({}): {msg}", self.index, self.name));
+        self.set_attrs(&err);
+        err
+    }
+
+    fn set_attrs(&self, err: &PyErr) {
+        let value = err.value(self.op.py());
+        // attributes on a freshly created exception never fail to set
+        let _ = value.setattr("index", self.index);
+        let _ = value.setattr("op", self.op);
+    }
+
+    fn member(&self, key: &str) -> PyResult<Bound<'py, PyAny>> {
+        self.op
+            .get_item(key)?
+            .ok_or_else(|| self.error(&format!("missing member {key:?}")))
+    }
+
+    fn str_member(&self, key: &str) -> PyResult<String> {
+        self.member(key)?
+            .extract::<String>()
+            .map_err(|_| self.error(&format!("member {key:?} must be a string")))
+    }
+
+    fn locate(&self, doc: &Bound<'py, PyAny>, pointer: &str) -> PyResult<Location<'py>> {
+        let tokens = parse_pointer(pointer).map_err(|e| self.error(&e.to_string()))?;
+        let Some((last, parents)) = tokens.split_last() else {
+            return Ok(Location::Root);
+        };
+        let mut parent = doc.clone();
+        for token in parents {
+            parent = child(&parent, token)?
+                .ok_or_else(|| self.error(&format!("path {pointer:?} does not exist")))?;
+        }
+        if let Ok(dict) = parent.cast::<PyDict>() {
+            Ok(Location::Key(dict.clone(), last.to_string()))
+        } else if let Ok(list) = parent.cast::<PyList>() {
+            if last == "-" {
+                Ok(Location::End(list.clone()))
+            } else if let Some(i) = parse_index(last) {
+                Ok(Location::Index(list.clone(), i))
+            } else {
+                Err(self.error(&format!("invalid array index {last:?} in {pointer:?}")))
+            }
+        } else {
+            Err(self.error(&format!("path {pointer:?} does not exist")))
+        }
+    }
+
+    fn get(&self, doc: &Bound<'py, PyAny>, pointer: &str) -> PyResult<Bound<'py, PyAny>> {
+        let missing = || self.error(&format!("path {pointer:?} does not exist"));
+        match self.locate(doc, pointer)? {
+            Location::Root => Ok(doc.clone()),
+            Location::Key(dict, key) => dict.get_item(key)?.ok_or_else(missing),
+            Location::Index(list, i) if i < list.len() => list.get_item(i),
+            Location::Index(..) | Location::End(_) => Err(missing()),
+        }
+    }
+
+    fn add(
+        &self,
+        doc: Bound<'py, PyAny>,
+        pointer: &str,
+        value: Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match self.locate(&doc, pointer)? {
+            Location::Root => return Ok(value),
+            Location::Key(dict, key) => dict.set_item(key, value)?,
+            Location::Index(list, i) if i <= list.len() => list.insert(i, value)?,
+            Location::Index(..) => {
+                return Err(self.error(&format!("array index out of range in {pointer:?}")));
+            }
+            Location::End(list) => list.append(value)?,
+        }
+        Ok(doc)
+    }
+
+    fn remove(&self, doc: &Bound<'py, PyAny>, pointer: &str) -> PyResult<Bound<'py, PyAny>> {
+        let missing = || self.error(&format!("path {pointer:?} does not exist"));
+        match self.locate(doc, pointer)? {
+            Location::Root => Err(self.error("cannot remove the root document")),
+            Location::Key(dict, key) => {
+                let value = dict.get_item(&key)?.ok_or_else(missing)?;
+                dict.del_item(key)?;
+                Ok(value)
+            }
+            Location::Index(list, i) if i < list.len() => {
+                let value = list.get_item(i)?;
+                list.del_item(i)?;
+                Ok(value)
+            }
+            Location::Index(..) | Location::End(_) => Err(missing()),
+        }
+    }
+
+    fn replace(
+        &self,
+        doc: Bound<'py, PyAny>,
+        pointer: &str,
+        value: Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let missing = || self.error(&format!("path {pointer:?} does not exist"));
+        match self.locate(&doc, pointer)? {
+            Location::Root => return Ok(value),
+            Location::Key(dict, key) => {
+                if !dict.contains(&key)? {
+                    return Err(missing());
+                }
+                dict.set_item(key, value)?;
+            }
+            Location::Index(list, i) if i < list.len() => list.set_item(i, value)?,
+            Location::Index(..) | Location::End(_) => return Err(missing()),
+        }
+        Ok(doc)
+    }
+
+    fn test(&self, doc: &Bound<'py, PyAny>, pointer: &str) -> PyResult<()> {
+        let expected = self.member("value")?;
+        let actual = self.get(doc, pointer)?;
+        if json_eq(&actual, &expected)? {
+            return Ok(());
+        }
+        let err = JsonPatchTestError::new_err(format!(
+            "operation {} (test): value at {pointer:?} does not match",
+            self.index
+        ));
+        self.set_attrs(&err);
+        let value = err.value(doc.py());
+        let _ = value.setattr("path", pointer);
+        let _ = value.setattr("expected", expected);
+        let _ = value.setattr("actual", actual);
+        Err(err)
+    }
+
+    fn apply(&self, doc: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let path = self.str_member("path")?;
+        match self.name.as_str() {
+            "add" => self.add(doc, &path, self.member("value")?),
+            "remove" => {
+                self.remove(&doc, &path)?;
+                Ok(doc)
+            }
+            "replace" => self.replace(doc, &path, self.member("value")?),
+            "move" => {
+                let from = self.str_member("from")?;
+                if from == path {
+                    self.get(&doc, &from)?;
+                    return Ok(doc);
+                }
+                if path.starts_with(&format!("{from}/")) {
+                    return Err(self.error(&format!(
+                        "cannot move {from:?} into one of its children ({path:?})"
+                    )));
+                }
+                let value = self.remove(&doc, &from)?;
+                self.add(doc, &path, value)
+            }
+            "copy" => {
+                let from = self.str_member("from")?;
+                let value = deep_copy(&self.get(&doc, &from)?)?;
+                self.add(doc, &path, value)
+            }
+            "test" => {
+                self.test(&doc, &path)?;
+                Ok(doc)
+            }
+            _ => Err(self.error("unknown operation")),
+        }
+    }
+}
+
+pub(super) fn apply_patch<'py>(
+    mut doc: Bound<'py, PyAny>,
+    ops: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let Ok(ops) = ops.cast::<PyList>() else {
+        return Err(JsonPatchError::new_err(
+            "JSON Patch must be a list of operations",
+        ));
+    };
+    for (index, op) in ops.iter().enumerate() {
+        let Ok(op) = op.cast::<PyDict>() else {
+            return Err(JsonPatchError::new_err(format!(
+                "operation {index}: must be an object"
+            )));
+        };
+        let name = match op.get_item("op")? {
+            Some(name) if name.is_instance_of::<PyString>() => name.extract::<String>()?,
+            _ => {
+                return Err(JsonPatchError::new_err(format!(
+                    "operation {index}: missing or invalid member \"op\""
+                )));
+            }
+        };
+        doc = Operation { index, op, name }.apply(doc)?;
+    }
+    Ok(doc)
+}
+
+pub(super) fn merge_patch<'py>(
+    doc: Bound<'py, PyAny>,
+    patch: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let Ok(patch) = patch.cast::<PyDict>() else {
+        return Ok(patch.clone());
+    };
+    let target = match doc.cast_into::<PyDict>() {
+        Ok(dict) => dict,
+        Err(_) => PyDict::new(patch.py()),
+    };
+    for (key, value) in patch.iter() {
+        if value.is_none() {
+            if target.contains(&key)? {
+                target.del_item(&key)?;
+            }
+        } else {
+            let current = target
+                .get_item(&key)?
+                .unwrap_or_else(|| patch.py().None().into_bound(patch.py()));
+            target.set_item(&key, merge_patch(current, &value)?)?;
+        }
+    }
+    Ok(target.into_any())
+}
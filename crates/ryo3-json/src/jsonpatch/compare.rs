@@ -0,0 +1,84 @@
+//! structural diff producing RFC 6902 operations
+//!
+//! Objects are compared key by key and arrays index by index; extra array
+//! elements are removed from the end (highest index first) or appended.
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use super::json_eq;
+
+/// escape a reference token (`~` -> `~0`, `/` -> `~1`)
+fn escape_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn push_op<'py>(
+    ops: &Bound<'py, PyList>,
+    op: &str,
+    path: &str,
+    value: Option<&Bound<'py, PyAny>>,
+) -> PyResult<()> {
+    let py = ops.py();
+    let entry = PyDict::new(py);
+    entry.set_item("op", op)?;
+    entry.set_item("path", path)?;
+    if let Some(value) = value {
+        entry.set_item("value", value)?;
+    }
+    ops.append(entry)
+}
+
+fn diff_into<'py>(
+    ops: &Bound<'py, PyList>,
+    path: &str,
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    if let (Ok(a), Ok(b)) = (a.cast::<PyDict>(), b.cast::<PyDict>()) {
+        for (k, av) in a.iter() {
+            let child = format!("{path}/{}", escape_token(&k.extract::<String>()?));
+            match b.get_item(&k)? {
+                Some(bv) => diff_into(ops, &child, &av, &bv)?,
+                None => push_op(ops, "remove", &child, None)?,
+            }
+        }
+        for (k, bv) in b.iter() {
+            if !a.contains(&k)? {
+                let child = format!("{path}/{}", escape_token(&k.extract::<String>()?));
+                push_op(ops, "add", &child, Some(&bv))?;
+            }
+        }
+        return Ok(());
+    }
+    if let (Ok(a), Ok(b)) = (a.cast::<PyList>(), b.cast::<PyList>()) {
+        let common = a.len().min(b.len());
+        for i in 0..common {
+            diff_into(
+                ops,
+                &format!("{path}/{i}"),
+                &a.get_item(i)?,
+                &b.get_item(i)?,
+            )?;
+        }
+        for i in (common..a.len()).rev() {
+            push_op(ops, "remove", &format!("{path}/{i}"), None)?;
+        }
+        for i in common..b.len() {
+            push_op(ops, "add", &format!("{path}/{i}"), Some(&b.get_item(i)?))?;
+        }
+        return Ok(());
+    }
+    if !json_eq(a, b)? {
+        push_op(ops, "replace", path, Some(b))?;
+    }
+    Ok(())
+}
+
+pub(super) fn diff<'py>(
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyList>> {
+    let ops = PyList::empty(a.py());
+    diff_into(&ops, "", a, b)?;
+    Ok(ops)
+}
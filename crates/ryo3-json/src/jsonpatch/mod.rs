@@ -0,0 +1,127 @@
+//! JSON Patch (RFC 6902), JSON Merge Patch (RFC 7386) and structural diff
+//!
+//! Inputs are converted to fresh json-typed python objects (serialized with
+//! `ryo3-serde` and parsed back with jiter); patches are applied to those
+//! copies so the caller's objects are never mutated.
+mod apply;
+mod compare;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use ryo3_jiter::JiterParseOptions;
+use ryo3_serde::PyAnySerializer;
+
+use crate::serialize::map_serde_json_err;
+
+create_exception!(
+    ry.JSON,
+    JsonPatchError,
+    PyValueError,
+    "JSON Patch operation failed (invalid operation or missing path)"
+);
+
+create_exception!(
+    ry.JSON,
+    JsonPatchTestError,
+    JsonPatchError,
+    "JSON Patch `test` operation failed"
+);
+
+/// Convert `obj` to a new json-typed python object (dict/list/str/int/
+/// float/bool/None)
+fn to_json_value<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let s = PyAnySerializer::new_json(obj.as_borrowed(), None);
+    let bytes = serde_json::to_vec(&s).map_err(map_serde_json_err)?;
+    JiterParseOptions::new().parse(obj.py(), &bytes)
+}
+
+/// Deep copy of a json-typed python object (only containers are copied)
+fn deep_copy<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    if let Ok(dict) = value.cast::<PyDict>() {
+        let copy = PyDict::new(py);
+        for (k, v) in dict.iter() {
+            copy.set_item(k, deep_copy(&v)?)?;
+        }
+        Ok(copy.into_any())
+    } else if let Ok(list) = value.cast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|v| deep_copy(&v))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.into_any())
+    } else {
+        Ok(value.clone())
+    }
+}
+
+/// json equality: numbers compare by value, but `true`/`false` are not
+/// numbers and dict key order does not matter
+fn json_eq(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let a_bool = a.is_instance_of::<pyo3::types::PyBool>();
+    let b_bool = b.is_instance_of::<pyo3::types::PyBool>();
+    if a_bool || b_bool {
+        return Ok(a_bool && b_bool && a.is_truthy()? == b.is_truthy()?);
+    }
+    match (a.cast::<PyDict>(), b.cast::<PyDict>()) {
+        (Ok(a), Ok(b)) => {
+            if a.len() != b.len() {
+                return Ok(false);
+            }
+            for (k, av) in a.iter() {
+                match b.get_item(&k)? {
+                    Some(bv) if json_eq(&av, &bv)? => {}
+                    _ => return Ok(false),
+                }
+            }
+            return Ok(true);
+        }
+        (Ok(_), Err(_)) | (Err(_), Ok(_)) => return Ok(false),
+        (Err(_), Err(_)) => {}
+    }
+    match (a.cast::<PyList>(), b.cast::<PyList>()) {
+        (Ok(a), Ok(b)) => {
+            if a.len() != b.len() {
+                return Ok(false);
+            }
+            for (av, bv) in a.iter().zip(b.iter()) {
+                if !json_eq(&av, &bv)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        (Ok(_), Err(_)) | (Err(_), Ok(_)) => Ok(false),
+        (Err(_), Err(_)) => a.eq(b),
+    }
+}
+
+/// Return the JSON Patch (list of RFC 6902 operations) that turns `a` into `b`
+#[pyfunction(signature = (a, b, /))]
+pub fn diff<'py>(a: &Bound<'py, PyAny>, b: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyList>> {
+    let a = to_json_value(a)?;
+    let b = to_json_value(b)?;
+    compare::diff(&a, &b)
+}
+
+/// Apply the JSON Patch `ops` (RFC 6902) to `doc` and return the new document
+#[pyfunction(signature = (doc, ops, /))]
+pub fn patch<'py>(doc: &Bound<'py, PyAny>, ops: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let doc = to_json_value(doc)?;
+    let ops = to_json_value(ops)?;
+    apply::apply_patch(doc, &ops)
+}
+
+/// Apply the JSON Merge Patch `patch` (RFC 7386) to `doc` and return the new
+/// document
+#[pyfunction(signature = (doc, patch, /))]
+pub fn merge_patch<'py>(
+    doc: &Bound<'py, PyAny>,
+    patch: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let doc = to_json_value(doc)?;
+    let patch = to_json_value(patch)?;
+    apply::merge_patch(doc, &patch)
+}
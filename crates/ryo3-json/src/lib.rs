@@ -1,19 +1,52 @@
 #![doc = include_str!("../README.md")]
 
+mod canonical;
+mod decode;
+mod jsonpatch;
 pub mod orjson;
+mod select;
 mod serialize;
+mod stream;
 mod transcode;
+mod write;
 
+pub use canonical::canonicalize;
+pub use decode::{JsonValidationError, PyJsonDecoder};
+pub use jsonpatch::{JsonPatchError, JsonPatchTestError, diff, merge_patch, patch};
 use pyo3::prelude::*;
-pub use serialize::{dumps, stringify, to_vec};
+pub use select::{get, query};
+pub use serialize::{JsonIndent, JsonOptions, dumps, stringify, to_vec, to_vec_with};
+pub use stream::{PyJsonEventReader, PyJsonItemsIter, iter_items};
+pub use write::{JsonLinesCompression, JsonLinesEncoder, PyJsonLinesWriter};
 
 pub fn py_submod_register(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(serialize::stringify, m)?)?;
     m.add_function(wrap_pyfunction!(serialize::dumps, m)?)?;
     m.add_function(wrap_pyfunction!(transcode::minify, m)?)?;
     m.add_function(wrap_pyfunction!(transcode::fmt, m)?)?;
-    m.add_function(wrap_pyfunction!(ryo3_jiter::parse, m)?)?;
-    m.add_function(wrap_pyfunction!(ryo3_jiter::loads, m)?)?;
+    m.add_function(wrap_pyfunction!(canonical::canonicalize, m)?)?;
+    m.add_function(wrap_pyfunction!(stream::iter_items, m)?)?;
+    m.add_function(wrap_pyfunction!(select::get, m)?)?;
+    m.add_function(wrap_pyfunction!(select::query, m)?)?;
+    m.add_function(wrap_pyfunction!(jsonpatch::diff, m)?)?;
+    m.add_function(wrap_pyfunction!(jsonpatch::patch, m)?)?;
+    m.add_function(wrap_pyfunction!(jsonpatch::merge_patch, m)?)?;
+    m.add("JsonPatchError", m.py().get_type::<JsonPatchError>())?;
+    m.add(
+        "JsonPatchTestError",
+        m.py().get_type::<JsonPatchTestError>(),
+    )?;
+    m.add_class::<PyJsonEventReader>()?;
+    m.add_class::<PyJsonItemsIter>()?;
+    m.add_function(wrap_pyfunction!(write::write, m)?)?;
+    m.add_class::<PyJsonLinesWriter>()?;
+    m.add_function(wrap_pyfunction!(decode::parse, m)?)?;
+    m.add_function(wrap_pyfunction!(decode::loads, m)?)?;
+    m.add_class::<PyJsonDecoder>()?;
+    m.add(
+        "JsonValidationError",
+        m.py().get_type::<JsonValidationError>(),
+    )?;
     m.add_function(wrap_pyfunction!(ryo3_jiter::cache_clear, m)?)?;
     m.add_function(wrap_pyfunction!(ryo3_jiter::cache_usage, m)?)?;
     Ok(())
@@ -0,0 +1,65 @@
+//! Querying json without materializing it (`JSON.get` and `JSON.query`)
+//!
+//! Only the selected values are converted to python objects (by jiter, so
+//! they share its string cache).
+mod path;
+pub(crate) mod pointer;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyString};
+use ryo3_bytes::ReadableBuffer;
+use ryo3_core::PyCastExactOpt;
+use ryo3_core::macros::{py_type_err, py_value_error};
+use ryo3_jiter::JiterParseOptions;
+use serde_json_path::JsonPath;
+
+pub(crate) fn with_json_bytes<R>(
+    data: &Bound<'_, PyAny>,
+    f: impl FnOnce(&[u8]) -> PyResult<R>,
+) -> PyResult<R> {
+    if let Some(s) = data.cast_exact_opt::<PyString>() {
+        f(s.to_str()?.as_bytes())
+    } else if let Ok(buf) = data.extract::<ReadableBuffer>() {
+        f(buf.as_slice())
+    } else {
+        py_type_err!("Expected bytes-like, bytearray, pyo3-bytes object or str")
+    }
+}
+
+/// Return the value at the RFC 6901 json `pointer` (e.g. `"/items/0/id"`)
+/// or `default` if it does not exist
+#[pyfunction(signature = (data, pointer, /, default = None))]
+pub fn get<'py>(
+    py: Python<'py>,
+    data: &Bound<'py, PyAny>,
+    pointer: &str,
+    default: Option<Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let tokens = pointer::parse_pointer(pointer)?;
+    with_json_bytes(data, |buf| match pointer::resolve(buf, &tokens)? {
+        Some(raw) => JiterParseOptions::new().parse(py, raw),
+        None => Ok(default.unwrap_or_else(|| py.None().into_bound(py))),
+    })
+}
+
+/// Return the values selected by the RFC 9535 `JSONPath` `path` (e.g.
+/// `"$.items[*].id"`)
+///
+/// Paths of only name/index/wildcard selectors scan the raw bytes; other
+/// paths (filters, slices, descendants, ...) parse the whole document.
+#[pyfunction(signature = (data, path, /))]
+pub fn query<'py>(
+    py: Python<'py>,
+    data: &Bound<'py, PyAny>,
+    path: &str,
+) -> PyResult<Bound<'py, PyList>> {
+    let json_path = JsonPath::parse(path).map_err(|e| py_value_error!("invalid JSONPath: {e}"))?;
+    with_json_bytes(data, |buf| {
+        let parser = JiterParseOptions::new();
+        let nodes = path::query(buf, path, &json_path)?
+            .into_iter()
+            .map(|raw| parser.parse(py, raw))
+            .collect::<PyResult<Vec<_>>>()?;
+        PyList::new(py, nodes)
+    })
+}
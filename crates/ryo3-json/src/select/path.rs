@@ -0,0 +1,243 @@
+//! RFC 9535 `JSONPath` queries that return the raw json of the matches
+//!
+//! Paths made of only name/index/wildcard child selectors (e.g.
+//! `$.items[*].id`) are evaluated by scanning the raw bytes like
+//! `JSON.get`. Any other path (filters, slices, descendants, ...) is
+//! evaluated on a `serde_json::Value` of the whole document and the matched
+//! nodes are mapped back to their byte spans in the source. Either way the
+//! matches are parsed by jiter (object key order and big ints are
+//! preserved).
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use pyo3::prelude::*;
+use ryo3_core::macros::py_value_error;
+use serde_json::Value;
+use serde_json_path::JsonPath;
+
+use super::pointer::Scanner;
+
+type Spans = HashMap<*const Value, (usize, usize)>;
+
+/// child selector of a path that can be evaluated on the raw bytes
+enum Selector<'p> {
+    Name(&'p str),
+    Index(i64),
+    Wildcard,
+}
+
+/// Split `path` into child selectors; `None` if it uses anything else
+///
+/// `path` has already been validated by `JsonPath::parse`, so only the
+/// simple forms need to be recognized; paths with blank space or quoted
+/// names with escapes are left to the `Value` evaluation.
+fn parse_simple(path: &str) -> Option<Vec<Selector<'_>>> {
+    if path.bytes().any(|b| b.is_ascii_whitespace() || b == b'\\') {
+        return None;
+    }
+    let mut rest = path.strip_prefix('$')?;
+    let mut selectors = Vec::new();
+    while !rest.is_empty() {
+        let selector;
+        if let Some(r) = rest.strip_prefix(".*") {
+            selector = Selector::Wildcard;
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('.') {
+            let end = r.find(['.', '[']).unwrap_or(r.len());
+            if end == 0 {
+                // descendant segment (`..`)
+                return None;
+            }
+            selector = Selector::Name(&r[..end]);
+            rest = &r[end..];
+        } else if let Some(r) = rest.strip_prefix('[') {
+            let (inner, r) = match r.as_bytes().first() {
+                Some(&quote @ (b'\'' | b'"')) => {
+                    let end = r[1..].find(char::from(quote))? + 1;
+                    (&r[..=end], r[end + 1..].strip_prefix(']')?)
+                }
+                _ => r.split_once(']')?,
+            };
+            selector = match inner.as_bytes() {
+                [b'*'] => Selector::Wildcard,
+                [b'\'' | b'"', .., b'\'' | b'"'] => Selector::Name(&inner[1..inner.len() - 1]),
+                _ => Selector::Index(inner.parse().ok()?),
+            };
+            rest = r;
+        } else {
+            return None;
+        }
+        selectors.push(selector);
+    }
+    Some(selectors)
+}
+
+/// after `{` or `[`: the keys (`None` for arrays) and value offsets of the
+/// container's children
+fn children<'a>(scanner: &mut Scanner<'a>) -> PyResult<Vec<(Option<Cow<'a, str>>, usize)>> {
+    let close = if scanner.peek()? == b'{' { b'}' } else { b']' };
+    scanner.pos += 1;
+    let mut out = Vec::new();
+    if scanner.peek()? == close {
+        scanner.pos += 1;
+        return Ok(out);
+    }
+    loop {
+        let key = if close == b'}' {
+            let key = scanner.key()?;
+            scanner.expect(b':')?;
+            Some(key)
+        } else {
+            None
+        };
+        scanner.peek()?;
+        out.push((key, scanner.pos));
+        scanner.skip_value()?;
+        if scanner.peek()? == b',' {
+            scanner.pos += 1;
+        } else {
+            scanner.expect(close)?;
+            return Ok(out);
+        }
+    }
+}
+
+/// evaluate child `selectors` by scanning `buf`; the skipped parts of the
+/// document are not validated
+fn query_raw<'a>(buf: &'a [u8], selectors: &[Selector<'_>]) -> PyResult<Vec<&'a [u8]>> {
+    let mut scanner = Scanner::new(buf);
+    scanner.peek()?;
+    let mut nodes = vec![scanner.pos];
+    for selector in selectors {
+        let mut next = Vec::new();
+        for &start in &nodes {
+            scanner.pos = start;
+            match (selector, scanner.peek()?) {
+                (Selector::Name(name), b'{') => {
+                    // duplicate keys: the last one wins
+                    let found = children(&mut scanner)?
+                        .into_iter()
+                        .rfind(|(key, _)| key.as_deref() == Some(*name));
+                    next.extend(found.map(|(_, pos)| pos));
+                }
+                (Selector::Index(index), b'[') => {
+                    let items = children(&mut scanner)?;
+                    // negative indexes count from the end
+                    let len = i64::try_from(items.len()).unwrap_or(i64::MAX);
+                    let index = if *index < 0 { len + index } else { *index };
+                    let item = usize::try_from(index).ok().and_then(|i| items.get(i));
+                    next.extend(item.map(|(_, pos)| *pos));
+                }
+                (Selector::Wildcard, b'[') => {
+                    next.extend(children(&mut scanner)?.into_iter().map(|(_, pos)| pos));
+                }
+                (Selector::Wildcard, b'{') => {
+                    // duplicate keys: first position, last value (as a dict)
+                    let members = children(&mut scanner)?;
+                    let mut seen = HashMap::with_capacity(members.len());
+                    let mut values = Vec::with_capacity(members.len());
+                    for (key, pos) in members {
+                        match seen.entry(key) {
+                            Entry::Occupied(e) => values[*e.get()] = pos,
+                            Entry::Vacant(e) => {
+                                e.insert(values.len());
+                                values.push(pos);
+                            }
+                        }
+                    }
+                    next.extend(values);
+                }
+                _ => {}
+            }
+        }
+        nodes = next;
+    }
+    nodes
+        .into_iter()
+        .map(|start| {
+            scanner.pos = start;
+            scanner.skip_value()?;
+            Ok(&buf[start..scanner.pos])
+        })
+        .collect()
+}
+
+/// Raw json of the values selected by `path` (in query order)
+pub(crate) fn query<'a>(buf: &'a [u8], path_str: &str, path: &JsonPath) -> PyResult<Vec<&'a [u8]>> {
+    if let Some(selectors) = parse_simple(path_str) {
+        // on a scan error fall through so that invalid json raises the
+        // same error as for the other paths
+        if let Ok(nodes) = query_raw(buf, &selectors) {
+            return Ok(nodes);
+        }
+    }
+    let value = serde_json::from_slice::<Value>(buf)
+        .map_err(|e| py_value_error!("Failed to parse JSON: {e}"))?;
+    let nodes = path.query(&value);
+    if nodes.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut spans = Spans::new();
+    record_spans(&mut Scanner::new(buf), &value, &mut spans)?;
+    nodes
+        .iter()
+        .map(|node| {
+            let (start, end) = spans[&std::ptr::from_ref(*node)];
+            Ok(&buf[start..end])
+        })
+        .collect()
+}
+
+/// walk the source alongside `value` and record the span of every node
+fn record_spans(scanner: &mut Scanner<'_>, value: &Value, spans: &mut Spans) -> PyResult<()> {
+    let start = match (scanner.peek()?, value) {
+        (b'{', Value::Object(map)) => {
+            let start = scanner.pos;
+            scanner.pos += 1;
+            if scanner.peek()? == b'}' {
+                scanner.pos += 1;
+            } else {
+                loop {
+                    let key = scanner.key()?;
+                    scanner.expect(b':')?;
+                    // duplicate keys: the last one wins (as in `map`)
+                    match map.get(key.as_ref()) {
+                        Some(member) => record_spans(scanner, member, spans)?,
+                        None => scanner.skip_value()?,
+                    }
+                    if scanner.peek()? == b',' {
+                        scanner.pos += 1;
+                    } else {
+                        scanner.expect(b'}')?;
+                        break;
+                    }
+                }
+            }
+            start
+        }
+        (b'[', Value::Array(items)) => {
+            let start = scanner.pos;
+            scanner.pos += 1;
+            if scanner.peek()? == b']' {
+                scanner.pos += 1;
+            } else {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        scanner.expect(b',')?;
+                    }
+                    record_spans(scanner, item, spans)?;
+                }
+                scanner.expect(b']')?;
+            }
+            start
+        }
+        _ => {
+            let start = scanner.pos;
+            scanner.skip_value()?;
+            start
+        }
+    };
+    spans.insert(std::ptr::from_ref(value), (start, scanner.pos));
+    Ok(())
+}
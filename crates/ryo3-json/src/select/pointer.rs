@@ -0,0 +1,229 @@
+//! RFC 6901 json pointer lookup over raw json bytes
+//!
+//! The document is scanned (not parsed) up to the target value; values
+//! that are not on the way to the target are skipped without validation.
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use ryo3_core::macros::{py_value_err, py_value_error};
+
+/// Split a json pointer into its unescaped reference tokens
+pub(crate) fn parse_pointer(pointer: &str) -> PyResult<Vec<Cow<'_, str>>> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return py_value_err!("json pointer must be empty or start with '/': {pointer:?}");
+    };
+    rest.split('/')
+        .map(|token| {
+            if !token.contains('~') {
+                return Ok(Cow::Borrowed(token));
+            }
+            let mut unescaped = String::with_capacity(token.len());
+            let mut chars = token.chars();
+            while let Some(c) = chars.next() {
+                if c == '~' {
+                    match chars.next() {
+                        Some('0') => unescaped.push('~'),
+                        Some('1') => unescaped.push('/'),
+                        _ => {
+                            return py_value_err!("invalid escape in json pointer: {pointer:?}");
+                        }
+                    }
+                } else {
+                    unescaped.push(c);
+                }
+            }
+            Ok(Cow::Owned(unescaped))
+        })
+        .collect()
+}
+
+/// array index token (`0` or digits without a leading zero)
+pub(crate) fn parse_index(token: &str) -> Option<usize> {
+    if token.is_empty()
+        || (token.len() > 1 && token.starts_with('0'))
+        || !token.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    token.parse().ok()
+}
+
+pub(crate) struct Scanner<'a> {
+    buf: &'a [u8],
+    pub(crate) pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn error<T>(&self, msg: &str) -> PyResult<T> {
+        py_value_err!("invalid json: {} at byte {}", msg, self.pos)
+    }
+
+    fn skip_ws(&mut self) {
+        while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    pub(crate) fn peek(&mut self) -> PyResult<u8> {
+        self.skip_ws();
+        match self.buf.get(self.pos) {
+            Some(b) => Ok(*b),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    pub(crate) fn expect(&mut self, byte: u8) -> PyResult<()> {
+        if self.peek()? == byte {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("expected '{}'", byte as char))
+        }
+    }
+
+    /// skip a string starting at `self.pos` (the opening quote); returns the
+    /// span of the string contents and whether it contains escapes
+    fn skip_string(&mut self) -> PyResult<(usize, usize, bool)> {
+        let start = self.pos + 1;
+        let mut escaped = false;
+        let mut i = start;
+        while i < self.buf.len() {
+            match self.buf[i] {
+                b'\\' => {
+                    escaped = true;
+                    i += 2;
+                }
+                b'"' => {
+                    self.pos = i + 1;
+                    return Ok((start, i, escaped));
+                }
+                _ => i += 1,
+            }
+        }
+        self.pos = self.buf.len();
+        self.error("unterminated string")
+    }
+
+    pub(crate) fn skip_value(&mut self) -> PyResult<()> {
+        match self.peek()? {
+            b'"' => self.skip_string().map(|_| ()),
+            b'{' | b'[' => {
+                let mut depth = 0usize;
+                while self.pos < self.buf.len() {
+                    match self.buf[self.pos] {
+                        b'"' => {
+                            self.skip_string()?;
+                            continue;
+                        }
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                return Ok(());
+                            }
+                        }
+                        _ => {}
+                    }
+                    self.pos += 1;
+                }
+                self.error("unexpected end of input")
+            }
+            b'}' | b']' | b',' | b':' => self.error("expected value"),
+            _ => {
+                while self.pos < self.buf.len()
+                    && !matches!(self.buf[self.pos], b',' | b'}' | b']')
+                    && !self.buf[self.pos].is_ascii_whitespace()
+                {
+                    self.pos += 1;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// read the next object key (string)
+    pub(crate) fn key(&mut self) -> PyResult<Cow<'a, str>> {
+        if self.peek()? != b'"' {
+            return self.error("expected key");
+        }
+        let key_start = self.pos;
+        let (start, end, escaped) = self.skip_string()?;
+        if escaped {
+            serde_json::from_slice::<String>(&self.buf[key_start..self.pos])
+                .map(Cow::Owned)
+                .map_err(|e| py_value_error!("invalid json: {e} at byte {key_start}"))
+        } else {
+            std::str::from_utf8(&self.buf[start..end])
+                .map(Cow::Borrowed)
+                .map_err(|e| py_value_error!("invalid json: {e} at byte {key_start}"))
+        }
+    }
+
+    /// after `{`: move to the value of `key`; `false` if there is no such key
+    fn find_key(&mut self, key: &str) -> PyResult<bool> {
+        self.pos += 1;
+        if self.peek()? == b'}' {
+            return Ok(false);
+        }
+        loop {
+            let matched = self.key()? == key;
+            self.expect(b':')?;
+            if matched {
+                return Ok(true);
+            }
+            self.skip_value()?;
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b'}' => return Ok(false),
+                _ => return self.error("expected ',' or '}'"),
+            }
+        }
+    }
+
+    /// after `[`: move to element `index`; `false` if out of bounds
+    fn find_index(&mut self, index: usize) -> PyResult<bool> {
+        self.pos += 1;
+        if self.peek()? == b']' {
+            return Ok(false);
+        }
+        for _ in 0..index {
+            self.skip_value()?;
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b']' => return Ok(false),
+                _ => return self.error("expected ',' or ']'"),
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Raw json of the value at `tokens` or `None` if it does not exist
+pub(crate) fn resolve<'a>(buf: &'a [u8], tokens: &[Cow<'_, str>]) -> PyResult<Option<&'a [u8]>> {
+    let mut scanner = Scanner { buf, pos: 0 };
+    for token in tokens {
+        let found = match scanner.peek()? {
+            b'{' => scanner.find_key(token)?,
+            b'[' => match parse_index(token) {
+                Some(index) => scanner.find_index(index)?,
+                None => false,
+            },
+            _ => false,
+        };
+        if !found {
+            return Ok(None);
+        }
+    }
+    scanner.skip_ws();
+    let start = scanner.pos;
+    scanner.skip_value()?;
+    Ok(Some(&buf[start..scanner.pos]))
+}
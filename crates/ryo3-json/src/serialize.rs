@@ -1,10 +1,16 @@
+use std::io;
+
 use pyo3::IntoPyObjectExt;
 use pyo3::exceptions::{PyRecursionError, PyTypeError};
 use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
 use ryo3_bytes::RyBytes;
-use ryo3_serde::PyAnySerializer;
+use ryo3_core::macros::py_type_err;
+use ryo3_serde::{PyAnySerializer, PySerializeOptions};
+use serde::Serialize;
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
 
-fn map_serde_json_err<E: std::fmt::Display>(e: E) -> PyErr {
+pub(crate) fn map_serde_json_err<E: std::fmt::Display>(e: E) -> PyErr {
     if e.to_string().starts_with("recursion") {
         PyRecursionError::new_err("Recursion limit reached")
     } else {
@@ -12,11 +18,148 @@ fn map_serde_json_err<E: std::fmt::Display>(e: E) -> PyErr {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default)]
-struct JsonOptions {
-    fmt: bool,
-    sort_keys: bool,
-    append_newline: bool,
+/// `indent` kwarg ~ number of spaces or the indent string itself
+#[derive(Clone, Debug)]
+pub struct JsonIndent(String);
+
+impl<'py> FromPyObject<'_, 'py> for JsonIndent {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = obj.extract::<PyBackedStr>() {
+            Ok(Self(s.to_string()))
+        } else if let Ok(n) = obj.extract::<usize>() {
+            Ok(Self(" ".repeat(n)))
+        } else {
+            py_type_err!("indent must be a non-negative int or str")
+        }
+    }
+}
+
+impl JsonIndent {
+    /// resolve the `indent`/`fmt` kwargs; `indent` wins and `fmt` is a 2
+    /// space indent
+    pub(crate) fn resolve(indent: Option<Self>, fmt: bool) -> Option<String> {
+        match indent {
+            Some(Self(indent)) => Some(indent),
+            None if fmt => Some("  ".to_string()),
+            None => None,
+        }
+    }
+}
+
+/// `serde_json` formatter that escapes non-ascii characters as `\uXXXX`
+/// (utf-16 surrogate pairs for astral chars) like python's `json.dumps`
+struct AsciiFormatter<F: Formatter>(F);
+
+macro_rules! delegate_formatter {
+    ($($name:ident),* ; $($name_first:ident),*) => {
+        $(
+            #[inline]
+            fn $name<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+                self.0.$name(writer)
+            }
+        )*
+        $(
+            #[inline]
+            fn $name_first<W: ?Sized + io::Write>(
+                &mut self,
+                writer: &mut W,
+                first: bool,
+            ) -> io::Result<()> {
+                self.0.$name_first(writer, first)
+            }
+        )*
+    };
+}
+
+impl<F: Formatter> Formatter for AsciiFormatter<F> {
+    delegate_formatter!(
+        begin_array, end_array, end_array_value, begin_object, end_object,
+        end_object_key, begin_object_value, end_object_value;
+        begin_array_value, begin_object_key
+    );
+
+    fn write_string_fragment<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> io::Result<()> {
+        let mut start = 0;
+        for (i, c) in fragment.char_indices() {
+            if !c.is_ascii() {
+                writer.write_all(&fragment.as_bytes()[start..i])?;
+                let mut buf = [0u16; 2];
+                for unit in c.encode_utf16(&mut buf) {
+                    write!(writer, "\\u{unit:04x}")?;
+                }
+                start = i + c.len_utf8();
+            }
+        }
+        writer.write_all(&fragment.as_bytes()[start..])
+    }
+}
+
+fn write_with<W: io::Write, F: Formatter, T: Serialize + ?Sized>(
+    writer: W,
+    formatter: F,
+    ensure_ascii: bool,
+    value: &T,
+) -> serde_json::Result<()> {
+    if ensure_ascii {
+        let mut ser = serde_json::Serializer::with_formatter(writer, AsciiFormatter(formatter));
+        value.serialize(&mut ser)
+    } else {
+        let mut ser = serde_json::Serializer::with_formatter(writer, formatter);
+        value.serialize(&mut ser)
+    }
+}
+
+/// json output options (`stringify` kwargs)
+#[derive(Clone, Debug, Default)]
+pub struct JsonOptions {
+    /// indent string; `None` for compact output
+    pub indent: Option<String>,
+    pub sort_keys: bool,
+    pub append_newline: bool,
+    pub ensure_ascii: bool,
+    pub serialize: PySerializeOptions,
+}
+
+impl JsonOptions {
+    /// Options from the serialization kwargs shared by `stringify`/`dumps`,
+    /// `write` and the json lines writers
+    #[must_use]
+    #[expect(
+        clippy::fn_params_excessive_bools,
+        clippy::too_many_arguments,
+        reason = "python kwargs"
+    )]
+    pub fn from_kwargs(
+        indent: Option<String>,
+        sort_keys: bool,
+        append_newline: bool,
+        ensure_ascii: bool,
+        non_str_keys: bool,
+        naive_utc: bool,
+        utc_z: bool,
+        passthrough_dataclass: bool,
+        passthrough_datetime: bool,
+    ) -> Self {
+        Self {
+            indent,
+            sort_keys,
+            append_newline,
+            ensure_ascii,
+            serialize: PySerializeOptions {
+                non_str_keys,
+                naive_utc,
+                utc_z,
+                passthrough_dataclass,
+                passthrough_datetime,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -57,26 +200,32 @@ impl<'py> JsonSerializer<'py> {
         Ok(())
     }
 
+    fn write<T: Serialize + ?Sized>(&self, bytes: &mut Vec<u8>, value: &T) -> PyResult<()> {
+        let res = if let Some(indent) = &self.opts.indent {
+            let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+            write_with(bytes, formatter, self.opts.ensure_ascii, value)
+        } else {
+            write_with(bytes, CompactFormatter, self.opts.ensure_ascii, value)
+        };
+        res.map_err(map_serde_json_err)
+    }
+
     pub(crate) fn serialize_to_vec(&self, obj: &Bound<'py, PyAny>) -> PyResult<Vec<u8>> {
-        let s = PyAnySerializer::new_json(obj.as_borrowed(), self.default);
+        let s = PyAnySerializer::<ryo3_serde::JsonTarget>::with_options(
+            obj.as_borrowed(),
+            self.default,
+            self.opts.serialize,
+        );
+        // 4k seeeems is a reasonable default size for JSON serialization?
         let mut bytes: Vec<u8> = Vec::with_capacity(4096);
         if self.opts.sort_keys {
             // TODO: This is a very hacky way of handling sorting the keys...
             //       ideally this would be part of the serialization process
             //       I think
             let value = serde_json::to_value(&s).map_err(map_serde_json_err)?;
-            if self.opts.fmt {
-                serde_json::to_writer_pretty(&mut bytes, &value).map_err(map_serde_json_err)?;
-            } else {
-                serde_json::to_writer(&mut bytes, &value).map_err(map_serde_json_err)?;
-            }
+            self.write(&mut bytes, &value)?;
         } else {
-            // 4k seeeems is a reasonable default size for JSON serialization?
-            if self.opts.fmt {
-                serde_json::to_writer_pretty(&mut bytes, &s).map_err(map_serde_json_err)?;
-            } else {
-                serde_json::to_writer(&mut bytes, &s).map_err(map_serde_json_err)?;
-            }
+            self.write(&mut bytes, &s)?;
         }
 
         if self.opts.append_newline {
@@ -121,15 +270,49 @@ impl<'py> JsonSerializer<'py> {
 // stringify_fn!(dumps);
 // ```
 
-#[expect(clippy::fn_params_excessive_bools, reason = "python kwargs")]
+fn stringify_with<'py>(
+    py: Python<'py>,
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+    options: JsonOptions,
+    pybytes: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let serializer = JsonSerializer::new(default, options)?;
+    serializer.serialize_to_vec(obj).map(|v| {
+        if pybytes {
+            pyo3::types::PyBytes::new(py, &v).into_bound_py_any(py)
+        } else {
+            RyBytes::from(v).into_bound_py_any(py)
+        }
+    })?
+}
+
+/// Serialize `obj` to `Bytes` (or python `bytes` if `pybytes`) via
+/// `serde_json` with an orjson compatible python object coverage
+///
+/// `indent` (int or str) overrides `fmt` (2 space indent); `non_str_keys`,
+/// `naive_utc`, `utc_z`, `passthrough_dataclass` and `passthrough_datetime`
+/// mirror the orjson options of the same name.
+#[expect(
+    clippy::fn_params_excessive_bools,
+    clippy::too_many_arguments,
+    reason = "python kwargs"
+)]
 #[pyfunction(
     signature=(
         obj,
         *,
         default = None,
         fmt = false,
+        indent = None,
         sort_keys = false,
         append_newline = false,
+        ensure_ascii = false,
+        non_str_keys = false,
+        naive_utc = false,
+        utc_z = false,
+        passthrough_dataclass = false,
+        passthrough_datetime = false,
         pybytes = false
     )
 )]
@@ -138,45 +321,73 @@ pub fn stringify<'py>(
     obj: &Bound<'py, PyAny>,
     default: Option<&'py Bound<'py, PyAny>>,
     fmt: bool,
+    indent: Option<JsonIndent>,
     sort_keys: bool,
     append_newline: bool,
+    ensure_ascii: bool,
+    non_str_keys: bool,
+    naive_utc: bool,
+    utc_z: bool,
+    passthrough_dataclass: bool,
+    passthrough_datetime: bool,
     pybytes: bool,
 ) -> PyResult<Bound<'py, PyAny>> {
-    let serializer = JsonSerializer::new(
-        default,
-        JsonOptions {
-            fmt,
-            sort_keys,
-            append_newline,
-        },
-    )?;
-    serializer.serialize_to_vec(obj).map(|v| {
-        if pybytes {
-            pyo3::types::PyBytes::new(py, &v).into_bound_py_any(py)
-        } else {
-            RyBytes::from(v).into_bound_py_any(py)
-        }
-    })?
+    let options = JsonOptions::from_kwargs(
+        JsonIndent::resolve(indent, fmt),
+        sort_keys,
+        append_newline,
+        ensure_ascii,
+        non_str_keys,
+        naive_utc,
+        utc_z,
+        passthrough_dataclass,
+        passthrough_datetime,
+    );
+    stringify_with(py, obj, default, options, pybytes)
+}
+
+/// Serialize `obj` to python `bytes` with the default options
+pub(crate) fn stringify_pybytes<'py>(
+    py: Python<'py>,
+    obj: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    stringify_with(py, obj, None, JsonOptions::default(), true)
 }
 
 pub fn to_vec(obj: &Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
-    JsonSerializer::new_no_default(JsonOptions {
-        fmt: false,
-        sort_keys: false,
-        append_newline: false,
-    })
-    .serialize_to_vec(obj)
+    JsonSerializer::new_no_default(JsonOptions::default()).serialize_to_vec(obj)
+}
+
+/// Serialize `obj` to json bytes with `default` and `options`
+pub fn to_vec_with<'py>(
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+    options: JsonOptions,
+) -> PyResult<Vec<u8>> {
+    JsonSerializer::new(default, options)?.serialize_to_vec(obj)
 }
 
-#[expect(clippy::fn_params_excessive_bools, reason = "python kwargs")]
+/// Alias for `stringify`
+#[expect(
+    clippy::fn_params_excessive_bools,
+    clippy::too_many_arguments,
+    reason = "python kwargs"
+)]
 #[pyfunction(
     signature=(
         obj,
         *,
         default = None,
         fmt = false,
+        indent = None,
         sort_keys = false,
         append_newline = false,
+        ensure_ascii = false,
+        non_str_keys = false,
+        naive_utc = false,
+        utc_z = false,
+        passthrough_dataclass = false,
+        passthrough_datetime = false,
         pybytes = false
     )
 )]
@@ -185,9 +396,31 @@ pub fn dumps<'py>(
     obj: &Bound<'py, PyAny>,
     default: Option<&'py Bound<'py, PyAny>>,
     fmt: bool,
+    indent: Option<JsonIndent>,
     sort_keys: bool,
     append_newline: bool,
+    ensure_ascii: bool,
+    non_str_keys: bool,
+    naive_utc: bool,
+    utc_z: bool,
+    passthrough_dataclass: bool,
+    passthrough_datetime: bool,
     pybytes: bool,
 ) -> PyResult<Bound<'py, PyAny>> {
-    stringify(py, obj, default, fmt, sort_keys, append_newline, pybytes)
+    stringify(
+        py,
+        obj,
+        default,
+        fmt,
+        indent,
+        sort_keys,
+        append_newline,
+        ensure_ascii,
+        non_str_keys,
+        naive_utc,
+        utc_z,
+        passthrough_dataclass,
+        passthrough_datetime,
+        pybytes,
+    )
 }
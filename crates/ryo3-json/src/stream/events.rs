@@ -0,0 +1,51 @@
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use ryo3_jiter::JiterParseOptions;
+
+use crate::stream::DEFAULT_READ_SIZE;
+use crate::stream::parser::{JsonEventKind, JsonStreamParser};
+use crate::stream::source::JsonSource;
+
+type JsonEventTuple<'py> = (Bound<'py, PyString>, &'static str, Bound<'py, PyAny>);
+
+/// Iterator of `(prefix, event, value)` tuples (ijson `parse` style)
+#[pyclass(name = "JsonEventReader", immutable_type)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.JSON"))]
+pub struct PyJsonEventReader {
+    parser: JsonStreamParser,
+}
+
+#[pymethods]
+impl PyJsonEventReader {
+    #[new]
+    #[pyo3(signature = (source, *, read_size = DEFAULT_READ_SIZE))]
+    fn py_new(source: &Bound<'_, PyAny>, read_size: usize) -> PyResult<Self> {
+        let parser = JsonStreamParser::new(JsonSource::from_py(source)?, read_size)?;
+        Ok(Self { parser })
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> PyResult<Option<JsonEventTuple<'py>>> {
+        let Some(event) = self.parser.next_event(py)? else {
+            return Ok(None);
+        };
+        let prefix = PyString::new(py, &self.parser.prefix()[..event.prefix_len]);
+        let value = match &event.kind {
+            JsonEventKind::Key(key) => PyString::new(py, key).into_any(),
+            JsonEventKind::Value => {
+                JiterParseOptions::new().parse(py, self.parser.slice(event.start, event.end))?
+            }
+            _ => py.None().into_bound(py),
+        };
+        Ok(Some((prefix, event.kind.name(), value)))
+    }
+
+    /// current container depth
+    #[getter]
+    fn depth(&self) -> usize {
+        self.parser.depth()
+    }
+}
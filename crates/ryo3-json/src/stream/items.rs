@@ -0,0 +1,67 @@
+use pyo3::prelude::*;
+use ryo3_jiter::JiterParseOptions;
+
+use crate::stream::parser::{JsonEventKind, JsonStreamParser};
+
+/// Lazy iterator over the values at a prefix (see `JSON.iter_items`)
+#[pyclass(name = "JsonItemsIter", immutable_type)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.JSON"))]
+pub struct PyJsonItemsIter {
+    parser: JsonStreamParser,
+    prefix: String,
+}
+
+impl PyJsonItemsIter {
+    pub(crate) fn new(parser: JsonStreamParser, prefix: String) -> Self {
+        Self { parser, prefix }
+    }
+
+    fn advance<'py>(&mut self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        // container depth of the value being captured
+        let mut depth = 0usize;
+        while let Some(event) = self.parser.next_event(py)? {
+            match event.kind {
+                JsonEventKind::StartMap | JsonEventKind::StartArray if depth > 0 => depth += 1,
+                JsonEventKind::EndMap | JsonEventKind::EndArray if depth > 0 => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let data = self.parser.end_capture(event.end);
+                        return JiterParseOptions::new().parse(py, data).map(Some);
+                    }
+                }
+                _ if depth > 0 => {}
+                _ if self.parser.prefix()[..event.prefix_len] != self.prefix => {}
+                JsonEventKind::StartMap | JsonEventKind::StartArray => {
+                    self.parser.start_capture(event.start);
+                    depth = 1;
+                }
+                JsonEventKind::Value => {
+                    let data = self.parser.slice(event.start, event.end);
+                    return JiterParseOptions::new().parse(py, data).map(Some);
+                }
+                JsonEventKind::Key(_) | JsonEventKind::EndMap | JsonEventKind::EndArray => {}
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[pymethods]
+impl PyJsonItemsIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        self.advance(py)
+    }
+
+    /// Collect the remaining items into a list
+    fn collect<'py>(&mut self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyAny>>> {
+        let mut items = Vec::new();
+        while let Some(item) = self.advance(py)? {
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
@@ -0,0 +1,26 @@
+//! Streaming json parsing (`JSON.iter_items` and `JSON.JsonEventReader`)
+mod events;
+mod items;
+mod parser;
+mod source;
+
+pub use events::PyJsonEventReader;
+pub use items::PyJsonItemsIter;
+use pyo3::prelude::*;
+
+use crate::stream::parser::JsonStreamParser;
+use crate::stream::source::JsonSource;
+
+const DEFAULT_READ_SIZE: usize = 65536;
+
+/// Lazily parse the values at `prefix` (e.g. the elements of a huge
+/// top-level array) from a buffer, file path or iterable of chunks
+#[pyfunction(signature = (source, prefix = "item", *, read_size = DEFAULT_READ_SIZE))]
+pub fn iter_items(
+    source: &Bound<'_, PyAny>,
+    prefix: &str,
+    read_size: usize,
+) -> PyResult<PyJsonItemsIter> {
+    let parser = JsonStreamParser::new(JsonSource::from_py(source)?, read_size)?;
+    Ok(PyJsonItemsIter::new(parser, prefix.to_string()))
+}
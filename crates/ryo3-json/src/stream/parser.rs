@@ -0,0 +1,364 @@
+//! Incremental (pull) json parser
+//!
+//! Tokenizes a [`JsonSource`] chunk by chunk and yields ijson-style events
+//! with their dotted prefix (`"data.item.name"`; array elements are `item`).
+//! Only the unconsumed tail of the input is kept in memory, plus the value
+//! being captured (see [`JsonStreamParser::start_capture`]).
+//!
+//! Scalar tokens are only delimited here; their contents are validated when
+//! they are parsed into python objects.
+use pyo3::prelude::*;
+use ryo3_core::macros::{py_value_err, py_value_error};
+
+use crate::stream::source::JsonSource;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    StartMap,
+    EndMap,
+    StartArray,
+    EndArray,
+    Colon,
+    Comma,
+    String,
+    Scalar,
+}
+
+/// what the parser expects next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    /// a value (top level or after `:`)
+    Value,
+    /// a value or `]` (directly after `[`)
+    ValueOrEndArray,
+    /// a key or `}` (directly after `{`)
+    KeyOrEndMap,
+    /// a key (after `,` in a map)
+    Key,
+    Colon,
+    /// `,` or the end of the current container
+    CommaOrEnd,
+    /// the top level value is done
+    Done,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum JsonEventKind {
+    StartMap,
+    EndMap,
+    StartArray,
+    EndArray,
+    /// decoded map key (empty when keys are not being decoded)
+    Key(String),
+    /// scalar value; the raw json is `parser.slice(event.start, event.end)`
+    Value,
+}
+
+impl JsonEventKind {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Self::StartMap => "start_map",
+            Self::EndMap => "end_map",
+            Self::StartArray => "start_array",
+            Self::EndArray => "end_array",
+            Self::Key(_) => "key",
+            Self::Value => "value",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct JsonEvent {
+    pub(crate) kind: JsonEventKind,
+    /// start of the event's token in the parser buffer
+    pub(crate) start: usize,
+    /// end of the event's token in the parser buffer
+    pub(crate) end: usize,
+    /// the event's prefix is `parser.prefix()[..prefix_len]`
+    pub(crate) prefix_len: usize,
+}
+
+pub(crate) struct JsonStreamParser {
+    source: JsonSource,
+    read_size: usize,
+    buf: Vec<u8>,
+    /// read position in `buf`
+    pos: usize,
+    /// absolute (stream) offset of `buf[0]`
+    offset: usize,
+    eof: bool,
+    /// start of the value being captured; nothing from here on is dropped
+    capture: Option<usize>,
+    expect: Expect,
+    /// for each open container: `true` for maps and the prefix length of the
+    /// container itself
+    stack: Vec<(bool, usize)>,
+    prefix: String,
+    /// decode map keys (and keep the prefix up to date)
+    decode_keys: bool,
+}
+
+impl JsonStreamParser {
+    pub(crate) fn new(source: JsonSource, read_size: usize) -> PyResult<Self> {
+        if read_size == 0 {
+            return py_value_err!("read_size must be greater than 0");
+        }
+        Ok(Self {
+            source,
+            read_size,
+            buf: Vec::new(),
+            pos: 0,
+            offset: 0,
+            eof: false,
+            capture: None,
+            expect: Expect::Value,
+            stack: Vec::new(),
+            prefix: String::new(),
+            decode_keys: true,
+        })
+    }
+
+    /// current prefix; events carry the length of their own prefix
+    pub(crate) fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// current container depth
+    pub(crate) fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub(crate) fn slice(&self, start: usize, end: usize) -> &[u8] {
+        &self.buf[start..end]
+    }
+
+    /// keep everything from `start` (a buffer index returned with an event)
+    /// until [`Self::end_capture`]; map keys are not decoded while capturing
+    pub(crate) fn start_capture(&mut self, start: usize) {
+        self.capture = Some(start);
+        self.decode_keys = false;
+    }
+
+    /// stop capturing and return the captured bytes up to `end`
+    pub(crate) fn end_capture(&mut self, end: usize) -> &[u8] {
+        let start = self.capture.take().unwrap_or(end);
+        self.decode_keys = true;
+        &self.buf[start..end]
+    }
+
+    fn error_at<T>(&self, msg: &str, idx: usize) -> PyResult<T> {
+        py_value_err!("{} at byte {}", msg, self.offset + idx)
+    }
+
+    /// drop the consumed (and not captured) part of the buffer and read the
+    /// next chunk; returns `false` at the end of the input
+    fn fill(&mut self, py: Python<'_>) -> PyResult<bool> {
+        if self.eof {
+            return Ok(false);
+        }
+        let drop_to = self.capture.map_or(self.pos, |c| c.min(self.pos));
+        if drop_to > 0 {
+            self.buf.drain(..drop_to);
+            self.pos -= drop_to;
+            self.offset += drop_to;
+            if let Some(c) = self.capture.as_mut() {
+                *c -= drop_to;
+            }
+        }
+        let more = self.source.read_chunk(py, &mut self.buf, self.read_size)?;
+        if !more {
+            self.eof = true;
+        }
+        Ok(more)
+    }
+
+    /// index of the end of the token starting at `start`, or `None` if more
+    /// input is needed to find it
+    fn token_end(&self, start: usize) -> Option<usize> {
+        let buf = &self.buf;
+        if buf[start] == b'"' {
+            let mut i = start + 1;
+            while i < buf.len() {
+                match buf[i] {
+                    b'\\' => i += 2,
+                    b'"' => return Some(i + 1),
+                    _ => i += 1,
+                }
+            }
+            None
+        } else {
+            let end = buf[start..]
+                .iter()
+                .position(
+                    |b| !matches!(b, b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'+' | b'-' | b'.'),
+                )
+                .map(|n| start + n);
+            match end {
+                Some(end) => Some(end),
+                None if self.eof => Some(buf.len()),
+                None => None,
+            }
+        }
+    }
+
+    fn next_token(&mut self, py: Python<'_>) -> PyResult<Option<(Token, usize, usize)>> {
+        loop {
+            while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+                self.pos += 1;
+            }
+            if self.pos < self.buf.len() {
+                break;
+            }
+            if !self.fill(py)? {
+                return Ok(None);
+            }
+        }
+        let start = self.pos;
+        let token = match self.buf[start] {
+            b'{' => Token::StartMap,
+            b'}' => Token::EndMap,
+            b'[' => Token::StartArray,
+            b']' => Token::EndArray,
+            b':' => Token::Colon,
+            b',' => Token::Comma,
+            b'"' => Token::String,
+            b'-' | b'0'..=b'9' | b't' | b'f' | b'n' => Token::Scalar,
+            _ => return self.error_at("unexpected character", start),
+        };
+        let end = if matches!(token, Token::String | Token::Scalar) {
+            loop {
+                // `start` may move when the buffer is compacted
+                let start = self.pos;
+                if let Some(end) = self.token_end(start) {
+                    break end;
+                }
+                if !self.fill(py)? && self.buf[self.pos] == b'"' {
+                    return self.error_at("unterminated string", self.pos);
+                }
+            }
+        } else {
+            start + 1
+        };
+        let start = self.pos;
+        self.pos = end;
+        Ok(Some((token, start, end)))
+    }
+
+    fn push_container(&mut self, is_map: bool) {
+        self.stack.push((is_map, self.prefix.len()));
+        if is_map {
+            self.expect = Expect::KeyOrEndMap;
+        } else {
+            if !self.prefix.is_empty() {
+                self.prefix.push('.');
+            }
+            self.prefix.push_str("item");
+            self.expect = Expect::ValueOrEndArray;
+        }
+    }
+
+    fn pop_container(&mut self) {
+        if let Some((_, prefix_len)) = self.stack.pop() {
+            self.prefix.truncate(prefix_len);
+        }
+        self.after_value();
+    }
+
+    fn after_value(&mut self) {
+        self.expect = if self.stack.is_empty() {
+            Expect::Done
+        } else {
+            Expect::CommaOrEnd
+        };
+    }
+
+    fn key_event(&mut self, start: usize, end: usize) -> PyResult<JsonEvent> {
+        let base = self.stack.last().map_or(0, |(_, len)| *len);
+        self.prefix.truncate(base);
+        let key = if self.decode_keys {
+            let key = serde_json::from_slice::<String>(&self.buf[start..end])
+                .map_err(|e| py_value_error!("invalid key at byte {}: {e}", self.offset + start))?;
+            if base > 0 {
+                self.prefix.push('.');
+            }
+            self.prefix.push_str(&key);
+            key
+        } else {
+            String::new()
+        };
+        self.expect = Expect::Colon;
+        Ok(JsonEvent {
+            kind: JsonEventKind::Key(key),
+            start,
+            end,
+            prefix_len: base,
+        })
+    }
+
+    /// next event, `None` at the end of the (valid) document
+    pub(crate) fn next_event(&mut self, py: Python<'_>) -> PyResult<Option<JsonEvent>> {
+        loop {
+            let Some((token, start, end)) = self.next_token(py)? else {
+                return if self.expect == Expect::Done {
+                    Ok(None)
+                } else {
+                    self.error_at("unexpected end of input", self.buf.len())
+                };
+            };
+            let prefix_len = self.prefix.len();
+            let kind = match (self.expect, token) {
+                (Expect::Colon, Token::Colon) => {
+                    self.expect = Expect::Value;
+                    continue;
+                }
+                (Expect::CommaOrEnd, Token::Comma) => {
+                    self.expect = match self.stack.last() {
+                        Some((true, _)) => Expect::Key,
+                        _ => Expect::Value,
+                    };
+                    continue;
+                }
+                (Expect::KeyOrEndMap | Expect::Key, Token::String) => {
+                    return self.key_event(start, end).map(Some);
+                }
+                (Expect::Value | Expect::ValueOrEndArray, Token::StartMap | Token::StartArray) => {
+                    let is_map = token == Token::StartMap;
+                    self.push_container(is_map);
+                    if is_map {
+                        JsonEventKind::StartMap
+                    } else {
+                        JsonEventKind::StartArray
+                    }
+                }
+                (Expect::Value | Expect::ValueOrEndArray, Token::String | Token::Scalar) => {
+                    self.after_value();
+                    JsonEventKind::Value
+                }
+                (Expect::KeyOrEndMap | Expect::CommaOrEnd, Token::EndMap)
+                    if matches!(self.stack.last(), Some((true, _))) =>
+                {
+                    self.pop_container();
+                    JsonEventKind::EndMap
+                }
+                (Expect::ValueOrEndArray | Expect::CommaOrEnd, Token::EndArray)
+                    if matches!(self.stack.last(), Some((false, _))) =>
+                {
+                    self.pop_container();
+                    JsonEventKind::EndArray
+                }
+                (Expect::Done, _) => return self.error_at("trailing characters", start),
+                _ => return self.error_at("unexpected token", start),
+            };
+            let prefix_len = match kind {
+                JsonEventKind::EndMap | JsonEventKind::EndArray => self.prefix.len(),
+                _ => prefix_len,
+            };
+            return Ok(Some(JsonEvent {
+                kind,
+                start,
+                end,
+                prefix_len,
+            }));
+        }
+    }
+}
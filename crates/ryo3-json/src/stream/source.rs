@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyIterator, PyString};
+use ryo3_bytes::RyBytes;
+use ryo3_core::macros::py_type_err;
+
+/// Input for the streaming parser
+///
+/// - buffer-protocol objects are read `read_size` bytes at a time
+/// - `str`/`os.PathLike` objects are file paths (NOT json text)
+/// - any other iterable must yield bytes-like chunks (e.g. `FileReadStream`)
+pub(crate) enum JsonSource {
+    Buffer { data: RyBytes, pos: usize },
+    File(File),
+    Chunks(Py<PyIterator>),
+}
+
+impl JsonSource {
+    pub(crate) fn from_py(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if obj.is_instance_of::<PyString>() {
+            let path = obj.extract::<PathBuf>()?;
+            return Ok(Self::File(File::open(path)?));
+        }
+        if let Ok(data) = obj.extract::<RyBytes>() {
+            return Ok(Self::Buffer { data, pos: 0 });
+        }
+        if let Ok(path) = obj.extract::<PathBuf>() {
+            return Ok(Self::File(File::open(path)?));
+        }
+        if let Ok(it) = obj.try_iter() {
+            Ok(Self::Chunks(it.unbind()))
+        } else {
+            py_type_err!(
+                "source must be a bytes-like object, a path or an iterable of bytes-like chunks"
+            )
+        }
+    }
+
+    /// Append the next chunk to `buf`; returns `false` once exhausted
+    pub(crate) fn read_chunk(
+        &mut self,
+        py: Python<'_>,
+        buf: &mut Vec<u8>,
+        read_size: usize,
+    ) -> PyResult<bool> {
+        match self {
+            Self::Buffer { data, pos } => {
+                let remaining = &data.as_slice()[*pos..];
+                if remaining.is_empty() {
+                    return Ok(false);
+                }
+                let n = remaining.len().min(read_size);
+                buf.extend_from_slice(&remaining[..n]);
+                *pos += n;
+                Ok(true)
+            }
+            Self::File(file) => {
+                let start = buf.len();
+                buf.resize(start + read_size, 0);
+                let res = py.detach(|| file.read(&mut buf[start..]));
+                match res {
+                    Ok(n) => {
+                        buf.truncate(start + n);
+                        Ok(n > 0)
+                    }
+                    Err(e) => {
+                        buf.truncate(start);
+                        Err(e.into())
+                    }
+                }
+            }
+            Self::Chunks(it) => {
+                // skip empty chunks so that `false` always means exhausted
+                while let Some(chunk) = it.bind(py).clone().next() {
+                    let chunk = chunk?.extract::<RyBytes>()?;
+                    if !chunk.as_slice().is_empty() {
+                        buf.extend_from_slice(chunk.as_slice());
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+        }
+    }
+}
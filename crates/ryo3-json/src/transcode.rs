@@ -5,6 +5,7 @@ use pyo3::types::PyString;
 use ryo3_bytes::{ReadableBuffer, RyBytes};
 use ryo3_core::PyCastExactOpt;
 use ryo3_core::macros::{py_type_err, py_value_error};
+use ryo3_jiter::strip_jsonc;
 use serde_json::{Deserializer, Serializer};
 
 fn minify_json<W: io::Write>(input: &[u8], output: W) -> Result<(), serde_json::Error> {
@@ -17,19 +18,23 @@ fn py_minify_json<W: io::Write>(input: &[u8], output: W) -> PyResult<()> {
     minify_json(input, output).map_err(|e| py_value_error!("Failed to minify JSON: {e}"))
 }
 
-#[pyfunction(signature = (buf, /))]
-pub(crate) fn minify<'py>(buf: &'py Bound<'py, PyAny>) -> PyResult<RyBytes> {
+#[pyfunction(signature = (buf, /, *, strip_comments = false, strip_trailing_commas = false))]
+pub(crate) fn minify<'py>(
+    buf: &'py Bound<'py, PyAny>,
+    strip_comments: bool,
+    strip_trailing_commas: bool,
+) -> PyResult<RyBytes> {
     if let Some(s) = buf.cast_exact_opt::<PyString>() {
         // py-string
         let json_str = s.to_string();
-        let json_bytes = json_str.as_bytes();
+        let json_bytes = strip_jsonc(json_str.as_bytes(), strip_comments, strip_trailing_commas)?;
         let mut output = Vec::with_capacity(json_bytes.len() / 8);
-        py_minify_json(json_bytes, &mut output)?;
+        py_minify_json(&json_bytes, &mut output)?;
         Ok(RyBytes::from(output))
     } else if let Ok(pybytes) = buf.extract::<ReadableBuffer>() {
-        let json_bytes = pybytes.as_ref();
+        let json_bytes = strip_jsonc(pybytes.as_ref(), strip_comments, strip_trailing_commas)?;
         let mut output = Vec::with_capacity(json_bytes.len() / 8);
-        py_minify_json(json_bytes, &mut output)?;
+        py_minify_json(&json_bytes, &mut output)?;
         Ok(RyBytes::from(output))
     } else {
         py_type_err!("Expected bytes-like object, str or buffer-protocol object")
@@ -0,0 +1,113 @@
+use std::io::{self, Write};
+
+use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+use ryo3_core::macros::py_value_err;
+
+/// `compression` kwarg for json lines writers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonLinesCompression {
+    Gzip,
+    Zstd,
+}
+
+impl<'py> FromPyObject<'_, 'py> for JsonLinesCompression {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        let s = obj.extract::<PyBackedStr>()?;
+        match &*s {
+            "gzip" | "gz" => Ok(Self::Gzip),
+            "zstd" | "zst" => Ok(Self::Zstd),
+            _ => py_value_err!("compression must be 'gzip', 'zstd' or None (got {:?})", &*s),
+        }
+    }
+}
+
+/// Writer for json lines that optionally compresses everything written
+pub enum JsonLinesEncoder<W: Write> {
+    Plain(W),
+    #[cfg(feature = "flate2")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::write::Encoder<'static, W>),
+}
+
+impl<W: Write> JsonLinesEncoder<W> {
+    pub fn new(
+        writer: W,
+        compression: Option<JsonLinesCompression>,
+        level: Option<i32>,
+    ) -> PyResult<Self> {
+        match compression {
+            None => Ok(Self::Plain(writer)),
+            #[cfg(feature = "flate2")]
+            Some(JsonLinesCompression::Gzip) => {
+                let level = match level {
+                    None => flate2::Compression::default(),
+                    Some(level @ 0..=9) => flate2::Compression::new(level.unsigned_abs()),
+                    Some(level) => {
+                        return py_value_err!("gzip level must be between 0 and 9 (got {level})");
+                    }
+                };
+                Ok(Self::Gzip(flate2::write::GzEncoder::new(writer, level)))
+            }
+            #[cfg(feature = "zstd")]
+            Some(JsonLinesCompression::Zstd) => {
+                let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                Ok(Self::Zstd(zstd::stream::write::Encoder::new(
+                    writer, level,
+                )?))
+            }
+            #[cfg(not(all(feature = "flate2", feature = "zstd")))]
+            Some(compression) => {
+                let _ = level;
+                py_value_err!("{compression:?} compression is not enabled in this build")
+            }
+        }
+    }
+
+    /// the underlying (compressed) writer
+    pub fn get_mut(&mut self) -> &mut W {
+        match self {
+            Self::Plain(w) => w,
+            #[cfg(feature = "flate2")]
+            Self::Gzip(w) => w.get_mut(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(w) => w.get_mut(),
+        }
+    }
+
+    /// finish the compressed stream and return the underlying writer
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            Self::Plain(w) => Ok(w),
+            #[cfg(feature = "flate2")]
+            Self::Gzip(w) => w.finish(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(w) => w.finish(),
+        }
+    }
+}
+
+impl<W: Write> Write for JsonLinesEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(w) => w.write(buf),
+            #[cfg(feature = "flate2")]
+            Self::Gzip(w) => w.write(buf),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(w) => w.flush(),
+            #[cfg(feature = "flate2")]
+            Self::Gzip(w) => w.flush(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(w) => w.flush(),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::PoisonError;
+
+use pyo3::prelude::*;
+use ryo3_core::macros::py_value_err;
+use ryo3_core::sync::RyMutex;
+
+use crate::serialize::{JsonOptions, to_vec_with};
+use crate::write::encoder::{JsonLinesCompression, JsonLinesEncoder};
+
+/// flush serialized lines to the file once this many bytes are buffered
+const WRITELINES_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Json lines file writer; objects are serialized with the GIL held and
+/// compressed/written with it released
+#[pyclass(name = "JsonLinesWriter", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.JSON"))]
+pub struct PyJsonLinesWriter {
+    path: PathBuf,
+    default: Option<Py<PyAny>>,
+    options: JsonOptions,
+    encoder: RyMutex<Option<JsonLinesEncoder<BufWriter<File>>>>,
+}
+
+impl PyJsonLinesWriter {
+    fn serialize_line(&self, obj: &Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
+        let default = self.default.as_ref().map(|d| d.bind(obj.py()));
+        to_vec_with(obj, default, self.options.clone())
+    }
+
+    fn write_bytes(&self, py: Python<'_>, data: &[u8]) -> PyResult<()> {
+        let mut guard = self.encoder.py_lock_attached(py)?;
+        let Some(encoder) = guard.as_mut() else {
+            return py_value_err!("I/O operation on closed JsonLinesWriter");
+        };
+        py.detach(|| encoder.write_all(data))?;
+        Ok(())
+    }
+}
+
+impl Drop for PyJsonLinesWriter {
+    fn drop(&mut self) {
+        // finish the (compressed) stream if the writer was never closed so
+        // the file is not truncated; errors are ignored on drop
+        let encoder = self
+            .encoder
+            .0
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        if let Some(encoder) = encoder {
+            let _ = encoder.finish().and_then(|mut w| w.flush());
+        }
+    }
+}
+
+#[pymethods]
+impl PyJsonLinesWriter {
+    #[new]
+    #[expect(
+        clippy::fn_params_excessive_bools,
+        clippy::too_many_arguments,
+        reason = "python kwargs"
+    )]
+    #[pyo3(
+        signature = (
+            path,
+            *,
+            append = false,
+            compression = None,
+            level = None,
+            default = None,
+            sort_keys = false,
+            ensure_ascii = false,
+            non_str_keys = false,
+            naive_utc = false,
+            utc_z = false,
+            passthrough_dataclass = false,
+            passthrough_datetime = false,
+        )
+    )]
+    fn py_new(
+        py: Python<'_>,
+        path: PathBuf,
+        append: bool,
+        compression: Option<JsonLinesCompression>,
+        level: Option<i32>,
+        default: Option<Py<PyAny>>,
+        sort_keys: bool,
+        ensure_ascii: bool,
+        non_str_keys: bool,
+        naive_utc: bool,
+        utc_z: bool,
+        passthrough_dataclass: bool,
+        passthrough_datetime: bool,
+    ) -> PyResult<Self> {
+        let file = py.detach(|| {
+            OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(append)
+                .truncate(!append)
+                .open(&path)
+        })?;
+        let encoder = JsonLinesEncoder::new(BufWriter::new(file), compression, level)?;
+        let options = JsonOptions::from_kwargs(
+            None,
+            sort_keys,
+            true,
+            ensure_ascii,
+            non_str_keys,
+            naive_utc,
+            utc_z,
+            passthrough_dataclass,
+            passthrough_datetime,
+        );
+        Ok(Self {
+            path,
+            default,
+            options,
+            encoder: RyMutex::new(Some(encoder)),
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JsonLinesWriter(path='{}')", self.path.display())
+    }
+
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    #[pyo3(name = "__exit__")]
+    fn __exit__(
+        &self,
+        py: Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        self.close(py)
+    }
+
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    #[getter]
+    fn closed(&self, py: Python<'_>) -> PyResult<bool> {
+        Ok(self.encoder.py_lock_attached(py)?.is_none())
+    }
+
+    /// Write `obj` as a json line; returns the number of (uncompressed)
+    /// bytes written
+    fn write(&self, py: Python<'_>, obj: &Bound<'_, PyAny>) -> PyResult<usize> {
+        let line = self.serialize_line(obj)?;
+        self.write_bytes(py, &line)?;
+        Ok(line.len())
+    }
+
+    /// Write every object in `objs` as a json line; returns the number of
+    /// (uncompressed) bytes written
+    fn writelines(&self, py: Python<'_>, objs: &Bound<'_, PyAny>) -> PyResult<usize> {
+        let mut total = 0;
+        let mut buf = Vec::with_capacity(WRITELINES_CHUNK_SIZE);
+        for obj in objs.try_iter()? {
+            buf.extend(self.serialize_line(&obj?)?);
+            if buf.len() >= WRITELINES_CHUNK_SIZE {
+                self.write_bytes(py, &buf)?;
+                total += buf.len();
+                buf.clear();
+            }
+        }
+        if !buf.is_empty() {
+            self.write_bytes(py, &buf)?;
+            total += buf.len();
+        }
+        Ok(total)
+    }
+
+    fn flush(&self, py: Python<'_>) -> PyResult<()> {
+        let mut guard = self.encoder.py_lock_attached(py)?;
+        if let Some(encoder) = guard.as_mut() {
+            py.detach(|| encoder.flush())?;
+        }
+        Ok(())
+    }
+
+    /// Finish the (compressed) stream and close the file; no-op if closed
+    fn close(&self, py: Python<'_>) -> PyResult<()> {
+        let encoder = self.encoder.py_lock_attached(py)?.take();
+        if let Some(encoder) = encoder {
+            py.detach(|| encoder.finish()?.flush())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+//! Writing json to files (`JSON.write` and `JSON.JsonLinesWriter`)
+mod encoder;
+mod lines;
+
+use std::path::PathBuf;
+
+pub use encoder::{JsonLinesCompression, JsonLinesEncoder};
+pub use lines::PyJsonLinesWriter;
+use pyo3::prelude::*;
+
+use crate::serialize::{JsonIndent, JsonOptions, to_vec_with};
+
+/// Serialize `obj` (same options as `stringify`) and write it to `path`;
+/// returns the number of bytes written
+#[expect(
+    clippy::fn_params_excessive_bools,
+    clippy::needless_pass_by_value,
+    clippy::too_many_arguments,
+    reason = "python kwargs"
+)]
+#[pyfunction(
+    signature=(
+        path,
+        obj,
+        *,
+        default = None,
+        fmt = false,
+        indent = None,
+        sort_keys = false,
+        append_newline = false,
+        ensure_ascii = false,
+        non_str_keys = false,
+        naive_utc = false,
+        utc_z = false,
+        passthrough_dataclass = false,
+        passthrough_datetime = false,
+    )
+)]
+pub(crate) fn write<'py>(
+    py: Python<'py>,
+    path: PathBuf,
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+    fmt: bool,
+    indent: Option<JsonIndent>,
+    sort_keys: bool,
+    append_newline: bool,
+    ensure_ascii: bool,
+    non_str_keys: bool,
+    naive_utc: bool,
+    utc_z: bool,
+    passthrough_dataclass: bool,
+    passthrough_datetime: bool,
+) -> PyResult<usize> {
+    let options = JsonOptions::from_kwargs(
+        JsonIndent::resolve(indent, fmt),
+        sort_keys,
+        append_newline,
+        ensure_ascii,
+        non_str_keys,
+        naive_utc,
+        utc_z,
+        passthrough_dataclass,
+        passthrough_datetime,
+    );
+    let bytes = to_vec_with(obj, default, options)?;
+    py.detach(|| std::fs::write(&path, &bytes))?;
+    Ok(bytes.len())
+}
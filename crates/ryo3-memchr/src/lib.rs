@@ -1,8 +1,15 @@
 #![doc = include_str!("../README.md")]
 use pyo3::prelude::*;
+mod memmem;
 mod py_memchr;
 
-pub use crate::py_memchr::{memchr, memchr2, memchr3, memrchr, memrchr2, memrchr3};
+pub use crate::memmem::{
+    PyFinder, PyFinderRev, memmem_find, memmem_find_iter, memmem_rfind, memmem_rfind_iter,
+};
+pub use crate::py_memchr::{
+    memchr, memchr_iter, memchr2, memchr2_iter, memchr3, memchr3_iter, memrchr, memrchr_iter,
+    memrchr2, memrchr3,
+};
 
 pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // memchr
@@ -12,6 +19,16 @@ pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(crate::py_memchr::memrchr, m)?)?;
     m.add_function(wrap_pyfunction!(crate::py_memchr::memrchr2, m)?)?;
     m.add_function(wrap_pyfunction!(crate::py_memchr::memrchr3, m)?)?;
-    // memmem ~ TODO
+    m.add_function(wrap_pyfunction!(crate::py_memchr::memchr_iter, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::py_memchr::memchr2_iter, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::py_memchr::memchr3_iter, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::py_memchr::memrchr_iter, m)?)?;
+    // memmem
+    m.add_function(wrap_pyfunction!(crate::memmem::memmem_find, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::memmem::memmem_find_iter, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::memmem::memmem_rfind, m)?)?;
+    m.add_function(wrap_pyfunction!(crate::memmem::memmem_rfind_iter, m)?)?;
+    m.add_class::<PyFinder>()?;
+    m.add_class::<PyFinderRev>()?;
     Ok(())
 }
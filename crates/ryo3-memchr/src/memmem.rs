@@ -1 +1,160 @@
-//! FUTURE IMPL OF `memchr::memmem`
+//! `memchr::memmem` substring search
+//!
+//! Functions take `(haystack, needle)` like the `memchr::memmem` functions
+//! they wrap (NOT `(needle, haystack)` like the `memchr*` functions).
+#![expect(clippy::needless_pass_by_value)]
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use memchr::memmem;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+
+/// Return the index of the first occurrence of `needle` in `haystack`
+#[must_use]
+#[pyfunction]
+pub fn memmem_find(haystack: ReadableBuffer, needle: ReadableBuffer) -> Option<usize> {
+    memmem::find(haystack.as_slice(), needle.as_slice())
+}
+
+/// Return the index of the last occurrence of `needle` in `haystack`
+#[must_use]
+#[pyfunction]
+pub fn memmem_rfind(haystack: ReadableBuffer, needle: ReadableBuffer) -> Option<usize> {
+    memmem::rfind(haystack.as_slice(), needle.as_slice())
+}
+
+/// Return the indices of all non-overlapping occurrences of `needle` in
+/// `haystack`
+#[must_use]
+#[pyfunction]
+pub fn memmem_find_iter(haystack: ReadableBuffer, needle: ReadableBuffer) -> Vec<usize> {
+    memmem::find_iter(haystack.as_slice(), needle.as_slice()).collect()
+}
+
+/// Return the indices of all non-overlapping occurrences of `needle` in
+/// `haystack` in reverse order
+#[must_use]
+#[pyfunction]
+pub fn memmem_rfind_iter(haystack: ReadableBuffer, needle: ReadableBuffer) -> Vec<usize> {
+    memmem::rfind_iter(haystack.as_slice(), needle.as_slice()).collect()
+}
+
+fn needle_repr(py: Python<'_>, name: &str, needle: &[u8]) -> PyResult<String> {
+    Ok(format!("{name}({})", PyBytes::new(py, needle).repr()?))
+}
+
+fn needle_hash(needle: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    needle.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Forward substring searcher for a single (prebuilt) needle
+///
+/// The searcher is boxed as `memmem::Finder` may be over-aligned (simd
+/// vectors) which python object allocation does not guarantee.
+#[pyclass(name = "Finder", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyFinder(Box<memmem::Finder<'static>>);
+
+#[pymethods]
+impl PyFinder {
+    #[new]
+    fn py_new(needle: ReadableBuffer) -> Self {
+        Self(Box::new(
+            memmem::Finder::new(needle.as_slice()).into_owned(),
+        ))
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        needle_repr(py, "Finder", self.0.needle())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0.needle() == other.0.needle()
+    }
+
+    fn __hash__(&self) -> u64 {
+        needle_hash(self.0.needle())
+    }
+
+    fn __getnewargs__(&self) -> (RyBytes,) {
+        (RyBytes::from(self.0.needle().to_vec()),)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.needle().len()
+    }
+
+    /// The needle being searched for
+    #[getter]
+    fn needle(&self) -> RyBytes {
+        RyBytes::from(self.0.needle().to_vec())
+    }
+
+    /// Return the index of the first occurrence of the needle in `haystack`
+    fn find(&self, haystack: ReadableBuffer) -> Option<usize> {
+        self.0.find(haystack.as_slice())
+    }
+
+    /// Return the indices of all non-overlapping occurrences of the needle in
+    /// `haystack`
+    fn find_iter(&self, haystack: ReadableBuffer) -> Vec<usize> {
+        self.0.find_iter(haystack.as_slice()).collect()
+    }
+}
+
+/// Reverse substring searcher for a single (prebuilt) needle
+///
+/// Boxed for the same reason as `PyFinder`.
+#[pyclass(name = "FinderRev", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyFinderRev(Box<memmem::FinderRev<'static>>);
+
+#[pymethods]
+impl PyFinderRev {
+    #[new]
+    fn py_new(needle: ReadableBuffer) -> Self {
+        Self(Box::new(
+            memmem::FinderRev::new(needle.as_slice()).into_owned(),
+        ))
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        needle_repr(py, "FinderRev", self.0.needle())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0.needle() == other.0.needle()
+    }
+
+    fn __hash__(&self) -> u64 {
+        needle_hash(self.0.needle())
+    }
+
+    fn __getnewargs__(&self) -> (RyBytes,) {
+        (RyBytes::from(self.0.needle().to_vec()),)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.needle().len()
+    }
+
+    /// The needle being searched for
+    #[getter]
+    fn needle(&self) -> RyBytes {
+        RyBytes::from(self.0.needle().to_vec())
+    }
+
+    /// Return the index of the last occurrence of the needle in `haystack`
+    fn rfind(&self, haystack: ReadableBuffer) -> Option<usize> {
+        self.0.rfind(haystack.as_slice())
+    }
+
+    /// Return the indices of all non-overlapping occurrences of the needle in
+    /// `haystack` in reverse order
+    fn rfind_iter(&self, haystack: ReadableBuffer) -> Vec<usize> {
+        self.0.rfind_iter(haystack.as_slice()).collect()
+    }
+}
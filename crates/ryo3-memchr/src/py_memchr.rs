@@ -53,3 +53,43 @@ pub fn memrchr3(
 ) -> Option<usize> {
     ::memchr::memrchr3(*needle1, *needle2, *needle3, haystack.as_slice())
 }
+
+/// Return the indices of all occurrences of `needle` in `haystack`
+#[expect(clippy::needless_pass_by_value)]
+#[must_use]
+#[pyfunction]
+pub fn memchr_iter(needle: Byte, haystack: ReadableBuffer) -> Vec<usize> {
+    ::memchr::memchr_iter(*needle, haystack.as_slice()).collect()
+}
+
+/// Return the indices of all occurrences of `needle1` or `needle2` in
+/// `haystack`
+#[expect(clippy::needless_pass_by_value)]
+#[must_use]
+#[pyfunction]
+pub fn memchr2_iter(needle1: Byte, needle2: Byte, haystack: ReadableBuffer) -> Vec<usize> {
+    ::memchr::memchr2_iter(*needle1, *needle2, haystack.as_slice()).collect()
+}
+
+/// Return the indices of all occurrences of `needle1`, `needle2` or `needle3`
+/// in `haystack`
+#[expect(clippy::needless_pass_by_value)]
+#[must_use]
+#[pyfunction]
+pub fn memchr3_iter(
+    needle1: Byte,
+    needle2: Byte,
+    needle3: Byte,
+    haystack: ReadableBuffer,
+) -> Vec<usize> {
+    ::memchr::memchr3_iter(*needle1, *needle2, *needle3, haystack.as_slice()).collect()
+}
+
+/// Return the indices of all occurrences of `needle` in `haystack` in
+/// reverse order
+#[expect(clippy::needless_pass_by_value)]
+#[must_use]
+#[pyfunction]
+pub fn memrchr_iter(needle: Byte, haystack: ReadableBuffer) -> Vec<usize> {
+    ::memchr::memrchr_iter(*needle, haystack.as_slice()).collect()
+}
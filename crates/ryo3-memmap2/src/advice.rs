@@ -0,0 +1,63 @@
+use pyo3::prelude::*;
+use ryo3_macro_rules::{py_type_err, py_value_err};
+
+const ADVICE_ACCEPTED: &str = "'normal', 'random', 'sequential', 'willneed'";
+
+/// `madvise` hint; ignored on platforms without `madvise`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PyAdvice {
+    Normal,
+    Random,
+    Sequential,
+    WillNeed,
+}
+
+#[cfg(unix)]
+impl From<PyAdvice> for memmap2::Advice {
+    fn from(value: PyAdvice) -> Self {
+        match value {
+            PyAdvice::Normal => Self::Normal,
+            PyAdvice::Random => Self::Random,
+            PyAdvice::Sequential => Self::Sequential,
+            PyAdvice::WillNeed => Self::WillNeed,
+        }
+    }
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyAdvice {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = ob.extract::<&str>() {
+            match s.to_ascii_lowercase().as_str() {
+                "normal" => Ok(Self::Normal),
+                "random" => Ok(Self::Random),
+                "sequential" => Ok(Self::Sequential),
+                "willneed" | "will-need" | "will_need" => Ok(Self::WillNeed),
+                _ => py_value_err!("Invalid madvise advice: {s} (options: {ADVICE_ACCEPTED})"),
+            }
+        } else {
+            py_type_err!(
+                "Invalid type for madvise advice, expected a string (options: {ADVICE_ACCEPTED})"
+            )
+        }
+    }
+}
+
+/// Resolve a `(start, length)` range against a map of `len` bytes
+pub(crate) fn resolve_range(
+    len: usize,
+    start: usize,
+    length: Option<usize>,
+) -> PyResult<(usize, usize)> {
+    if start > len {
+        return py_value_err!("start ({start}) is beyond end of mmap (len = {len})");
+    }
+    let length = length.unwrap_or(len - start);
+    if length > len - start {
+        return py_value_err!(
+            "range [{start}, {start} + {length}) is beyond end of mmap (len = {len})"
+        );
+    }
+    Ok((start, length))
+}
@@ -0,0 +1,107 @@
+use std::ffi::c_int;
+use std::path::PathBuf;
+
+use pyo3::exceptions::PyBufferError;
+use pyo3::prelude::*;
+use ryo3_macro_rules::py_value_error;
+
+/// Shared state for `Mmap`/`MmapMut`; the map is `None` once closed.
+///
+/// `exports` counts the live buffer-protocol views so that `close` can refuse
+/// to unmap memory that a `memoryview`/`ry.Bytes`/etc still points into.
+pub(crate) struct MmapState<T> {
+    pub(crate) map: Option<T>,
+    pub(crate) exports: usize,
+}
+
+impl<T> MmapState<T> {
+    pub(crate) fn new(map: T) -> Self {
+        Self {
+            map: Some(map),
+            exports: 0,
+        }
+    }
+
+    pub(crate) fn get(&self) -> PyResult<&T> {
+        self.map
+            .as_ref()
+            .ok_or_else(|| py_value_error!("mmap closed or invalid"))
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.map.is_none()
+    }
+
+    /// Take the map out of the state (closing it) if no buffers are exported
+    pub(crate) fn take(&mut self) -> PyResult<Option<T>> {
+        if self.exports > 0 {
+            return Err(PyBufferError::new_err(
+                "cannot close exported pointers exist",
+            ));
+        }
+        Ok(self.map.take())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PyMmapOptions {
+    pub(crate) path: PathBuf,
+    pub(crate) offset: u64,
+    pub(crate) length: Option<usize>,
+}
+
+impl PyMmapOptions {
+    pub(crate) fn memmap2_options(&self) -> memmap2::MmapOptions {
+        let mut opts = memmap2::MmapOptions::new();
+        opts.offset(self.offset);
+        if let Some(len) = self.length {
+            opts.len(len);
+        }
+        opts
+    }
+
+    pub(crate) fn repr(&self, name: &str, len: Option<usize>) -> String {
+        let offset = if self.offset == 0 {
+            String::new()
+        } else {
+            format!(", offset={}", self.offset)
+        };
+        match len {
+            Some(len) => format!(
+                "{name}(path='{}'{offset}, length={len})",
+                self.path.display()
+            ),
+            None => format!(
+                "{name}(path='{}'{offset}, closed=True)",
+                self.path.display()
+            ),
+        }
+    }
+}
+
+/// Fill a `Py_buffer` view for the memory at `ptr`/`len` owned by `owner`
+#[expect(unsafe_code)]
+pub(crate) unsafe fn fill_buffer(
+    py: Python<'_>,
+    owner: *mut pyo3::ffi::PyObject,
+    view: *mut pyo3::ffi::Py_buffer,
+    ptr: *const u8,
+    len: usize,
+    readonly: bool,
+    flags: c_int,
+) -> PyResult<()> {
+    unsafe {
+        let ret = pyo3::ffi::PyBuffer_FillInfo(
+            view,
+            owner,
+            ptr.cast_mut().cast(),
+            len.try_into()?,
+            c_int::from(readonly),
+            flags,
+        );
+        if ret == -1 {
+            return Err(PyErr::fetch(py));
+        }
+        Ok(())
+    }
+}
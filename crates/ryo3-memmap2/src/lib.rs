@@ -0,0 +1,41 @@
+#![doc = include_str!("../README.md")]
+
+mod advice;
+mod buffer;
+mod py_mmap;
+mod py_mmap_mut;
+
+use std::path::PathBuf;
+
+use pyo3::prelude::*;
+
+pub use crate::py_mmap::PyMmap;
+pub use crate::py_mmap_mut::PyMmapMut;
+
+/// Memory-map a file, returning an `Mmap` or, if `writable`, an `MmapMut`
+#[pyfunction]
+#[pyo3(signature = (path, *, offset = 0, length = None, writable = false))]
+pub fn mmap(
+    py: Python<'_>,
+    path: PathBuf,
+    offset: u64,
+    length: Option<usize>,
+    writable: bool,
+) -> PyResult<Bound<'_, PyAny>> {
+    if writable {
+        PyMmapMut::open(py, path, offset, length)?
+            .into_pyobject(py)
+            .map(Bound::into_any)
+    } else {
+        PyMmap::open(py, path, offset, length)?
+            .into_pyobject(py)
+            .map(Bound::into_any)
+    }
+}
+
+pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyMmap>()?;
+    m.add_class::<PyMmapMut>()?;
+    m.add_function(wrap_pyfunction!(mmap, m)?)?;
+    Ok(())
+}
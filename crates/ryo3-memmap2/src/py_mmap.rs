@@ -0,0 +1,150 @@
+use std::ffi::c_int;
+use std::fs::File;
+use std::path::PathBuf;
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use ryo3_core::sync::RyMutex;
+
+use crate::advice::{PyAdvice, resolve_range};
+use crate::buffer::{MmapState, PyMmapOptions, fill_buffer};
+
+/// Read-only memory-mapped file
+#[pyclass(name = "Mmap", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyMmap {
+    options: PyMmapOptions,
+    state: RyMutex<MmapState<memmap2::Mmap>, false>,
+}
+
+impl PyMmap {
+    pub(crate) fn open(
+        py: Python<'_>,
+        path: PathBuf,
+        offset: u64,
+        length: Option<usize>,
+    ) -> PyResult<Self> {
+        let options = PyMmapOptions {
+            path,
+            offset,
+            length,
+        };
+        let map = py.detach(|| {
+            let file = File::open(&options.path)?;
+            // SAFETY: the file may be modified by other processes while mapped;
+            // that is inherent to memory maps and documented on the python side
+            #[expect(unsafe_code)]
+            unsafe {
+                options.memmap2_options().map(&file)
+            }
+        })?;
+        Ok(Self {
+            options,
+            state: RyMutex::new(MmapState::new(map)),
+        })
+    }
+}
+
+#[pymethods]
+impl PyMmap {
+    #[new]
+    #[pyo3(signature = (path, *, offset = 0, length = None))]
+    fn py_new(py: Python<'_>, path: PathBuf, offset: u64, length: Option<usize>) -> PyResult<Self> {
+        Self::open(py, path, offset, length)
+    }
+
+    fn __repr__(&self) -> String {
+        let state = self.state.py_lock();
+        let len = state.map.as_ref().map(|m| m.len());
+        self.options.repr("Mmap", len)
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        self.state.py_lock().get().map(|m| m.len())
+    }
+
+    fn __bytes__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let state = self.state.py_lock();
+        Ok(PyBytes::new(py, state.get()?))
+    }
+
+    fn __enter__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        py: Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        self.close(py)
+    }
+
+    #[expect(unsafe_code, clippy::needless_pass_by_value)]
+    unsafe fn __getbuffer__(
+        slf: PyRef<Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let mut state = slf.state.py_lock();
+        let map = state.get()?;
+        unsafe {
+            fill_buffer(
+                slf.py(),
+                slf.as_ptr(),
+                view,
+                map.as_ptr(),
+                map.len(),
+                true,
+                flags,
+            )?;
+        }
+        state.exports += 1;
+        Ok(())
+    }
+
+    #[expect(unsafe_code)]
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {
+        let mut state = self.state.py_lock();
+        state.exports = state.exports.saturating_sub(1);
+    }
+
+    /// Path of the mapped file
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.options.path.clone()
+    }
+
+    /// Offset into the file at which the map starts
+    #[getter]
+    fn offset(&self) -> u64 {
+        self.options.offset
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.state.py_lock().is_closed()
+    }
+
+    /// Unmap the file; raises `BufferError` if buffers are still exported
+    fn close(&self, py: Python<'_>) -> PyResult<()> {
+        let map = self.state.py_lock().take()?;
+        // unmapping a large map is not free
+        py.detach(|| drop(map));
+        Ok(())
+    }
+
+    #[pyo3(signature = (advice, start = 0, length = None))]
+    fn madvise(&self, advice: PyAdvice, start: usize, length: Option<usize>) -> PyResult<()> {
+        let state = self.state.py_lock();
+        let map = state.get()?;
+        let (start, length) = resolve_range(map.len(), start, length)?;
+        #[cfg(unix)]
+        map.advise_range(advice.into(), start, length)?;
+        #[cfg(not(unix))]
+        let _ = (advice, start, length);
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use std::ffi::c_int;
+use std::fs::OpenOptions;
+use std::path::PathBuf;
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use ryo3_core::sync::RyMutex;
+
+use crate::advice::{PyAdvice, resolve_range};
+use crate::buffer::{MmapState, PyMmapOptions, fill_buffer};
+
+/// Writable memory-mapped file; writes go straight to the underlying file
+#[pyclass(name = "MmapMut", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyMmapMut {
+    options: PyMmapOptions,
+    state: RyMutex<MmapState<memmap2::MmapMut>, false>,
+}
+
+impl PyMmapMut {
+    pub(crate) fn open(
+        py: Python<'_>,
+        path: PathBuf,
+        offset: u64,
+        length: Option<usize>,
+    ) -> PyResult<Self> {
+        let options = PyMmapOptions {
+            path,
+            offset,
+            length,
+        };
+        let map = py.detach(|| {
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(&options.path)?;
+            // SAFETY: the file may be modified by other processes while mapped;
+            // that is inherent to memory maps and documented on the python side
+            #[expect(unsafe_code)]
+            unsafe {
+                options.memmap2_options().map_mut(&file)
+            }
+        })?;
+        Ok(Self {
+            options,
+            state: RyMutex::new(MmapState::new(map)),
+        })
+    }
+}
+
+#[pymethods]
+impl PyMmapMut {
+    #[new]
+    #[pyo3(signature = (path, *, offset = 0, length = None))]
+    fn py_new(py: Python<'_>, path: PathBuf, offset: u64, length: Option<usize>) -> PyResult<Self> {
+        Self::open(py, path, offset, length)
+    }
+
+    fn __repr__(&self) -> String {
+        let state = self.state.py_lock();
+        let len = state.map.as_ref().map(|m| m.len());
+        self.options.repr("MmapMut", len)
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        self.state.py_lock().get().map(|m| m.len())
+    }
+
+    fn __bytes__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let state = self.state.py_lock();
+        Ok(PyBytes::new(py, state.get()?))
+    }
+
+    fn __enter__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        py: Python<'_>,
+        _exc_type: &Bound<'_, PyAny>,
+        _exc_value: &Bound<'_, PyAny>,
+        _traceback: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        self.close(py)
+    }
+
+    #[expect(unsafe_code, clippy::needless_pass_by_value)]
+    unsafe fn __getbuffer__(
+        slf: PyRef<Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let mut state = slf.state.py_lock();
+        let map = state.get()?;
+        unsafe {
+            fill_buffer(
+                slf.py(),
+                slf.as_ptr(),
+                view,
+                map.as_ptr(),
+                map.len(),
+                false,
+                flags,
+            )?;
+        }
+        state.exports += 1;
+        Ok(())
+    }
+
+    #[expect(unsafe_code)]
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {
+        let mut state = self.state.py_lock();
+        state.exports = state.exports.saturating_sub(1);
+    }
+
+    /// Path of the mapped file
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.options.path.clone()
+    }
+
+    /// Offset into the file at which the map starts
+    #[getter]
+    fn offset(&self) -> u64 {
+        self.options.offset
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.state.py_lock().is_closed()
+    }
+
+    /// Flush outstanding changes and unmap the file; raises `BufferError` if
+    /// buffers are still exported
+    fn close(&self, py: Python<'_>) -> PyResult<()> {
+        let map = self.state.py_lock().take()?;
+        if let Some(map) = map {
+            py.detach(|| {
+                map.flush()?;
+                drop(map);
+                Ok::<_, std::io::Error>(())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Flush outstanding changes (or those in the given range) to disk
+    #[pyo3(signature = (start = 0, length = None, *, sync = true))]
+    fn flush(&self, start: usize, length: Option<usize>, sync: bool) -> PyResult<()> {
+        let state = self.state.py_lock();
+        let map = state.get()?;
+        let (start, length) = resolve_range(map.len(), start, length)?;
+        // not detached: the state lock is not GIL-aware
+        if sync {
+            map.flush_range(start, length)?;
+        } else {
+            map.flush_async_range(start, length)?;
+        }
+        Ok(())
+    }
+
+    #[pyo3(signature = (advice, start = 0, length = None))]
+    fn madvise(&self, advice: PyAdvice, start: usize, length: Option<usize>) -> PyResult<()> {
+        let state = self.state.py_lock();
+        let map = state.get()?;
+        let (start, length) = resolve_range(map.len(), start, length)?;
+        #[cfg(unix)]
+        map.advise_range(advice.into(), start, length)?;
+        #[cfg(not(unix))]
+        let _ = (advice, start, length);
+        Ok(())
+    }
+}
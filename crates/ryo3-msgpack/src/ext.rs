@@ -0,0 +1,76 @@
+#![expect(clippy::needless_pass_by_value)]
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+use ryo3_macro_rules::py_value_err;
+
+/// msgpack extension type: an application defined type code (`-128..=127`)
+/// and the raw data
+///
+/// The module is `ry.msgpack` (not `ry.ryo3`) so `Ext` objects can be pickled.
+#[pyclass(name = "Ext", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.msgpack"))]
+#[derive(Debug, Clone)]
+pub struct PyExt {
+    pub(crate) code: i8,
+    pub(crate) data: RyBytes,
+}
+
+impl PyExt {
+    pub(crate) fn new(code: i8, data: &[u8]) -> Self {
+        Self {
+            code,
+            data: RyBytes::from(data.to_vec()),
+        }
+    }
+}
+
+#[pymethods]
+impl PyExt {
+    #[new]
+    fn py_new(code: i64, data: ReadableBuffer<'_, '_>) -> PyResult<Self> {
+        let Ok(code) = i8::try_from(code) else {
+            return py_value_err!("ext code must be in range -128..=127, got {code}");
+        };
+        Ok(Self {
+            code,
+            data: data.to_rybytes(),
+        })
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "Ext(code={}, data={})",
+            self.code,
+            PyBytes::new(py, self.data.as_slice()).repr()?
+        ))
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.code == other.code && self.data.as_slice() == other.data.as_slice()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (self.code, self.data.as_slice()).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __getnewargs__(&self) -> (i8, RyBytes) {
+        (self.code, self.data.clone())
+    }
+
+    /// Extension type code
+    #[getter]
+    fn code(&self) -> i8 {
+        self.code
+    }
+
+    /// Extension data
+    #[getter]
+    fn data(&self) -> RyBytes {
+        self.data.clone()
+    }
+}
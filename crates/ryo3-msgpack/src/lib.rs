@@ -0,0 +1,20 @@
+#![doc = include_str!("../README.md")]
+
+mod ext;
+mod pack;
+mod target;
+mod unpack;
+
+use pyo3::prelude::*;
+
+pub use ext::PyExt;
+pub use pack::{packb, to_vec};
+pub use target::MsgpackTarget;
+pub use unpack::unpackb;
+
+pub fn pysubmod_register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyExt>()?;
+    m.add_function(wrap_pyfunction!(pack::packb, m)?)?;
+    m.add_function(wrap_pyfunction!(unpack::unpackb, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::{PyRecursionError, PyTypeError};
+use pyo3::prelude::*;
+use ryo3_bytes::RyBytes;
+use ryo3_serde::{PyAnySerializer, check_callable};
+use serde::Serialize;
+
+use crate::target::MsgpackTarget;
+
+fn map_rmp_encode_err(e: &rmp_serde::encode::Error) -> PyErr {
+    if e.to_string().starts_with("recursion") {
+        PyRecursionError::new_err("Recursion limit reached")
+    } else {
+        PyTypeError::new_err(format!("Failed to serialize: {e}"))
+    }
+}
+
+/// Serialize a python object to msgpack bytes
+///
+/// Packs with human readable serde representations so uuids, ulids, urls,
+/// datetimes etc. are packed as strings (same as `ry.JSON.stringify`).
+pub fn to_vec<'py>(
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+) -> PyResult<Vec<u8>> {
+    let s = PyAnySerializer::<MsgpackTarget>::with_target(obj.as_borrowed(), default);
+    let mut buf = Vec::with_capacity(256);
+    let mut serializer = rmp_serde::Serializer::new(&mut buf).with_human_readable();
+    s.serialize(&mut serializer)
+        .map_err(|e| map_rmp_encode_err(&e))?;
+    Ok(buf)
+}
+
+/// Serialize `obj` to msgpack; `default` is called with objects that cannot
+/// otherwise be serialized and should return a serializable object
+#[pyfunction(signature = (obj, *, default = None))]
+pub fn packb<'py>(
+    py: Python<'py>,
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    check_callable(default)?;
+    let buf = to_vec(obj, default)?;
+    RyBytes::from(buf).into_bound_py_any(py)
+}
@@ -0,0 +1,36 @@
+use ryo3_bytes::ReadableBuffer;
+use ryo3_serde::{PyAnySerializer, PySerializeTarget};
+use serde::ser::Serializer;
+
+use crate::ext::PyExt;
+
+/// `ryo3-serde` target for msgpack
+///
+/// - bytes-like objects are packed as msgpack `bin` (not arrays of ints)
+/// - `Ext` objects are packed as msgpack `ext`
+/// - any other buffer-protocol object (e.g. `ry.Bytes`) is packed as `bin`
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MsgpackTarget;
+
+impl PySerializeTarget for MsgpackTarget {
+    const KIND: &'static str = "msgpack";
+    const NATIVE_BYTES: bool = true;
+
+    fn serialize_ext<S: Serializer>(
+        ser: &PyAnySerializer<'_, '_, Self>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        let obj = ser.obj();
+        if let Ok(ext) = obj.cast::<PyExt>() {
+            let ext = ext.get();
+            Ok(serializer.serialize_newtype_struct(
+                rmp_serde::MSGPACK_EXT_STRUCT_NAME,
+                &(ext.code, serde_bytes::Bytes::new(ext.data.as_slice())),
+            ))
+        } else if let Ok(buf) = obj.extract::<ReadableBuffer<'_, '_>>() {
+            Ok(serializer.serialize_bytes(buf.as_slice()))
+        } else {
+            Err(serializer)
+        }
+    }
+}
@@ -0,0 +1,103 @@
+#![expect(clippy::needless_pass_by_value)]
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList, PyTuple};
+use rmpv::ValueRef;
+use ryo3_bytes::ReadableBuffer;
+use ryo3_macro_rules::{py_type_err, py_value_err, py_value_error};
+use ryo3_serde::check_callable;
+
+use crate::ext::PyExt;
+
+struct Unpacker<'a, 'py> {
+    py: Python<'py>,
+    ext_hook: Option<&'a Bound<'py, PyAny>>,
+}
+
+impl<'py> Unpacker<'_, 'py> {
+    fn value(&self, value: &ValueRef<'_>) -> PyResult<Bound<'py, PyAny>> {
+        let py = self.py;
+        match value {
+            ValueRef::Nil => Ok(py.None().into_bound(py)),
+            ValueRef::Boolean(b) => b.into_bound_py_any(py),
+            ValueRef::Integer(i) => {
+                if let Some(i) = i.as_i64() {
+                    i.into_bound_py_any(py)
+                } else if let Some(u) = i.as_u64() {
+                    u.into_bound_py_any(py)
+                } else {
+                    py_value_err!("invalid msgpack integer: {i}")
+                }
+            }
+            ValueRef::F32(f) => f64::from(*f).into_bound_py_any(py),
+            ValueRef::F64(f) => f.into_bound_py_any(py),
+            ValueRef::String(s) => {
+                if let Some(s) = s.as_str() {
+                    s.into_bound_py_any(py)
+                } else {
+                    py_value_err!("invalid utf-8 in msgpack str")
+                }
+            }
+            ValueRef::Binary(b) => Ok(PyBytes::new(py, b).into_any()),
+            ValueRef::Array(values) => {
+                let items = values
+                    .iter()
+                    .map(|v| self.value(v))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(PyList::new(py, items)?.into_any())
+            }
+            ValueRef::Map(entries) => {
+                let dict = PyDict::new(py);
+                for (k, v) in entries {
+                    dict.set_item(self.key(k)?, self.value(v)?)?;
+                }
+                Ok(dict.into_any())
+            }
+            ValueRef::Ext(code, data) => {
+                if let Some(ext_hook) = self.ext_hook {
+                    ext_hook.call1((*code, PyBytes::new(py, data)))
+                } else {
+                    PyExt::new(*code, data).into_bound_py_any(py)
+                }
+            }
+        }
+    }
+
+    /// Map keys; arrays become tuples so they are hashable
+    fn key(&self, key: &ValueRef<'_>) -> PyResult<Bound<'py, PyAny>> {
+        match key {
+            ValueRef::Array(values) => {
+                let items = values
+                    .iter()
+                    .map(|v| self.key(v))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(PyTuple::new(self.py, items)?.into_any())
+            }
+            ValueRef::Map(_) => py_type_err!("unhashable msgpack map key (map)"),
+            _ => self.value(key),
+        }
+    }
+}
+
+/// Deserialize msgpack `data` into python objects
+///
+/// `bin` is returned as `bytes`; `ext` types as `Ext` objects unless an
+/// `ext_hook(code, data)` is given in which case its return value is used.
+#[pyfunction(signature = (data, *, ext_hook = None))]
+pub fn unpackb<'py>(
+    py: Python<'py>,
+    data: ReadableBuffer<'_, 'py>,
+    ext_hook: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    check_callable(ext_hook)?;
+    let mut rd = data.as_slice();
+    let value = rmpv::decode::read_value_ref(&mut rd)
+        .map_err(|e| py_value_error!("Failed to deserialize msgpack: {e}"))?;
+    if !rd.is_empty() {
+        return py_value_err!(
+            "Failed to deserialize msgpack: {} bytes of trailing data",
+            rd.len()
+        );
+    }
+    Unpacker { py, ext_hook }.value(&value)
+}
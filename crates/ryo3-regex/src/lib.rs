@@ -1,13 +1,33 @@
 #![doc = include_str!("../README.md")]
 
+mod py_bytes_captures;
+mod py_bytes_regex;
+mod py_bytes_regex_set;
+mod py_captures;
 mod py_regex;
 mod py_regex_options;
+mod py_regex_set;
+mod py_set_matches;
 
 use pyo3::prelude::*;
 
+pub use crate::py_bytes_captures::{PyBytesCaptures, PyBytesCapturesIter};
+pub use crate::py_bytes_regex::PyBytesRegex;
+pub use crate::py_bytes_regex_set::PyBytesRegexSet;
+pub use crate::py_captures::{PyCaptures, PyCapturesIter};
 pub use crate::py_regex::PyRegex;
+pub use crate::py_regex_set::PyRegexSet;
+pub use crate::py_set_matches::PySetMatches;
 
 pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBytesCaptures>()?;
+    m.add_class::<PyBytesCapturesIter>()?;
+    m.add_class::<PyBytesRegex>()?;
+    m.add_class::<PyBytesRegexSet>()?;
+    m.add_class::<PyCaptures>()?;
+    m.add_class::<PyCapturesIter>()?;
     m.add_class::<PyRegex>()?;
+    m.add_class::<PyRegexSet>()?;
+    m.add_class::<PySetMatches>()?;
     Ok(())
 }
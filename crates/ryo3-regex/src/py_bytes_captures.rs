@@ -0,0 +1,248 @@
+//! Python `re.Match`-like captures object(s) for `BytesRegex`
+//!
+//! Spans are byte offsets into the haystack; matched groups are returned as
+//! zero-copy `ry.Bytes` slices of the haystack.
+use std::sync::Arc;
+
+use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+use regex::bytes::{CaptureLocations, Regex};
+use ryo3_bytes::{Bytes, RyBytes};
+
+use crate::py_captures::PyGroup;
+
+/// Captures for a single match of a `BytesRegex`
+#[pyclass(name = "BytesCaptures", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyBytesCaptures {
+    pub(crate) re: Arc<Regex>,
+    pub(crate) haystack: Bytes,
+    /// byte spans of each group (group 0 is the overall match)
+    pub(crate) spans: Vec<Option<(usize, usize)>>,
+}
+
+impl PyBytesCaptures {
+    pub(crate) fn from_locations(re: Arc<Regex>, haystack: Bytes, locs: &CaptureLocations) -> Self {
+        let spans = (0..locs.len()).map(|i| locs.get(i)).collect::<Vec<_>>();
+        Self {
+            re,
+            haystack,
+            spans,
+        }
+    }
+
+    /// Resolve a group index/name to an index into the spans
+    pub(crate) fn group_index(&self, group: PyGroup) -> PyResult<usize> {
+        match group {
+            PyGroup::Index(index) => usize::try_from(index)
+                .ok()
+                .filter(|i| *i < self.spans.len()),
+            PyGroup::Name(name) => self
+                .re
+                .capture_names()
+                .position(|n| n == Some(name.as_ref())),
+        }
+        .ok_or_else(|| PyIndexError::new_err("no such group"))
+    }
+
+    /// Return the matched bytes for group `i` (if it participated)
+    pub(crate) fn get(&self, i: usize) -> Option<Bytes> {
+        self.spans
+            .get(i)
+            .copied()
+            .flatten()
+            .map(|(start, end)| self.haystack.slice(start..end))
+    }
+
+    fn group_or_default<'py>(
+        &self,
+        py: Python<'py>,
+        i: usize,
+        default: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match self.get(i) {
+            Some(b) => RyBytes::from(b).into_bound_py_any(py),
+            None => Ok(default.clone()),
+        }
+    }
+
+    fn span_of(&self, group: Option<PyGroup>) -> PyResult<(isize, isize)> {
+        let i = group.map_or(Ok(0), |g| self.group_index(g))?;
+        Ok(self.spans[i].map_or((-1, -1), |(start, end)| {
+            (start.cast_signed(), end.cast_signed())
+        }))
+    }
+}
+
+#[pymethods]
+impl PyBytesCaptures {
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let (start, end) = self.spans[0].unwrap_or_default();
+        let matched = pyo3::types::PyBytes::new(py, &self.get(0).unwrap_or_default()).repr()?;
+        Ok(format!(
+            "BytesCaptures(span=({start}, {end}), match={matched})"
+        ))
+    }
+
+    fn __len__(&self) -> usize {
+        self.spans.len()
+    }
+
+    fn __getitem__<'py>(&self, py: Python<'py>, group: PyGroup) -> PyResult<Bound<'py, PyAny>> {
+        let i = self.group_index(group)?;
+        self.group_or_default(py, i, &py.None().into_bound(py))
+    }
+
+    /// The haystack the regex was matched against
+    #[getter]
+    fn string(&self) -> RyBytes {
+        RyBytes::from(self.haystack.clone())
+    }
+
+    /// Return one or more subgroups of the match (group 0 if no args given)
+    #[pyo3(signature = (*groups))]
+    fn group<'py>(
+        &self,
+        py: Python<'py>,
+        groups: &Bound<'py, PyTuple>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let none = py.None().into_bound(py);
+        match groups.len() {
+            0 => self.group_or_default(py, 0, &none),
+            1 => {
+                let i = self.group_index(groups.get_item(0)?.extract::<PyGroup>()?)?;
+                self.group_or_default(py, i, &none)
+            }
+            _ => {
+                let items = groups
+                    .iter()
+                    .map(|g| {
+                        let i = self.group_index(g.extract::<PyGroup>()?)?;
+                        self.group_or_default(py, i, &none)
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                PyTuple::new(py, items).map(Bound::into_any)
+            }
+        }
+    }
+
+    /// Return a tuple of all the subgroups (1..n) of the match
+    #[pyo3(signature = (default = None))]
+    fn groups<'py>(
+        &self,
+        py: Python<'py>,
+        default: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let default = default.unwrap_or_else(|| py.None().into_bound(py));
+        let items = (1..self.spans.len())
+            .map(|i| self.group_or_default(py, i, &default))
+            .collect::<PyResult<Vec<_>>>()?;
+        PyTuple::new(py, items)
+    }
+
+    /// Return a dict of all the named subgroups of the match
+    #[pyo3(signature = (default = None))]
+    fn groupdict<'py>(
+        &self,
+        py: Python<'py>,
+        default: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let default = default.unwrap_or_else(|| py.None().into_bound(py));
+        let dict = PyDict::new(py);
+        for (i, name) in self.re.capture_names().enumerate() {
+            if let Some(name) = name {
+                dict.set_item(name, self.group_or_default(py, i, &default)?)?;
+            }
+        }
+        Ok(dict)
+    }
+
+    /// Return the `(start, end)` (byte offsets) of a group; `(-1, -1)` if the
+    /// group did not participate in the match
+    #[pyo3(signature = (group = None, /))]
+    fn span(&self, group: Option<PyGroup>) -> PyResult<(isize, isize)> {
+        self.span_of(group)
+    }
+
+    /// Return the start offset of a group (-1 if it did not participate)
+    #[pyo3(signature = (group = None, /))]
+    fn start(&self, group: Option<PyGroup>) -> PyResult<isize> {
+        self.span_of(group).map(|(start, _)| start)
+    }
+
+    /// Return the end offset of a group (-1 if it did not participate)
+    #[pyo3(signature = (group = None, /))]
+    fn end(&self, group: Option<PyGroup>) -> PyResult<isize> {
+        self.span_of(group).map(|(_, end)| end)
+    }
+}
+
+/// Lazy iterator over the captures of successive non-overlapping matches
+#[pyclass(name = "BytesCapturesIter", immutable_type)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyBytesCapturesIter {
+    re: Arc<Regex>,
+    haystack: Bytes,
+    locs: CaptureLocations,
+    /// byte offset to start the next search at (`None` when exhausted)
+    pos: Option<usize>,
+    last_match_end: Option<usize>,
+}
+
+impl PyBytesCapturesIter {
+    pub(crate) fn new(re: Arc<Regex>, haystack: Bytes) -> Self {
+        let locs = re.capture_locations();
+        Self {
+            re,
+            haystack,
+            locs,
+            pos: Some(0),
+            last_match_end: None,
+        }
+    }
+
+    /// Advance to the next match (same semantics as `Regex::captures_iter`:
+    /// an empty match immediately following a match is skipped)
+    fn advance(&mut self) -> Option<PyBytesCaptures> {
+        loop {
+            let pos = self.pos?;
+            let Some(m) = self
+                .re
+                .captures_read_at(&mut self.locs, &self.haystack, pos)
+            else {
+                self.pos = None;
+                return None;
+            };
+            if m.is_empty() && Some(m.end()) == self.last_match_end {
+                // skip a byte and search again
+                self.pos = (m.end() < self.haystack.len()).then_some(m.end() + 1);
+                continue;
+            }
+            self.pos = Some(m.end());
+            self.last_match_end = Some(m.end());
+            return Some(PyBytesCaptures::from_locations(
+                Arc::clone(&self.re),
+                self.haystack.clone(),
+                &self.locs,
+            ));
+        }
+    }
+}
+
+#[pymethods]
+impl PyBytesCapturesIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<PyBytesCaptures> {
+        self.advance()
+    }
+
+    /// Collect the remaining captures into a list
+    fn collect(&mut self) -> Vec<PyBytesCaptures> {
+        std::iter::from_fn(|| self.advance()).collect()
+    }
+}
@@ -0,0 +1,239 @@
+#![expect(clippy::needless_pass_by_value)]
+
+use std::borrow::{Borrow, Cow};
+
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use regex::bytes::Regex;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+
+use crate::py_bytes_captures::{PyBytesCaptures, PyBytesCapturesIter};
+use crate::py_regex_options::PyRegexOptions;
+
+/// Regex matching arbitrary bytes (`regex::bytes::Regex`)
+#[pyclass(name = "BytesRegex", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+#[derive(Clone, Debug)]
+pub struct PyBytesRegex {
+    pub re: std::sync::Arc<Regex>,
+    pub options: Option<PyRegexOptions>,
+}
+
+impl From<Regex> for PyBytesRegex {
+    fn from(re: Regex) -> Self {
+        Self {
+            re: std::sync::Arc::new(re),
+            options: None,
+        }
+    }
+}
+
+#[pymethods]
+impl PyBytesRegex {
+    #[new]
+    #[pyo3(signature = (
+        pattern,
+        *,
+        case_insensitive = false,
+        crlf = false,
+        dot_matches_new_line = false,
+        ignore_whitespace = false,
+        line_terminator = None,
+        multi_line = false,
+        octal = false,
+        size_limit = None,
+        swap_greed = false,
+        unicode = true
+    ))]
+    #[expect(clippy::too_many_arguments, reason = "python kwargs")]
+    #[expect(clippy::fn_params_excessive_bools)]
+    fn py_new(
+        pattern: &str,
+        // kwargs
+        case_insensitive: bool,
+        crlf: bool,
+        dot_matches_new_line: bool,
+        ignore_whitespace: bool,
+        line_terminator: Option<ryo3_core::types::Byte>,
+        multi_line: bool,
+        octal: bool,
+        size_limit: Option<usize>,
+        swap_greed: bool,
+        unicode: bool,
+    ) -> PyResult<Self> {
+        let options = PyRegexOptions {
+            case_insensitive,
+            crlf,
+            dot_matches_new_line,
+            ignore_whitespace,
+            line_terminator: line_terminator.map_or(b'\n', |lt| *lt),
+            multi_line,
+            octal,
+            size_limit,
+            swap_greed,
+            unicode,
+        };
+
+        let re = options.build_bytes_pattern(pattern)?;
+        Ok(Self {
+            re: std::sync::Arc::new(re),
+            options: Some(options),
+        })
+    }
+
+    fn __getnewargs_ex__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, pyo3::types::PyTuple>> {
+        let kwargs = if let Some(opts) = &self.options {
+            opts.as_pydict(py)?.into_bound_py_any(py)?
+        } else {
+            pyo3::types::PyDict::new(py).into_bound_py_any(py)?
+        };
+
+        let args = pyo3::types::PyTuple::new(py, [self.re.as_str().into_bound_py_any(py)?])?;
+        pyo3::types::PyTuple::new(py, &[args.into_bound_py_any(py)?, kwargs])
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self}")
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.re.as_str() == other.re.as_str() && self.options == other.options
+    }
+
+    fn __ne__(&self, other: &Self) -> bool {
+        !self.__eq__(other)
+    }
+
+    /// Returns true if and only if there is a match for the regex anywhere in the haystack given.
+    fn is_match(&self, haystack: ReadableBuffer<'_, '_>) -> bool {
+        self.re.is_match(haystack.as_slice())
+    }
+
+    /// Returns true if and only if there is a match for the regex anywhere in the haystack given.
+    fn test(&self, haystack: ReadableBuffer<'_, '_>) -> bool {
+        self.re.is_match(haystack.as_slice())
+    }
+
+    /// Return the `(start, end)` byte span of the leftmost-first match
+    fn find(&self, haystack: ReadableBuffer<'_, '_>) -> Option<(usize, usize)> {
+        self.re
+            .find(haystack.as_slice())
+            .map(|m| (m.start(), m.end()))
+    }
+
+    /// Return the `(start, end)` byte spans of all successive
+    /// non-overlapping matches
+    fn find_all(&self, haystack: ReadableBuffer<'_, '_>) -> Vec<(usize, usize)> {
+        self.re
+            .find_iter(haystack.as_slice())
+            .map(|m| (m.start(), m.end()))
+            .collect()
+    }
+
+    fn findall(&self, haystack: ReadableBuffer<'_, '_>) -> Vec<(usize, usize)> {
+        self.find_all(haystack)
+    }
+
+    /// Return the matched bytes of all successive non-overlapping matches
+    /// (zero-copy slices of the haystack)
+    fn find_all_bytes(&self, haystack: ReadableBuffer<'_, '_>) -> Vec<RyBytes> {
+        let hay = haystack.to_bytes();
+        self.re
+            .find_iter(&hay)
+            .map(|m| RyBytes::from(hay.slice(m.range())))
+            .collect()
+    }
+
+    /// Return the captures of the leftmost-first match in the haystack (or
+    /// `None` if there is no match)
+    fn captures(&self, haystack: ReadableBuffer<'_, '_>) -> Option<PyBytesCaptures> {
+        let hay = haystack.to_bytes();
+        let mut locs = self.re.capture_locations();
+        self.re.captures_read(&mut locs, &hay)?;
+        Some(PyBytesCaptures::from_locations(
+            std::sync::Arc::clone(&self.re),
+            hay,
+            &locs,
+        ))
+    }
+
+    /// Return a lazy iterator over the captures of all successive
+    /// non-overlapping matches in the haystack
+    fn captures_iter(&self, haystack: ReadableBuffer<'_, '_>) -> PyBytesCapturesIter {
+        PyBytesCapturesIter::new(std::sync::Arc::clone(&self.re), haystack.to_bytes())
+    }
+
+    /// Return the names of all capture groups (`None` for unnamed groups;
+    /// the first element is always `None` for the implicit group 0)
+    fn capture_names(&self) -> Vec<Option<&str>> {
+        self.re.capture_names().collect()
+    }
+
+    /// Return the number of capture groups (including the implicit group 0)
+    fn captures_len(&self) -> usize {
+        self.re.captures_len()
+    }
+
+    fn replace(
+        &self,
+        haystack: ReadableBuffer<'_, '_>,
+        replacement: ReadableBuffer<'_, '_>,
+    ) -> RyBytes {
+        match self.re.replace(haystack.as_slice(), replacement.as_slice()) {
+            Cow::Borrowed(_) => haystack.to_rybytes(),
+            Cow::Owned(replaced) => RyBytes::from(replaced),
+        }
+    }
+
+    fn replace_all(
+        &self,
+        haystack: ReadableBuffer<'_, '_>,
+        replacement: ReadableBuffer<'_, '_>,
+    ) -> RyBytes {
+        match self
+            .re
+            .replace_all(haystack.as_slice(), replacement.as_slice())
+        {
+            Cow::Borrowed(_) => haystack.to_rybytes(),
+            Cow::Owned(replaced) => RyBytes::from(replaced),
+        }
+    }
+
+    /// Split the haystack by the regex (zero-copy slices of the haystack)
+    fn split(&self, haystack: ReadableBuffer<'_, '_>) -> Vec<RyBytes> {
+        let hay = haystack.to_bytes();
+        self.re
+            .split(&hay)
+            .map(|part| RyBytes::from(hay.slice_ref(part)))
+            .collect()
+    }
+
+    /// Split the haystack by the regex at most `n - 1` times (zero-copy
+    /// slices of the haystack)
+    fn splitn(&self, haystack: ReadableBuffer<'_, '_>, n: usize) -> Vec<RyBytes> {
+        let hay = haystack.to_bytes();
+        self.re
+            .splitn(&hay, n)
+            .map(|part| RyBytes::from(hay.slice_ref(part)))
+            .collect()
+    }
+}
+
+impl Borrow<Regex> for PyBytesRegex {
+    fn borrow(&self) -> &Regex {
+        &self.re
+    }
+}
+
+impl std::fmt::Display for PyBytesRegex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BytesRegex(r'{}'", self.re.as_str())?;
+        if let Some(opts) = &self.options {
+            opts.write_regex_kwargs(f)?;
+        }
+        write!(f, ")")
+    }
+}
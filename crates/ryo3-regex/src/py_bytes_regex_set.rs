@@ -0,0 +1,178 @@
+#![expect(clippy::needless_pass_by_value)]
+
+use std::borrow::Borrow;
+
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+use pyo3::types::{PyIterator, PyList};
+use regex::bytes::RegexSet;
+use ryo3_bytes::{ReadableBuffer, RyBytes};
+
+use crate::py_regex_options::PyRegexOptions;
+use crate::py_set_matches::PySetMatches;
+
+/// Set of regexes matching arbitrary bytes (`regex::bytes::RegexSet`)
+#[pyclass(name = "BytesRegexSet", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+#[derive(Clone, Debug)]
+pub struct PyBytesRegexSet {
+    pub re: std::sync::Arc<RegexSet>,
+    pub options: Option<PyRegexOptions>,
+}
+
+impl From<RegexSet> for PyBytesRegexSet {
+    fn from(re: RegexSet) -> Self {
+        Self {
+            re: std::sync::Arc::new(re),
+            options: None,
+        }
+    }
+}
+
+#[pymethods]
+impl PyBytesRegexSet {
+    #[new]
+    #[pyo3(signature = (
+        patterns,
+        *,
+        case_insensitive = false,
+        crlf = false,
+        dot_matches_new_line = false,
+        ignore_whitespace = false,
+        line_terminator = None,
+        multi_line = false,
+        octal = false,
+        size_limit = None,
+        swap_greed = false,
+        unicode = true
+    ))]
+    #[expect(clippy::too_many_arguments, reason = "python kwargs")]
+    #[expect(clippy::fn_params_excessive_bools)]
+    fn py_new(
+        patterns: Vec<PyBackedStr>,
+        // kwargs
+        case_insensitive: bool,
+        crlf: bool,
+        dot_matches_new_line: bool,
+        ignore_whitespace: bool,
+        line_terminator: Option<ryo3_core::types::Byte>,
+        multi_line: bool,
+        octal: bool,
+        size_limit: Option<usize>,
+        swap_greed: bool,
+        unicode: bool,
+    ) -> PyResult<Self> {
+        let options = PyRegexOptions {
+            case_insensitive,
+            crlf,
+            dot_matches_new_line,
+            ignore_whitespace,
+            line_terminator: line_terminator.map_or(b'\n', |lt| *lt),
+            multi_line,
+            octal,
+            size_limit,
+            swap_greed,
+            unicode,
+        };
+
+        let re = options.build_bytes_patterns(patterns)?;
+        Ok(Self {
+            re: std::sync::Arc::new(re),
+            options: Some(options),
+        })
+    }
+
+    fn __getnewargs_ex__<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, pyo3::types::PyTuple>> {
+        let kwargs = if let Some(opts) = &self.options {
+            opts.as_pydict(py)?.into_bound_py_any(py)?
+        } else {
+            pyo3::types::PyDict::new(py).into_bound_py_any(py)?
+        };
+
+        let args = pyo3::types::PyTuple::new(py, [self.re.patterns().into_bound_py_any(py)?])?;
+        pyo3::types::PyTuple::new(py, &[args.into_bound_py_any(py)?, kwargs])
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self}")
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.re.patterns() == other.re.patterns() && self.options == other.options
+    }
+
+    fn __ne__(&self, other: &Self) -> bool {
+        !self.__eq__(other)
+    }
+
+    /// Returns true if and only if any regex in the set matches anywhere in the haystack given.
+    fn is_match(&self, haystack: ReadableBuffer<'_, '_>) -> bool {
+        self.re.is_match(haystack.as_slice())
+    }
+
+    /// Returns true if and only if any regex in the set matches anywhere in the haystack given.
+    fn test(&self, haystack: ReadableBuffer<'_, '_>) -> bool {
+        self.re.is_match(haystack.as_slice())
+    }
+
+    fn __len__(&self) -> usize {
+        self.re.len()
+    }
+
+    /// Iterate over the patterns in the set
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.re.patterns())?.try_iter()
+    }
+
+    /// The patterns in the set (in the order given)
+    #[getter]
+    fn patterns(&self) -> Vec<&str> {
+        self.re.patterns().iter().map(String::as_str).collect()
+    }
+
+    /// Return the number of patterns in the set
+    fn len(&self) -> usize {
+        self.re.len()
+    }
+
+    /// Return `True` if the set contains no patterns
+    fn is_empty(&self) -> bool {
+        self.re.is_empty()
+    }
+
+    /// Return the indices of every pattern in the set that matches anywhere
+    /// in the haystack
+    fn matches(&self, haystack: ReadableBuffer<'_, '_>) -> PySetMatches {
+        PySetMatches::from(self.re.matches(haystack.as_slice()))
+    }
+
+    /// Run `matches` over every haystack in a sequence (without the GIL)
+    fn matches_many(&self, py: Python<'_>, haystacks: Vec<RyBytes>) -> Vec<PySetMatches> {
+        py.detach(|| {
+            haystacks
+                .iter()
+                .map(|haystack| PySetMatches::from(self.re.matches(haystack.as_slice())))
+                .collect()
+        })
+    }
+}
+
+impl Borrow<RegexSet> for PyBytesRegexSet {
+    fn borrow(&self) -> &RegexSet {
+        &self.re
+    }
+}
+
+impl std::fmt::Display for PyBytesRegexSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BytesRegexSet({:?}", self.re.patterns())?;
+        if let Some(opts) = &self.options {
+            opts.write_regex_kwargs(f)?;
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,403 @@
+//! Python `re.Match`-like captures object(s)
+//!
+//! Spans are reported as python `str` (code point) indices, NOT the utf-8
+//! byte offsets used by the `regex` crate.
+use std::sync::Arc;
+
+use pyo3::exceptions::{PyIndexError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+use pyo3::types::{PyDict, PyString, PyTuple};
+use regex::{CaptureLocations, Regex};
+
+/// Byte-offset => char-offset converter
+///
+/// Caches the last converted (byte, char) offset pair so converting the spans
+/// of successive matches (as in `captures_iter`) is linear in the haystack
+/// length.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CharOffsets {
+    ascii: bool,
+    byte: usize,
+    char: usize,
+}
+
+impl CharOffsets {
+    pub(crate) fn new(haystack: &str) -> Self {
+        Self {
+            ascii: haystack.is_ascii(),
+            byte: 0,
+            char: 0,
+        }
+    }
+
+    /// Convert the (byte) span of every group to char spans; all group spans
+    /// must be within the span of group 0 and group 0 must not start before
+    /// the start of any previously converted match.
+    fn char_spans(
+        &mut self,
+        haystack: &str,
+        spans: &[Option<(usize, usize)>],
+    ) -> Vec<Option<(usize, usize)>> {
+        if self.ascii {
+            return spans.to_vec();
+        }
+        let Some(Some((match_start, _))) = spans.first() else {
+            return spans.to_vec();
+        };
+        if *match_start < self.byte {
+            // only happens if used out of order; restart from the beginning
+            self.byte = 0;
+            self.char = 0;
+        }
+        self.char += haystack[self.byte..*match_start].chars().count();
+        self.byte = *match_start;
+        let (anchor_byte, anchor_char) = (self.byte, self.char);
+        let to_char = |byte: usize| anchor_char + haystack[anchor_byte..byte].chars().count();
+        spans
+            .iter()
+            .map(|span| span.map(|(start, end)| (to_char(start), to_char(end))))
+            .collect()
+    }
+}
+
+/// Group index/name argument
+pub(crate) enum PyGroup {
+    Index(isize),
+    Name(PyBackedStr),
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyGroup {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(name) = ob.extract::<PyBackedStr>() {
+            Ok(Self::Name(name))
+        } else if let Ok(index) = ob.extract::<isize>() {
+            Ok(Self::Index(index))
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "group index must be an int or str, not '{}'",
+                ob.get_type().name()?
+            )))
+        }
+    }
+}
+
+/// Captures for a single match of a `Regex`
+#[pyclass(name = "Captures", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyCaptures {
+    pub(crate) re: Arc<Regex>,
+    pub(crate) haystack: PyBackedStr,
+    /// utf-8 byte spans of each group (group 0 is the overall match)
+    pub(crate) spans: Vec<Option<(usize, usize)>>,
+    /// python str index spans of each group
+    pub(crate) char_spans: Vec<Option<(usize, usize)>>,
+}
+
+impl PyCaptures {
+    pub(crate) fn from_locations(
+        re: Arc<Regex>,
+        haystack: PyBackedStr,
+        locs: &CaptureLocations,
+        offsets: &mut CharOffsets,
+    ) -> Self {
+        let spans = (0..locs.len()).map(|i| locs.get(i)).collect::<Vec<_>>();
+        Self::from_spans(re, haystack, spans, offsets)
+    }
+
+    pub(crate) fn from_captures(
+        re: Arc<Regex>,
+        haystack: PyBackedStr,
+        caps: &regex::Captures<'_>,
+        offsets: &mut CharOffsets,
+    ) -> Self {
+        let spans = (0..caps.len())
+            .map(|i| caps.get(i).map(|m| (m.start(), m.end())))
+            .collect::<Vec<_>>();
+        Self::from_spans(re, haystack, spans, offsets)
+    }
+
+    fn from_spans(
+        re: Arc<Regex>,
+        haystack: PyBackedStr,
+        spans: Vec<Option<(usize, usize)>>,
+        offsets: &mut CharOffsets,
+    ) -> Self {
+        let char_spans = offsets.char_spans(&haystack, &spans);
+        Self {
+            re,
+            haystack,
+            spans,
+            char_spans,
+        }
+    }
+
+    /// Resolve a group index/name to an index into the spans
+    pub(crate) fn group_index(&self, group: PyGroup) -> PyResult<usize> {
+        match group {
+            PyGroup::Index(index) => usize::try_from(index)
+                .ok()
+                .filter(|i| *i < self.spans.len()),
+            PyGroup::Name(name) => self
+                .re
+                .capture_names()
+                .position(|n| n == Some(name.as_ref())),
+        }
+        .ok_or_else(|| PyIndexError::new_err("no such group"))
+    }
+
+    /// Return the matched text for group `i` (if it participated)
+    pub(crate) fn get(&self, i: usize) -> Option<&str> {
+        self.spans
+            .get(i)
+            .copied()
+            .flatten()
+            .map(|(start, end)| &self.haystack[start..end])
+    }
+
+    fn group_or_default<'py>(
+        &self,
+        py: Python<'py>,
+        i: usize,
+        default: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match self.get(i) {
+            Some(s) => s.into_pyobject(py).map(Bound::into_any).map_err(Into::into),
+            None => Ok(default.clone()),
+        }
+    }
+
+    /// Append `template` to `dst` with `$name`/`${name}` references replaced
+    /// by the matched groups (same syntax as `regex::Captures::expand`:
+    /// `$$` is a literal `$` and unknown/unmatched groups expand to "")
+    pub(crate) fn expand_into(&self, template: &str, dst: &mut String) {
+        let mut rest = template;
+        while let Some(i) = rest.find('$') {
+            dst.push_str(&rest[..i]);
+            rest = &rest[i + 1..];
+            if let Some(tail) = rest.strip_prefix('$') {
+                dst.push('$');
+                rest = tail;
+                continue;
+            }
+            let (name, tail) = if let Some(braced) = rest.strip_prefix('{') {
+                let Some(end) = braced.find('}') else {
+                    dst.push('$');
+                    continue;
+                };
+                (&braced[..end], &braced[end + 1..])
+            } else {
+                let end = rest
+                    .find(|c: char| c != '_' && !c.is_ascii_alphanumeric())
+                    .unwrap_or(rest.len());
+                if end == 0 {
+                    dst.push('$');
+                    continue;
+                }
+                (&rest[..end], &rest[end..])
+            };
+            rest = tail;
+            let index = name
+                .parse::<usize>()
+                .ok()
+                .or_else(|| self.re.capture_names().position(|n| n == Some(name)));
+            if let Some(matched) = index.and_then(|i| self.get(i)) {
+                dst.push_str(matched);
+            }
+        }
+        dst.push_str(rest);
+    }
+
+    fn span_of(&self, group: Option<PyGroup>) -> PyResult<(isize, isize)> {
+        let i = group.map_or(Ok(0), |g| self.group_index(g))?;
+        Ok(self.char_spans[i].map_or((-1, -1), |(start, end)| {
+            (start.cast_signed(), end.cast_signed())
+        }))
+    }
+}
+
+#[pymethods]
+impl PyCaptures {
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let (start, end) = self.char_spans[0].unwrap_or_default();
+        let matched = PyString::new(py, self.get(0).unwrap_or_default()).repr()?;
+        Ok(format!("Captures(span=({start}, {end}), match={matched})"))
+    }
+
+    fn __len__(&self) -> usize {
+        self.spans.len()
+    }
+
+    fn __getitem__<'py>(&self, py: Python<'py>, group: PyGroup) -> PyResult<Bound<'py, PyAny>> {
+        let i = self.group_index(group)?;
+        self.group_or_default(py, i, &py.None().into_bound(py))
+    }
+
+    /// The string the regex was matched against
+    #[getter]
+    fn string(&self) -> &PyBackedStr {
+        &self.haystack
+    }
+
+    /// Return one or more subgroups of the match (group 0 if no args given)
+    #[pyo3(signature = (*groups))]
+    fn group<'py>(
+        &self,
+        py: Python<'py>,
+        groups: &Bound<'py, PyTuple>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let none = py.None().into_bound(py);
+        match groups.len() {
+            0 => self.group_or_default(py, 0, &none),
+            1 => {
+                let i = self.group_index(groups.get_item(0)?.extract::<PyGroup>()?)?;
+                self.group_or_default(py, i, &none)
+            }
+            _ => {
+                let items = groups
+                    .iter()
+                    .map(|g| {
+                        let i = self.group_index(g.extract::<PyGroup>()?)?;
+                        self.group_or_default(py, i, &none)
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                PyTuple::new(py, items).map(Bound::into_any)
+            }
+        }
+    }
+
+    /// Expand `$name`/`${name}` (or `$1`/`${1}`) group references in the
+    /// template; `$$` is a literal `$`
+    fn expand(&self, template: &str) -> String {
+        let mut dst = String::with_capacity(template.len());
+        self.expand_into(template, &mut dst);
+        dst
+    }
+
+    /// Return a tuple of all the subgroups (1..n) of the match
+    #[pyo3(signature = (default = None))]
+    fn groups<'py>(
+        &self,
+        py: Python<'py>,
+        default: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let default = default.unwrap_or_else(|| py.None().into_bound(py));
+        let items = (1..self.spans.len())
+            .map(|i| self.group_or_default(py, i, &default))
+            .collect::<PyResult<Vec<_>>>()?;
+        PyTuple::new(py, items)
+    }
+
+    /// Return a dict of all the named subgroups of the match
+    #[pyo3(signature = (default = None))]
+    fn groupdict<'py>(
+        &self,
+        py: Python<'py>,
+        default: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let default = default.unwrap_or_else(|| py.None().into_bound(py));
+        let dict = PyDict::new(py);
+        for (i, name) in self.re.capture_names().enumerate() {
+            if let Some(name) = name {
+                dict.set_item(name, self.group_or_default(py, i, &default)?)?;
+            }
+        }
+        Ok(dict)
+    }
+
+    /// Return the `(start, end)` (str indices) of a group; `(-1, -1)` if the
+    /// group did not participate in the match
+    #[pyo3(signature = (group = None, /))]
+    fn span(&self, group: Option<PyGroup>) -> PyResult<(isize, isize)> {
+        self.span_of(group)
+    }
+
+    /// Return the start index of a group (-1 if it did not participate)
+    #[pyo3(signature = (group = None, /))]
+    fn start(&self, group: Option<PyGroup>) -> PyResult<isize> {
+        self.span_of(group).map(|(start, _)| start)
+    }
+
+    /// Return the end index of a group (-1 if it did not participate)
+    #[pyo3(signature = (group = None, /))]
+    fn end(&self, group: Option<PyGroup>) -> PyResult<isize> {
+        self.span_of(group).map(|(_, end)| end)
+    }
+}
+
+/// Lazy iterator over the captures of successive non-overlapping matches
+#[pyclass(name = "CapturesIter", immutable_type)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+pub struct PyCapturesIter {
+    re: Arc<Regex>,
+    haystack: PyBackedStr,
+    locs: CaptureLocations,
+    offsets: CharOffsets,
+    /// byte offset to start the next search at (`None` when exhausted)
+    pos: Option<usize>,
+    last_match_end: Option<usize>,
+}
+
+impl PyCapturesIter {
+    pub(crate) fn new(re: Arc<Regex>, haystack: PyBackedStr) -> Self {
+        let locs = re.capture_locations();
+        let offsets = CharOffsets::new(&haystack);
+        Self {
+            re,
+            haystack,
+            locs,
+            offsets,
+            pos: Some(0),
+            last_match_end: None,
+        }
+    }
+
+    /// Advance to the next match (same semantics as `Regex::captures_iter`:
+    /// an empty match immediately following a match is skipped)
+    fn advance(&mut self, py: Python<'_>) -> Option<PyCaptures> {
+        loop {
+            let pos = self.pos?;
+            let Some(m) = self
+                .re
+                .captures_read_at(&mut self.locs, &self.haystack, pos)
+            else {
+                self.pos = None;
+                return None;
+            };
+            if m.is_empty() && Some(m.end()) == self.last_match_end {
+                // skip to the next char boundary and search again
+                self.pos = self.haystack[m.end()..]
+                    .chars()
+                    .next()
+                    .map(|c| m.end() + c.len_utf8());
+                continue;
+            }
+            self.pos = Some(m.end());
+            self.last_match_end = Some(m.end());
+            return Some(PyCaptures::from_locations(
+                Arc::clone(&self.re),
+                self.haystack.clone_ref(py),
+                &self.locs,
+                &mut self.offsets,
+            ));
+        }
+    }
+}
+
+#[pymethods]
+impl PyCapturesIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<PyCaptures> {
+        self.advance(py)
+    }
+
+    /// Collect the remaining captures into a list
+    fn collect(&mut self, py: Python<'_>) -> Vec<PyCaptures> {
+        std::iter::from_fn(|| self.advance(py)).collect()
+    }
+}
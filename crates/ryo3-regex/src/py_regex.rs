@@ -1,9 +1,12 @@
 use std::borrow::{Borrow, Cow};
 
 use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
 use regex::{Regex, RegexBuilder};
 
+use crate::py_captures::{CharOffsets, PyCaptures, PyCapturesIter};
 use crate::py_regex_options::PyRegexOptions;
 
 #[pyclass(name = "Regex", frozen, immutable_type, skip_from_py_object)]
@@ -166,12 +169,71 @@ impl PyRegex {
         self.find_all(haystack)
     }
 
-    fn replace<'py>(&self, haystack: &'py str, replacement: &str) -> Cow<'py, str> {
-        self.re.replace(haystack, replacement)
+    /// Return the captures of the leftmost-first match in the haystack (or
+    /// `None` if there is no match)
+    fn captures(&self, haystack: PyBackedStr) -> Option<PyCaptures> {
+        let mut locs = self.re.capture_locations();
+        self.re.captures_read(&mut locs, &haystack)?;
+        let mut offsets = CharOffsets::new(&haystack);
+        Some(PyCaptures::from_locations(
+            std::sync::Arc::clone(&self.re),
+            haystack,
+            &locs,
+            &mut offsets,
+        ))
     }
 
-    fn replace_all(&self, haystack: &str, replacement: &str) -> String {
-        self.re.replace_all(haystack, replacement).to_string()
+    /// Return a lazy iterator over the captures of all successive
+    /// non-overlapping matches in the haystack
+    fn captures_iter(&self, haystack: PyBackedStr) -> PyCapturesIter {
+        PyCapturesIter::new(std::sync::Arc::clone(&self.re), haystack)
+    }
+
+    /// Return the names of all capture groups (`None` for unnamed groups;
+    /// the first element is always `None` for the implicit group 0)
+    fn capture_names(&self) -> Vec<Option<&str>> {
+        self.re.capture_names().collect()
+    }
+
+    /// Return the number of capture groups (including the implicit group 0)
+    fn captures_len(&self) -> usize {
+        self.re.captures_len()
+    }
+
+    /// Replace the leftmost-first match with the replacement
+    ///
+    /// The replacement is either a template string (`$name`/`${1}` group
+    /// references are expanded, `$$` is a literal `$`) or a callable that
+    /// receives the `Captures` of the match and returns a string.
+    fn replace<'py>(
+        &self,
+        py: Python<'py>,
+        haystack: PyBackedStr,
+        replacement: PyReplacement<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.replacen_impl(py, haystack, 1, replacement)
+    }
+
+    /// Replace all non-overlapping matches with the replacement
+    fn replace_all<'py>(
+        &self,
+        py: Python<'py>,
+        haystack: PyBackedStr,
+        replacement: PyReplacement<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.replacen_impl(py, haystack, 0, replacement)
+    }
+
+    /// Replace at most `limit` non-overlapping matches with the replacement
+    /// (`limit=0` replaces all matches)
+    fn replacen<'py>(
+        &self,
+        py: Python<'py>,
+        haystack: PyBackedStr,
+        limit: usize,
+        replacement: PyReplacement<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.replacen_impl(py, haystack, limit, replacement)
     }
 
     fn split(&self, haystack: &str) -> Vec<String> {
@@ -189,6 +251,74 @@ impl PyRegex {
     }
 }
 
+impl PyRegex {
+    fn replacen_impl<'py>(
+        &self,
+        py: Python<'py>,
+        haystack: PyBackedStr,
+        limit: usize,
+        replacement: PyReplacement<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        match replacement {
+            PyReplacement::Template(template) => {
+                match self.re.replacen(&haystack, limit, &*template) {
+                    // nothing replaced; return the original string object
+                    Cow::Borrowed(_) => haystack.into_bound_py_any(py),
+                    Cow::Owned(replaced) => replaced.into_bound_py_any(py),
+                }
+            }
+            PyReplacement::Callable(func) => {
+                let mut offsets = CharOffsets::new(&haystack);
+                let mut replaced = String::with_capacity(haystack.len());
+                let mut last_match_end = None;
+                let limit = if limit == 0 { usize::MAX } else { limit };
+                for caps in self.re.captures_iter(&haystack).take(limit) {
+                    let m = caps.get_match();
+                    replaced.push_str(&haystack[last_match_end.unwrap_or_default()..m.start()]);
+                    let py_caps = PyCaptures::from_captures(
+                        std::sync::Arc::clone(&self.re),
+                        haystack.clone_ref(py),
+                        &caps,
+                        &mut offsets,
+                    );
+                    let rep = func.call1((py_caps,))?;
+                    replaced.push_str(rep.extract::<PyBackedStr>()?.as_ref());
+                    last_match_end = Some(m.end());
+                }
+                let Some(last_match_end) = last_match_end else {
+                    // nothing replaced; return the original string object
+                    return haystack.into_bound_py_any(py);
+                };
+                replaced.push_str(&haystack[last_match_end..]);
+                replaced.into_bound_py_any(py)
+            }
+        }
+    }
+}
+
+/// Regex replacement: template string or callable receiving `Captures`
+pub(crate) enum PyReplacement<'py> {
+    Template(PyBackedStr),
+    Callable(Bound<'py, PyAny>),
+}
+
+impl<'py> FromPyObject<'_, 'py> for PyReplacement<'py> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(template) = ob.extract::<PyBackedStr>() {
+            Ok(Self::Template(template))
+        } else if ob.is_callable() {
+            Ok(Self::Callable(ob.to_owned()))
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "replacement must be a str or callable, not '{}'",
+                ob.get_type().name()?
+            )))
+        }
+    }
+}
+
 impl Borrow<Regex> for PyRegex {
     fn borrow(&self) -> &Regex {
         &self.re
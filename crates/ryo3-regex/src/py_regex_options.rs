@@ -4,8 +4,33 @@
 //!
 //! REF: <https://docs.rs/regex/latest/regex/struct.RegexBuilder.html>
 use pyo3::IntoPyObjectExt;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+/// Apply the options to a `regex` builder; all of the `regex` builders
+/// (`RegexBuilder`, `RegexSetBuilder` and their `regex::bytes` counterparts)
+/// share the same option methods.
+macro_rules! configure_builder {
+    ($builder:ident, $opts:expr) => {{
+        let opts = $opts;
+        let builder = $builder
+            .case_insensitive(opts.case_insensitive)
+            .crlf(opts.crlf)
+            .dot_matches_new_line(opts.dot_matches_new_line)
+            .ignore_whitespace(opts.ignore_whitespace)
+            .line_terminator(opts.line_terminator)
+            .multi_line(opts.multi_line)
+            .octal(opts.octal)
+            .swap_greed(opts.swap_greed)
+            .unicode(opts.unicode);
+        if let Some(size_limit) = opts.size_limit {
+            builder.size_limit(size_limit)
+        } else {
+            builder
+        }
+    }};
+}
+
 #[expect(clippy::struct_excessive_bools)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct PyRegexOptions {
@@ -118,46 +143,37 @@ impl PyRegexOptions {
 
     pub(crate) fn build_pattern(&self, pattern: &str) -> PyResult<regex::Regex> {
         let mut builder = regex::RegexBuilder::new(pattern);
-        let mut builder = builder
-            .case_insensitive(self.case_insensitive)
-            .crlf(self.crlf)
-            .dot_matches_new_line(self.dot_matches_new_line)
-            .ignore_whitespace(self.ignore_whitespace)
-            .line_terminator(self.line_terminator)
-            .multi_line(self.multi_line)
-            .octal(self.octal)
-            .swap_greed(self.swap_greed)
-            .unicode(self.unicode);
-        if let Some(size_limit) = self.size_limit {
-            builder = builder.size_limit(size_limit);
-        }
-        builder.build().map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid regex: {e}"))
-        })
+        configure_builder!(builder, self)
+            .build()
+            .map_err(|e| PyValueError::new_err(format!("Invalid regex: {e}")))
     }
 
-    // TODO: use for `regex::RegexSet` wrapper
-    // pub(crate) fn build_patterns<I, S>(&self, exprs: I) -> PyResult<regex::RegexSet>
-    // where
-    //     S: AsRef<str>,
-    //     I: IntoIterator<Item = S>,
-    // {
-    //     let mut builder = regex::RegexSetBuilder::new(exprs);
-    //     let mut builder = builder
-    //         .case_insensitive(self.case_insensitive)
-    //         .crlf(self.crlf)
-    //         .dot_matches_new_line(self.dot_matches_new_line)
-    //         .ignore_whitespace(self.ignore_whitespace)
-    //         .line_terminator(self.line_terminator)
-    //         .multi_line(self.multi_line)
-    //         .octal(self.octal)
-    //         .swap_greed(self.swap_greed)
-    //         .unicode(self.unicode);
-    //     if let Some(size_limit) = self.size_limit {
-    //         builder = builder.size_limit(size_limit);
-    //     }
-    //     builder.build().map_err(|e| {
-    //         PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid regex-set: {e}"))
-    //     })
-    // }
+    pub(crate) fn build_patterns<I, S>(&self, exprs: I) -> PyResult<regex::RegexSet>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+    {
+        let mut builder = regex::RegexSetBuilder::new(exprs);
+        configure_builder!(builder, self)
+            .build()
+            .map_err(|e| PyValueError::new_err(format!("Invalid regex-set: {e}")))
+    }
+
+    pub(crate) fn build_bytes_pattern(&self, pattern: &str) -> PyResult<regex::bytes::Regex> {
+        let mut builder = regex::bytes::RegexBuilder::new(pattern);
+        configure_builder!(builder, self)
+            .build()
+            .map_err(|e| PyValueError::new_err(format!("Invalid regex: {e}")))
+    }
+
+    pub(crate) fn build_bytes_patterns<I, S>(&self, exprs: I) -> PyResult<regex::bytes::RegexSet>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+    {
+        let mut builder = regex::bytes::RegexSetBuilder::new(exprs);
+        configure_builder!(builder, self)
+            .build()
+            .map_err(|e| PyValueError::new_err(format!("Invalid regex-set: {e}")))
+    }
 }
@@ -1,7 +1,9 @@
 use crate::py_regex_options::PyRegexOptions;
+use crate::py_set_matches::PySetMatches;
+use pyo3::types::{PyIterator, PyList};
 use pyo3::{IntoPyObjectExt, prelude::*, pybacked::PyBackedStr};
-use regex::{Regex, RegexBuilder, RegexSet, RegexSetBuilder};
-use std::borrow::{Borrow, Cow};
+use regex::{RegexSet, RegexSetBuilder};
+use std::borrow::Borrow;
 
 #[pyclass(name = "RegexSet", frozen, immutable_type, skip_from_py_object)]
 #[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
@@ -39,7 +41,7 @@ impl TryFrom<RegexSetBuilder> for PyRegexSet {
 impl PyRegexSet {
     #[new]
     #[pyo3(signature = (
-        pattern,
+        patterns,
         *,
         case_insensitive = false,
         crlf = false,
@@ -55,7 +57,7 @@ impl PyRegexSet {
     #[expect(clippy::too_many_arguments, reason = "python kwargs")]
     #[expect(clippy::fn_params_excessive_bools)]
     fn py_new(
-        pattern: Vec<PyBackedStr>,
+        patterns: Vec<PyBackedStr>,
         // kwargs
         case_insensitive: bool,
         crlf: bool,
@@ -68,7 +70,6 @@ impl PyRegexSet {
         swap_greed: bool,
         unicode: bool,
     ) -> PyResult<Self> {
-        // let line_terminator_u8: u8 = get_line_terminator_u8(line_terminator)?;
         let options = PyRegexOptions {
             case_insensitive,
             crlf,
@@ -82,7 +83,7 @@ impl PyRegexSet {
             unicode,
         };
 
-        let re = options.build_patterns(pattern)?;
+        let re = options.build_patterns(patterns)?;
         Ok(Self {
             re: std::sync::Arc::new(re),
             options: Some(options),
@@ -138,6 +139,48 @@ impl PyRegexSet {
     fn test(&self, text: &str) -> bool {
         self.re.is_match(text)
     }
+
+    fn __len__(&self) -> usize {
+        self.re.len()
+    }
+
+    /// Iterate over the patterns in the set
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.re.patterns())?.try_iter()
+    }
+
+    /// The patterns in the set (in the order given)
+    #[getter]
+    fn patterns(&self) -> Vec<&str> {
+        self.re.patterns().iter().map(String::as_str).collect()
+    }
+
+    /// Return the number of patterns in the set
+    fn len(&self) -> usize {
+        self.re.len()
+    }
+
+    /// Return `True` if the set contains no patterns
+    fn is_empty(&self) -> bool {
+        self.re.is_empty()
+    }
+
+    /// Return the indices of every pattern in the set that matches anywhere
+    /// in the haystack
+    fn matches(&self, text: &str) -> PySetMatches {
+        PySetMatches::from(self.re.matches(text))
+    }
+
+    /// Run `matches` over every haystack in a sequence (without the GIL)
+    #[expect(clippy::needless_pass_by_value)]
+    fn matches_many(&self, py: Python<'_>, texts: Vec<PyBackedStr>) -> Vec<PySetMatches> {
+        py.detach(|| {
+            texts
+                .iter()
+                .map(|text| PySetMatches::from(self.re.matches(text)))
+                .collect()
+        })
+    }
 }
 
 impl Borrow<RegexSet> for PyRegexSet {
@@ -0,0 +1,104 @@
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+use pyo3::types::{PyIterator, PyList};
+
+/// Indices of the patterns in a `RegexSet`/`BytesRegexSet` that matched a
+/// haystack (`regex::SetMatches`)
+#[pyclass(name = "SetMatches", frozen, immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.ryo3"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PySetMatches {
+    /// indices of the matched patterns (ascending)
+    matched: Vec<usize>,
+    /// number of patterns in the set
+    patterns_len: usize,
+}
+
+impl From<regex::SetMatches> for PySetMatches {
+    fn from(matches: regex::SetMatches) -> Self {
+        Self {
+            matched: matches.iter().collect(),
+            patterns_len: matches.len(),
+        }
+    }
+}
+
+impl From<regex::bytes::SetMatches> for PySetMatches {
+    fn from(matches: regex::bytes::SetMatches) -> Self {
+        Self {
+            matched: matches.iter().collect(),
+            patterns_len: matches.len(),
+        }
+    }
+}
+
+impl PySetMatches {
+    fn is_match(&self, index: usize) -> bool {
+        self.matched.binary_search(&index).is_ok()
+    }
+}
+
+#[pymethods]
+impl PySetMatches {
+    fn __repr__(&self) -> String {
+        format!("SetMatches({:?})", self.matched)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Number of patterns that matched
+    fn __len__(&self) -> usize {
+        self.matched.len()
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.matched.is_empty()
+    }
+
+    /// non-index values (negative ints, non-ints) are never contained
+    fn __contains__(&self, index: &Bound<'_, PyAny>) -> bool {
+        index
+            .extract::<usize>()
+            .is_ok_and(|index| self.is_match(index))
+    }
+
+    /// Iterate over the indices of the matched patterns (ascending)
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, &self.matched)?.try_iter()
+    }
+
+    /// Return `True` if any pattern in the set matched
+    fn matched_any(&self) -> bool {
+        !self.matched.is_empty()
+    }
+
+    /// Return `True` if every pattern in the set matched
+    fn matched_all(&self) -> bool {
+        self.matched.len() == self.patterns_len
+    }
+
+    /// Return `True` if the pattern at `index` matched
+    fn matched(&self, index: usize) -> PyResult<bool> {
+        if index < self.patterns_len {
+            Ok(self.is_match(index))
+        } else {
+            Err(PyIndexError::new_err(format!(
+                "pattern index out of range: {index} >= {}",
+                self.patterns_len
+            )))
+        }
+    }
+
+    /// Return the indices of the matched patterns as a list
+    fn to_list(&self) -> Vec<usize> {
+        self.matched.clone()
+    }
+
+    /// Number of patterns in the set that produced these matches
+    #[getter]
+    fn patterns_len(&self) -> usize {
+        self.patterns_len
+    }
+}
@@ -1,5 +1,4 @@
 use bytes::Bytes;
-use jiter::map_json_error;
 use pyo3::prelude::*;
 use ryo3_jiter::JiterParseOptions;
 
@@ -35,10 +34,6 @@ impl<'py> IntoPyObject<'py> for Pyo3JsonBytes {
     type Error = PyErr;
 
     fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        let b_u8 = &self.bytes[..];
-        let parser = self.options.parser();
-        parser
-            .python_parse(py, b_u8)
-            .map_err(|e| map_json_error(b_u8, &e))
+        self.options.parse(py, &self.bytes[..])
     }
 }
@@ -288,9 +288,13 @@ impl RyResponse {
             cache_mode = jiter::StringCacheMode::All,
             partial_mode = jiter::PartialMode::Off,
             catch_duplicate_keys = false,
+            float_mode = jiter::FloatMode::Float,
+            big_int_mode = ryo3_jiter::BigIntMode::Int,
+            lazy_numbers = false,
         ),
-        text_signature = "(self, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False)"
+        text_signature = "(self, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False)"
     )]
+    #[expect(clippy::too_many_arguments, reason = "python kwargs")]
     fn json<'py>(
         &'py self,
         py: Python<'py>,
@@ -298,13 +302,19 @@ impl RyResponse {
         cache_mode: jiter::StringCacheMode,
         partial_mode: jiter::PartialMode,
         catch_duplicate_keys: bool,
+        float_mode: jiter::FloatMode,
+        big_int_mode: ryo3_jiter::BigIntMode,
+        lazy_numbers: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         let body = self.take_body()?;
         let options = ryo3_jiter::JiterParseOptions::new()
             .with_allow_inf_nan(allow_inf_nan)
             .with_cache_mode(cache_mode)
             .with_partial_mode(partial_mode)
-            .with_catch_duplicate_keys(catch_duplicate_keys);
+            .with_catch_duplicate_keys(catch_duplicate_keys)
+            .with_float_mode(float_mode)
+            .with_big_int_mode(big_int_mode)
+            .with_lazy_numbers(lazy_numbers);
         future_into_py(py, async move {
             read_body_bytes(body)
                 .await
@@ -490,15 +500,22 @@ impl RyResponse {
             cache_mode = jiter::StringCacheMode::All,
             partial_mode = jiter::PartialMode::Off,
             catch_duplicate_keys = false,
+            float_mode = jiter::FloatMode::Float,
+            big_int_mode = ryo3_jiter::BigIntMode::Int,
+            lazy_numbers = false,
         ),
-        text_signature = "(self, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False)"
+        text_signature = "(self, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False)"
     )]
+    #[expect(clippy::too_many_arguments, reason = "python kwargs")]
     async fn json(
         &self,
         allow_inf_nan: bool,
         cache_mode: jiter::StringCacheMode,
         partial_mode: jiter::PartialMode,
         catch_duplicate_keys: bool,
+        float_mode: jiter::FloatMode,
+        big_int_mode: ryo3_jiter::BigIntMode,
+        lazy_numbers: bool,
         #[pyo3(cancel_handle)] cancel: CancelHandle,
     ) -> PyResult<Pyo3JsonBytes> {
         let body = self.take_body()?;
@@ -506,7 +523,10 @@ impl RyResponse {
             .with_allow_inf_nan(allow_inf_nan)
             .with_cache_mode(cache_mode)
             .with_partial_mode(partial_mode)
-            .with_catch_duplicate_keys(catch_duplicate_keys);
+            .with_catch_duplicate_keys(catch_duplicate_keys)
+            .with_float_mode(float_mode)
+            .with_big_int_mode(big_int_mode)
+            .with_lazy_numbers(lazy_numbers);
 
         on_tokio_py(async move {
             read_body_bytes_with_cancel(body, cancel)
@@ -690,9 +710,13 @@ impl RyBlockingResponse {
             cache_mode = jiter::StringCacheMode::All,
             partial_mode = jiter::PartialMode::Off,
             catch_duplicate_keys = false,
+            float_mode = jiter::FloatMode::Float,
+            big_int_mode = ryo3_jiter::BigIntMode::Int,
+            lazy_numbers = false,
         ),
-        text_signature = "(self, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False)"
+        text_signature = "(self, *, allow_inf_nan=False, cache_mode=\"all\", partial_mode=False, catch_duplicate_keys=False, float_mode=\"float\", big_int_mode=\"int\", lazy_numbers=False)"
     )]
+    #[expect(clippy::too_many_arguments, reason = "python kwargs")]
     fn json(
         &self,
         py: Python<'_>,
@@ -700,13 +724,19 @@ impl RyBlockingResponse {
         cache_mode: jiter::StringCacheMode,
         partial_mode: jiter::PartialMode,
         catch_duplicate_keys: bool,
+        float_mode: jiter::FloatMode,
+        big_int_mode: ryo3_jiter::BigIntMode,
+        lazy_numbers: bool,
     ) -> PyResult<Pyo3JsonBytes> {
         let body = self.take_body()?;
         let options = ryo3_jiter::JiterParseOptions::new()
             .with_allow_inf_nan(allow_inf_nan)
             .with_cache_mode(cache_mode)
             .with_partial_mode(partial_mode)
-            .with_catch_duplicate_keys(catch_duplicate_keys);
+            .with_catch_duplicate_keys(catch_duplicate_keys)
+            .with_float_mode(float_mode)
+            .with_big_int_mode(big_int_mode)
+            .with_lazy_numbers(lazy_numbers);
 
         py.detach(|| {
             get_tokio_runtime().block_on(async {
@@ -10,4 +10,7 @@ mod ob_type_cache;
 pub mod ser;
 
 pub(crate) use constants::{Depth, MAX_DEPTH};
-pub use ser::{JsonTarget, PyAnySerializer, PySerializeTarget, SerdeTarget};
+pub use ser::{
+    JsonTarget, PyAnySerializer, PySerializeOptions, PySerializeTarget, SerdeTarget,
+    check_callable, strip_tz_annotation,
+};
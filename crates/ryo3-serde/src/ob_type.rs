@@ -1,3 +1,5 @@
+use crate::ser::PySerializeTarget;
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub(crate) enum PyObType {
     Unknown,
@@ -40,6 +42,12 @@ pub(crate) enum PyObType {
     // UUID
     // ------------------------------------------------------------------------
     PyUuid,
+    // ------------------------------------------------------------------------
+    // DECIMAL/PATHLIB/IPADDRESS (serialized as `str()`)
+    // ------------------------------------------------------------------------
+    PyDecimal,
+    PyPath,
+    PyIpAddress,
     // ========================================================================
     // RY-TYPES
     // ========================================================================
@@ -102,3 +110,36 @@ pub(crate) enum PyObType {
     #[cfg(feature = "ryo3-jiff")]
     RyZoned,
 }
+
+impl PyObType {
+    /// `True` for date/time/datetime-like types (python stdlib + ry/jiff)
+    #[inline]
+    pub(crate) fn is_datetime_like(self) -> bool {
+        match self {
+            Self::DateTime | Self::Date | Self::Time => true,
+            #[cfg(feature = "ryo3-jiff")]
+            Self::RyDate | Self::RyDateTime | Self::RyTime | Self::RyTimestamp | Self::RyZoned => {
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// `True` for uuid types (python stdlib + ry)
+    #[inline]
+    pub(crate) fn is_uuid_like(self) -> bool {
+        match self {
+            Self::PyUuid => true,
+            #[cfg(feature = "ryo3-uuid")]
+            Self::RyUuid => true,
+            _ => false,
+        }
+    }
+
+    /// `True` if the target has a native representation for this type (see
+    /// `PySerializeTarget::NATIVE_DATETIMES`/`NATIVE_UUIDS`)
+    #[inline]
+    pub(crate) fn is_native<T: PySerializeTarget>(self) -> bool {
+        (T::NATIVE_DATETIMES && self.is_datetime_like()) || (T::NATIVE_UUIDS && self.is_uuid_like())
+    }
+}
@@ -40,6 +40,20 @@ pub(crate) struct PyTypeCache {
     pub py_uuid: usize,
     // enum `enum.EnumMeta`
     pub enum_meta: usize,
+    // decimal
+    pub py_decimal: usize,
+    // pathlib
+    pub py_posix_path: usize,
+    pub py_windows_path: usize,
+    pub py_pure_posix_path: usize,
+    pub py_pure_windows_path: usize,
+    // ipaddress
+    pub py_ipv4_address: usize,
+    pub py_ipv6_address: usize,
+    pub py_ipv4_network: usize,
+    pub py_ipv6_network: usize,
+    pub py_ipv4_interface: usize,
+    pub py_ipv6_interface: usize,
     // ------------------------------------------------------------------------
     // RY-TYPES
     // ------------------------------------------------------------------------
@@ -125,6 +139,20 @@ impl PyTypeCache {
             py_uuid: get_uuid_ob_pointer(py), // use uuid.NAMESPACE_DNS as a proxy for the uuid type
             // enum
             enum_meta: get_enum_meta_pointer(py),
+            // decimal
+            py_decimal: get_type_pointer(py, "decimal", "Decimal"),
+            // pathlib
+            py_posix_path: get_type_pointer(py, "pathlib", "PosixPath"),
+            py_windows_path: get_type_pointer(py, "pathlib", "WindowsPath"),
+            py_pure_posix_path: get_type_pointer(py, "pathlib", "PurePosixPath"),
+            py_pure_windows_path: get_type_pointer(py, "pathlib", "PureWindowsPath"),
+            // ipaddress
+            py_ipv4_address: get_type_pointer(py, "ipaddress", "IPv4Address"),
+            py_ipv6_address: get_type_pointer(py, "ipaddress", "IPv6Address"),
+            py_ipv4_network: get_type_pointer(py, "ipaddress", "IPv4Network"),
+            py_ipv6_network: get_type_pointer(py, "ipaddress", "IPv6Network"),
+            py_ipv4_interface: get_type_pointer(py, "ipaddress", "IPv4Interface"),
+            py_ipv6_interface: get_type_pointer(py, "ipaddress", "IPv6Interface"),
 
             // ----------------------------------------------------------------
             // RY-TYPES
@@ -232,6 +260,17 @@ fn get_enum_meta_pointer(py: Python) -> usize {
     enum_meta.as_ptr() as usize
 }
 
+/// pointer of a stdlib type (e.g. `decimal.Decimal`)
+///
+/// If the type cannot be imported there can be no instances of it, so the
+/// (never matching) null pointer is used and any error is left for the
+/// python code that imports it to raise.
+fn get_type_pointer(py: Python, module: &str, name: &str) -> usize {
+    py.import(module)
+        .and_then(|m| m.getattr(name))
+        .map_or(0, |tp| tp.as_ptr() as usize)
+}
+
 macro_rules! py_obj_ptr {
     ($self:ident, $ptr:ident, $field:ident, $Variant:ident) => {
         if $ptr == $self.$field {
@@ -279,6 +318,17 @@ impl PyTypeCache {
         py_obj_ptr!(self, ptr, ellipsis, Ellipsis);
         py_obj_ptr!(self, ptr, frozenset, FrozenSet);
         py_obj_ptr!(self, ptr, set, Set);
+        py_obj_ptr!(self, ptr, py_decimal, PyDecimal);
+        py_obj_ptr!(self, ptr, py_posix_path, PyPath);
+        py_obj_ptr!(self, ptr, py_windows_path, PyPath);
+        py_obj_ptr!(self, ptr, py_pure_posix_path, PyPath);
+        py_obj_ptr!(self, ptr, py_pure_windows_path, PyPath);
+        py_obj_ptr!(self, ptr, py_ipv4_address, PyIpAddress);
+        py_obj_ptr!(self, ptr, py_ipv6_address, PyIpAddress);
+        py_obj_ptr!(self, ptr, py_ipv4_network, PyIpAddress);
+        py_obj_ptr!(self, ptr, py_ipv6_network, PyIpAddress);
+        py_obj_ptr!(self, ptr, py_ipv4_interface, PyIpAddress);
+        py_obj_ptr!(self, ptr, py_ipv6_interface, PyIpAddress);
 
         // --- ryo3-* features ---
         // Ordered by how much (i (jesse)) use them
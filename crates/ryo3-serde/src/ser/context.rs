@@ -2,8 +2,9 @@ use core::marker::PhantomData;
 
 use pyo3::{Bound, PyAny};
 
+use crate::ob_type::PyObType;
 use crate::ob_type_cache::PyTypeCache;
-use crate::ser::{PySerializeTarget, SerdeTarget};
+use crate::ser::{PySerializeOptions, PySerializeTarget, SerdeTarget};
 
 #[derive(Copy, Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -13,6 +14,7 @@ where
 {
     pub(crate) default: Option<&'py Bound<'py, PyAny>>,
     pub(crate) typeref: &'py PyTypeCache,
+    pub(crate) opts: PySerializeOptions,
     _target: PhantomData<T>,
 }
 
@@ -20,11 +22,43 @@ impl<'py, T> PySerializeContext<'py, T>
 where
     T: PySerializeTarget,
 {
-    pub(crate) fn new(default: Option<&'py Bound<'py, PyAny>>, typeref: &'py PyTypeCache) -> Self {
+    pub(crate) fn new(
+        default: Option<&'py Bound<'py, PyAny>>,
+        typeref: &'py PyTypeCache,
+        opts: PySerializeOptions,
+    ) -> Self {
         Self {
             default,
             typeref,
+            opts,
             _target: PhantomData,
         }
     }
+
+    /// `true` if objects of `ob_type` must go through the `PyAnySerializer`
+    /// (target hooks and serialize options) instead of the fast paths for
+    /// sequence elements/mapping values
+    #[inline]
+    pub(crate) fn is_special(&self, ob_type: PyObType) -> bool {
+        ob_type.is_native::<T>()
+            || (self.opts.datetime_opts()
+                && matches!(
+                    ob_type,
+                    PyObType::DateTime | PyObType::Date | PyObType::Time
+                ))
+    }
+
+    /// context for serializing non-str map keys; the passthrough options only
+    /// apply to values (keys are always stringified)
+    #[inline]
+    pub(crate) fn for_keys(self) -> Self {
+        Self {
+            opts: PySerializeOptions {
+                passthrough_dataclass: false,
+                passthrough_datetime: false,
+                ..self.opts
+            },
+            ..self
+        }
+    }
 }
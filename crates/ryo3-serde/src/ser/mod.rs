@@ -1,6 +1,8 @@
 //! Serialize python objects
 mod context;
 pub(crate) mod dataclass;
+mod object_kind;
+mod options;
 pub(crate) mod py_serialize;
 #[expect(clippy::inline_always)]
 pub(crate) mod py_types;
@@ -8,5 +10,6 @@ mod ry_types;
 mod target;
 
 pub(crate) use context::PySerializeContext;
+pub use options::PySerializeOptions;
 pub use py_serialize::PyAnySerializer;
-pub use target::{JsonTarget, PySerializeTarget, SerdeTarget};
+pub use target::{JsonTarget, PySerializeTarget, SerdeTarget, check_callable, strip_tz_annotation};
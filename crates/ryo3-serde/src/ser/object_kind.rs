@@ -0,0 +1,96 @@
+//! Per-type cache of the python protocols probed on the slow (unknown type)
+//! path: pydantic models, `attrs` classes and namedtuples
+//!
+//! These are plain python classes (no type pointer to compare against), so
+//! detecting them takes a few attribute lookups on the type; the result is
+//! cached per type. The cache holds a strong reference to each type so a
+//! cached pointer can never be reused by a new type, and it is cleared when
+//! full so dynamically created classes do not pile up.
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{PyTuple, PyType};
+
+use crate::ser::py_types::{attrs_fields, is_pydantic_model, namedtuple_fields};
+
+/// max number of cached types (the cache is cleared when full)
+const MAX_CACHED_TYPES: usize = 1024;
+
+pub(crate) enum ObjectKind<'py> {
+    Pydantic,
+    /// `attrs` class instance and its `__attrs_attrs__`
+    Attrs(Bound<'py, PyTuple>),
+    /// namedtuple and its `_fields`
+    NamedTuple(Bound<'py, PyTuple>),
+    Other,
+}
+
+enum CachedKind {
+    Pydantic,
+    Attrs(Py<PyTuple>),
+    NamedTuple(Py<PyTuple>),
+    Other,
+}
+
+impl CachedKind {
+    fn probe(tp: &Bound<'_, PyType>) -> Self {
+        if is_pydantic_model(tp) {
+            Self::Pydantic
+        } else if let Some(fields) = attrs_fields(tp) {
+            Self::Attrs(fields.unbind())
+        } else if let Some(fields) = namedtuple_fields(tp) {
+            Self::NamedTuple(fields.unbind())
+        } else {
+            Self::Other
+        }
+    }
+
+    fn bind<'py>(&self, py: Python<'py>) -> ObjectKind<'py> {
+        match self {
+            Self::Pydantic => ObjectKind::Pydantic,
+            Self::Attrs(fields) => ObjectKind::Attrs(fields.bind(py).clone()),
+            Self::NamedTuple(fields) => ObjectKind::NamedTuple(fields.bind(py).clone()),
+            Self::Other => ObjectKind::Other,
+        }
+    }
+}
+
+type KindCache = HashMap<usize, (Py<PyType>, CachedKind)>;
+
+static KIND_CACHE: PyOnceLock<Mutex<KindCache>> = PyOnceLock::new();
+
+/// pydantic model, `attrs` instance, namedtuple or other object
+pub(crate) fn object_kind<'py>(obj: Borrowed<'_, 'py, PyAny>) -> ObjectKind<'py> {
+    let py = obj.py();
+    // classes themselves are never serialized as instances
+    if obj.is_instance_of::<PyType>() {
+        return ObjectKind::Other;
+    }
+    let tp = obj.get_type();
+    let ptr = tp.as_type_ptr() as usize;
+    let cache = KIND_CACHE.get_or_init(py, || Mutex::new(HashMap::new()));
+    if let Some((_, kind)) = cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&ptr)
+    {
+        return kind.bind(py);
+    }
+    let kind = CachedKind::probe(&tp);
+    let object_kind = kind.bind(py);
+    let evicted = {
+        let mut cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+        let evicted = if cache.len() >= MAX_CACHED_TYPES {
+            std::mem::take(&mut *cache)
+        } else {
+            KindCache::new()
+        };
+        cache.insert(ptr, (tp.unbind(), kind));
+        evicted
+    };
+    // dropping types can run python code, so not while holding the lock
+    drop(evicted);
+    object_kind
+}
@@ -0,0 +1,32 @@
+/// Runtime (per call) serialization options
+///
+/// Unlike the `PySerializeTarget` consts these are set from python kwargs
+/// (e.g. `ry.stringify(obj, non_str_keys=True)`).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct PySerializeOptions {
+    /// Serialize `int`/`float`/`bool`/`None`/uuid/date/time/datetime/enum
+    /// mapping keys as strings instead of raising (orjson `OPT_NON_STR_KEYS`)
+    pub non_str_keys: bool,
+    /// Serialize naive `datetime.datetime` objects as UTC (orjson
+    /// `OPT_NAIVE_UTC`)
+    pub naive_utc: bool,
+    /// Serialize UTC `datetime.datetime` objects with a `Z` suffix instead of
+    /// `+00:00` (orjson `OPT_UTC_Z`)
+    pub utc_z: bool,
+    /// Pass dataclasses to `default` (orjson `OPT_PASSTHROUGH_DATACLASS`)
+    pub passthrough_dataclass: bool,
+    /// Pass `datetime.datetime`/`datetime.date`/`datetime.time` objects to
+    /// `default` (orjson `OPT_PASSTHROUGH_DATETIME`)
+    pub passthrough_datetime: bool,
+}
+
+impl PySerializeOptions {
+    /// `true` if any option changes how `datetime.datetime` objects are
+    /// serialized
+    #[inline]
+    #[must_use]
+    pub(crate) fn datetime_opts(self) -> bool {
+        self.naive_utc || self.utc_z || self.passthrough_datetime
+    }
+}
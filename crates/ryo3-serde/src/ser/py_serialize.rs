@@ -11,12 +11,12 @@ use crate::ob_type_cache::PyTypeCache;
 use crate::ser::py_types::{
     PyBoolSerializer, PyBytesLikeSerializer, PyDateSerializer, PyDateTimeSerializer,
     PyDictSerializer, PyFloatSerializer, PyFrozenSetSerializer, PyIntSerializer, PyListSerializer,
-    PyNoneSerializer, PySetSerializer, PyStrSerializer, PyTimeDeltaSerializer, PyTimeSerializer,
-    PyTupleSerializer, PyUnknownSerializer, PyUuidSerializer,
+    PyNoneSerializer, PySetSerializer, PyStrLikeSerializer, PyStrSerializer, PyTimeDeltaSerializer,
+    PyTimeSerializer, PyTupleSerializer, PyUnknownSerializer, PyUuidSerializer,
 };
 #[cfg(feature = "ry")]
 use crate::ser::ry_types;
-use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
+use crate::ser::{PySerializeContext, PySerializeOptions, PySerializeTarget, SerdeTarget};
 use crate::{Depth, JsonTarget, MAX_DEPTH, serde_err_recursion};
 
 pub struct PyAnySerializer<'a, 'py, T = SerdeTarget>
@@ -37,13 +37,39 @@ where
     pub fn with_target(
         obj: Borrowed<'a, 'py, PyAny>,
         default: Option<&'py Bound<'py, PyAny>>,
+    ) -> Self {
+        Self::with_options(obj, default, PySerializeOptions::default())
+    }
+
+    /// Serializer with runtime (python kwargs) serialization options
+    #[inline]
+    #[must_use]
+    pub fn with_options(
+        obj: Borrowed<'a, 'py, PyAny>,
+        default: Option<&'py Bound<'py, PyAny>>,
+        opts: PySerializeOptions,
     ) -> Self {
         let py = obj.py();
         let typeref = PyTypeCache::cached(py);
-        let ctx = PySerializeContext::<T>::new(default, typeref);
+        let ctx = PySerializeContext::<T>::new(default, typeref, opts);
         Self { obj, ctx, depth: 0 }
     }
 
+    /// The python object being serialized
+    #[inline]
+    #[must_use]
+    pub fn obj(&self) -> Borrowed<'a, 'py, PyAny> {
+        self.obj
+    }
+
+    /// Serializer for a python object nested in this one (same target,
+    /// `default` fn and recursion limit)
+    #[inline]
+    #[must_use]
+    pub fn nested<'b>(&self, obj: Borrowed<'b, 'py, PyAny>) -> PyAnySerializer<'b, 'py, T> {
+        PyAnySerializer::new_with_depth(obj, self.ctx, self.depth + 1)
+    }
+
     #[inline]
     #[must_use]
     pub(crate) fn new_with_depth(
@@ -88,10 +114,31 @@ where
             return serde_err_recursion!();
         }
         let ob_type = self.ctx.typeref.obtype(self.obj);
+        if self.ctx.opts.passthrough_datetime
+            && matches!(
+                ob_type,
+                PyObType::DateTime | PyObType::Date | PyObType::Time
+            )
+        {
+            return PyUnknownSerializer::new(self.obj, self.ctx, self.depth).serialize(serializer);
+        }
+        let serializer = if ob_type.is_native::<T>() {
+            let res = if ob_type.is_uuid_like() {
+                T::serialize_uuid(self.obj, serializer)
+            } else {
+                T::serialize_datetime(self.obj, serializer)
+            };
+            match res {
+                Ok(res) => return res,
+                Err(serializer) => serializer,
+            }
+        } else {
+            serializer
+        };
         match ob_type {
             PyObType::None | PyObType::Ellipsis => PyNoneSerializer::new().serialize(serializer),
             PyObType::Bool => PyBoolSerializer::new_unchecked(self.obj).serialize(serializer),
-            PyObType::Int => PyIntSerializer::new_unchecked(self.obj).serialize(serializer),
+            PyObType::Int => PyIntSerializer::<T>::new_unchecked(self.obj).serialize(serializer),
             PyObType::Float => PyFloatSerializer::new_unchecked(self.obj).serialize(serializer),
             PyObType::String => PyStrSerializer::new_unchecked(self.obj).serialize(serializer),
             PyObType::List => PyListSerializer::new_unchecked(self.obj, self.ctx, self.depth)
@@ -107,9 +154,9 @@ where
                 PyFrozenSetSerializer::new_unchecked(self.obj, self.ctx, self.depth)
                     .serialize(serializer)
             }
-            PyObType::DateTime => {
-                PyDateTimeSerializer::new_unchecked(self.obj).serialize(serializer)
-            }
+            PyObType::DateTime => PyDateTimeSerializer::new_unchecked(self.obj)
+                .with_options(self.ctx.opts)
+                .serialize(serializer),
             PyObType::Date => PyDateSerializer::new_unchecked(self.obj).serialize(serializer),
             PyObType::Time => PyTimeSerializer::new_unchecked(self.obj).serialize(serializer),
             PyObType::Timedelta => {
@@ -119,6 +166,9 @@ where
                 PyBytesLikeSerializer::<T>::new(self.obj).serialize(serializer)
             }
             PyObType::PyUuid => PyUuidSerializer::new(self.obj).serialize(serializer),
+            PyObType::PyDecimal | PyObType::PyPath | PyObType::PyIpAddress => {
+                PyStrLikeSerializer::new(self.obj).serialize(serializer)
+            }
             // ------------------------------------------------------------
             // RY-TYPES
             // ------------------------------------------------------------
@@ -1,3 +1,4 @@
+mod py_attrs;
 mod py_bool;
 mod py_byteslike;
 mod py_dataclass;
@@ -7,12 +8,16 @@ mod py_float;
 mod py_int;
 mod py_map;
 mod py_mapping_key;
+mod py_namedtuple;
 mod py_none;
+mod py_pydantic;
 mod py_seq;
 mod py_str;
+mod py_str_like;
 mod py_unknown;
 mod py_uuid;
 
+pub(crate) use py_attrs::{PyAttrsSerializer, attrs_fields};
 pub(crate) use py_bool::PyBoolSerializer;
 pub(crate) use py_byteslike::PyBytesLikeSerializer;
 pub(crate) use py_dataclass::PyDataclassSerializer;
@@ -24,11 +29,14 @@ pub(crate) use py_float::PyFloatSerializer;
 pub(crate) use py_int::PyIntSerializer;
 pub(crate) use py_map::{PyDictSerializer, PyMappingSerializer};
 pub(crate) use py_mapping_key::PyMappingKeySerializer;
+pub(crate) use py_namedtuple::{PyNamedTupleSerializer, namedtuple_fields};
 pub(crate) use py_none::PyNoneSerializer;
+pub(crate) use py_pydantic::{PyPydanticSerializer, is_pydantic_model};
 pub(crate) use py_seq::{
     PyFrozenSetSerializer, PyListSerializer, PySequenceSerializer, PySetSerializer,
     PyTupleSerializer,
 };
 pub(crate) use py_str::{PyStrSerializer, PyStrSubclassSerializer};
+pub(crate) use py_str_like::PyStrLikeSerializer;
 pub(crate) use py_unknown::PyUnknownSerializer;
 pub(crate) use py_uuid::PyUuidSerializer;
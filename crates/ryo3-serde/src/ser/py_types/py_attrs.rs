@@ -0,0 +1,74 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyString, PyTuple, PyType};
+use pyo3::{Bound, intern};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::errors::pyerr2sererr;
+use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
+use crate::{Depth, MAX_DEPTH, PyAnySerializer, serde_err_recursion};
+
+/// Returns the `__attrs_attrs__` of an `attrs` class (like `attrs.has(tp)`)
+pub(crate) fn attrs_fields<'py>(tp: &Bound<'py, PyType>) -> Option<Bound<'py, PyTuple>> {
+    tp.getattr(intern!(tp.py(), "__attrs_attrs__"))
+        .ok()?
+        .cast_into::<PyTuple>()
+        .ok()
+}
+
+/// Serializes `attrs` class instances as maps of attribute names to values
+pub(crate) struct PyAttrsSerializer<'a, 'py, T = SerdeTarget>
+where
+    T: PySerializeTarget,
+{
+    ctx: PySerializeContext<'py, T>,
+    obj: Borrowed<'a, 'py, PyAny>,
+    fields: Bound<'py, PyTuple>,
+    depth: Depth,
+}
+
+impl<'a, 'py, T> PyAttrsSerializer<'a, 'py, T>
+where
+    T: PySerializeTarget,
+{
+    pub(crate) fn new(
+        obj: Borrowed<'a, 'py, PyAny>,
+        fields: Bound<'py, PyTuple>,
+        ctx: PySerializeContext<'py, T>,
+        depth: Depth,
+    ) -> Self {
+        Self {
+            ctx,
+            obj,
+            fields,
+            depth,
+        }
+    }
+}
+
+impl<T> Serialize for PyAttrsSerializer<'_, '_, T>
+where
+    T: PySerializeTarget,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.depth == MAX_DEPTH {
+            return serde_err_recursion!();
+        }
+        let py = self.obj.py();
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for field in &self.fields {
+            let name = field
+                .getattr(intern!(py, "name"))
+                .and_then(|name| Ok(name.cast_into::<PyString>()?))
+                .map_err(pyerr2sererr)?;
+            let value = self.obj.getattr(&name).map_err(pyerr2sererr)?;
+            let key = name.to_str().map_err(pyerr2sererr)?;
+            let value_ser =
+                PyAnySerializer::new_with_depth(value.as_borrowed(), self.ctx, self.depth + 1);
+            map.serialize_entry(key, &value_ser)?;
+        }
+        map.end()
+    }
+}
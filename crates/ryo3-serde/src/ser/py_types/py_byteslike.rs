@@ -1,5 +1,6 @@
 use core::marker::PhantomData;
 
+use pyo3::buffer::PyBuffer;
 use pyo3::prelude::*;
 use serde::ser::{Serialize, Serializer};
 
@@ -36,9 +37,22 @@ where
     where
         S: Serializer,
     {
-        match self.obj.extract::<&[u8]>() {
-            Ok(v) => v.serialize(serializer),
-            Err(e) => Err(pyerr2sererr(e)),
+        if let Ok(v) = self.obj.extract::<&[u8]>() {
+            if T::NATIVE_BYTES {
+                serializer.serialize_bytes(v)
+            } else {
+                v.serialize(serializer)
+            }
+        } else {
+            // bytearray/memoryview ~ copy out of the buffer
+            let v = PyBuffer::<u8>::get(&self.obj)
+                .and_then(|buf| buf.to_vec(self.obj.py()))
+                .map_err(pyerr2sererr)?;
+            if T::NATIVE_BYTES {
+                serializer.serialize_bytes(&v)
+            } else {
+                v.serialize(serializer)
+            }
         }
     }
 }
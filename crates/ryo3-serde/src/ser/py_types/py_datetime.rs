@@ -3,6 +3,7 @@ use pyo3::types::{PyDate, PyDateTime, PyDelta, PyTime};
 use serde::ser::{Serialize, Serializer};
 
 use crate::errors::pyerr2sererr;
+use crate::ser::PySerializeOptions;
 
 // ---------------------------------------------------------------------------
 // python stdlib `datetime.date`
@@ -101,12 +102,28 @@ impl Serialize for PyTimeSerializer<'_, '_> {
 // ---------------------------------------------------------------------------
 pub(crate) struct PyDateTimeSerializer<'a, 'py> {
     obj: Borrowed<'a, 'py, PyDateTime>,
+    naive_utc: bool,
+    utc_z: bool,
 }
 
 impl<'a, 'py> PyDateTimeSerializer<'a, 'py> {
     #[inline]
     pub(crate) fn new(obj: Borrowed<'a, 'py, PyDateTime>) -> Self {
-        Self { obj }
+        Self {
+            obj,
+            naive_utc: false,
+            utc_z: false,
+        }
+    }
+
+    /// apply the `naive_utc`/`utc_z` serialize options
+    #[inline]
+    pub(crate) fn with_options(self, opts: PySerializeOptions) -> Self {
+        Self {
+            naive_utc: opts.naive_utc,
+            utc_z: opts.utc_z,
+            ..self
+        }
     }
 
     #[inline]
@@ -129,11 +146,27 @@ impl Serialize for PyDateTimeSerializer<'_, '_> {
         // let has_tzinfo = dt.get_tzinfo().is_some();
         if let Some(_tzinfo) = self.obj.get_tzinfo() {
             let zdt: jiff::Zoned = self.obj.extract().map_err(pyerr2sererr)?;
-            zdt.serialize(serializer)
+            if self.utc_z && zdt.offset() == jiff::tz::Offset::UTC {
+                zdt.timestamp().serialize(serializer)
+            } else {
+                zdt.serialize(serializer)
+            }
         } else {
             // if no tzinfo, use jiff to serialize
             let dt: jiff::civil::DateTime = self.obj.extract().map_err(pyerr2sererr)?;
-            dt.serialize(serializer)
+            if self.naive_utc {
+                let ts = dt
+                    .to_zoned(jiff::tz::TimeZone::UTC)
+                    .map_err(serde::ser::Error::custom)?
+                    .timestamp();
+                if self.utc_z {
+                    ts.serialize(serializer)
+                } else {
+                    serializer.collect_str(&ts.display_with_offset(jiff::tz::Offset::UTC))
+                }
+            } else {
+                dt.serialize(serializer)
+            }
         }
     }
 }
@@ -145,10 +178,14 @@ impl Serialize for PyDateTimeSerializer<'_, '_> {
     where
         S: Serializer,
     {
+        use pyo3::types::PyTzInfoAccess;
         let dt_pystr = self.obj.str().map_err(pyerr2sererr)?;
         let dt_str = dt_pystr.to_str().map_err(pyerr2sererr)?;
         // TODO: use jiff to do all the date-time formatting
-        let iso_str = dt_str.replacen("+00:00", "Z", 1).replace(' ', "T");
+        let mut iso_str = dt_str.replacen("+00:00", "Z", 1).replace(' ', "T");
+        if self.naive_utc && self.obj.get_tzinfo().is_none() {
+            iso_str.push_str(if self.utc_z { "Z" } else { "+00:00" });
+        }
         serializer.serialize_str(iso_str.as_ref())
     }
 }
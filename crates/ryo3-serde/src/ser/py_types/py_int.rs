@@ -1,17 +1,30 @@
+use core::marker::PhantomData;
+
 use pyo3::prelude::*;
 use pyo3::types::PyInt;
 use serde::ser::{Serialize, Serializer};
 
 use crate::errors::pyerr2sererr;
+use crate::ser::{PySerializeTarget, SerdeTarget};
 
-pub(crate) struct PyIntSerializer<'a, 'py> {
+pub(crate) struct PyIntSerializer<'a, 'py, T = SerdeTarget>
+where
+    T: PySerializeTarget,
+{
     obj: Borrowed<'a, 'py, PyInt>,
+    _target: PhantomData<T>,
 }
 
-impl<'a, 'py> PyIntSerializer<'a, 'py> {
+impl<'a, 'py, T> PyIntSerializer<'a, 'py, T>
+where
+    T: PySerializeTarget,
+{
     #[inline]
     pub(crate) fn new(obj: Borrowed<'a, 'py, PyInt>) -> Self {
-        Self { obj }
+        Self {
+            obj,
+            _target: PhantomData,
+        }
     }
 
     #[inline]
@@ -22,13 +35,21 @@ impl<'a, 'py> PyIntSerializer<'a, 'py> {
     }
 }
 
-impl Serialize for PyIntSerializer<'_, '_> {
+impl<T> Serialize for PyIntSerializer<'_, '_, T>
+where
+    T: PySerializeTarget,
+{
     #[inline(always)]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let v: i64 = self.obj.extract().map_err(pyerr2sererr)?;
-        serializer.serialize_i64(v)
+        match self.obj.extract::<i64>() {
+            Ok(v) => serializer.serialize_i64(v),
+            Err(e) => match T::serialize_bigint(self.obj, serializer) {
+                Ok(res) => res,
+                Err(_) => Err(pyerr2sererr(e)),
+            },
+        }
     }
 }
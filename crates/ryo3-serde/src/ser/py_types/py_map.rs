@@ -8,9 +8,9 @@ use crate::ob_type::PyObType;
 use crate::ser::py_types::{
     PyBoolSerializer, PyBytesLikeSerializer, PyDateSerializer, PyDateTimeSerializer,
     PyFloatSerializer, PyFrozenSetSerializer, PyIntSerializer, PyListSerializer,
-    PyMappingKeySerializer, PyNoneSerializer, PySetSerializer, PyStrSerializer,
-    PyTimeDeltaSerializer, PyTimeSerializer, PyTupleSerializer, PyUnknownSerializer,
-    PyUuidSerializer,
+    PyMappingKeySerializer, PyNoneSerializer, PySetSerializer, PyStrLikeSerializer,
+    PyStrSerializer, PyTimeDeltaSerializer, PyTimeSerializer, PyTupleSerializer,
+    PyUnknownSerializer, PyUuidSerializer,
 };
 #[cfg(any(
     feature = "ryo3-http",
@@ -22,7 +22,7 @@ use crate::ser::py_types::{
 ))]
 use crate::ser::ry_types;
 use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
-use crate::serde_err_recursion;
+use crate::{PyAnySerializer, serde_err_recursion};
 
 pub(crate) struct PyDictSerializer<'a, 'py, T = SerdeTarget>
 where
@@ -62,6 +62,13 @@ where
 macro_rules! serialize_map_value {
     ($ob_type:expr, $map:expr, $self:expr, $value:expr) => {
         match $ob_type {
+            _ if $self.ctx.is_special($ob_type) => {
+                $map.serialize_value(&PyAnySerializer::new_with_depth(
+                    $value,
+                    $self.ctx,
+                    $self.depth + 1,
+                ))?;
+            }
             PyObType::None | PyObType::Ellipsis => {
                 $map.serialize_value(&PyNoneSerializer::new())?;
             }
@@ -69,7 +76,7 @@ macro_rules! serialize_map_value {
                 $map.serialize_value(&PyBoolSerializer::new_unchecked($value))?;
             }
             PyObType::Int => {
-                $map.serialize_value(&PyIntSerializer::new_unchecked($value))?;
+                $map.serialize_value(&PyIntSerializer::<T>::new_unchecked($value))?;
             }
             PyObType::Float => {
                 $map.serialize_value(&PyFloatSerializer::new_unchecked($value))?;
@@ -130,6 +137,9 @@ macro_rules! serialize_map_value {
             PyObType::PyUuid => {
                 $map.serialize_value(&PyUuidSerializer::new($value))?;
             }
+            PyObType::PyDecimal | PyObType::PyPath | PyObType::PyIpAddress => {
+                $map.serialize_value(&PyStrLikeSerializer::new($value))?;
+            }
             // ------------------------------------------------------------
             // RY-TYPES
             // ------------------------------------------------------------
@@ -308,7 +318,11 @@ where
             if self.ctx.typeref.is_exact_string_ptr(key_type_ptr) {
                 m.serialize_key(&PyStrSerializer::new_unchecked(map_key))?;
             } else {
-                m.serialize_key(&PyMappingKeySerializer::new(self.ctx, map_key))?;
+                m.serialize_key(&PyMappingKeySerializer::new(
+                    self.ctx,
+                    map_key,
+                    self.depth + 1,
+                ))?;
             }
             serialize_map_value!(ob_type, m, self, map_val);
         }
@@ -332,7 +346,11 @@ where
             if self.ctx.typeref.is_exact_string_ptr(key_type_ptr) {
                 m.serialize_key(&PyStrSerializer::new_unchecked(map_key))?;
             } else {
-                m.serialize_key(&PyMappingKeySerializer::new(self.ctx, map_key))?;
+                m.serialize_key(&PyMappingKeySerializer::new(
+                    self.ctx,
+                    map_key,
+                    self.depth + 1,
+                ))?;
             }
             serialize_map_value!(ob_type, m, self, map_val);
         }
@@ -384,7 +402,7 @@ where
         for (k, v) in keys.iter().zip(values.iter()) {
             let k = k.as_borrowed();
             let v = v.as_borrowed();
-            let sk = PyMappingKeySerializer::new(self.ctx, k);
+            let sk = PyMappingKeySerializer::new(self.ctx, k, self.depth + 1);
             let ob_type = self.ctx.typeref.obtype(v);
             m.serialize_key(&sk)?;
             serialize_map_value!(ob_type, m, self, v);
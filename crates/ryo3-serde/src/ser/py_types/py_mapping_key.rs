@@ -5,7 +5,7 @@ use crate::any_repr::any_repr;
 use crate::ob_type::PyObType;
 use crate::ser::py_types::{PyBoolSerializer, PyStrSerializer};
 use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
-use crate::serde_err;
+use crate::{Depth, PyAnySerializer, serde_err};
 
 pub(crate) struct PyMappingKeySerializer<'a, 'py, T = SerdeTarget>
 where
@@ -13,6 +13,8 @@ where
 {
     pub(crate) ctx: PySerializeContext<'py, T>,
     obj: Borrowed<'a, 'py, PyAny>,
+    /// depth of the mapping's values (keys like tuples/dataclasses recurse)
+    depth: Depth,
 }
 
 impl<'a, 'py, T> PyMappingKeySerializer<'a, 'py, T>
@@ -20,8 +22,12 @@ where
     T: PySerializeTarget,
 {
     #[inline]
-    pub(crate) fn new(ctx: PySerializeContext<'py, T>, obj: Borrowed<'a, 'py, PyAny>) -> Self {
-        Self { ctx, obj }
+    pub(crate) fn new(
+        ctx: PySerializeContext<'py, T>,
+        obj: Borrowed<'a, 'py, PyAny>,
+        depth: Depth,
+    ) -> Self {
+        Self { ctx, obj, depth }
     }
 }
 
@@ -38,6 +44,19 @@ where
         match obtype {
             PyObType::String => PyStrSerializer::new_unchecked(self.obj).serialize(serializer),
             PyObType::Bool => PyBoolSerializer::new_unchecked(self.obj).serialize(serializer),
+            _ if T::NATIVE_MAP_KEYS => {
+                PyAnySerializer::new_with_depth(self.obj, self.ctx, self.depth)
+                    .serialize(serializer)
+            }
+            _ if self.ctx.opts.non_str_keys => {
+                if self.obj.is_none() {
+                    serializer.serialize_str("null")
+                } else {
+                    // serde_json map-key serializer stringifies ints/floats
+                    PyAnySerializer::new_with_depth(self.obj, self.ctx.for_keys(), self.depth)
+                        .serialize(serializer)
+                }
+            }
             _ => {
                 let key_repr = any_repr(self.obj);
                 serde_err!("{} is not serializable as map-key", key_repr)
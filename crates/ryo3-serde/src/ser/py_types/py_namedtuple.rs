@@ -0,0 +1,78 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyString, PyTuple, PyType};
+use pyo3::{Bound, intern};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::errors::pyerr2sererr;
+use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
+use crate::{Depth, MAX_DEPTH, PyAnySerializer, serde_err, serde_err_recursion};
+
+/// Returns the `_fields` of a `collections.namedtuple`/`typing.NamedTuple`
+/// class (a tuple subclass with a `_fields` tuple of str)
+pub(crate) fn namedtuple_fields<'py>(tp: &Bound<'py, PyType>) -> Option<Bound<'py, PyTuple>> {
+    if !tp.is_subclass_of::<PyTuple>().unwrap_or(false) {
+        return None;
+    }
+    tp.getattr(intern!(tp.py(), "_fields"))
+        .ok()?
+        .cast_into::<PyTuple>()
+        .ok()
+}
+
+/// Serializes namedtuples as maps of field names to values
+pub(crate) struct PyNamedTupleSerializer<'a, 'py, T = SerdeTarget>
+where
+    T: PySerializeTarget,
+{
+    ctx: PySerializeContext<'py, T>,
+    obj: Borrowed<'a, 'py, PyAny>,
+    fields: Bound<'py, PyTuple>,
+    depth: Depth,
+}
+
+impl<'a, 'py, T> PyNamedTupleSerializer<'a, 'py, T>
+where
+    T: PySerializeTarget,
+{
+    pub(crate) fn new(
+        obj: Borrowed<'a, 'py, PyAny>,
+        fields: Bound<'py, PyTuple>,
+        ctx: PySerializeContext<'py, T>,
+        depth: Depth,
+    ) -> Self {
+        Self {
+            ctx,
+            obj,
+            fields,
+            depth,
+        }
+    }
+}
+
+impl<T> Serialize for PyNamedTupleSerializer<'_, '_, T>
+where
+    T: PySerializeTarget,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.depth == MAX_DEPTH {
+            return serde_err_recursion!();
+        }
+        #[expect(unsafe_code)]
+        let values = unsafe { self.obj.cast_unchecked::<PyTuple>() };
+        if values.len() != self.fields.len() {
+            return serde_err!("namedtuple length does not match its `_fields`");
+        }
+        let mut map = serializer.serialize_map(Some(values.len()))?;
+        for (field, value) in self.fields.iter().zip(values.iter()) {
+            let field = field.cast_into::<PyString>().map_err(pyerr2sererr)?;
+            let key = field.to_str().map_err(pyerr2sererr)?;
+            let value_ser =
+                PyAnySerializer::new_with_depth(value.as_borrowed(), self.ctx, self.depth + 1);
+            map.serialize_entry(key, &value_ser)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,60 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use serde::ser::{Serialize, Serializer};
+
+use crate::errors::pyerr2sererr;
+use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
+use crate::{Depth, MAX_DEPTH, PyAnySerializer, serde_err_recursion};
+
+/// `true` for pydantic (v2) `BaseModel` classes
+pub(crate) fn is_pydantic_model(tp: &Bound<'_, PyType>) -> bool {
+    let py = tp.py();
+    tp.hasattr(intern!(py, "__pydantic_serializer__"))
+        .unwrap_or(false)
+        && tp.hasattr(intern!(py, "model_dump")).unwrap_or(false)
+}
+
+/// Serializes pydantic models via `model.model_dump()`; the dumped python
+/// objects are then serialized like any other object
+pub(crate) struct PyPydanticSerializer<'a, 'py, T = SerdeTarget>
+where
+    T: PySerializeTarget,
+{
+    ctx: PySerializeContext<'py, T>,
+    obj: Borrowed<'a, 'py, PyAny>,
+    depth: Depth,
+}
+
+impl<'a, 'py, T> PyPydanticSerializer<'a, 'py, T>
+where
+    T: PySerializeTarget,
+{
+    pub(crate) fn new(
+        obj: Borrowed<'a, 'py, PyAny>,
+        ctx: PySerializeContext<'py, T>,
+        depth: Depth,
+    ) -> Self {
+        Self { ctx, obj, depth }
+    }
+}
+
+impl<T> Serialize for PyPydanticSerializer<'_, '_, T>
+where
+    T: PySerializeTarget,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.depth == MAX_DEPTH {
+            return serde_err_recursion!();
+        }
+        let dumped = self
+            .obj
+            .call_method0(intern!(self.obj.py(), "model_dump"))
+            .map_err(pyerr2sererr)?;
+        PyAnySerializer::new_with_depth(dumped.as_borrowed(), self.ctx, self.depth + 1)
+            .serialize(serializer)
+    }
+}
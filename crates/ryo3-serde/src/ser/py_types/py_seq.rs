@@ -7,8 +7,9 @@ use crate::errors::pyerr2sererr;
 use crate::ob_type::PyObType;
 use crate::ser::py_types::{
     PyBoolSerializer, PyBytesLikeSerializer, PyDateSerializer, PyDateTimeSerializer,
-    PyDictSerializer, PyFloatSerializer, PyIntSerializer, PyNoneSerializer, PyStrSerializer,
-    PyTimeDeltaSerializer, PyTimeSerializer, PyUnknownSerializer, PyUuidSerializer,
+    PyDictSerializer, PyFloatSerializer, PyIntSerializer, PyNoneSerializer, PyStrLikeSerializer,
+    PyStrSerializer, PyTimeDeltaSerializer, PyTimeSerializer, PyUnknownSerializer,
+    PyUuidSerializer,
 };
 #[cfg(any(
     feature = "ryo3-http",
@@ -20,11 +21,18 @@ use crate::ser::py_types::{
 ))]
 use crate::ser::ry_types;
 use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
-use crate::serde_err_recursion;
+use crate::{PyAnySerializer, serde_err_recursion};
 
 macro_rules! serialize_seq_element {
     ($ob_type:expr, $seq:expr, $self:expr, $element:expr) => {
         match $ob_type {
+            _ if $self.ctx.is_special($ob_type) => {
+                $seq.serialize_element(&PyAnySerializer::new_with_depth(
+                    $element,
+                    $self.ctx,
+                    $self.depth + 1,
+                ))?;
+            }
             PyObType::None | PyObType::Ellipsis => {
                 $seq.serialize_element(&PyNoneSerializer::new())?;
             }
@@ -32,7 +40,7 @@ macro_rules! serialize_seq_element {
                 $seq.serialize_element(&PyBoolSerializer::new_unchecked($element))?;
             }
             PyObType::Int => {
-                $seq.serialize_element(&PyIntSerializer::new_unchecked($element))?;
+                $seq.serialize_element(&PyIntSerializer::<T>::new_unchecked($element))?;
             }
             PyObType::Float => {
                 $seq.serialize_element(&PyFloatSerializer::new_unchecked($element))?;
@@ -93,6 +101,9 @@ macro_rules! serialize_seq_element {
             PyObType::PyUuid => {
                 $seq.serialize_element(&PyUuidSerializer::new($element))?;
             }
+            PyObType::PyDecimal | PyObType::PyPath | PyObType::PyIpAddress => {
+                $seq.serialize_element(&PyStrLikeSerializer::new($element))?;
+            }
             // ------------------------------------------------------------
             // RY-TYPES
             // ------------------------------------------------------------
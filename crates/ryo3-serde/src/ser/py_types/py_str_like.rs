@@ -0,0 +1,29 @@
+use pyo3::prelude::*;
+use serde::ser::{Serialize, Serializer};
+
+use crate::errors::pyerr2sererr;
+
+/// Serializer for python objects that are serialized as their `str()`
+/// (`decimal.Decimal`, `pathlib` paths and `ipaddress` objects)
+pub(crate) struct PyStrLikeSerializer<'a, 'py> {
+    obj: Borrowed<'a, 'py, PyAny>,
+}
+
+impl<'a, 'py> PyStrLikeSerializer<'a, 'py> {
+    #[inline]
+    pub(crate) fn new(obj: Borrowed<'a, 'py, PyAny>) -> Self {
+        Self { obj }
+    }
+}
+
+impl Serialize for PyStrLikeSerializer<'_, '_> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let pystr = self.obj.str().map_err(pyerr2sererr)?;
+        let s = pystr.to_str().map_err(pyerr2sererr)?;
+        serializer.serialize_str(s)
+    }
+}
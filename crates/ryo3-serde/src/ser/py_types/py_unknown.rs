@@ -5,9 +5,10 @@ use serde::ser::{Serialize, Serializer};
 use crate::any_repr::any_repr;
 use crate::errors::pyerr2sererr;
 use crate::ser::dataclass::is_dataclass;
+use crate::ser::object_kind::{ObjectKind, object_kind};
 use crate::ser::py_types::{
-    PyDataclassSerializer, PyEnumSerializer, PyMappingSerializer, PySequenceSerializer,
-    PyStrSubclassSerializer,
+    PyAttrsSerializer, PyDataclassSerializer, PyEnumSerializer, PyMappingSerializer,
+    PyNamedTupleSerializer, PyPydanticSerializer, PySequenceSerializer, PyStrSubclassSerializer,
 };
 use crate::ser::{PySerializeContext, PySerializeTarget, SerdeTarget};
 use crate::{Depth, PyAnySerializer, serde_err};
@@ -44,13 +45,46 @@ where
     where
         S: Serializer,
     {
+        let this = PyAnySerializer::new_with_depth(self.obj, self.ctx, self.depth);
+        let serializer = match T::serialize_ext(&this, serializer) {
+            Ok(res) => return res,
+            Err(serializer) => serializer,
+        };
         if let Ok(pystr_subclass) = self.obj.cast::<PyString>() {
             PyStrSubclassSerializer::new(pystr_subclass).serialize(serializer)
         } else if self.ctx.typeref.is_enum(self.obj) {
             PyEnumSerializer::new(self.obj, self.ctx, self.depth).serialize(serializer)
-        } else if is_dataclass(self.obj) {
+        } else if !self.ctx.opts.passthrough_dataclass && is_dataclass(self.obj) {
             PyDataclassSerializer::new(self.obj, self.ctx, self.depth).serialize(serializer)
-        } else if let Ok(py_map) = self.obj.cast::<PyMapping>() {
+        } else {
+            match object_kind(self.obj) {
+                ObjectKind::Pydantic => {
+                    PyPydanticSerializer::new(self.obj, self.ctx, self.depth).serialize(serializer)
+                }
+                ObjectKind::Attrs(fields) => {
+                    PyAttrsSerializer::new(self.obj, fields, self.ctx, self.depth)
+                        .serialize(serializer)
+                }
+                ObjectKind::NamedTuple(fields) => {
+                    PyNamedTupleSerializer::new(self.obj, fields, self.ctx, self.depth)
+                        .serialize(serializer)
+                }
+                ObjectKind::Other => self.serialize_other(serializer),
+            }
+        }
+    }
+}
+
+impl<T> PyUnknownSerializer<'_, '_, T>
+where
+    T: PySerializeTarget,
+{
+    /// generic mappings/sequences, then the `default` fn
+    fn serialize_other<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Ok(py_map) = self.obj.cast::<PyMapping>() {
             PyMappingSerializer::new_with_depth(py_map, self.ctx, self.depth).serialize(serializer)
         } else if let Ok(py_seq) = self.obj.cast::<PySequence>() {
             PySequenceSerializer::new_with_depth(py_seq, self.ctx, self.depth).serialize(serializer)
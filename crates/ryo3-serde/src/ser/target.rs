@@ -1,7 +1,108 @@
 use core::fmt::Debug;
 
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+use serde::ser::Serializer;
+
+use crate::PyAnySerializer;
+
 pub trait PySerializeTarget: Copy + Clone + Debug + Default + 'static {
     const KIND: &'static str;
+
+    /// Serialize bytes-like objects with `Serializer::serialize_bytes` (binary
+    /// formats like msgpack/cbor) instead of as a sequence of ints
+    const NATIVE_BYTES: bool = false;
+
+    /// Offer date/time/datetime objects (python stdlib and ry/jiff types) to
+    /// `serialize_datetime` before serializing them as strings (formats with
+    /// native datetimes like toml)
+    const NATIVE_DATETIMES: bool = false;
+
+    /// Offer uuid objects (python stdlib and ry) to `serialize_uuid` before
+    /// serializing them as strings (e.g. cbor tag 37)
+    const NATIVE_UUIDS: bool = false;
+
+    /// Serialize non-string mapping keys (ints, floats, bytes, tuples...) as
+    /// is for formats with arbitrary map keys (cbor) instead of raising
+    const NATIVE_MAP_KEYS: bool = false;
+
+    /// Hook for target specific types (e.g. msgpack ext types); called for
+    /// objects of unknown type before the `default` fn
+    ///
+    /// `ser` is the serializer for the object (`ser.obj()`); nested python
+    /// objects should be serialized with `ser.nested(..)` so the `default` fn
+    /// and recursion limit carry over.
+    ///
+    /// Returns the serializer back (`Err(serializer)`) if the object is not
+    /// handled by the target.
+    #[inline]
+    fn serialize_ext<S: Serializer>(
+        _ser: &PyAnySerializer<'_, '_, Self>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        Err(serializer)
+    }
+
+    /// Hook for date/time/datetime-like objects; only called if
+    /// `NATIVE_DATETIMES` is set
+    ///
+    /// Returns the serializer back (`Err(serializer)`) to fall back to the
+    /// default (string) serialization.
+    #[inline]
+    fn serialize_datetime<S: Serializer>(
+        _obj: Borrowed<'_, '_, PyAny>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        Err(serializer)
+    }
+
+    /// Hook for uuid objects; only called if `NATIVE_UUIDS` is set
+    ///
+    /// Returns the serializer back (`Err(serializer)`) to fall back to the
+    /// default (string) serialization.
+    #[inline]
+    fn serialize_uuid<S: Serializer>(
+        _obj: Borrowed<'_, '_, PyAny>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        Err(serializer)
+    }
+
+    /// Hook for ints that do not fit in an `i64` (e.g. cbor bignums)
+    ///
+    /// Returns the serializer back (`Err(serializer)`) to raise the default
+    /// `OverflowError`.
+    #[inline]
+    fn serialize_bigint<S: Serializer>(
+        _obj: Borrowed<'_, '_, PyInt>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        Err(serializer)
+    }
+}
+
+/// Strip the RFC 9557 time zone annotation (`[America/New_York]`) from a
+/// zoned datetime string leaving the RFC 3339 offset datetime; for
+/// `serialize_datetime` impls that parse the `str()` of the object
+#[must_use]
+pub fn strip_tz_annotation(s: &str) -> &str {
+    match s.find('[') {
+        Some(i) if s.ends_with(']') => &s[..i],
+        _ => s,
+    }
+}
+
+/// Raise a `TypeError` if the `default` fn (or a decode hook) is given but
+/// is not callable
+pub fn check_callable(f: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+    match f {
+        Some(f) if !f.is_callable() => Err(PyTypeError::new_err(format!(
+            "'{}' is not callable",
+            f.get_type().name()?
+        ))),
+        _ => Ok(()),
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default)]
@@ -13,6 +13,10 @@ mod async_file;
 mod async_file_read_stream;
 pub use async_file::PyAsyncFile;
 pub use async_file_read_stream::PyAsyncFileReadStream;
+#[cfg(feature = "json")]
+mod async_json_lines_writer;
+#[cfg(feature = "json")]
+pub use async_json_lines_writer::PyAsyncJsonLinesWriter;
 mod async_read_dir;
 #[cfg(feature = "experimental-async")]
 use pyo3::coroutine::CancelHandle;
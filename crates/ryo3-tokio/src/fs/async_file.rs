@@ -22,7 +22,7 @@ enum FileState {
     // Consumed,
 }
 
-struct PyAsyncFileInner {
+pub(crate) struct PyAsyncFileInner {
     state: FileState,
     path: PathBuf,
     open_options: PyOpenOptions,
@@ -49,7 +49,15 @@ fn apply_py_open_options_to_tokio(py_opts: PyOpenOptions, tokio_opts: &mut tokio
 }
 
 impl PyAsyncFileInner {
-    async fn open(&mut self) -> PyResult<()> {
+    pub(crate) fn new(path: PathBuf, open_options: PyOpenOptions) -> Self {
+        Self {
+            state: FileState::Closed,
+            path,
+            open_options,
+        }
+    }
+
+    pub(crate) async fn open(&mut self) -> PyResult<()> {
         let opts = self.open_options;
         let mut open_opts = tokio::fs::OpenOptions::new();
         apply_py_open_options_to_tokio(opts, &mut open_opts);
@@ -90,7 +98,7 @@ impl PyAsyncFileInner {
         Ok(r)
     }
 
-    async fn flush(&mut self) -> PyResult<()> {
+    pub(crate) async fn flush(&mut self) -> PyResult<()> {
         let file = self.get_file_mut()?;
         file.flush()
             .await
@@ -98,7 +106,7 @@ impl PyAsyncFileInner {
         Ok(())
     }
 
-    async fn close(&mut self) -> PyResult<()> {
+    pub(crate) async fn close(&mut self) -> PyResult<()> {
         match std::mem::replace(&mut self.state, FileState::Closed) {
             FileState::Open(mut file) => {
                 file.flush()
@@ -186,7 +194,7 @@ impl PyAsyncFileInner {
         Ok(size)
     }
 
-    async fn write(&mut self, buf: &[u8]) -> PyResult<usize> {
+    pub(crate) async fn write(&mut self, buf: &[u8]) -> PyResult<usize> {
         let file = self.get_file_mut()?;
         file.write_all(buf)
             .await
@@ -204,7 +212,7 @@ impl PyAsyncFileInner {
         }
     }
 
-    fn is_closed(&self) -> bool {
+    pub(crate) fn is_closed(&self) -> bool {
         matches!(self.state, FileState::Closed)
     }
 }
@@ -234,11 +242,7 @@ pub struct PyAsyncFile {
 
 impl PyAsyncFile {
     pub(crate) fn new(p: PathBuf, options: PyOpenOptions) -> Self {
-        let inner = PyAsyncFileInner {
-            state: FileState::Closed,
-            path: p,
-            open_options: options,
-        };
+        let inner = PyAsyncFileInner::new(p, options);
         Self {
             props: AsyncFileProperties::from(&inner.open_options),
             inner: Arc::new(Mutex::new(inner)),
@@ -0,0 +1,303 @@
+//! `JSON.AsyncJsonLinesWriter` ~ json lines writer on top of `AsyncFile`
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use pyo3::prelude::*;
+use ryo3_core::types::PyOpenMode;
+use ryo3_json::{JsonLinesCompression, JsonLinesEncoder, JsonOptions, to_vec_with};
+use ryo3_macro_rules::py_value_err;
+use ryo3_tokio_rt::{future_into_py, get_tokio_runtime};
+use tokio::sync::Mutex;
+
+use crate::fs::async_file::PyAsyncFileInner;
+
+/// flush serialized lines to the file once this many bytes are buffered
+const WRITELINES_CHUNK_SIZE: usize = 64 * 1024;
+
+struct AsyncJsonLinesInner {
+    file: PyAsyncFileInner,
+    /// compresses into an in-memory buffer that is drained into `file`;
+    /// `None` once closed
+    encoder: Option<JsonLinesEncoder<Vec<u8>>>,
+    opened: bool,
+}
+
+impl AsyncJsonLinesInner {
+    async fn ensure_open(&mut self) -> PyResult<()> {
+        if !self.opened {
+            self.file.open().await?;
+            self.opened = true;
+        }
+        Ok(())
+    }
+
+    async fn drain(&mut self) -> PyResult<()> {
+        let Some(encoder) = self.encoder.as_mut() else {
+            return Ok(());
+        };
+        let buf = std::mem::take(encoder.get_mut());
+        if !buf.is_empty() {
+            self.file.write(&buf).await?;
+        }
+        Ok(())
+    }
+
+    async fn write(&mut self, data: &[u8]) -> PyResult<()> {
+        let Some(encoder) = self.encoder.as_mut() else {
+            return py_value_err!("I/O operation on closed AsyncJsonLinesWriter");
+        };
+        encoder.write_all(data)?;
+        self.ensure_open().await?;
+        self.drain().await
+    }
+
+    async fn flush(&mut self) -> PyResult<()> {
+        if let Some(encoder) = self.encoder.as_mut() {
+            encoder.flush()?;
+            self.ensure_open().await?;
+            self.drain().await?;
+            self.file.flush().await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> PyResult<()> {
+        if let Some(encoder) = self.encoder.take() {
+            let tail = encoder.finish()?;
+            self.ensure_open().await?;
+            if !tail.is_empty() {
+                self.file.write(&tail).await?;
+            }
+            self.file.close().await?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AsyncJsonLinesInner {
+    fn drop(&mut self) {
+        // finish the (compressed) stream if the writer was never closed so
+        // the file is not truncated; errors are ignored on drop
+        let Some(encoder) = self.encoder.take() else {
+            return;
+        };
+        // swap in a never-opened placeholder (its drop is a no-op) so the
+        // file can be moved into the future
+        let placeholder = PyAsyncFileInner::new(PathBuf::new(), PyOpenMode::WriteBinary.into());
+        let mut file = std::mem::replace(&mut self.file, placeholder);
+        let opened = self.opened;
+        let finish = async move {
+            let tail = encoder.finish()?;
+            if !opened {
+                file.open().await?;
+            }
+            if !tail.is_empty() {
+                file.write(&tail).await?;
+            }
+            file.close().await
+        };
+        // dropped on a runtime thread if a pending write held the last ref
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(finish);
+        } else {
+            let _ = get_tokio_runtime().block_on(finish);
+        }
+    }
+}
+
+/// Async json lines file writer; objects are serialized with the GIL held
+/// and compressed/written on the tokio runtime
+///
+/// Use `async with` or `await close()`; an unclosed writer is finished when
+/// it is garbage collected (blocking on the runtime).
+#[pyclass(
+    name = "AsyncJsonLinesWriter",
+    frozen,
+    immutable_type,
+    skip_from_py_object
+)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.JSON"))]
+pub struct PyAsyncJsonLinesWriter {
+    path: PathBuf,
+    default: Option<Py<PyAny>>,
+    options: JsonOptions,
+    inner: Arc<Mutex<AsyncJsonLinesInner>>,
+    /// tracked outside of `inner` so `closed` never waits on pending i/o
+    closed: Arc<AtomicBool>,
+}
+
+impl PyAsyncJsonLinesWriter {
+    fn serialize_line(&self, obj: &Bound<'_, PyAny>) -> PyResult<Vec<u8>> {
+        let default = self.default.as_ref().map(|d| d.bind(obj.py()));
+        to_vec_with(obj, default, self.options.clone())
+    }
+}
+
+#[pymethods]
+impl PyAsyncJsonLinesWriter {
+    #[new]
+    #[expect(
+        clippy::fn_params_excessive_bools,
+        clippy::too_many_arguments,
+        reason = "python kwargs"
+    )]
+    #[pyo3(
+        signature = (
+            path,
+            *,
+            append = false,
+            compression = None,
+            level = None,
+            default = None,
+            sort_keys = false,
+            ensure_ascii = false,
+            non_str_keys = false,
+            naive_utc = false,
+            utc_z = false,
+            passthrough_dataclass = false,
+            passthrough_datetime = false,
+        )
+    )]
+    fn py_new(
+        path: PathBuf,
+        append: bool,
+        compression: Option<JsonLinesCompression>,
+        level: Option<i32>,
+        default: Option<Py<PyAny>>,
+        sort_keys: bool,
+        ensure_ascii: bool,
+        non_str_keys: bool,
+        naive_utc: bool,
+        utc_z: bool,
+        passthrough_dataclass: bool,
+        passthrough_datetime: bool,
+    ) -> PyResult<Self> {
+        let encoder = JsonLinesEncoder::new(Vec::new(), compression, level)?;
+        let mode = if append {
+            PyOpenMode::AppendBinary
+        } else {
+            PyOpenMode::WriteBinary
+        };
+        let options = JsonOptions::from_kwargs(
+            None,
+            sort_keys,
+            true,
+            ensure_ascii,
+            non_str_keys,
+            naive_utc,
+            utc_z,
+            passthrough_dataclass,
+            passthrough_datetime,
+        );
+        let inner = AsyncJsonLinesInner {
+            file: PyAsyncFileInner::new(path.clone(), mode.into()),
+            encoder: Some(encoder),
+            opened: false,
+        };
+        Ok(Self {
+            path,
+            default,
+            options,
+            inner: Arc::new(Mutex::new(inner)),
+            closed: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AsyncJsonLinesWriter(path='{}')", self.path.display())
+    }
+
+    fn __aenter__(slf: Py<Self>, py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+        let inner = Arc::clone(&slf.get().inner);
+        future_into_py(py, async move {
+            inner.lock().await.ensure_open().await?;
+            Ok(slf)
+        })
+    }
+
+    #[pyo3(name = "__aexit__")]
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: &Bound<'py, PyAny>,
+        _exc_value: &Bound<'py, PyAny>,
+        _traceback: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.close(py)
+    }
+
+    #[getter]
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+
+    #[getter]
+    fn closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Write `obj` as a json line; resolves to the number of (uncompressed)
+    /// bytes written
+    fn write<'py>(&self, py: Python<'py>, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let line = self.serialize_line(obj)?;
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            inner.lock().await.write(&line).await?;
+            Ok(line.len())
+        })
+    }
+
+    /// Write every object in `objs` as a json line; resolves to the number
+    /// of (uncompressed) bytes written
+    fn writelines<'py>(
+        &self,
+        py: Python<'py>,
+        objs: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut chunks = Vec::new();
+        let mut buf = Vec::with_capacity(WRITELINES_CHUNK_SIZE);
+        for obj in objs.try_iter()? {
+            buf.extend(self.serialize_line(&obj?)?);
+            if buf.len() >= WRITELINES_CHUNK_SIZE {
+                chunks.push(std::mem::replace(
+                    &mut buf,
+                    Vec::with_capacity(WRITELINES_CHUNK_SIZE),
+                ));
+            }
+        }
+        if !buf.is_empty() {
+            chunks.push(buf);
+        }
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let mut locked = inner.lock().await;
+            let mut total = 0;
+            for chunk in chunks {
+                locked.write(&chunk).await?;
+                total += chunk.len();
+            }
+            Ok(total)
+        })
+    }
+
+    fn flush<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            inner.lock().await.flush().await?;
+            Ok(())
+        })
+    }
+
+    /// Finish the (compressed) stream and close the file; no-op if closed
+    fn close<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+        let closed = Arc::clone(&self.closed);
+        future_into_py(py, async move {
+            let mut locked = inner.lock().await;
+            closed.store(true, Ordering::Release);
+            locked.close().await
+        })
+    }
+}
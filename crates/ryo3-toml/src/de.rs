@@ -0,0 +1,118 @@
+//! toml (`toml_edit`) => python conversion
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDict, PyList, PyTime, PyTzInfo};
+use ryo3_macro_rules::py_value_error;
+use toml_edit::{Item, TableLike, Value};
+
+pub(crate) fn item_to_py<'py>(py: Python<'py>, item: &Item) -> PyResult<Bound<'py, PyAny>> {
+    match item {
+        Item::None => Ok(py.None().into_bound(py)),
+        Item::Value(v) => value_to_py(py, v),
+        Item::Table(t) => table_to_py(py, t),
+        Item::ArrayOfTables(a) => {
+            let tables = a
+                .iter()
+                .map(|t| table_to_py(py, t))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new(py, tables)?.into_any())
+        }
+    }
+}
+
+pub(crate) fn table_to_py<'py>(
+    py: Python<'py>,
+    table: &dyn TableLike,
+) -> PyResult<Bound<'py, PyAny>> {
+    let dict = PyDict::new(py);
+    for (k, item) in table.iter() {
+        dict.set_item(k, item_to_py(py, item)?)?;
+    }
+    Ok(dict.into_any())
+}
+
+fn value_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    match value {
+        Value::String(s) => s.value().into_bound_py_any(py),
+        Value::Integer(i) => i.value().into_bound_py_any(py),
+        Value::Float(f) => f.value().into_bound_py_any(py),
+        Value::Boolean(b) => b.value().into_bound_py_any(py),
+        Value::Datetime(dt) => datetime_to_py(py, dt.value()),
+        Value::Array(a) => {
+            let items = a
+                .iter()
+                .map(|v| value_to_py(py, v))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new(py, items)?.into_any())
+        }
+        Value::InlineTable(t) => table_to_py(py, t),
+    }
+}
+
+/// toml datetime => `datetime.datetime`/`datetime.date`/`datetime.time`
+/// (like `tomllib`; sub-microsecond precision is truncated)
+fn datetime_to_py<'py>(py: Python<'py>, dt: &toml_edit::Datetime) -> PyResult<Bound<'py, PyAny>> {
+    let tzinfo = match dt.offset {
+        None => None,
+        Some(toml_edit::Offset::Z) => Some(PyTzInfo::utc(py)?.to_owned()),
+        Some(toml_edit::Offset::Custom { minutes }) => {
+            let delta = PyDelta::new(py, 0, i32::from(minutes) * 60, 0, true)?;
+            Some(PyTzInfo::fixed_offset(py, delta)?)
+        }
+    };
+    match (dt.date, dt.time) {
+        (Some(date), Some(time)) => PyDateTime::new(
+            py,
+            i32::from(date.year),
+            date.month,
+            date.day,
+            time.hour,
+            time.minute,
+            time.second.unwrap_or(0),
+            time.nanosecond.unwrap_or(0) / 1000,
+            tzinfo.as_ref(),
+        )
+        .map(Bound::into_any),
+        (Some(date), None) => {
+            PyDate::new(py, i32::from(date.year), date.month, date.day).map(Bound::into_any)
+        }
+        (None, Some(time)) => PyTime::new(
+            py,
+            time.hour,
+            time.minute,
+            time.second.unwrap_or(0),
+            time.nanosecond.unwrap_or(0) / 1000,
+            None,
+        )
+        .map(Bound::into_any),
+        (None, None) => Err(py_value_error!("invalid toml datetime: {dt}")),
+    }
+}
+
+pub(crate) fn map_toml_de_err(e: &toml_edit::TomlError) -> PyErr {
+    py_value_error!("Failed to parse toml: {e}")
+}
+
+/// Parse a toml string into a python `dict`
+#[pyfunction]
+pub fn loads<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyAny>> {
+    let doc = s
+        .parse::<toml_edit::Document<String>>()
+        .map_err(|e| map_toml_de_err(&e))?;
+    table_to_py(py, doc.as_table())
+}
+
+/// Parse a toml binary file-like object (`fp.read()` returning `bytes`)
+#[pyfunction]
+pub fn load<'py>(fp: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = fp.py();
+    let data = fp.call_method0(pyo3::intern!(py, "read"))?;
+    if let Ok(s) = data.extract::<&str>() {
+        loads(py, s)
+    } else {
+        let b = data.extract::<Vec<u8>>()?;
+        let s =
+            std::str::from_utf8(&b).map_err(|e| py_value_error!("toml is not valid utf-8: {e}"))?;
+        loads(py, s)
+    }
+}
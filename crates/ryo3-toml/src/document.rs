@@ -0,0 +1,239 @@
+//! Style-preserving toml document (`toml_edit::DocumentMut`)
+#![expect(clippy::needless_pass_by_value)]
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use ryo3_macro_rules::{py_key_err, py_type_err};
+use toml_edit::{DocumentMut, InlineTable, Item, Table, Value};
+
+use crate::de::{item_to_py, map_toml_de_err, table_to_py};
+
+/// Key path into a document; a `str` is a single key (`"a.b"` is the key
+/// `"a.b"` NOT `a` -> `b`) and a `tuple`/`list` of `str` is a path of nested
+/// keys
+struct TomlPath(Vec<String>);
+
+impl<'py> FromPyObject<'_, 'py> for TomlPath {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(s) = ob.cast::<PyString>() {
+            return Ok(Self(vec![s.to_str()?.to_string()]));
+        }
+        if ob.is_instance_of::<PyTuple>() || ob.is_instance_of::<PyList>() {
+            let path = ob.extract::<Vec<String>>()?;
+            if path.is_empty() {
+                return py_key_err!("empty key path");
+            }
+            return Ok(Self(path));
+        }
+        py_type_err!(
+            "key must be a str or tuple/list of str, not '{}'",
+            ob.get_type().name()?
+        )
+    }
+}
+
+impl TomlPath {
+    fn display(&self) -> String {
+        self.0.join(".")
+    }
+}
+
+/// Style-preserving toml document
+///
+/// Edits (set/delete) only touch the keys being edited; comments, whitespace
+/// and ordering of everything else are kept as-is.
+#[pyclass(name = "TomlDocument", immutable_type, skip_from_py_object)]
+#[cfg_attr(feature = "ry", pyo3(module = "ry.toml"))]
+pub struct PyTomlDocument(DocumentMut);
+
+impl PyTomlDocument {
+    fn get_item(&self, path: &TomlPath) -> Option<&Item> {
+        let mut item = self.0.as_item();
+        for k in &path.0 {
+            item = item.get(k.as_str())?;
+        }
+        (!item.is_none()).then_some(item)
+    }
+}
+
+/// Convert a python value to a (position-less) `toml_edit::Item` by
+/// round-tripping `{key: value}` through the toml serializer
+fn py_to_item(key: &str, value: &Bound<'_, PyAny>) -> PyResult<Item> {
+    let py = value.py();
+    let d = PyDict::new(py);
+    d.set_item(key, value)?;
+    let s = crate::ser::to_string(d.as_any(), None, false)?;
+    let mut doc = s.parse::<DocumentMut>().map_err(|e| map_toml_de_err(&e))?;
+    let mut item = doc
+        .remove(key)
+        .ok_or_else(|| PyTypeError::new_err("cannot set a toml value to None"))?;
+    clear_positions(&mut item);
+    if let Item::Value(v) = &mut item {
+        // default (context dependent) whitespace instead of `key = value`'s
+        v.decor_mut().clear();
+    }
+    Ok(item)
+}
+
+fn clear_positions(item: &mut Item) {
+    match item {
+        Item::Table(t) => clear_table_positions(t),
+        Item::ArrayOfTables(a) => a.iter_mut().for_each(clear_table_positions),
+        Item::None | Item::Value(_) => {}
+    }
+}
+
+fn clear_table_positions(table: &mut Table) {
+    table.set_position(None);
+    for (_, item) in table.iter_mut() {
+        clear_positions(item);
+    }
+}
+
+/// Keep the formatting (comments/whitespace/position) of a replaced item;
+/// values stay values (e.g. an inline table is not turned into a `[table]`)
+fn keep_style(old: &Item, new: &mut Item) {
+    if old.is_value()
+        && !new.is_value()
+        && let Ok(v) = std::mem::take(new).into_value()
+    {
+        *new = Item::Value(v);
+    }
+    match (old, new) {
+        (Item::Value(old), Item::Value(new)) => *new.decor_mut() = old.decor().clone(),
+        (Item::Table(old), Item::Table(new)) => {
+            *new.decor_mut() = old.decor().clone();
+            new.set_position(old.position());
+        }
+        _ => {}
+    }
+}
+
+fn not_a_table(path: &[String]) -> PyErr {
+    PyTypeError::new_err(format!("'{}' is not a table", path.join(".")))
+}
+
+#[pymethods]
+impl PyTomlDocument {
+    #[new]
+    #[pyo3(signature = (s = ""))]
+    fn py_new(s: &str) -> PyResult<Self> {
+        s.parse::<DocumentMut>()
+            .map(Self)
+            .map_err(|e| map_toml_de_err(&e))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TomlDocument({:?})", self.0.to_string())
+    }
+
+    fn __getnewargs__(&self) -> (String,) {
+        (self.0.to_string(),)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __contains__(&self, key: TomlPath) -> bool {
+        self.get_item(&key).is_some()
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        PyList::new(py, self.keys())?
+            .try_iter()
+            .map(Bound::into_any)
+    }
+
+    fn __getitem__<'py>(&self, py: Python<'py>, key: TomlPath) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(item) = self.get_item(&key) {
+            item_to_py(py, item)
+        } else {
+            py_key_err!("{}", key.display())
+        }
+    }
+
+    fn __setitem__(&mut self, key: TomlPath, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let (last, parents) = key.0.split_last().expect("non-empty key path");
+        let mut new = py_to_item(last, value)?;
+        let mut item = self.0.as_item_mut();
+        for (i, k) in parents.iter().enumerate() {
+            let inline = item.is_value();
+            let table = item
+                .as_table_like_mut()
+                .ok_or_else(|| not_a_table(&parents[..i]))?;
+            item = table.entry(k).or_insert_with(|| {
+                if inline {
+                    Item::Value(Value::InlineTable(InlineTable::new()))
+                } else {
+                    let mut t = Table::new();
+                    t.set_implicit(true);
+                    Item::Table(t)
+                }
+            });
+        }
+        let table = item
+            .as_table_like_mut()
+            .ok_or_else(|| not_a_table(parents))?;
+        // replace in place so the key's formatting (e.g. comments above it)
+        // is kept; `insert` would reset it
+        if let Some(old) = table.get_mut(last) {
+            keep_style(old, &mut new);
+            *old = new;
+        } else {
+            table.insert(last, new);
+        }
+        Ok(())
+    }
+
+    fn __delitem__(&mut self, key: TomlPath) -> PyResult<()> {
+        let (last, parents) = key.0.split_last().expect("non-empty key path");
+        let mut item = self.0.as_item_mut();
+        for k in parents {
+            match item.get_mut(k.as_str()) {
+                Some(next) => item = next,
+                None => return py_key_err!("{}", key.display()),
+            }
+        }
+        if item
+            .as_table_like_mut()
+            .and_then(|t| t.remove(last))
+            .is_some()
+        {
+            Ok(())
+        } else {
+            py_key_err!("{}", key.display())
+        }
+    }
+
+    /// Return the value at `key` or `default` if missing
+    #[pyo3(signature = (key, default = None))]
+    fn get<'py>(
+        &self,
+        py: Python<'py>,
+        key: TomlPath,
+        default: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(item) = self.get_item(&key) {
+            item_to_py(py, item)
+        } else {
+            Ok(default.unwrap_or_else(|| py.None().into_bound(py)))
+        }
+    }
+
+    /// Return the top-level keys
+    fn keys(&self) -> Vec<String> {
+        self.0.iter().map(|(k, _)| k.to_string()).collect()
+    }
+
+    /// Convert the document to a python `dict` (same as `ry.toml.loads`)
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        table_to_py(py, self.0.as_table())
+    }
+}
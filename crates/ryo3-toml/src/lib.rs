@@ -0,0 +1,22 @@
+#![doc = include_str!("../README.md")]
+
+mod de;
+mod document;
+mod ser;
+mod target;
+
+use pyo3::prelude::*;
+
+pub use de::{load, loads};
+pub use document::PyTomlDocument;
+pub use ser::{dump, dumps, to_string};
+pub use target::TomlTarget;
+
+pub fn pysubmod_register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyTomlDocument>()?;
+    m.add_function(wrap_pyfunction!(de::load, m)?)?;
+    m.add_function(wrap_pyfunction!(de::loads, m)?)?;
+    m.add_function(wrap_pyfunction!(ser::dump, m)?)?;
+    m.add_function(wrap_pyfunction!(ser::dumps, m)?)?;
+    Ok(())
+}
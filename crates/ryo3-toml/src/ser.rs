@@ -0,0 +1,64 @@
+//! python => toml serialization via `ryo3-serde`
+use pyo3::exceptions::{PyRecursionError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use ryo3_serde::{PyAnySerializer, check_callable};
+
+use crate::target::TomlTarget;
+
+fn map_toml_ser_err(e: &toml::ser::Error) -> PyErr {
+    let msg = e.to_string();
+    if msg.starts_with("recursion") {
+        PyRecursionError::new_err("Recursion limit reached")
+    } else {
+        PyTypeError::new_err(format!("Failed to serialize: {msg}"))
+    }
+}
+
+/// Serialize a python object (a mapping at the top level) to a toml string
+pub fn to_string<'py>(
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+    pretty: bool,
+) -> PyResult<String> {
+    let s = PyAnySerializer::<TomlTarget>::with_target(obj.as_borrowed(), default);
+    let res = if pretty {
+        toml::to_string_pretty(&s)
+    } else {
+        toml::to_string(&s)
+    };
+    res.map_err(|e| map_toml_ser_err(&e))
+}
+
+/// Serialize `obj` to a toml string
+///
+/// `default` is called with objects that cannot otherwise be serialized and
+/// should return a serializable object; `pretty` puts array elements on
+/// their own lines.
+#[pyfunction(signature = (obj, *, default = None, pretty = false))]
+pub fn dumps<'py>(
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+    pretty: bool,
+) -> PyResult<String> {
+    check_callable(default)?;
+    to_string(obj, default, pretty)
+}
+
+/// Serialize `obj` as toml to a binary file-like object (`fp.write(bytes)`)
+#[pyfunction(signature = (obj, fp, *, default = None, pretty = false))]
+pub fn dump<'py>(
+    obj: &Bound<'py, PyAny>,
+    fp: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+    pretty: bool,
+) -> PyResult<()> {
+    check_callable(default)?;
+    let s = to_string(obj, default, pretty)?;
+    let py = fp.py();
+    fp.call_method1(
+        pyo3::intern!(py, "write"),
+        (PyBytes::new(py, s.as_bytes()),),
+    )?;
+    Ok(())
+}
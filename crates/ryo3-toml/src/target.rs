@@ -0,0 +1,33 @@
+use pyo3::prelude::*;
+use ryo3_serde::{PySerializeTarget, strip_tz_annotation};
+use serde::Serialize;
+use serde::ser::Serializer;
+
+/// `ryo3-serde` target for toml
+///
+/// Dates, times and datetimes (python stdlib and ry/jiff types) are written
+/// as native toml (offset/local) datetimes; values that toml cannot represent
+/// (e.g. a time with a utc-offset) fall back to strings.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TomlTarget;
+
+impl PySerializeTarget for TomlTarget {
+    const KIND: &'static str = "toml";
+    const NATIVE_DATETIMES: bool = true;
+
+    fn serialize_datetime<S: Serializer>(
+        obj: Borrowed<'_, '_, PyAny>,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S::Error>, S> {
+        let Ok(pystr) = obj.str() else {
+            return Err(serializer);
+        };
+        let Ok(s) = pystr.to_str() else {
+            return Err(serializer);
+        };
+        match strip_tz_annotation(s).parse::<toml::value::Datetime>() {
+            Ok(dt) => Ok(dt.serialize(serializer)),
+            Err(_) => Err(serializer),
+        }
+    }
+}
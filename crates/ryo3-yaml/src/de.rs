@@ -0,0 +1,85 @@
+//! yaml => python conversion
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+use ryo3_macro_rules::{py_type_err, py_value_error};
+use serde::Deserialize;
+use serde_yaml_ng::{Deserializer, Value};
+
+fn map_yaml_de_err(e: &serde_yaml_ng::Error) -> PyErr {
+    // the error message includes the line/column (`... at line 2 column 5`)
+    py_value_error!("Failed to parse yaml: {e}")
+}
+
+/// Parse a single yaml document resolving merge keys (`<<: *anchor`)
+fn parse_document(de: Deserializer<'_>) -> PyResult<Value> {
+    let mut value = Value::deserialize(de).map_err(|e| map_yaml_de_err(&e))?;
+    value.apply_merge().map_err(|e| map_yaml_de_err(&e))?;
+    Ok(value)
+}
+
+/// yaml value => python object; tags (`!Tag value`) are dropped
+fn value_to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    match value {
+        Value::Null => Ok(py.None().into_bound(py)),
+        Value::Bool(b) => b.into_bound_py_any(py),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                i.into_bound_py_any(py)
+            } else if let Some(u) = n.as_u64() {
+                u.into_bound_py_any(py)
+            } else {
+                n.as_f64().unwrap_or(f64::NAN).into_bound_py_any(py)
+            }
+        }
+        Value::String(s) => s.into_bound_py_any(py),
+        Value::Sequence(seq) => {
+            let items = seq
+                .iter()
+                .map(|v| value_to_py(py, v))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new(py, items)?.into_any())
+        }
+        Value::Mapping(map) => {
+            let dict = PyDict::new(py);
+            for (k, v) in map {
+                dict.set_item(key_to_py(py, k)?, value_to_py(py, v)?)?;
+            }
+            Ok(dict.into_any())
+        }
+        Value::Tagged(tagged) => value_to_py(py, &tagged.value),
+    }
+}
+
+/// yaml mapping key => python object; sequence keys become tuples
+fn key_to_py<'py>(py: Python<'py>, key: &Value) -> PyResult<Bound<'py, PyAny>> {
+    match key {
+        Value::Sequence(seq) => {
+            let items = seq
+                .iter()
+                .map(|v| key_to_py(py, v))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyTuple::new(py, items)?.into_any())
+        }
+        Value::Mapping(_) => py_type_err!("yaml mapping keys must be hashable, not a mapping"),
+        Value::Tagged(tagged) => key_to_py(py, &tagged.value),
+        _ => value_to_py(py, key),
+    }
+}
+
+/// Parse a (single document) yaml string into python objects
+#[pyfunction]
+pub fn loads<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyAny>> {
+    let value = parse_document(Deserializer::from_str(s))?;
+    value_to_py(py, &value)
+}
+
+/// Parse a multi-document yaml string (`---` separated) into a list of
+/// python objects
+#[pyfunction]
+pub fn loads_all<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyList>> {
+    let docs = Deserializer::from_str(s)
+        .map(|de| parse_document(de).and_then(|v| value_to_py(py, &v)))
+        .collect::<PyResult<Vec<_>>>()?;
+    PyList::new(py, docs)
+}
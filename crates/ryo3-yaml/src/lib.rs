@@ -0,0 +1,18 @@
+#![doc = include_str!("../README.md")]
+
+mod de;
+mod ser;
+mod target;
+
+use pyo3::prelude::*;
+
+pub use de::{loads, loads_all};
+pub use ser::{dumps, to_string};
+pub use target::YamlTarget;
+
+pub fn pysubmod_register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(de::loads, m)?)?;
+    m.add_function(wrap_pyfunction!(de::loads_all, m)?)?;
+    m.add_function(wrap_pyfunction!(ser::dumps, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+//! python => yaml serialization via `ryo3-serde`
+use pyo3::exceptions::{PyRecursionError, PyTypeError};
+use pyo3::prelude::*;
+use ryo3_serde::{PyAnySerializer, check_callable};
+
+use crate::target::YamlTarget;
+
+fn map_yaml_ser_err(e: &serde_yaml_ng::Error) -> PyErr {
+    let msg = e.to_string();
+    if msg.starts_with("recursion") {
+        PyRecursionError::new_err("Recursion limit reached")
+    } else {
+        PyTypeError::new_err(format!("Failed to serialize: {msg}"))
+    }
+}
+
+/// Serialize a python object to a yaml string
+pub fn to_string<'py>(
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+) -> PyResult<String> {
+    let s = PyAnySerializer::<YamlTarget>::with_target(obj.as_borrowed(), default);
+    serde_yaml_ng::to_string(&s).map_err(|e| map_yaml_ser_err(&e))
+}
+
+/// Serialize `obj` to a yaml string; `default` is called with objects that
+/// cannot otherwise be serialized and should return a serializable object
+#[pyfunction(signature = (obj, *, default = None))]
+pub fn dumps<'py>(
+    obj: &Bound<'py, PyAny>,
+    default: Option<&'py Bound<'py, PyAny>>,
+) -> PyResult<String> {
+    check_callable(default)?;
+    to_string(obj, default)
+}
@@ -0,0 +1,9 @@
+use ryo3_serde::PySerializeTarget;
+
+/// `ryo3-serde` target for yaml
+#[derive(Copy, Clone, Debug, Default)]
+pub struct YamlTarget;
+
+impl PySerializeTarget for YamlTarget {
+    const KIND: &'static str = "yaml";
+}
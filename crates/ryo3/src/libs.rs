@@ -2,6 +2,8 @@ use pyo3::prelude::*;
 
 #[allow(unused_variables)]
 pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    #[cfg(feature = "aho-corasick")]
+    ryo3_aho_corasick::pymod_add(m)?;
     #[cfg(feature = "aws-lc")]
     ryo3_aws_lc::pymod_add(m)?;
     #[cfg(feature = "brotli")]
@@ -12,6 +14,8 @@ pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
     ryo3_bytes::pymod_add(m)?;
     #[cfg(feature = "cookie")]
     ryo3_cookie::pymod_add(m)?;
+    #[cfg(feature = "data-encoding")]
+    ryo3_data_encoding::pymod_add(m)?;
     #[cfg(feature = "dirs")]
     ryo3_dirs::pymod_add(m)?;
     #[cfg(feature = "flate2")]
@@ -34,6 +38,8 @@ pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
     ryo3_lz4rip::pymod_add(m)?;
     #[cfg(feature = "memchr")]
     ryo3_memchr::pymod_add(m)?;
+    #[cfg(feature = "memmap2")]
+    ryo3_memmap2::pymod_add(m)?;
     #[cfg(feature = "regex")]
     ryo3_regex::pymod_add(m)?;
     #[cfg(feature = "reqwest")]
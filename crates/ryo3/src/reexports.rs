@@ -16,10 +16,13 @@ macro_rules! ryo3_features_reexport {
     };
 }
 ryo3_features_reexport! {
+    "aho-corasick", ryo3_aho_corasick,
     "brotli", ryo3_brotli,
     "bytes", ryo3_bytes,
     "bzip2", ryo3_bzip2,
+    "cbor", ryo3_cbor,
     "cookie", ryo3_cookie,
+    "data-encoding", ryo3_data_encoding,
     "dirs", ryo3_dirs,
     "flate2", ryo3_flate2,
     "fnv", ryo3_fnv,
@@ -28,6 +31,8 @@ ryo3_features_reexport! {
     "jiff", ryo3_jiff,
     "jiter", ryo3_jiter,
     "memchr", ryo3_memchr,
+    "memmap2", ryo3_memmap2,
+    "msgpack", ryo3_msgpack,
     "regex", ryo3_regex,
     "reqwest", ryo3_reqwest,
     "same-file", ryo3_same_file,
@@ -36,11 +41,13 @@ ryo3_features_reexport! {
     "sqlformat", ryo3_sqlformat,
     "tokio", ryo3_tokio,
     "tokio-websockets", ryo3_tokio_websockets,
+    "toml", ryo3_toml,
     "unindent", ryo3_unindent,
     "url", ryo3_url,
     "walkdir", ryo3_walkdir,
     "which", ryo3_which,
     "twox-hash", ryo3_twox_hash,
+    "yaml", ryo3_yaml,
     "zstd", ryo3_zstd,
 }
 
@@ -9,6 +9,34 @@ pub fn twox_hash(m: &Bound<'_, PyModule>) -> PyResult<()> {
     Ok(())
 }
 
+#[cfg(feature = "cbor")]
+#[pymodule(gil_used = false, submodule, name = "cbor")]
+pub fn cbor(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    ryo3_cbor::pysubmod_register(m)?;
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+#[pymodule(gil_used = false, submodule, name = "msgpack")]
+pub fn msgpack(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    ryo3_msgpack::pysubmod_register(m)?;
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[pymodule(gil_used = false, submodule, name = "toml")]
+pub fn toml(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    ryo3_toml::pysubmod_register(m)?;
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+#[pymodule(gil_used = false, submodule, name = "yaml")]
+pub fn yaml(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    ryo3_yaml::pysubmod_register(m)?;
+    Ok(())
+}
+
 #[cfg(feature = "zstd")]
 #[pymodule(gil_used = false, submodule, name = "zstd")]
 pub fn zstd(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -27,6 +55,10 @@ pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
     sys_modules.set_item(intern!(py, "ry.JSON"), m.getattr(intern!(py, "JSON"))?)?;
     let attr = m.getattr(intern!(py, "JSON"))?;
     attr.setattr(intern!(py, "__name__"), intern!(py, "ry.JSON"))?;
+    // async json lines writer lives in ryo3-tokio (built on `AsyncFile`)
+    #[cfg(all(feature = "json", feature = "tokio"))]
+    attr.cast::<PyModule>()?
+        .add_class::<ryo3_tokio::fs::PyAsyncJsonLinesWriter>()?;
 
     #[cfg(feature = "twox-hash")]
     m.add_wrapped(pyo3::wrap_pymodule!(twox_hash))?;
@@ -41,6 +73,37 @@ pub fn pymod_add(m: &Bound<'_, PyModule>) -> PyResult<()> {
     let attr = m.getattr(intern!(py, "xxhash"))?;
     attr.setattr(intern!(py, "__name__"), intern!(py, "ry.xxhash"))?;
 
+    // cbor
+    #[cfg(feature = "cbor")]
+    m.add_wrapped(pyo3::wrap_pymodule!(cbor))?;
+    sys_modules.set_item(intern!(py, "ry.cbor"), m.getattr(intern!(py, "cbor"))?)?;
+    let attr = m.getattr(intern!(py, "cbor"))?;
+    attr.setattr(intern!(py, "__name__"), intern!(py, "ry.cbor"))?;
+
+    // msgpack
+    #[cfg(feature = "msgpack")]
+    m.add_wrapped(pyo3::wrap_pymodule!(msgpack))?;
+    sys_modules.set_item(
+        intern!(py, "ry.msgpack"),
+        m.getattr(intern!(py, "msgpack"))?,
+    )?;
+    let attr = m.getattr(intern!(py, "msgpack"))?;
+    attr.setattr(intern!(py, "__name__"), intern!(py, "ry.msgpack"))?;
+
+    // toml
+    #[cfg(feature = "toml")]
+    m.add_wrapped(pyo3::wrap_pymodule!(toml))?;
+    sys_modules.set_item(intern!(py, "ry.toml"), m.getattr(intern!(py, "toml"))?)?;
+    let attr = m.getattr(intern!(py, "toml"))?;
+    attr.setattr(intern!(py, "__name__"), intern!(py, "ry.toml"))?;
+
+    // yaml
+    #[cfg(feature = "yaml")]
+    m.add_wrapped(pyo3::wrap_pymodule!(yaml))?;
+    sys_modules.set_item(intern!(py, "ry.yaml"), m.getattr(intern!(py, "yaml"))?)?;
+    let attr = m.getattr(intern!(py, "yaml"))?;
+    attr.setattr(intern!(py, "__name__"), intern!(py, "ry.yaml"))?;
+
     // zstd
     #[cfg(feature = "zstd")]
     m.add_wrapped(pyo3::wrap_pymodule!(zstd))?;
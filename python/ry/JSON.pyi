@@ -1,21 +1,53 @@
 """ry.JSON"""
 
+from ry.ryo3.JSON import AsyncJsonLinesWriter as AsyncJsonLinesWriter
+from ry.ryo3.JSON import JsonDecoder as JsonDecoder
+from ry.ryo3.JSON import JsonEventReader as JsonEventReader
+from ry.ryo3.JSON import JsonItemsIter as JsonItemsIter
+from ry.ryo3.JSON import JsonLinesWriter as JsonLinesWriter
+from ry.ryo3.JSON import JsonPatchError as JsonPatchError
+from ry.ryo3.JSON import JsonPatchTestError as JsonPatchTestError
+from ry.ryo3.JSON import JsonValidationError as JsonValidationError
 from ry.ryo3.JSON import cache_clear as cache_clear
 from ry.ryo3.JSON import cache_usage as cache_usage
+from ry.ryo3.JSON import canonicalize as canonicalize
+from ry.ryo3.JSON import diff as diff
 from ry.ryo3.JSON import dumps as dumps
 from ry.ryo3.JSON import fmt as fmt
+from ry.ryo3.JSON import get as get
+from ry.ryo3.JSON import iter_items as iter_items
 from ry.ryo3.JSON import loads as loads
+from ry.ryo3.JSON import merge_patch as merge_patch
 from ry.ryo3.JSON import minify as minify
 from ry.ryo3.JSON import parse as parse
+from ry.ryo3.JSON import patch as patch
+from ry.ryo3.JSON import query as query
 from ry.ryo3.JSON import stringify as stringify
+from ry.ryo3.JSON import write as write
 
 __all__ = (
+    "AsyncJsonLinesWriter",
+    "JsonDecoder",
+    "JsonEventReader",
+    "JsonItemsIter",
+    "JsonLinesWriter",
+    "JsonPatchError",
+    "JsonPatchTestError",
+    "JsonValidationError",
     "cache_clear",
     "cache_usage",
+    "canonicalize",
+    "diff",
     "dumps",
     "fmt",
+    "get",
+    "iter_items",
     "loads",
+    "merge_patch",
     "minify",
     "parse",
+    "patch",
+    "query",
     "stringify",
+    "write",
 )
@@ -42,10 +42,19 @@ from ry.ryo3 import (
     USIZE_MAX,
     USIZE_MIN,
     UUID,
+    AhoCorasick,
+    AhoCorasickMatch,
+    AhoCorasickStreamIter,
     AsyncFile,
     AsyncFileReadStream,
     BlockingClient,
     Bytes,
+    BytesCaptures,
+    BytesReader,
+    BytesRegex,
+    BytesRegexSet,
+    BytesWriter,
+    Captures,
     Certificate,
     CertificateRevocationList,
     Client,
@@ -60,6 +69,8 @@ from ry.ryo3 import (
     FeatureNotEnabledError,
     FileReadStream,
     FileType,
+    Finder,
+    FinderRev,
     FsPath,
     Glob,
     GlobPattern,
@@ -77,14 +88,18 @@ from ry.ryo3 import (
     Lz4BlockDecompressor,
     Lz4FrameCompressor,
     Metadata,
+    Mmap,
+    MmapMut,
     Offset,
     OffsetRound,
     PanicError,
     Proxy,
     ReadDir,
     Regex,
+    RegexSet,
     ReqwestError,
     Response,
+    SetMatches,
     SignedDuration,
     SignedDurationRound,
     Size,
@@ -128,6 +143,18 @@ from ry.ryo3 import (
     aopen,
     asleep,
     audio_dir,
+    base32_decode,
+    base32_encode,
+    base32_encode_str,
+    base58_decode,
+    base58_encode,
+    base58_encode_str,
+    base64_decode,
+    base64_encode,
+    base64_encode_str,
+    base85_decode,
+    base85_encode,
+    base85_encode_str,
     brotli,
     brotli_decode,
     brotli_encode,
@@ -138,6 +165,7 @@ from ry.ryo3 import (
     camel_case,
     canonicalize,
     canonicalize_async,
+    cbor,
     cd,
     config_dir,
     config_local_dir,
@@ -171,6 +199,9 @@ from ry.ryo3 import (
     gzip_encode,
     hard_link,
     hard_link_async,
+    hex_decode,
+    hex_encode,
+    hex_encode_str,
     home,
     home_dir,
     instant,
@@ -188,14 +219,24 @@ from ry.ryo3 import (
     lz4_train_dict,
     memchr,
     memchr2,
+    memchr2_iter,
     memchr3,
+    memchr3_iter,
+    memchr_iter,
+    memmem_find,
+    memmem_find_iter,
+    memmem_rfind,
+    memmem_rfind_iter,
     memrchr,
     memrchr2,
     memrchr3,
+    memrchr_iter,
     metadata,
     metadata_async,
     mkdir,
     mkdirp,
+    mmap,
+    msgpack,
     now,
     offset,
     orjson_default,
@@ -259,6 +300,7 @@ from ry.ryo3 import (
     time,
     timespan,
     title_case,
+    toml,
     train_case,
     try_exists_async,
     unindent,
@@ -287,6 +329,7 @@ from ry.ryo3 import (
     xxh32,
     xxh64,
     xxhash,
+    yaml,
     zoned,
     zstd,
     zstd_compress,
@@ -336,10 +379,19 @@ __all__ = (
     "USIZE_MAX",
     "USIZE_MIN",
     "UUID",
+    "AhoCorasick",
+    "AhoCorasickMatch",
+    "AhoCorasickStreamIter",
     "AsyncFile",
     "AsyncFileReadStream",
     "BlockingClient",
     "Bytes",
+    "BytesCaptures",
+    "BytesReader",
+    "BytesRegex",
+    "BytesRegexSet",
+    "BytesWriter",
+    "Captures",
     "Certificate",
     "CertificateRevocationList",
     "Client",
@@ -354,6 +406,8 @@ __all__ = (
     "FeatureNotEnabledError",
     "FileReadStream",
     "FileType",
+    "Finder",
+    "FinderRev",
     "FsPath",
     "Glob",
     "GlobPattern",
@@ -371,14 +425,18 @@ __all__ = (
     "Lz4BlockDecompressor",
     "Lz4FrameCompressor",
     "Metadata",
+    "Mmap",
+    "MmapMut",
     "Offset",
     "OffsetRound",
     "PanicError",
     "Proxy",
     "ReadDir",
     "Regex",
+    "RegexSet",
     "ReqwestError",
     "Response",
+    "SetMatches",
     "SignedDuration",
     "SignedDurationRound",
     "Size",
@@ -422,6 +480,18 @@ __all__ = (
     "aopen",
     "asleep",
     "audio_dir",
+    "base32_decode",
+    "base32_encode",
+    "base32_encode_str",
+    "base58_decode",
+    "base58_encode",
+    "base58_encode_str",
+    "base64_decode",
+    "base64_encode",
+    "base64_encode_str",
+    "base85_decode",
+    "base85_encode",
+    "base85_encode_str",
     "brotli",
     "brotli_decode",
     "brotli_encode",
@@ -432,6 +502,7 @@ __all__ = (
     "camel_case",
     "canonicalize",
     "canonicalize_async",
+    "cbor",
     "cd",
     "config_dir",
     "config_local_dir",
@@ -465,6 +536,9 @@ __all__ = (
     "gzip_encode",
     "hard_link",
     "hard_link_async",
+    "hex_decode",
+    "hex_encode",
+    "hex_encode_str",
     "home",
     "home_dir",
     "instant",
@@ -482,14 +556,24 @@ __all__ = (
     "lz4_train_dict",
     "memchr",
     "memchr2",
+    "memchr2_iter",
     "memchr3",
+    "memchr3_iter",
+    "memchr_iter",
+    "memmem_find",
+    "memmem_find_iter",
+    "memmem_rfind",
+    "memmem_rfind_iter",
     "memrchr",
     "memrchr2",
     "memrchr3",
+    "memrchr_iter",
     "metadata",
     "metadata_async",
     "mkdir",
     "mkdirp",
+    "mmap",
+    "msgpack",
     "now",
     "offset",
     "orjson_default",
@@ -554,6 +638,7 @@ __all__ = (
     "time",
     "timespan",
     "title_case",
+    "toml",
     "train_case",
     "try_exists_async",
     "unindent",
@@ -582,6 +667,7 @@ __all__ = (
     "xxh32",
     "xxh64",
     "xxhash",
+    "yaml",
     "zoned",
     "zstd",
     "zstd_compress",
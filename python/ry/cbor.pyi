@@ -0,0 +1,9 @@
+from ry.ryo3.cbor import Tag as Tag
+from ry.ryo3.cbor import dumps as dumps
+from ry.ryo3.cbor import loads as loads
+
+__all__ = (
+    "Tag",
+    "dumps",
+    "loads",
+)
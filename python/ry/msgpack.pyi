@@ -0,0 +1,9 @@
+from ry.ryo3.msgpack import Ext as Ext
+from ry.ryo3.msgpack import packb as packb
+from ry.ryo3.msgpack import unpackb as unpackb
+
+__all__ = (
+    "Ext",
+    "packb",
+    "unpackb",
+)
@@ -0,0 +1,782 @@
+"""ry.re ~ stdlib `re` compatible api on top of `ry.Regex`/`ry.BytesRegex`
+
+Swap `import re` for `from ry import re` in modules that only use the subset
+of regex syntax supported by the rust `regex` crate (no look-around and no
+backreferences; both raise `re.error` at compile time).
+
+Differences from the stdlib:
+
+- matching is done in linear time by the regex crate; leftmost-first
+  alternation semantics match the stdlib, but an empty match directly after
+  a non-empty match is skipped (rust semantics) by `finditer`/`sub`/`split`
+- `$` only matches at the very end of the string (or line in multi-line
+  mode), not before a trailing newline; use `\\z` or `\\n?$` explicitly
+- `pos`/`endpos` slice the string, so `^`/`\\A`/`\\b` see `pos` as the start
+  of the string
+- only the `I`, `M`, `S`, `X` (and no-op `U`) flags are supported
+"""
+
+from __future__ import annotations
+
+import enum
+import functools
+import re as _re
+import typing as t
+from types import MappingProxyType
+
+from ry.ryo3 import BytesRegex, Regex
+
+if t.TYPE_CHECKING:
+    from collections.abc import Callable, Iterator
+
+    from ry.ryo3 import BytesCaptures, Captures
+
+__all__ = (
+    "DOTALL",
+    "IGNORECASE",
+    "MULTILINE",
+    "NOFLAG",
+    "UNICODE",
+    "VERBOSE",
+    "I",
+    "M",
+    "S",
+    "U",
+    "X",
+    "Match",
+    "Pattern",
+    "RegexFlag",
+    "compile",
+    "error",
+    "escape",
+    "findall",
+    "finditer",
+    "fullmatch",
+    "match",
+    "purge",
+    "search",
+    "split",
+    "sub",
+    "subn",
+)
+
+AnyStr = t.TypeVar("AnyStr", str, bytes)
+
+error = _re.error
+
+
+class RegexFlag(enum.IntFlag):
+    """Supported regex flags (same values as the stdlib `re` flags)"""
+
+    NOFLAG = 0
+    IGNORECASE = I = _re.IGNORECASE.value
+    UNICODE = U = _re.UNICODE.value
+    MULTILINE = M = _re.MULTILINE.value
+    DOTALL = S = _re.DOTALL.value
+    VERBOSE = X = _re.VERBOSE.value
+
+
+NOFLAG: t.Final = RegexFlag.NOFLAG
+IGNORECASE: t.Final = RegexFlag.IGNORECASE
+I: t.Final = RegexFlag.IGNORECASE  # noqa: E741
+UNICODE: t.Final = RegexFlag.UNICODE
+U: t.Final = RegexFlag.UNICODE
+MULTILINE: t.Final = RegexFlag.MULTILINE
+M: t.Final = RegexFlag.MULTILINE
+DOTALL: t.Final = RegexFlag.DOTALL
+S: t.Final = RegexFlag.DOTALL
+VERBOSE: t.Final = RegexFlag.VERBOSE
+X: t.Final = RegexFlag.VERBOSE
+
+_SUPPORTED_FLAGS = int(IGNORECASE | UNICODE | MULTILINE | DOTALL | VERBOSE)
+_LEADING_VERBOSE_FLAG = _re.compile(r"\(\?[a-zA-Z]*x[a-zA-Z]*\)")
+
+# =============================================================================
+# pattern translation
+# =============================================================================
+
+
+# `{m}`, `{m,}`, `{,n}`, `{m,n}` and `{,}`; any other `{` is a literal
+_REPETITION = _re.compile(r"\{(\d*)(,?)(\d*)\}")
+
+# escape lengths of `\xhh`, `\uhhhh` and `\Uhhhhhhhh` (after the letter)
+_HEX_ESCAPE_LEN = {"x": 2, "u": 4, "U": 8}
+
+
+def _translate(pattern: str) -> str:
+    """Translate a stdlib pattern to regex-crate syntax
+
+    Raises `error` for constructs the regex crate can't express
+    (look-around, backreferences, conditionals).
+    """
+    out: list[str] = []
+    i = 0
+    n = len(pattern)
+    while i < n:
+        c = pattern[i]
+        if c == "\\":
+            nxt = pattern[i + 1 : i + 2]
+            if nxt.isdigit() and nxt != "0":
+                msg = "backreferences are not supported by the regex crate"
+                raise error(msg, pattern, i)
+            out.append("\\z" if nxt == "Z" else c + nxt)
+            i += 2
+            continue
+        if c == "[":
+            cls, i = _translate_class(pattern, i)
+            out.append(cls)
+            continue
+        if c == "{":
+            m = _REPETITION.match(pattern, i)
+            if m is None or m.group() == "{}":
+                out.append("\\{")
+            else:
+                lo, comma, hi = m.groups()
+                out.append(f"{{{lo or 0}{comma}{hi}}}")
+                i = m.end()
+                continue
+            i += 1
+            continue
+        if c == "}":
+            out.append("\\}")
+            i += 1
+            continue
+        if pattern.startswith("(?", i):
+            ext = pattern[i + 2 : i + 4]
+            if ext[:1] in ("=", "!") or ext in ("<=", "<!"):
+                msg = "look-around is not supported by the regex crate"
+                raise error(msg, pattern, i)
+            if ext == "P=":
+                msg = "backreferences are not supported by the regex crate"
+                raise error(msg, pattern, i)
+            if ext[:1] == "(":
+                msg = "conditional groups are not supported by the regex crate"
+                raise error(msg, pattern, i)
+            if ext[:1] == "#":
+                # comment group; drop it
+                end = pattern.find(")", i)
+                if end == -1:
+                    msg = "missing ), unterminated comment"
+                    raise error(msg, pattern, i)
+                i = end + 1
+                continue
+        out.append(c)
+        i += 1
+    return "".join(out)
+
+
+def _class_item(pattern: str, i: int) -> tuple[str, int]:
+    """One (possibly escaped) character of a `[...]` class"""
+    c = pattern[i]
+    if c == "\\":
+        end = i + 2 + _HEX_ESCAPE_LEN.get(pattern[i + 1 : i + 2], 0)
+        return pattern[i:end], end
+    # literals in the stdlib; nested classes and the `&&`, `--` and `~~` set
+    # operators in the regex crate
+    if c in "[&-~":
+        return "\\" + c, i + 1
+    return c, i + 1
+
+
+def _translate_class(pattern: str, i: int) -> tuple[str, int]:
+    """Translate the `[...]` class starting at `pattern[i]`
+
+    Returns the translated class and the index just past its closing `]`.
+    """
+    n = len(pattern)
+    out = ["["]
+    i += 1
+    if pattern[i : i + 1] == "^":
+        out.append("^")
+        i += 1
+    # a `]` right after the opening bracket (or `^`) is a literal
+    if pattern[i : i + 1] == "]":
+        out.append("\\]")
+        i += 1
+    while i < n and pattern[i] != "]":
+        item, i = _class_item(pattern, i)
+        out.append(item)
+        # `a-z` range; a `-` right before the closing `]` is a literal
+        if pattern[i : i + 1] == "-" and pattern[i + 1 : i + 2] not in ("", "]"):
+            item, i = _class_item(pattern, i + 1)
+            out.append("-" + item)
+    if i < n:
+        out.append("]")
+        i += 1
+    return "".join(out), i
+
+
+def _group_close_order(source: str) -> list[int]:
+    """Rank (by position of the closing paren) of each capture group
+
+    Index 0 is the whole match. Used to break ties for `Match.lastindex` when
+    groups end at the same offset (`(a)(b*)` vs `(a(b*))`).
+    """
+    order = [0]
+    stack: list[int | None] = []
+    rank = 0
+    i = 0
+    n = len(source)
+    while i < n:
+        c = source[i]
+        if c == "\\":
+            i += 2
+            continue
+        if c == "[":
+            i = _translate_class(source, i)[1]
+            continue
+        if c == "(":
+            named = source.startswith(("(?P<", "(?<"), i)
+            if named or not source.startswith("(?", i):
+                order.append(0)
+                stack.append(len(order) - 1)
+            else:
+                stack.append(None)
+        elif c == ")" and stack:
+            group = stack.pop()
+            if group is not None:
+                rank += 1
+                order[group] = rank
+        i += 1
+    return order
+
+
+def _bytes_pattern_to_str(pattern: bytes) -> str:
+    out: list[str] = []
+    i = 0
+    n = len(pattern)
+    while i < n:
+        b = pattern[i]
+        if b == 0x5C and i + 1 < n:  # backslash
+            nxt = pattern[i + 1]
+            # `\\<byte>` escapes a non-ascii byte; the escape is the byte
+            out.append(f"\\x{nxt:02x}" if nxt >= 0x80 else "\\" + chr(nxt))
+            i += 2
+            continue
+        out.append(chr(b) if b < 0x80 else f"\\x{b:02x}")
+        i += 1
+    return "".join(out)
+
+
+def _regex_kwargs(flags: int) -> dict[str, bool]:
+    if flags & ~_SUPPORTED_FLAGS:
+        unsupported = _re.RegexFlag(flags & ~_SUPPORTED_FLAGS)
+        msg = f"unsupported regex flag(s): {unsupported!r}"
+        raise ValueError(msg)
+    return {
+        "case_insensitive": bool(flags & IGNORECASE),
+        "multi_line": bool(flags & MULTILINE),
+        "dot_matches_new_line": bool(flags & DOTALL),
+        "ignore_whitespace": bool(flags & VERBOSE),
+    }
+
+
+# =============================================================================
+# replacement templates
+# =============================================================================
+
+_TEMPLATE_ESCAPES = {
+    "n": "\n",
+    "t": "\t",
+    "r": "\r",
+    "f": "\f",
+    "v": "\v",
+    "a": "\a",
+    "b": "\b",
+    "\\": "\\",
+}
+
+_TemplatePart: t.TypeAlias = "str | int"
+
+
+def _parse_template(template: str, pattern: Pattern[t.Any]) -> list[_TemplatePart]:
+    """Parse a stdlib replacement template into literals and group indices"""
+    parts: list[_TemplatePart] = []
+    literal: list[str] = []
+    i = 0
+    n = len(template)
+    while i < n:
+        c = template[i]
+        i += 1
+        if c != "\\":
+            literal.append(c)
+            continue
+        if i >= n:
+            msg = "bad escape (end of pattern)"
+            raise error(msg, template, i - 1)
+        c = template[i]
+        i += 1
+        group: int | None = None
+        if c == "g":
+            end = template.find(">", i)
+            if template[i : i + 1] != "<" or end == -1:
+                msg = "missing group name"
+                raise error(msg, template, i)
+            name = template[i + 1 : end]
+            i = end + 1
+            if name.isdigit():
+                group = int(name)
+            elif name in pattern.groupindex:
+                group = pattern.groupindex[name]
+            else:
+                msg = f"unknown group name {name!r}"
+                raise IndexError(msg)
+        elif c == "0":
+            # octal escape (up to 3 digits)
+            digits = c
+            while i < n and len(digits) < 3 and template[i] in "01234567":
+                digits += template[i]
+                i += 1
+            literal.append(chr(int(digits, 8)))
+        elif c.isdigit():
+            digits = c
+            if i < n and template[i].isdigit():
+                digits += template[i]
+                i += 1
+            group = int(digits)
+        elif c in _TEMPLATE_ESCAPES:
+            literal.append(_TEMPLATE_ESCAPES[c])
+        elif c.isascii() and c.isalpha():
+            msg = f"bad escape \\{c}"
+            raise error(msg, template, i - 2)
+        else:
+            literal.append("\\" + c)
+        if group is not None:
+            if group > pattern.groups:
+                msg = f"invalid group reference {group}"
+                raise error(msg, template, i)
+            if literal:
+                parts.append("".join(literal))
+                literal = []
+            parts.append(group)
+    if literal:
+        parts.append("".join(literal))
+    return parts
+
+
+def _expand(parts: list[_TemplatePart], m: Match[AnyStr]) -> AnyStr:
+    empty = m._empty
+    out = []
+    for part in parts:
+        if isinstance(part, int):
+            out.append(m.group(part) or empty)
+        elif isinstance(empty, bytes):
+            out.append(part.encode("latin-1"))
+        else:
+            out.append(part)
+    return empty.join(out)
+
+
+def _template_fn(
+    pattern: Pattern[AnyStr], repl: AnyStr | Callable[[Match[AnyStr]], AnyStr]
+) -> Callable[[Match[AnyStr]], AnyStr]:
+    if callable(repl):
+        return repl
+    if isinstance(repl, (bytes, bytearray, memoryview)):
+        template = bytes(repl)
+        if b"\\" not in template:
+            return lambda _m: template  # type: ignore[return-value]
+        parts = _parse_template(template.decode("latin-1"), pattern)
+    else:
+        if "\\" not in repl:
+            return lambda _m: repl
+        parts = _parse_template(repl, pattern)
+    return functools.partial(_expand, parts)
+
+
+# =============================================================================
+# Match
+# =============================================================================
+
+
+@t.final
+class Match(t.Generic[AnyStr]):
+    """`re.Match` compatible wrapper around `ry.Captures`/`ry.BytesCaptures`"""
+
+    __slots__ = ("_caps", "_empty", "_offset", "endpos", "pos", "re", "string")
+
+    def __init__(
+        self,
+        caps: Captures | BytesCaptures,
+        pattern: Pattern[AnyStr],
+        string: AnyStr,
+        pos: int,
+        endpos: int,
+    ) -> None:
+        self._caps = caps
+        self._offset = pos
+        self._empty: AnyStr = pattern._empty
+        self.re = pattern
+        self.string = string
+        self.pos = pos
+        self.endpos = endpos
+
+    def __repr__(self) -> str:
+        return f"<ry.re.Match object; span={self.span()!r}, match={self.group()!r}>"
+
+    def __bool__(self) -> bool:
+        return True
+
+    def __getitem__(self, group: int | str) -> AnyStr | t.Any:
+        return self._group(group)
+
+    def _group(self, group: int | str, default: t.Any = None) -> AnyStr | t.Any:
+        value = self._caps[group]
+        if value is None:
+            return default
+        if isinstance(self._empty, bytes):
+            return bytes(value)
+        return value
+
+    def group(self, *groups: int | str) -> AnyStr | t.Any:
+        if not groups:
+            return self._group(0)
+        if len(groups) == 1:
+            return self._group(groups[0])
+        return tuple(self._group(g) for g in groups)
+
+    def groups(self, default: t.Any = None) -> tuple[AnyStr | t.Any, ...]:
+        return tuple(self._group(i, default) for i in range(1, len(self._caps)))
+
+    def groupdict(self, default: t.Any = None) -> dict[str, AnyStr | t.Any]:
+        return {
+            name: self._group(index, default)
+            for name, index in self.re.groupindex.items()
+        }
+
+    def span(self, group: int | str = 0) -> tuple[int, int]:
+        start, end = self._caps.span(group)
+        if start == -1:
+            return (-1, -1)
+        return (start + self._offset, end + self._offset)
+
+    def start(self, group: int | str = 0) -> int:
+        return self.span(group)[0]
+
+    def end(self, group: int | str = 0) -> int:
+        return self.span(group)[1]
+
+    def expand(self, template: AnyStr) -> AnyStr:
+        return _template_fn(self.re, template)(self)
+
+    @property
+    def lastindex(self) -> int | None:
+        # the group that closed last: the one ending furthest in the string,
+        # ties (empty/nested groups) go to the one closing later in the pattern
+        close_order = self.re._close_order
+        last: int | None = None
+        last_key = (-1, -1)
+        for i in range(1, len(self._caps)):
+            end = self._caps.end(i)
+            if end == -1:
+                continue
+            key = (end, close_order[i])
+            if key > last_key:
+                last, last_key = i, key
+        return last
+
+    @property
+    def lastgroup(self) -> str | None:
+        lastindex = self.lastindex
+        if lastindex is None:
+            return None
+        for name, index in self.re.groupindex.items():
+            if index == lastindex:
+                return name
+        return None
+
+    @property
+    def regs(self) -> tuple[tuple[int, int], ...]:
+        return tuple(self.span(i) for i in range(len(self._caps)))
+
+
+# =============================================================================
+# Pattern
+# =============================================================================
+
+
+@t.final
+class Pattern(t.Generic[AnyStr]):
+    """`re.Pattern` compatible wrapper around `ry.Regex`/`ry.BytesRegex`"""
+
+    __slots__ = (
+        "_anchored",
+        "_close_order",
+        "_empty",
+        "_full",
+        "_regex",
+        "flags",
+        "groupindex",
+        "groups",
+        "pattern",
+    )
+
+    def __init__(self, pattern: AnyStr, flags: int = 0) -> None:
+        flags = int(flags)
+        kwargs = _regex_kwargs(flags)
+        if isinstance(pattern, str):
+            source = _translate(pattern)
+            regex_cls: type[Regex | BytesRegex] = Regex
+            self._empty: AnyStr = ""  # type: ignore[assignment]
+            self.flags = flags | UNICODE
+        elif isinstance(pattern, bytes):
+            if flags & UNICODE:
+                msg = "cannot use UNICODE flag with a bytes pattern"
+                raise ValueError(msg)
+            source = _translate(_bytes_pattern_to_str(pattern))
+            regex_cls = BytesRegex
+            kwargs["unicode"] = False  # ascii semantics like the stdlib
+            self._empty = b""  # type: ignore[assignment]
+            self.flags = flags
+        else:
+            msg = f"first argument must be string or compiled pattern, not {type(pattern).__name__}"
+            raise TypeError(msg)
+
+        # a trailing comment in verbose mode would swallow the closing paren
+        verbose = bool(flags & VERBOSE) or bool(_LEADING_VERBOSE_FLAG.match(source))
+        tail = "\n)" if verbose else ")"
+        try:
+            self._regex = regex_cls(source, **kwargs)
+            self._anchored = regex_cls(rf"\A(?:{source}{tail}", **kwargs)
+            self._full = regex_cls(rf"\A(?:{source}{tail}\z", **kwargs)
+        except ValueError as e:
+            raise error(str(e), pattern) from e
+        self.pattern: AnyStr = pattern
+        self.groups: int = self._regex.captures_len() - 1
+        self._close_order = _group_close_order(source)
+        if len(self._close_order) != self.groups + 1:
+            # e.g. parens in verbose mode comments; fall back to group order
+            self._close_order = list(range(self.groups + 1))
+        self.groupindex: t.Mapping[str, int] = MappingProxyType({
+            name: index
+            for index, name in enumerate(self._regex.capture_names())
+            if name is not None
+        })
+
+    def __repr__(self) -> str:
+        flags = self.flags & ~UNICODE if isinstance(self.pattern, str) else self.flags
+        if flags:
+            names = "|".join(
+                f"ry.re.{flag.name}"
+                for flag in RegexFlag
+                if flag.value and flags & flag.value and flag.name
+            )
+            return f"ry.re.compile({self.pattern!r}, {names})"
+        return f"ry.re.compile({self.pattern!r})"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, Pattern):
+            return NotImplemented
+        return (self.pattern, self.flags) == (other.pattern, other.flags)
+
+    def __hash__(self) -> int:
+        return hash((self.pattern, self.flags))
+
+    def _haystack(
+        self, string: AnyStr, pos: int, endpos: int | None
+    ) -> tuple[AnyStr, int, int] | None:
+        n = len(string)
+        pos = min(max(pos, 0), n)
+        endpos = n if endpos is None else min(max(endpos, 0), n)
+        if endpos < pos:
+            return None
+        if pos == 0 and endpos == n:
+            return string, pos, endpos
+        return string[pos:endpos], pos, endpos
+
+    def _match(
+        self,
+        regex: Regex | BytesRegex,
+        string: AnyStr,
+        pos: int,
+        endpos: int | None,
+    ) -> Match[AnyStr] | None:
+        haystack = self._haystack(string, pos, endpos)
+        if haystack is None:
+            return None
+        caps = regex.captures(haystack[0])  # type: ignore[arg-type]
+        if caps is None:
+            return None
+        return Match(caps, self, string, haystack[1], haystack[2])
+
+    def search(
+        self, string: AnyStr, pos: int = 0, endpos: int | None = None
+    ) -> Match[AnyStr] | None:
+        return self._match(self._regex, string, pos, endpos)
+
+    def match(
+        self, string: AnyStr, pos: int = 0, endpos: int | None = None
+    ) -> Match[AnyStr] | None:
+        return self._match(self._anchored, string, pos, endpos)
+
+    def fullmatch(
+        self, string: AnyStr, pos: int = 0, endpos: int | None = None
+    ) -> Match[AnyStr] | None:
+        return self._match(self._full, string, pos, endpos)
+
+    def finditer(
+        self, string: AnyStr, pos: int = 0, endpos: int | None = None
+    ) -> Iterator[Match[AnyStr]]:
+        haystack = self._haystack(string, pos, endpos)
+        if haystack is None:
+            return
+        hay, start, end = haystack
+        for caps in self._regex.captures_iter(hay):  # type: ignore[arg-type]
+            yield Match(caps, self, string, start, end)
+
+    def findall(
+        self, string: AnyStr, pos: int = 0, endpos: int | None = None
+    ) -> list[t.Any]:
+        empty = self._empty
+        if self.groups == 0:
+            return [m.group() for m in self.finditer(string, pos, endpos)]
+        if self.groups == 1:
+            return [m.group(1) or empty for m in self.finditer(string, pos, endpos)]
+        return [m.groups(empty) for m in self.finditer(string, pos, endpos)]
+
+    def split(self, string: AnyStr, maxsplit: int = 0) -> list[AnyStr | t.Any]:
+        parts: list[AnyStr | t.Any] = []
+        last = 0
+        for i, m in enumerate(self.finditer(string)):
+            if maxsplit and i >= maxsplit:
+                break
+            start, end = m.span()
+            parts.append(string[last:start])
+            parts.extend(m.groups())
+            last = end
+        parts.append(string[last:])
+        return parts
+
+    def subn(
+        self,
+        repl: AnyStr | Callable[[Match[AnyStr]], AnyStr],
+        string: AnyStr,
+        count: int = 0,
+    ) -> tuple[AnyStr, int]:
+        fn = _template_fn(self, repl)
+        pieces: list[AnyStr] = []
+        last = 0
+        n = 0
+        for m in self.finditer(string):
+            if count and n >= count:
+                break
+            start, end = m.span()
+            pieces.append(string[last:start])
+            pieces.append(fn(m))
+            last = end
+            n += 1
+        if n == 0:
+            return string, 0
+        pieces.append(string[last:])
+        return self._empty.join(pieces), n
+
+    def sub(
+        self,
+        repl: AnyStr | Callable[[Match[AnyStr]], AnyStr],
+        string: AnyStr,
+        count: int = 0,
+    ) -> AnyStr:
+        return self.subn(repl, string, count)[0]
+
+
+# =============================================================================
+# module level functions
+# =============================================================================
+
+
+@functools.lru_cache(maxsize=512)
+def _compile(pattern: AnyStr, flags: int) -> Pattern[AnyStr]:
+    return Pattern(pattern, flags)
+
+
+def compile(  # noqa: A001
+    pattern: AnyStr | Pattern[AnyStr], flags: int = 0
+) -> Pattern[AnyStr]:
+    """Compile a pattern (cached) returning a `Pattern`"""
+    if isinstance(pattern, Pattern):
+        if flags:
+            msg = "cannot process flags argument with a compiled pattern"
+            raise ValueError(msg)
+        return pattern
+    return _compile(pattern, int(flags))
+
+
+def purge() -> None:
+    """Clear the compiled pattern cache"""
+    _compile.cache_clear()
+
+
+def search(
+    pattern: AnyStr | Pattern[AnyStr], string: AnyStr, flags: int = 0
+) -> Match[AnyStr] | None:
+    return compile(pattern, flags).search(string)
+
+
+def match(
+    pattern: AnyStr | Pattern[AnyStr], string: AnyStr, flags: int = 0
+) -> Match[AnyStr] | None:
+    return compile(pattern, flags).match(string)
+
+
+def fullmatch(
+    pattern: AnyStr | Pattern[AnyStr], string: AnyStr, flags: int = 0
+) -> Match[AnyStr] | None:
+    return compile(pattern, flags).fullmatch(string)
+
+
+def findall(
+    pattern: AnyStr | Pattern[AnyStr], string: AnyStr, flags: int = 0
+) -> list[t.Any]:
+    return compile(pattern, flags).findall(string)
+
+
+def finditer(
+    pattern: AnyStr | Pattern[AnyStr], string: AnyStr, flags: int = 0
+) -> Iterator[Match[AnyStr]]:
+    return compile(pattern, flags).finditer(string)
+
+
+def sub(
+    pattern: AnyStr | Pattern[AnyStr],
+    repl: AnyStr | Callable[[Match[AnyStr]], AnyStr],
+    string: AnyStr,
+    count: int = 0,
+    flags: int = 0,
+) -> AnyStr:
+    return compile(pattern, flags).sub(repl, string, count)
+
+
+def subn(
+    pattern: AnyStr | Pattern[AnyStr],
+    repl: AnyStr | Callable[[Match[AnyStr]], AnyStr],
+    string: AnyStr,
+    count: int = 0,
+    flags: int = 0,
+) -> tuple[AnyStr, int]:
+    return compile(pattern, flags).subn(repl, string, count)
+
+
+def split(
+    pattern: AnyStr | Pattern[AnyStr],
+    string: AnyStr,
+    maxsplit: int = 0,
+    flags: int = 0,
+) -> list[AnyStr | t.Any]:
+    return compile(pattern, flags).split(string, maxsplit)
+
+
+_ESCAPE_MAP = {
+    **{i: "\\" + chr(i) for i in b"()[]{}?*+-|^$\\.&~# "},
+    ord("\t"): "\\t",
+    ord("\n"): "\\n",
+    ord("\r"): "\\r",
+    ord("\v"): "\\v",
+    ord("\f"): "\\f",
+}
+
+
+def escape(pattern: AnyStr) -> AnyStr:
+    """Escape special characters in a string (valid for both `re` and ry)"""
+    if isinstance(pattern, str):
+        return pattern.translate(_ESCAPE_MAP)
+    return pattern.decode("latin-1").translate(_ESCAPE_MAP).encode("latin-1")
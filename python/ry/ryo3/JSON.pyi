@@ -1,18 +1,39 @@
 """ry.ryo3.JSON"""
 
+import builtins
+import pathlib
 import typing as t
+from types import TracebackType
 
-from ry._types import Buffer
+from ry._types import Buffer, FsPathLike
 from ry.ryo3._bytes import Bytes
 from ry.ryo3._jiter import _JsonValue
+from ry.ryo3._std import FileReadStream
 
-def minify(buf: Buffer | str, /) -> Bytes:
+_T = t.TypeVar("_T")
+_JsonSource: t.TypeAlias = Buffer | FsPathLike | FileReadStream | t.Iterable[Buffer]
+_JsonLinesCompression: t.TypeAlias = t.Literal["gzip", "gz", "zstd", "zst"]
+_JsonEventName: t.TypeAlias = t.Literal[
+    "start_map", "end_map", "start_array", "end_array", "key", "value"
+]
+
+def minify(
+    buf: Buffer | str,
+    /,
+    *,
+    strip_comments: bool = False,
+    strip_trailing_commas: bool = False,
+) -> Bytes:
     """Return minified json data (remove whitespace, newlines)
 
     Parameters
     ----------
     buf : Buffer | str
         JSON buffer/string to minify
+    strip_comments : bool
+        Strip `//` and `/* */` comments (JSONC); default is False
+    strip_trailing_commas : bool
+        Strip trailing commas in arrays/objects (JSONC); default is False
 
     Returns
     -------
@@ -33,6 +54,9 @@ def minify(buf: Buffer | str, /) -> Bytes:
     }
     >>> bytes(JSON.minify(json_str))
     b'{"key":"value","number":123,"bool":true}'
+    >>> jsonc = '{"a": 1, /* one */ "b": [2,], // two\\n}'
+    >>> bytes(JSON.minify(jsonc, strip_comments=True, strip_trailing_commas=True))
+    b'{"a":1,"b":[2]}'
 
     """
 
@@ -78,8 +102,15 @@ def stringify(
     *,
     default: t.Callable[[t.Any], t.Any] | None = None,
     fmt: bool = False,
+    indent: int | str | None = None,
     sort_keys: bool = False,
     append_newline: bool = False,
+    ensure_ascii: bool = False,
+    non_str_keys: bool = False,
+    naive_utc: bool = False,
+    utc_z: bool = False,
+    passthrough_dataclass: bool = False,
+    passthrough_datetime: bool = False,
     pybytes: t.Literal[True],
 ) -> bytes: ...
 @t.overload
@@ -88,8 +119,15 @@ def stringify(
     *,
     default: t.Callable[[t.Any], t.Any] | None = None,
     fmt: bool = False,
+    indent: int | str | None = None,
     sort_keys: bool = False,
     append_newline: bool = False,
+    ensure_ascii: bool = False,
+    non_str_keys: bool = False,
+    naive_utc: bool = False,
+    utc_z: bool = False,
+    passthrough_dataclass: bool = False,
+    passthrough_datetime: bool = False,
     pybytes: t.Literal[False] = False,
 ) -> Bytes: ...
 @t.overload
@@ -98,8 +136,15 @@ def dumps(
     *,
     default: t.Callable[[t.Any], t.Any] | None = None,
     fmt: bool = False,
+    indent: int | str | None = None,
     sort_keys: bool = False,
     append_newline: bool = False,
+    ensure_ascii: bool = False,
+    non_str_keys: bool = False,
+    naive_utc: bool = False,
+    utc_z: bool = False,
+    passthrough_dataclass: bool = False,
+    passthrough_datetime: bool = False,
     pybytes: t.Literal[True],
 ) -> bytes: ...
 @t.overload
@@ -108,25 +153,491 @@ def dumps(
     *,
     default: t.Callable[[t.Any], t.Any] | None = None,
     fmt: bool = False,
+    indent: int | str | None = None,
     sort_keys: bool = False,
     append_newline: bool = False,
+    ensure_ascii: bool = False,
+    non_str_keys: bool = False,
+    naive_utc: bool = False,
+    utc_z: bool = False,
+    passthrough_dataclass: bool = False,
+    passthrough_datetime: bool = False,
     pybytes: t.Literal[False] = False,
 ) -> Bytes: ...
+def canonicalize(
+    obj: t.Any, /, *, default: t.Callable[[t.Any], t.Any] | None = None
+) -> Bytes:
+    """Serialize to RFC 8785 canonical json (JCS)
+
+    Object members are sorted by their utf-16 code units, numbers are
+    formatted like ECMAScript and there is no whitespace, so equal data
+    always produces identical bytes (for hashing/signing). NaN/Infinity and
+    ints outside +/- 2**53 raise `ValueError`.
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> bytes(JSON.canonicalize({"b": [1.0, 1e21], "a": "€"}))
+    b'{"a":"\\xe2\\x82\\xac","b":[1,1e+21]}'
+
+    """
+
+@t.overload
 def loads(
     data: Buffer | bytes | str,
     *,
+    type: None = None,
     allow_inf_nan: bool = False,
     cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
     partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
     catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
 ) -> _JsonValue: ...
+@t.overload
+def loads(
+    data: Buffer | bytes | str,
+    *,
+    type: type[_T],
+    allow_inf_nan: bool = False,
+    cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
+    partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
+    catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
+) -> _T: ...
+@t.overload
+def loads(
+    data: Buffer | bytes | str,
+    *,
+    type: t.Any,
+    allow_inf_nan: bool = False,
+    cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
+    partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
+    catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
+) -> t.Any: ...
+@t.overload
 def parse(
     data: Buffer | bytes | str,
     *,
+    type: None = None,
     allow_inf_nan: bool = False,
     cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
     partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
     catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
 ) -> _JsonValue: ...
+@t.overload
+def parse(
+    data: Buffer | bytes | str,
+    *,
+    type: type[_T],
+    allow_inf_nan: bool = False,
+    cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
+    partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
+    catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
+) -> _T: ...
+@t.overload
+def parse(
+    data: Buffer | bytes | str,
+    *,
+    type: t.Any,
+    allow_inf_nan: bool = False,
+    cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
+    partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
+    catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
+) -> t.Any: ...
 def cache_clear() -> None: ...
 def cache_usage() -> int: ...
+
+# =============================================================================
+# QUERYING
+# =============================================================================
+def get(
+    data: Buffer | bytes | str, pointer: str, /, default: t.Any = None
+) -> t.Any:
+    """Return the value at an RFC 6901 json pointer or `default`
+
+    The document is only scanned up to the target value and only the target
+    is converted to a python object.
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> data = b'{"items": [{"id": 1}, {"id": 2}]}'
+    >>> JSON.get(data, "/items/1/id")
+    2
+    >>> JSON.get(data, "/items/2/id", "missing")
+    'missing'
+
+    """
+
+def query(data: Buffer | bytes | str, path: str, /) -> list[t.Any]:
+    """Return the values selected by an RFC 9535 JSONPath query
+
+    The matched values are parsed from their source json (object key order
+    and big ints are preserved).
+
+    Paths made of only name, index and wildcard selectors (e.g.
+    `"$.items[*].id"`) scan the raw json like `get`; any other path (filters,
+    slices, descendant segments, ...) parses the whole document into memory
+    first, which can take several times the size of the document.
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> data = b'{"items": [{"id": 1}, {"id": 2, "ok": true}]}'
+    >>> JSON.query(data, "$.items[*].id")
+    [1, 2]
+    >>> JSON.query(data, "$.items[?@.ok].id")
+    [2]
+
+    """
+
+# =============================================================================
+# TYPED DECODING
+# =============================================================================
+class JsonValidationError(ValueError):
+    """JSON value does not match the expected type"""
+
+    path: str
+
+class JsonDecoder(t.Generic[_T]):
+    """Decoder for json data into a python type
+
+    Supports dataclasses, `TypedDict`s, `list`/`tuple`/`set`/`dict`,
+    `Optional`/`Union`, `Literal`, enums and ry types parsed from strings
+    (jiff, `UUID`, `ULID`, `URL`, ip addresses); the type is inspected once
+    on construction.
+
+    Examples
+    --------
+    >>> import dataclasses
+    >>> from ry import JSON
+    >>> @dataclasses.dataclass
+    ... class Point:
+    ...     x: int
+    ...     y: int = 0
+    >>> decoder = JSON.JsonDecoder(list[Point])
+    >>> decoder.decode(b'[{"x": 1}, {"x": 2, "y": 3}]')
+    [Point(x=1, y=0), Point(x=2, y=3)]
+    >>> try:
+    ...     decoder.decode(b'[{"x": 1}, {"x": "2"}]')
+    ... except JSON.JsonValidationError as e:
+    ...     print(e.path, "|", e)
+    $[1].x | Expected `int`, got `str` - at `$[1].x`
+
+    """
+
+    def __init__(
+        self,
+        type: builtins.type[_T],
+        /,
+        *,
+        allow_inf_nan: bool = False,
+        catch_duplicate_keys: bool = False,
+        allow_comments: bool = False,
+        allow_trailing_commas: bool = False,
+    ) -> None: ...
+    @property
+    def type(self) -> builtins.type[_T]: ...
+    def decode(self, data: Buffer | bytes | str) -> _T: ...
+    def decode_lines(self, data: Buffer | bytes | str) -> list[_T]: ...
+    def convert(self, obj: t.Any) -> _T: ...
+
+# =============================================================================
+# PATCHING
+# =============================================================================
+class JsonPatchError(ValueError):
+    """JSON Patch operation failed (invalid operation or missing path)"""
+
+    index: int
+    op: dict[str, t.Any]
+
+class JsonPatchTestError(JsonPatchError):
+    """JSON Patch `test` operation failed"""
+
+    path: str
+    expected: t.Any
+    actual: t.Any
+
+def diff(a: t.Any, b: t.Any, /) -> list[dict[str, t.Any]]:
+    """Return the RFC 6902 JSON Patch that turns `a` into `b`
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> JSON.diff({"a": 1, "b": [1, 2]}, {"a": 2, "b": [1]})
+    [{'op': 'replace', 'path': '/a', 'value': 2}, {'op': 'remove', 'path': '/b/1'}]
+
+    """
+
+def patch(doc: t.Any, ops: t.Sequence[t.Mapping[str, t.Any]], /) -> t.Any:
+    """Apply an RFC 6902 JSON Patch and return the new document
+
+    `doc` is not modified; a failed `test` operation raises
+    `JsonPatchTestError` (with `path`, `expected` and `actual`).
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> JSON.patch({"a": [1]}, [{"op": "add", "path": "/a/-", "value": 2}])
+    {'a': [1, 2]}
+
+    """
+
+def merge_patch(doc: t.Any, patch: t.Any, /) -> t.Any:
+    """Apply an RFC 7386 JSON Merge Patch and return the new document
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> JSON.merge_patch({"a": 1, "b": {"c": 2}}, {"a": None, "b": {"d": 3}})
+    {'b': {'c': 2, 'd': 3}}
+
+    """
+
+# =============================================================================
+# STREAMING
+# =============================================================================
+def iter_items(
+    source: _JsonSource,
+    prefix: str = "item",
+    *,
+    read_size: int = 65536,
+) -> JsonItemsIter:
+    """Lazily parse the values at `prefix` from a (huge) json document
+
+    The input is read `read_size` bytes at a time and only the current item
+    is kept in memory. Prefixes are ijson style: dot separated keys with
+    `item` for array elements (`"item"` are the elements of a top-level
+    array, `"data.item"` the elements of the array at key `data` and `""` is
+    the whole document).
+
+    Parameters
+    ----------
+    source : Buffer | FsPathLike | FileReadStream | Iterable[Buffer]
+        bytes-like object, file path (`str` is a path, NOT json text) or an
+        iterable of bytes-like chunks
+    prefix : str
+        prefix of the values to yield (default="item")
+    read_size : int
+        number of bytes to read at a time from buffers and files
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> data = b'{"data": [{"id": 1}, {"id": 2}], "total": 2}'
+    >>> list(JSON.iter_items(data, "data.item"))
+    [{'id': 1}, {'id': 2}]
+    >>> list(JSON.iter_items(data, "data.item.id"))
+    [1, 2]
+
+    """
+
+@t.final
+class JsonItemsIter:
+    def __iter__(self) -> t.Self: ...
+    def __next__(self) -> _JsonValue: ...
+    def collect(self) -> list[_JsonValue]: ...
+
+@t.final
+class JsonEventReader:
+    """Event level streaming json parser
+
+    Yields ijson style `(prefix, event, value)` tuples; `value` is the key
+    for `"key"` events, the parsed scalar for `"value"` events and `None`
+    otherwise.
+
+    Examples
+    --------
+    >>> from ry import JSON
+    >>> for event in JSON.JsonEventReader(b'{"a": [1]}'):
+    ...     print(event)
+    ('', 'start_map', None)
+    ('', 'key', 'a')
+    ('a', 'start_array', None)
+    ('a.item', 'value', 1)
+    ('a', 'end_array', None)
+    ('', 'end_map', None)
+
+    """
+
+    def __init__(self, source: _JsonSource, *, read_size: int = 65536) -> None: ...
+    def __iter__(self) -> t.Self: ...
+    def __next__(self) -> tuple[str, _JsonEventName, t.Any]: ...
+    @property
+    def depth(self) -> int: ...
+
+# =============================================================================
+# WRITING
+# =============================================================================
+def write(
+    path: FsPathLike,
+    obj: t.Any,
+    *,
+    default: t.Callable[[t.Any], t.Any] | None = None,
+    fmt: bool = False,
+    indent: int | str | None = None,
+    sort_keys: bool = False,
+    append_newline: bool = False,
+    ensure_ascii: bool = False,
+    non_str_keys: bool = False,
+    naive_utc: bool = False,
+    utc_z: bool = False,
+    passthrough_dataclass: bool = False,
+    passthrough_datetime: bool = False,
+) -> int:
+    """Serialize `obj` to json and write it to `path`
+
+    Takes the same options as `stringify`; the file write happens with the
+    GIL released. Returns the number of bytes written.
+
+    Examples
+    --------
+    >>> import tempfile, os
+    >>> from ry import JSON
+    >>> with tempfile.TemporaryDirectory() as tmp:
+    ...     path = os.path.join(tmp, "data.json")
+    ...     JSON.write(path, {"a": [1, 2]})
+    ...     JSON.parse(open(path, "rb").read())
+    13
+    {'a': [1, 2]}
+
+    """
+
+@t.final
+class JsonLinesWriter:
+    """Json lines (ndjson) file writer with optional gzip/zstd compression
+
+    Objects are serialized with the GIL held; compression and file io run
+    with it released.
+
+    Examples
+    --------
+    >>> import gzip, tempfile, os
+    >>> from ry import JSON
+    >>> with tempfile.TemporaryDirectory() as tmp:
+    ...     path = os.path.join(tmp, "data.jsonl.gz")
+    ...     with JSON.JsonLinesWriter(path, compression="gzip") as w:
+    ...         _ = w.write({"id": 1})
+    ...         _ = w.writelines([{"id": 2}, {"id": 3}])
+    ...     gzip.open(path).read()
+    b'{"id":1}\n{"id":2}\n{"id":3}\n'
+
+    """
+
+    def __init__(
+        self,
+        path: FsPathLike,
+        *,
+        append: bool = False,
+        compression: _JsonLinesCompression | None = None,
+        level: int | None = None,
+        default: t.Callable[[t.Any], t.Any] | None = None,
+        sort_keys: bool = False,
+        ensure_ascii: bool = False,
+        non_str_keys: bool = False,
+        naive_utc: bool = False,
+        utc_z: bool = False,
+        passthrough_dataclass: bool = False,
+        passthrough_datetime: bool = False,
+    ) -> None: ...
+    def __enter__(self) -> t.Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None: ...
+    @property
+    def path(self) -> pathlib.Path: ...
+    @property
+    def closed(self) -> bool: ...
+    def write(self, obj: t.Any) -> int: ...
+    def writelines(self, objs: t.Iterable[t.Any]) -> int: ...
+    def flush(self) -> None: ...
+    def close(self) -> None: ...
+
+@t.final
+class AsyncJsonLinesWriter:
+    """Async json lines writer built on `AsyncFile`
+
+    Objects are serialized with the GIL held when `write`/`writelines` is
+    called; compression and file io run on the tokio runtime.
+
+    Use `async with` or `await close()` to finish the (compressed) stream; an
+    unclosed writer is finished (blocking) when it is garbage collected.
+
+    Examples
+    --------
+    >>> import asyncio, tempfile, os
+    >>> from ry import JSON
+    >>> async def main(path: str) -> None:
+    ...     async with JSON.AsyncJsonLinesWriter(path) as w:
+    ...         await w.write({"id": 1})
+    ...         await w.writelines([{"id": 2}])
+    >>> with tempfile.TemporaryDirectory() as tmp:
+    ...     path = os.path.join(tmp, "data.jsonl")
+    ...     asyncio.run(main(path))
+    ...     open(path).read()
+    '{"id":1}\n{"id":2}\n'
+
+    """
+
+    def __init__(
+        self,
+        path: FsPathLike,
+        *,
+        append: bool = False,
+        compression: _JsonLinesCompression | None = None,
+        level: int | None = None,
+        default: t.Callable[[t.Any], t.Any] | None = None,
+        sort_keys: bool = False,
+        ensure_ascii: bool = False,
+        non_str_keys: bool = False,
+        naive_utc: bool = False,
+        utc_z: bool = False,
+        passthrough_dataclass: bool = False,
+        passthrough_datetime: bool = False,
+    ) -> None: ...
+    async def __aenter__(self) -> t.Self: ...
+    async def __aexit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None: ...
+    @property
+    def path(self) -> pathlib.Path: ...
+    @property
+    def closed(self) -> bool: ...
+    async def write(self, obj: t.Any) -> int: ...
+    async def writelines(self, objs: t.Iterable[t.Any]) -> int: ...
+    async def flush(self) -> None: ...
+    async def close(self) -> None: ...
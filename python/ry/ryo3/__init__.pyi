@@ -1,7 +1,11 @@
 """ry api ~ type annotations"""
 
 from ry.ryo3 import JSON as JSON
+from ry.ryo3 import cbor as cbor
+from ry.ryo3 import msgpack as msgpack
+from ry.ryo3 import toml as toml
 from ry.ryo3 import xxhash as xxhash
+from ry.ryo3 import yaml as yaml
 from ry.ryo3 import zstd as zstd
 from ry.ryo3.__about__ import __allocator__ as __allocator__
 from ry.ryo3.__about__ import __authors__ as __authors__
@@ -16,6 +20,9 @@ from ry.ryo3.__about__ import __pkg_name__ as __pkg_name__
 from ry.ryo3.__about__ import __pyo3_experimental_async__ as __pyo3_experimental_async__
 from ry.ryo3.__about__ import __target__ as __target__
 from ry.ryo3.__about__ import __version__ as __version__
+from ry.ryo3._aho_corasick import AhoCorasick as AhoCorasick
+from ry.ryo3._aho_corasick import AhoCorasickMatch as AhoCorasickMatch
+from ry.ryo3._aho_corasick import AhoCorasickStreamIter as AhoCorasickStreamIter
 from ry.ryo3._aws_lc import sha1 as sha1
 from ry.ryo3._aws_lc import sha3_256 as sha3_256
 from ry.ryo3._aws_lc import sha3_384 as sha3_384
@@ -29,10 +36,27 @@ from ry.ryo3._brotli import brotli as brotli
 from ry.ryo3._brotli import brotli_decode as brotli_decode
 from ry.ryo3._brotli import brotli_encode as brotli_encode
 from ry.ryo3._bytes import Bytes as Bytes
+from ry.ryo3._bytes import BytesReader as BytesReader
+from ry.ryo3._bytes import BytesWriter as BytesWriter
 from ry.ryo3._bzip2 import bzip2 as bzip2
 from ry.ryo3._bzip2 import bzip2_decode as bzip2_decode
 from ry.ryo3._bzip2 import bzip2_encode as bzip2_encode
 from ry.ryo3._cookie import Cookie as Cookie
+from ry.ryo3._data_encoding import base32_decode as base32_decode
+from ry.ryo3._data_encoding import base32_encode as base32_encode
+from ry.ryo3._data_encoding import base32_encode_str as base32_encode_str
+from ry.ryo3._data_encoding import base58_decode as base58_decode
+from ry.ryo3._data_encoding import base58_encode as base58_encode
+from ry.ryo3._data_encoding import base58_encode_str as base58_encode_str
+from ry.ryo3._data_encoding import base64_decode as base64_decode
+from ry.ryo3._data_encoding import base64_encode as base64_encode
+from ry.ryo3._data_encoding import base64_encode_str as base64_encode_str
+from ry.ryo3._data_encoding import base85_decode as base85_decode
+from ry.ryo3._data_encoding import base85_encode as base85_encode
+from ry.ryo3._data_encoding import base85_encode_str as base85_encode_str
+from ry.ryo3._data_encoding import hex_decode as hex_decode
+from ry.ryo3._data_encoding import hex_encode as hex_encode
+from ry.ryo3._data_encoding import hex_encode_str as hex_encode_str
 from ry.ryo3._dirs import audio_dir as audio_dir
 from ry.ryo3._dirs import cache_dir as cache_dir
 from ry.ryo3._dirs import config_dir as config_dir
@@ -127,15 +151,34 @@ from ry.ryo3._lz4rip import lz4_compress_block as lz4_compress_block
 from ry.ryo3._lz4rip import lz4_decompress as lz4_decompress
 from ry.ryo3._lz4rip import lz4_decompress_block as lz4_decompress_block
 from ry.ryo3._lz4rip import lz4_train_dict as lz4_train_dict
+from ry.ryo3._memchr import Finder as Finder
+from ry.ryo3._memchr import FinderRev as FinderRev
 from ry.ryo3._memchr import memchr as memchr
 from ry.ryo3._memchr import memchr2 as memchr2
+from ry.ryo3._memchr import memchr2_iter as memchr2_iter
 from ry.ryo3._memchr import memchr3 as memchr3
+from ry.ryo3._memchr import memchr3_iter as memchr3_iter
+from ry.ryo3._memchr import memchr_iter as memchr_iter
+from ry.ryo3._memchr import memmem_find as memmem_find
+from ry.ryo3._memchr import memmem_find_iter as memmem_find_iter
+from ry.ryo3._memchr import memmem_rfind as memmem_rfind
+from ry.ryo3._memchr import memmem_rfind_iter as memmem_rfind_iter
 from ry.ryo3._memchr import memrchr as memrchr
 from ry.ryo3._memchr import memrchr2 as memrchr2
 from ry.ryo3._memchr import memrchr3 as memrchr3
+from ry.ryo3._memchr import memrchr_iter as memrchr_iter
+from ry.ryo3._memmap2 import Mmap as Mmap
+from ry.ryo3._memmap2 import MmapMut as MmapMut
+from ry.ryo3._memmap2 import mmap as mmap
 from ry.ryo3._orjson import orjson_default as orjson_default
 from ry.ryo3._quick_maths import quick_maths as quick_maths
+from ry.ryo3._regex import BytesCaptures as BytesCaptures
+from ry.ryo3._regex import BytesRegex as BytesRegex
+from ry.ryo3._regex import BytesRegexSet as BytesRegexSet
+from ry.ryo3._regex import Captures as Captures
 from ry.ryo3._regex import Regex as Regex
+from ry.ryo3._regex import RegexSet as RegexSet
+from ry.ryo3._regex import SetMatches as SetMatches
 from ry.ryo3._reqwest import BlockingClient as BlockingClient
 from ry.ryo3._reqwest import BlockingResponse as BlockingResponse
 from ry.ryo3._reqwest import BlockingResponseStream as BlockingResponseStream
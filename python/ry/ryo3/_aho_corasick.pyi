@@ -0,0 +1,80 @@
+"""ryo3-aho-corasick ~ types"""
+
+import typing as t
+
+from ry._types import Buffer
+from ry.ryo3._bytes import Bytes
+
+_MatchKind: t.TypeAlias = t.Literal["standard", "leftmost-first", "leftmost-longest"]
+
+@t.final
+class AhoCorasick:
+    """Multi-pattern (keyword) searcher
+
+    Offsets are `str` (code point) indices for `str` haystacks and byte
+    offsets for bytes-like haystacks.
+
+    Examples
+    --------
+    >>> import ry
+    >>> ac = ry.AhoCorasick(["apple", "maple", "snapple"])
+    >>> [m.pattern for m in ac.find_iter("maple apple snapple")]
+    [1, 0, 2]
+    >>> ac.replace_all("maple apple", ["fruit", "syrup", "drink"])
+    'syrup fruit'
+
+    """
+
+    def __new__(
+        cls,
+        patterns: t.Sequence[str | Buffer],
+        *,
+        match_kind: _MatchKind = "leftmost-first",
+        ascii_case_insensitive: bool = False,
+    ) -> t.Self: ...
+    def __len__(self) -> int: ...
+    @property
+    def patterns(self) -> list[str | bytes]: ...
+    @property
+    def patterns_len(self) -> int: ...
+    @property
+    def match_kind(self) -> _MatchKind: ...
+    @property
+    def ascii_case_insensitive(self) -> bool: ...
+    def is_match(self, haystack: str | Buffer) -> bool: ...
+    def find(self, haystack: str | Buffer) -> AhoCorasickMatch | None: ...
+    def find_iter(self, haystack: str | Buffer) -> list[AhoCorasickMatch]:
+        """Return all non-overlapping matches in the haystack"""
+
+    def count(self, haystack: str | Buffer) -> int:
+        """Return the number of non-overlapping matches in the haystack"""
+
+    @t.overload
+    def replace_all(self, haystack: str, replacements: t.Sequence[str]) -> str: ...
+    @t.overload
+    def replace_all(
+        self, haystack: Buffer, replacements: t.Sequence[Buffer]
+    ) -> Bytes: ...
+    def stream_find_iter(
+        self, stream: t.Iterable[Buffer]
+    ) -> AhoCorasickStreamIter:
+        """Lazily search an iterable of bytes-like chunks (e.g. a
+        `FileReadStream`); matches spanning chunk boundaries are found and
+        offsets are absolute byte offsets into the stream"""
+
+@t.final
+class AhoCorasickMatch:
+    def __len__(self) -> int: ...
+    @property
+    def pattern(self) -> int: ...
+    @property
+    def start(self) -> int: ...
+    @property
+    def end(self) -> int: ...
+    def span(self) -> tuple[int, int]: ...
+
+@t.final
+class AhoCorasickStreamIter:
+    def __iter__(self) -> t.Self: ...
+    def __next__(self) -> AhoCorasickMatch: ...
+    def collect(self) -> list[AhoCorasickMatch]: ...
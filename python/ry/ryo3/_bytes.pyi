@@ -2,6 +2,7 @@
 
 import sys
 import typing as t
+from types import TracebackType
 
 from ry.protocols import RyIterator
 
@@ -172,10 +173,32 @@ class Bytes(Buffer):
     @classmethod
     def fromhex(cls, string: str) -> Bytes:
         """Construct a `Bytes` object from a hexadecimal string."""
-    def startswith(self, prefix: Buffer, /) -> bool:
-        """Return `True` if the binary data starts with the prefix string, `False` otherwise."""
-    def endswith(self, suffix: Buffer, /) -> bool:
-        """Return `True` if the binary data ends with the suffix string, `False` otherwise."""
+    def startswith(
+        self,
+        prefix: Buffer | tuple[Buffer, ...],
+        start: int | None = None,
+        end: int | None = None,
+        /,
+    ) -> bool:
+        """Return `True` if the binary data starts with the prefix, `False` otherwise.
+
+        `prefix` can also be a tuple of prefixes to look for. With optional
+        `start`, test beginning at that position. With optional `end`, stop
+        comparing at that position.
+        """
+    def endswith(
+        self,
+        suffix: Buffer | tuple[Buffer, ...],
+        start: int | None = None,
+        end: int | None = None,
+        /,
+    ) -> bool:
+        """Return `True` if the binary data ends with the suffix, `False` otherwise.
+
+        `suffix` can also be a tuple of suffixes to look for. With optional
+        `start`, test beginning at that position. With optional `end`, stop
+        comparing at that position.
+        """
     def capitalize(self) -> Bytes:
         """
         Return a copy of the sequence with the first byte converted to uppercase and
@@ -220,6 +243,14 @@ class Bytes(Buffer):
         If `count` is given and not negative, only the first `count` occurrences are
         replaced.
         """
+    def count(
+        self,
+        sub: Buffer | int,
+        start: int | None = None,
+        end: int | None = None,
+        /,
+    ) -> int:
+        """Return the number of non-overlapping occurrences of `sub` in the range."""
     def find(
         self,
         sub: Buffer | int,
@@ -256,6 +287,64 @@ class Bytes(Buffer):
         """Return a list of the words in the bytes, using `sep` as the delimiter."""
     def rsplit(self, sep: Buffer | None = None, maxsplit: int = -1, /) -> list[Bytes]:
         """Return a list of the words in the bytes, using `sep` as the delimiter."""
+    def splitlines(self, keepends: bool = False) -> list[Bytes]:
+        """Return a list of the lines in the bytes, breaking at line boundaries.
+
+        Only `\\n`, `\\r` and `\\r\\n` are line boundaries. Line breaks are not
+        included in the resulting list unless `keepends` is given and true. The
+        lines are zero-copy slices of the underlying buffer.
+
+        Examples
+        --------
+        >>> from ry import Bytes
+        >>> Bytes(b"ab\\r\\ncd\\n").splitlines()
+        [Bytes(b"ab"), Bytes(b"cd")]
+        >>> Bytes(b"ab\\r\\ncd\\n").splitlines(keepends=True)
+        [Bytes(b"ab\\r\\n"), Bytes(b"cd\\n")]
+
+        """
+    def join(self, iterable_of_bytes: t.Iterable[Buffer], /) -> Bytes:
+        """Concatenate bytes-like objects with this `Bytes` as the separator.
+
+        Examples
+        --------
+        >>> from ry import Bytes
+        >>> Bytes(b", ").join([b"a", Bytes(b"b"), bytearray(b"c")])
+        Bytes(b"a, b, c")
+
+        """
+    def center(self, width: int, fillbyte: Buffer = b" ", /) -> Bytes:
+        """Return a centered copy of length `width` padded with `fillbyte`."""
+    def ljust(self, width: int, fillbyte: Buffer = b" ", /) -> Bytes:
+        """Return a left-justified copy of length `width` padded with `fillbyte`."""
+    def rjust(self, width: int, fillbyte: Buffer = b" ", /) -> Bytes:
+        """Return a right-justified copy of length `width` padded with `fillbyte`."""
+    def zfill(self, width: int, /) -> Bytes:
+        """Pad with ASCII `0` digits on the left to fill a field of `width`.
+
+        A leading sign prefix (`b"+"`/`b"-"`) is handled by inserting the padding
+        after the sign character rather than before.
+        """
+    def translate(self, table: Buffer | None, /, delete: Buffer = b"") -> Bytes:
+        """Return a copy with each byte mapped through the translation `table`.
+
+        All bytes occurring in `delete` are removed; `table` must be a 256 byte
+        table (see `Bytes.maketrans`) or `None` to only delete bytes.
+        """
+    @staticmethod
+    def maketrans(frm: Buffer, to: Buffer, /) -> Bytes:
+        """Return a translation table for `Bytes.translate`.
+
+        Each byte in `frm` is mapped to the byte at the same position in `to`;
+        `frm` and `to` must be the same length.
+
+        Examples
+        --------
+        >>> from ry import Bytes
+        >>> Bytes(b"abc").translate(Bytes.maketrans(b"ab", b"xy"))
+        Bytes(b"xyc")
+
+        """
     def partition(self, sep: Buffer, /) -> tuple[Bytes, Bytes, Bytes]:
         """Partition the bytes into three parts using the given separator.
 
@@ -331,6 +420,124 @@ class Bytes(Buffer):
 
         """
 
+class BytesReader:
+    """Binary file-like reader over a `Bytes` buffer
+
+    Implements the `io.BufferedIOBase` api (and is registered as a virtual
+    subclass of it); `read`/`readline` return zero-copy slices of the
+    underlying buffer.
+
+    Examples
+    --------
+    >>> import ry
+    >>> reader = ry.BytesReader(b"hello\nworld\n")
+    >>> reader.readline()
+    Bytes(b"hello\n")
+    >>> reader.read(3)
+    Bytes(b"wor")
+    >>> reader.tell()
+    9
+    >>> list(reader)
+    [Bytes(b"ld\n")]
+
+    """
+
+    def __init__(self, data: Buffer = b"", /) -> None: ...
+    def __iter__(self) -> t.Self: ...
+    def __next__(self) -> Bytes: ...
+    def __enter__(self) -> t.Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None: ...
+    @property
+    def closed(self) -> bool: ...
+    @property
+    def mode(self) -> t.Literal["rb"]: ...
+    def close(self) -> None: ...
+    def flush(self) -> None: ...
+    def readable(self) -> bool: ...
+    def writable(self) -> bool: ...
+    def seekable(self) -> bool: ...
+    def isatty(self) -> bool: ...
+    def fileno(self) -> t.NoReturn: ...
+    def detach(self) -> t.NoReturn: ...
+    def write(self, *args: t.Any) -> t.NoReturn: ...
+    def truncate(self, *args: t.Any) -> t.NoReturn: ...
+    def tell(self) -> int: ...
+    def seek(self, offset: int, whence: int = 0, /) -> int: ...
+    def read(self, size: int | None = -1, /) -> Bytes: ...
+    def read1(self, size: int | None = -1, /) -> Bytes: ...
+    def readall(self) -> Bytes: ...
+    def readinto(self, buffer: Buffer, /) -> int: ...
+    def readinto1(self, buffer: Buffer, /) -> int: ...
+    def readline(self, size: int | None = -1, /) -> Bytes: ...
+    def readlines(self, hint: int | None = -1, /) -> list[Bytes]: ...
+    def peek(self, size: int = 0, /) -> Bytes:
+        """Return remaining bytes (up to `size` if > 0) without advancing"""
+
+    def getvalue(self) -> Bytes:
+        """Return the entire underlying buffer (zero-copy)"""
+
+class BytesWriter:
+    """Binary file-like writer that grows a buffer
+
+    Implements the `io.BufferedIOBase` api (and is registered as a virtual
+    subclass of it); call `getvalue()` for a copy of the written bytes or
+    `freeze()` to get them as `Bytes` without copying.
+
+    Examples
+    --------
+    >>> import ry
+    >>> writer = ry.BytesWriter()
+    >>> writer.write(b"hello ")
+    6
+    >>> writer.writelines([b"wor", b"ld"])
+    >>> writer.freeze()
+    Bytes(b"hello world")
+    >>> writer.closed
+    True
+
+    """
+
+    def __init__(self, capacity: int = 0) -> None: ...
+    def __len__(self) -> int: ...
+    def __enter__(self) -> t.Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None: ...
+    @property
+    def closed(self) -> bool: ...
+    @property
+    def mode(self) -> t.Literal["wb"]: ...
+    def close(self) -> None: ...
+    def flush(self) -> None: ...
+    def readable(self) -> bool: ...
+    def writable(self) -> bool: ...
+    def seekable(self) -> bool: ...
+    def isatty(self) -> bool: ...
+    def fileno(self) -> t.NoReturn: ...
+    def detach(self) -> t.NoReturn: ...
+    def read(self, *args: t.Any) -> t.NoReturn: ...
+    def read1(self, *args: t.Any) -> t.NoReturn: ...
+    def readinto(self, *args: t.Any) -> t.NoReturn: ...
+    def readline(self, *args: t.Any) -> t.NoReturn: ...
+    def tell(self) -> int: ...
+    def seek(self, offset: int, whence: int = 0, /) -> int: ...
+    def truncate(self, size: int | None = None, /) -> int: ...
+    def write(self, data: Buffer, /) -> int: ...
+    def writelines(self, lines: t.Iterable[Buffer], /) -> None: ...
+    def getvalue(self) -> Bytes:
+        """Return a copy of the written bytes"""
+
+    def freeze(self) -> Bytes:
+        """Return the written bytes as `Bytes` (no copy) and close the writer"""
+
 class _BytesSliceIter(t.Protocol):
     def __iter__(self) -> t.Self: ...
     def __next__(self) -> Bytes: ...
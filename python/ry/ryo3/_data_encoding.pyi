@@ -0,0 +1,248 @@
+"""ryo3-data-encoding ~ types"""
+
+import typing as t
+
+from ry._types import Buffer
+from ry.ryo3._bytes import Bytes
+
+_Base64Variant: t.TypeAlias = t.Literal[
+    "standard", "standard-nopad", "urlsafe", "urlsafe-nopad", "mime"
+]
+_Base32Variant: t.TypeAlias = t.Literal[
+    "rfc4648", "rfc4648-nopad", "hex", "hex-nopad", "crockford"
+]
+_Base58Alphabet: t.TypeAlias = t.Literal["bitcoin", "ripple", "flickr"]
+_Base85Variant: t.TypeAlias = t.Literal["rfc1924", "z85"]
+
+# =============================================================================
+# BASE64
+# =============================================================================
+def base64_encode(data: Buffer, *, variant: _Base64Variant = "standard") -> Bytes:
+    """Base64 encode a buffer
+
+    Parameters
+    ----------
+    data : Buffer
+        bytes-like object to encode
+    variant : str, optional
+        `"standard"` (default), `"standard-nopad"`, `"urlsafe"`,
+        `"urlsafe-nopad"` or `"mime"` (76-char lines separated by `\\r\\n`)
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.base64_encode(b"hello?")
+    Bytes(b"aGVsbG8/")
+    >>> ry.base64_encode(b"hello?", variant="urlsafe")
+    Bytes(b"aGVsbG8_")
+
+    """
+
+def base64_encode_str(data: Buffer, *, variant: _Base64Variant = "standard") -> str:
+    """Base64 encode a buffer returning a `str`
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.base64_encode_str(b"hi", variant="standard-nopad")
+    'aGk'
+
+    """
+
+def base64_decode(
+    data: Buffer | str,
+    *,
+    variant: _Base64Variant = "standard",
+    strict: bool = True,
+) -> Bytes:
+    """Base64 decode a buffer or ascii string
+
+    Parameters
+    ----------
+    data : Buffer | str
+        base64 encoded bytes-like object or ascii string
+    variant : str, optional
+        alphabet/padding variant (default="standard")
+    strict : bool, optional
+        if `True` (default) the input must be canonical for the variant; if
+        `False` ascii whitespace is ignored, padding is optional and non-zero
+        trailing bits are accepted
+
+    Raises
+    ------
+    ValueError
+        If the input is not valid for the variant.
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.base64_decode("aGk=")
+    Bytes(b"hi")
+    >>> ry.base64_decode(b"aG k", strict=False)
+    Bytes(b"hi")
+
+    """
+
+# =============================================================================
+# BASE32
+# =============================================================================
+def base32_encode(data: Buffer, *, variant: _Base32Variant = "rfc4648") -> Bytes:
+    """Base32 encode a buffer
+
+    Parameters
+    ----------
+    data : Buffer
+        bytes-like object to encode
+    variant : str, optional
+        `"rfc4648"` (default), `"rfc4648-nopad"`, `"hex"` (base32hex),
+        `"hex-nopad"` or `"crockford"` (no padding)
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.base32_encode(b"hi")
+    Bytes(b"NBUQ====")
+    >>> ry.base32_encode(b"hi", variant="crockford")
+    Bytes(b"D1MG")
+
+    """
+
+def base32_encode_str(data: Buffer, *, variant: _Base32Variant = "rfc4648") -> str:
+    """Base32 encode a buffer returning a `str`"""
+
+def base32_decode(
+    data: Buffer | str,
+    *,
+    variant: _Base32Variant = "rfc4648",
+    strict: bool = True,
+) -> Bytes:
+    """Base32 decode a buffer or ascii string
+
+    In non-strict mode decoding is case-insensitive, ascii whitespace is
+    ignored, padding is optional and non-zero trailing bits are accepted; for
+    `"crockford"` `I`/`L` decode as `1`, `O` as `0` and hyphens are ignored.
+
+    Raises
+    ------
+    ValueError
+        If the input is not valid for the variant.
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.base32_decode("NBUQ====")
+    Bytes(b"hi")
+    >>> ry.base32_decode("d1-mg", variant="crockford", strict=False)
+    Bytes(b"hi")
+
+    """
+
+# =============================================================================
+# BASE58
+# =============================================================================
+def base58_encode(data: Buffer, *, alphabet: _Base58Alphabet = "bitcoin") -> Bytes:
+    """Base58 encode a buffer
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.base58_encode(b"hello world")
+    Bytes(b"StV1DL6CwTryKyV")
+
+    """
+
+def base58_encode_str(
+    data: Buffer, *, alphabet: _Base58Alphabet = "bitcoin"
+) -> str:
+    """Base58 encode a buffer returning a `str`"""
+
+def base58_decode(
+    data: Buffer | str,
+    *,
+    alphabet: _Base58Alphabet = "bitcoin",
+    strict: bool = True,
+) -> Bytes:
+    """Base58 decode a buffer or ascii string
+
+    Non-strict mode ignores ascii whitespace.
+
+    Raises
+    ------
+    ValueError
+        If the input contains characters not in the alphabet.
+
+    """
+
+# =============================================================================
+# BASE85
+# =============================================================================
+def base85_encode(
+    data: Buffer, *, variant: _Base85Variant = "rfc1924", pad: bool = False
+) -> Bytes:
+    """Base85 encode a buffer
+
+    `"rfc1924"` matches python's `base64.b85encode` and `"z85"` matches
+    `base64.z85encode` (python 3.13+); if `pad` is `True` the input is padded
+    to a multiple of 4 bytes first.
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.base85_encode(b"hello")
+    Bytes(b"Xk~0{Zv")
+    >>> ry.base85_encode(b"hell", variant="z85")
+    Bytes(b"xK#0@")
+
+    """
+
+def base85_encode_str(
+    data: Buffer, *, variant: _Base85Variant = "rfc1924", pad: bool = False
+) -> str:
+    """Base85 encode a buffer returning a `str`"""
+
+def base85_decode(
+    data: Buffer | str,
+    *,
+    variant: _Base85Variant = "rfc1924",
+    strict: bool = True,
+) -> Bytes:
+    """Base85 decode a buffer or ascii string
+
+    Non-strict mode ignores ascii whitespace and (like python's
+    `base64.b85decode`) a dangling single trailing character.
+
+    Raises
+    ------
+    ValueError
+        If the input contains invalid characters or overflowing chunks.
+
+    """
+
+# =============================================================================
+# HEX
+# =============================================================================
+def hex_encode(data: Buffer, *, upper: bool = False) -> Bytes:
+    """Hex encode a buffer
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.hex_encode(b"\\x01\\xab")
+    Bytes(b"01ab")
+
+    """
+
+def hex_encode_str(data: Buffer, *, upper: bool = False) -> str:
+    """Hex encode a buffer returning a `str`"""
+
+def hex_decode(data: Buffer | str, *, strict: bool = True) -> Bytes:
+    """Hex decode (either case) a buffer or ascii string
+
+    Non-strict mode ignores ascii whitespace.
+
+    Raises
+    ------
+    ValueError
+        If the input has odd length or non-hex characters.
+
+    """
@@ -22,6 +22,11 @@ def parse_json(
     cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
     partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
     catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
 ) -> _JsonValue: ...
 def parse_jsonl(
     data: Buffer | bytes | str,
@@ -30,6 +35,11 @@ def parse_jsonl(
     cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
     partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
     catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
 ) -> list[_JsonValue]: ...
 def read_json(
     p: str | PathLike[str],
@@ -38,6 +48,11 @@ def read_json(
     cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
     partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
     catch_duplicate_keys: bool = False,
+    float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+    big_int_mode: t.Literal["int", "str"] = "int",
+    lazy_numbers: bool = False,
+    allow_comments: bool = False,
+    allow_trailing_commas: bool = False,
     lines: bool = False,
 ) -> _JsonValue: ...
 def json_cache_clear() -> None: ...
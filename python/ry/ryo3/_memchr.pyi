@@ -3,6 +3,7 @@
 import typing as t
 
 from ry._types import Buffer
+from ry.ryo3._bytes import Bytes
 
 _Byte: t.TypeAlias = int | bytes
 
@@ -16,3 +17,77 @@ def memrchr2(needle1: _Byte, needle2: _Byte, haystack: Buffer) -> int | None: ..
 def memrchr3(
     needle1: _Byte, needle2: _Byte, needle3: _Byte, haystack: Buffer
 ) -> int | None: ...
+def memchr_iter(needle: _Byte, haystack: Buffer) -> list[int]:
+    """Return the indices of all occurrences of `needle` in `haystack`"""
+
+def memchr2_iter(needle1: _Byte, needle2: _Byte, haystack: Buffer) -> list[int]:
+    """Return the indices of all occurrences of either needle in `haystack`"""
+
+def memchr3_iter(
+    needle1: _Byte, needle2: _Byte, needle3: _Byte, haystack: Buffer
+) -> list[int]:
+    """Return the indices of all occurrences of any needle in `haystack`"""
+
+def memrchr_iter(needle: _Byte, haystack: Buffer) -> list[int]:
+    """Return the indices of all occurrences of `needle` in `haystack` in
+    reverse order"""
+
+# =============================================================================
+# memmem ~ NOTE: `(haystack, needle)` argument order like `memchr::memmem`
+# =============================================================================
+
+def memmem_find(haystack: Buffer, needle: Buffer) -> int | None:
+    """Return the index of the first occurrence of `needle` in `haystack`
+
+    Examples
+    --------
+    >>> import ry
+    >>> ry.memmem_find(b"foo bar baz", b"ba")
+    4
+    >>> ry.memmem_find_iter(b"foo bar baz", b"ba")
+    [4, 8]
+
+    """
+
+def memmem_rfind(haystack: Buffer, needle: Buffer) -> int | None:
+    """Return the index of the last occurrence of `needle` in `haystack`"""
+
+def memmem_find_iter(haystack: Buffer, needle: Buffer) -> list[int]:
+    """Return the indices of all non-overlapping occurrences of `needle`"""
+
+def memmem_rfind_iter(haystack: Buffer, needle: Buffer) -> list[int]:
+    """Return the indices of all non-overlapping occurrences of `needle` in
+    reverse order"""
+
+@t.final
+class Finder:
+    """Substring searcher with a prebuilt needle
+
+    Examples
+    --------
+    >>> import ry
+    >>> finder = ry.Finder(b"\\r\\n")
+    >>> finder.find_iter(b"a\\r\\nb\\r\\nc")
+    [1, 4]
+
+    """
+
+    def __new__(cls, needle: Buffer) -> t.Self: ...
+    def __len__(self) -> int: ...
+    def __hash__(self) -> int: ...
+    @property
+    def needle(self) -> Bytes: ...
+    def find(self, haystack: Buffer) -> int | None: ...
+    def find_iter(self, haystack: Buffer) -> list[int]: ...
+
+@t.final
+class FinderRev:
+    """Reverse substring searcher with a prebuilt needle"""
+
+    def __new__(cls, needle: Buffer) -> t.Self: ...
+    def __len__(self) -> int: ...
+    def __hash__(self) -> int: ...
+    @property
+    def needle(self) -> Bytes: ...
+    def rfind(self, haystack: Buffer) -> int | None: ...
+    def rfind_iter(self, haystack: Buffer) -> list[int]: ...
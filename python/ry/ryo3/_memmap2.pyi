@@ -0,0 +1,203 @@
+"""ryo3-memmap2 ~ types"""
+
+import pathlib
+import typing as t
+from types import TracebackType
+
+from ry._types import FsPathLike
+
+_MadviseAdvice: t.TypeAlias = t.Literal["normal", "random", "sequential", "willneed"]
+
+class Mmap:
+    """Read-only memory-mapped file
+
+    Implements the buffer protocol, so an `Mmap` can be passed to anything
+    that takes a `Buffer` (hashers, compressors, `memchr`, `JSON.parse`, ...).
+    `ry.Bytes(mmap)` wraps the map without copying.
+
+    The map can only be closed once all exported buffers (`memoryview`,
+    `ry.Bytes`, ...) have been released.
+
+    """
+
+    def __new__(
+        cls,
+        path: FsPathLike,
+        *,
+        offset: int = 0,
+        length: int | None = None,
+    ) -> t.Self:
+        """Memory-map a file read-only
+
+        Parameters
+        ----------
+        path : FsPathLike
+            path-like object
+        offset : int, optional
+            byte offset into the file at which the map starts (default=0)
+        length : int | None, optional
+            number of bytes to map (default=None ~ rest of the file)
+
+        Raises
+        ------
+        FileNotFoundError
+            If file does not exist.
+
+        """
+    def __len__(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __buffer__(self, flags: int) -> memoryview: ...
+    def __release_buffer__(self, buffer: memoryview) -> None: ...
+    def __enter__(self) -> t.Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None: ...
+    @property
+    def path(self) -> pathlib.Path: ...
+    @property
+    def offset(self) -> int: ...
+    @property
+    def closed(self) -> bool: ...
+    def close(self) -> None:
+        """Unmap the file
+
+        Raises
+        ------
+        BufferError
+            If buffers (`memoryview`, `ry.Bytes`, ...) are still exported.
+
+        """
+    def madvise(
+        self,
+        advice: _MadviseAdvice,
+        start: int = 0,
+        length: int | None = None,
+    ) -> None:
+        """Advise the kernel about how the map (or a range of it) will be used
+
+        The hint is ignored on platforms without `madvise` (windows).
+
+        """
+
+class MmapMut:
+    """Writable memory-mapped file
+
+    Writes through the (writable) buffer protocol go straight to the file;
+    `flush` forces them to disk.
+
+    Examples
+    --------
+    >>> import ry
+    >>> import tempfile, pathlib
+    >>> with tempfile.TemporaryDirectory() as tmpdir:
+    ...     p = pathlib.Path(tmpdir) / "data.bin"
+    ...     _ = p.write_bytes(b"hello world")
+    ...     with ry.mmap(p, writable=True) as mm:
+    ...         with memoryview(mm) as mv:
+    ...             mv[:5] = b"HELLO"
+    ...     p.read_bytes()
+    b'HELLO world'
+
+    """
+
+    def __new__(
+        cls,
+        path: FsPathLike,
+        *,
+        offset: int = 0,
+        length: int | None = None,
+    ) -> t.Self:
+        """Memory-map a file read-write
+
+        Parameters
+        ----------
+        path : FsPathLike
+            path-like object (opened read-write)
+        offset : int, optional
+            byte offset into the file at which the map starts (default=0)
+        length : int | None, optional
+            number of bytes to map (default=None ~ rest of the file)
+
+        Raises
+        ------
+        FileNotFoundError
+            If file does not exist.
+
+        """
+    def __len__(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __buffer__(self, flags: int) -> memoryview: ...
+    def __release_buffer__(self, buffer: memoryview) -> None: ...
+    def __enter__(self) -> t.Self: ...
+    def __exit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: TracebackType | None,
+    ) -> None: ...
+    @property
+    def path(self) -> pathlib.Path: ...
+    @property
+    def offset(self) -> int: ...
+    @property
+    def closed(self) -> bool: ...
+    def close(self) -> None:
+        """Flush outstanding changes and unmap the file
+
+        Raises
+        ------
+        BufferError
+            If buffers (`memoryview`, `ry.Bytes`, ...) are still exported.
+
+        """
+    def flush(
+        self,
+        start: int = 0,
+        length: int | None = None,
+        *,
+        sync: bool = True,
+    ) -> None:
+        """Flush outstanding changes (or those in the given range) to disk
+
+        If `sync` is False the flush is only initiated (`MS_ASYNC`).
+
+        """
+    def madvise(
+        self,
+        advice: _MadviseAdvice,
+        start: int = 0,
+        length: int | None = None,
+    ) -> None:
+        """Advise the kernel about how the map (or a range of it) will be used
+
+        The hint is ignored on platforms without `madvise` (windows).
+
+        """
+
+@t.overload
+def mmap(
+    path: FsPathLike,
+    *,
+    offset: int = 0,
+    length: int | None = None,
+    writable: t.Literal[False] = False,
+) -> Mmap: ...
+@t.overload
+def mmap(
+    path: FsPathLike,
+    *,
+    offset: int = 0,
+    length: int | None = None,
+    writable: t.Literal[True],
+) -> MmapMut: ...
+@t.overload
+def mmap(
+    path: FsPathLike,
+    *,
+    offset: int = 0,
+    length: int | None = None,
+    writable: bool,
+) -> Mmap | MmapMut: ...
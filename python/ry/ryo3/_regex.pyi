@@ -2,6 +2,11 @@
 
 import typing as t
 
+from ry._types import Buffer
+from ry.ryo3._bytes import Bytes
+
+_T = t.TypeVar("_T")
+
 # =============================================================================
 # Regex
 # =============================================================================
@@ -28,7 +33,306 @@ class Regex:
     def find(self, haystack: str) -> str | None: ...
     def find_all(self, haystack: str) -> list[tuple[int, int]]: ...
     def findall(self, haystack: str) -> list[tuple[int, int]]: ...
-    def replace(self, haystack: str, replacement: str) -> str: ...
-    def replace_all(self, haystack: str, replacement: str) -> str: ...
+    def captures(self, haystack: str) -> Captures | None:
+        """Return the captures of the leftmost-first match (or `None`)
+
+        Examples
+        --------
+        >>> import ry
+        >>> re = ry.Regex(r"(?<year>\d{4})-(?<month>\d{2})")
+        >>> caps = re.captures("released 2024-03")
+        >>> caps.group("year"), caps[2], caps.span()
+        ('2024', '03', (9, 16))
+
+        """
+
+    def captures_iter(self, haystack: str) -> CapturesIter:
+        """Return a lazy iterator over the captures of all successive
+        non-overlapping matches"""
+
+    def capture_names(self) -> list[str | None]:
+        """Return the capture group names (`None` for unnamed groups; the
+        first element is always `None` for the implicit group 0)"""
+
+    def captures_len(self) -> int:
+        """Return the number of capture groups (including group 0)"""
+
+    def replace(
+        self, haystack: str, replacement: str | t.Callable[[Captures], str]
+    ) -> str:
+        """Replace the leftmost-first match
+
+        The replacement is either a template (`$name`/`${1}` references are
+        expanded; `$$` is a literal `$`) or a callable receiving the
+        `Captures` of the match.
+
+        Examples
+        --------
+        >>> import ry
+        >>> re = ry.Regex(r"(?<last>\w+),\s+(?<first>\w+)")
+        >>> re.replace("Springsteen, Bruce", "$first $last")
+        'Bruce Springsteen'
+        >>> re.replace("Springsteen, Bruce", lambda c: c["first"].upper())
+        'BRUCE'
+
+        """
+
+    def replace_all(
+        self, haystack: str, replacement: str | t.Callable[[Captures], str]
+    ) -> str:
+        """Replace all non-overlapping matches (see `replace`)"""
+
+    def replacen(
+        self,
+        haystack: str,
+        limit: int,
+        replacement: str | t.Callable[[Captures], str],
+    ) -> str:
+        """Replace at most `limit` non-overlapping matches (`0` == all)"""
+
     def split(self, haystack: str) -> list[str]: ...
     def splitn(self, haystack: str, n: int) -> list[str]: ...
+
+# =============================================================================
+# RegexSet
+# =============================================================================
+
+@t.final
+class RegexSet:
+    """Set of regexes matched in a single scan of the haystack
+
+    Examples
+    --------
+    >>> import ry
+    >>> rs = ry.RegexSet([r"\w+", r"\d+", r"foo", r"bar"])
+    >>> list(rs.matches("foo123"))
+    [0, 1, 2]
+    >>> rs.matches_many(["foo", "bar", "..."])
+    [SetMatches([0, 2]), SetMatches([0, 3]), SetMatches([])]
+
+    """
+
+    def __new__(
+        cls,
+        patterns: t.Sequence[str],
+        *,
+        case_insensitive: bool = False,
+        crlf: bool = False,
+        dot_matches_new_line: bool = False,
+        ignore_whitespace: bool = False,
+        line_terminator: bytes | int | None = None,
+        multi_line: bool = False,
+        octal: bool = False,
+        size_limit: int | None = None,
+        swap_greed: bool = False,
+        unicode: bool = True,
+    ) -> t.Self: ...
+    def is_match(self, haystack: str) -> bool: ...
+    def test(self, haystack: str) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> t.Iterator[str]: ...
+    @property
+    def patterns(self) -> list[str]: ...
+    def len(self) -> int: ...
+    def is_empty(self) -> bool: ...
+    def matches(self, haystack: str) -> SetMatches:
+        """Return the indices of every pattern that matches the haystack"""
+
+    def matches_many(self, haystacks: t.Sequence[str]) -> list[SetMatches]:
+        """Run `matches` over every haystack (releases the GIL)"""
+
+@t.final
+class SetMatches:
+    """Indices of the patterns in a regex-set that matched a haystack"""
+
+    def __len__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def __contains__(self, index: object) -> bool: ...
+    def __iter__(self) -> t.Iterator[int]: ...
+    @property
+    def patterns_len(self) -> int:
+        """Number of patterns in the set"""
+
+    def matched_any(self) -> bool: ...
+    def matched_all(self) -> bool: ...
+    def matched(self, index: int) -> bool: ...
+    def to_list(self) -> list[int]: ...
+
+# =============================================================================
+# Captures
+# =============================================================================
+
+@t.final
+class Captures:
+    """Captures for a single regex match (`re.Match`-like)
+
+    Spans/indices are python `str` indices (code points); groups that did
+    not participate in the match are `None` and have span `(-1, -1)`.
+    """
+
+    def __len__(self) -> int: ...
+    def __getitem__(self, group: int | str, /) -> str | None: ...
+    @property
+    def string(self) -> str: ...
+    @t.overload
+    def group(self, /) -> str: ...
+    @t.overload
+    def group(self, group: int | str, /) -> str | None: ...
+    @t.overload
+    def group(
+        self, group1: int | str, group2: int | str, /, *groups: int | str
+    ) -> tuple[str | None, ...]: ...
+    @t.overload
+    def groups(self) -> tuple[str | None, ...]: ...
+    @t.overload
+    def groups(self, default: _T) -> tuple[str | _T, ...]: ...
+    @t.overload
+    def groupdict(self) -> dict[str, str | None]: ...
+    @t.overload
+    def groupdict(self, default: _T) -> dict[str, str | _T]: ...
+    def span(self, group: int | str = 0, /) -> tuple[int, int]: ...
+    def start(self, group: int | str = 0, /) -> int: ...
+    def end(self, group: int | str = 0, /) -> int: ...
+    def expand(self, template: str) -> str:
+        """Expand `$name`/`${name}`/`$1`/`${1}` group references in the
+        template (`$$` is a literal `$`; unknown groups expand to "")
+
+        Examples
+        --------
+        >>> import ry
+        >>> caps = ry.Regex(r"(?<y>\d{4})-(?<m>\d{2})").captures("2024-03")
+        >>> caps.expand("${m}/$y")
+        '03/2024'
+
+        """
+
+@t.final
+class CapturesIter:
+    def __iter__(self) -> t.Self: ...
+    def __next__(self) -> Captures: ...
+    def collect(self) -> list[Captures]: ...
+
+# =============================================================================
+# BytesRegex
+# =============================================================================
+
+@t.final
+class BytesRegex:
+    """Regex matching arbitrary bytes (`regex::bytes::Regex`)
+
+    Haystacks may be any buffer (`bytes`, `bytearray`, `memoryview`,
+    `ry.Bytes`, ...); spans are byte offsets and matched bytes are returned as
+    zero-copy `ry.Bytes` slices of the haystack.
+
+    Examples
+    --------
+    >>> import ry
+    >>> re = ry.BytesRegex(r"(?-u)\xff(?<n>\d+)")
+    >>> caps = re.captures(b"ab\xff123cd")
+    >>> caps["n"], caps.span()
+    (Bytes(b"123"), (2, 6))
+    >>> re.split(b"a\xff1b\xff2c")
+    [Bytes(b"a"), Bytes(b"b"), Bytes(b"c")]
+
+    """
+
+    def __new__(
+        cls,
+        pattern: str,
+        *,
+        case_insensitive: bool = False,
+        crlf: bool = False,
+        dot_matches_new_line: bool = False,
+        ignore_whitespace: bool = False,
+        line_terminator: bytes | int | None = None,
+        multi_line: bool = False,
+        octal: bool = False,
+        size_limit: int | None = None,
+        swap_greed: bool = False,
+        unicode: bool = True,
+    ) -> t.Self: ...
+    def is_match(self, haystack: Buffer) -> bool: ...
+    def test(self, haystack: Buffer) -> bool: ...
+    def find(self, haystack: Buffer) -> tuple[int, int] | None: ...
+    def find_all(self, haystack: Buffer) -> list[tuple[int, int]]: ...
+    def findall(self, haystack: Buffer) -> list[tuple[int, int]]: ...
+    def find_all_bytes(self, haystack: Buffer) -> list[Bytes]:
+        """Return the matched bytes of all successive non-overlapping matches"""
+
+    def captures(self, haystack: Buffer) -> BytesCaptures | None: ...
+    def captures_iter(self, haystack: Buffer) -> BytesCapturesIter: ...
+    def capture_names(self) -> list[str | None]: ...
+    def captures_len(self) -> int: ...
+    def replace(self, haystack: Buffer, replacement: Buffer) -> Bytes: ...
+    def replace_all(self, haystack: Buffer, replacement: Buffer) -> Bytes: ...
+    def split(self, haystack: Buffer) -> list[Bytes]: ...
+    def splitn(self, haystack: Buffer, n: int) -> list[Bytes]: ...
+
+@t.final
+class BytesRegexSet:
+    """Set of regexes matching arbitrary bytes (`regex::bytes::RegexSet`)"""
+
+    def __new__(
+        cls,
+        patterns: t.Sequence[str],
+        *,
+        case_insensitive: bool = False,
+        crlf: bool = False,
+        dot_matches_new_line: bool = False,
+        ignore_whitespace: bool = False,
+        line_terminator: bytes | int | None = None,
+        multi_line: bool = False,
+        octal: bool = False,
+        size_limit: int | None = None,
+        swap_greed: bool = False,
+        unicode: bool = True,
+    ) -> t.Self: ...
+    def is_match(self, haystack: Buffer) -> bool: ...
+    def test(self, haystack: Buffer) -> bool: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> t.Iterator[str]: ...
+    @property
+    def patterns(self) -> list[str]: ...
+    def len(self) -> int: ...
+    def is_empty(self) -> bool: ...
+    def matches(self, haystack: Buffer) -> SetMatches: ...
+    def matches_many(self, haystacks: t.Sequence[Buffer]) -> list[SetMatches]:
+        """Run `matches` over every haystack (releases the GIL)"""
+
+@t.final
+class BytesCaptures:
+    """Captures for a single `BytesRegex` match (`re.Match`-like)
+
+    Spans are byte offsets; groups that did not participate in the match are
+    `None` and have span `(-1, -1)`.
+    """
+
+    def __len__(self) -> int: ...
+    def __getitem__(self, group: int | str, /) -> Bytes | None: ...
+    @property
+    def string(self) -> Bytes: ...
+    @t.overload
+    def group(self, /) -> Bytes: ...
+    @t.overload
+    def group(self, group: int | str, /) -> Bytes | None: ...
+    @t.overload
+    def group(
+        self, group1: int | str, group2: int | str, /, *groups: int | str
+    ) -> tuple[Bytes | None, ...]: ...
+    @t.overload
+    def groups(self) -> tuple[Bytes | None, ...]: ...
+    @t.overload
+    def groups(self, default: _T) -> tuple[Bytes | _T, ...]: ...
+    @t.overload
+    def groupdict(self) -> dict[str, Bytes | None]: ...
+    @t.overload
+    def groupdict(self, default: _T) -> dict[str, Bytes | _T]: ...
+    def span(self, group: int | str = 0, /) -> tuple[int, int]: ...
+    def start(self, group: int | str = 0, /) -> int: ...
+    def end(self, group: int | str = 0, /) -> int: ...
+
+@t.final
+class BytesCapturesIter:
+    def __iter__(self) -> t.Self: ...
+    def __next__(self) -> BytesCaptures: ...
+    def collect(self) -> list[BytesCaptures]: ...
@@ -358,6 +358,9 @@ class Response:
         cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
         partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
         catch_duplicate_keys: bool = False,
+        float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+        big_int_mode: t.Literal["int", "str"] = "int",
+        lazy_numbers: bool = False,
     ) -> t.Any: ...
     async def bytes(self) -> ry.Bytes: ...
     def bytes_stream(
@@ -419,6 +422,9 @@ class BlockingResponse:
         cache_mode: t.Literal[True, False, "all", "keys", "none"] = "all",
         partial_mode: t.Literal[True, False, "off", "on", "trailing-strings"] = False,
         catch_duplicate_keys: bool = False,
+        float_mode: t.Literal["float", "decimal", "lossless-float"] = "float",
+        big_int_mode: t.Literal["int", "str"] = "int",
+        lazy_numbers: bool = False,
     ) -> t.Any: ...
     def bytes(self) -> ry.Bytes: ...
     def bytes_stream(self, min_read_size: int = 0, /) -> BlockingResponseStream: ...
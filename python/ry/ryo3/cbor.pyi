@@ -0,0 +1,30 @@
+"""ryo3-cbor types"""
+
+import typing as t
+
+from ry import Bytes
+from ry._types import Buffer
+
+@t.final
+class Tag:
+    def __init__(self, tag: int, value: t.Any) -> None: ...
+    @property
+    def tag(self) -> int: ...
+    @property
+    def value(self) -> t.Any: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[int, t.Any]: ...
+
+def dumps(
+    obj: t.Any,
+    *,
+    default: t.Callable[[t.Any], t.Any] | None = None,
+    canonical: bool = False,
+    datetime_as_timestamp: bool = False,
+) -> Bytes: ...
+def loads(
+    data: Buffer,
+    *,
+    tag_hook: t.Callable[[int, t.Any], t.Any] | None = None,
+) -> t.Any: ...
@@ -0,0 +1,28 @@
+"""ryo3-msgpack types"""
+
+import typing as t
+
+from ry import Bytes
+from ry._types import Buffer
+
+@t.final
+class Ext:
+    def __init__(self, code: int, data: Buffer) -> None: ...
+    @property
+    def code(self) -> int: ...
+    @property
+    def data(self) -> Bytes: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __getnewargs__(self) -> tuple[int, Bytes]: ...
+
+def packb(
+    obj: t.Any,
+    *,
+    default: t.Callable[[t.Any], t.Any] | None = None,
+) -> Bytes: ...
+def unpackb(
+    data: Buffer,
+    *,
+    ext_hook: t.Callable[[int, bytes], t.Any] | None = None,
+) -> t.Any: ...
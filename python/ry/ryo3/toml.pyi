@@ -0,0 +1,37 @@
+"""ryo3-toml types"""
+
+import typing as t
+
+from ry._types import Buffer
+
+TomlKey: t.TypeAlias = str | tuple[str, ...] | list[str]
+
+@t.final
+class TomlDocument:
+    def __init__(self, s: str = "") -> None: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> t.Iterator[str]: ...
+    def __contains__(self, key: TomlKey) -> bool: ...
+    def __getitem__(self, key: TomlKey) -> t.Any: ...
+    def __setitem__(self, key: TomlKey, value: t.Any) -> None: ...
+    def __delitem__(self, key: TomlKey) -> None: ...
+    def __getnewargs__(self) -> tuple[str]: ...
+    def get(self, key: TomlKey, default: t.Any = None) -> t.Any: ...
+    def keys(self) -> list[str]: ...
+    def to_dict(self) -> dict[str, t.Any]: ...
+
+def loads(s: str) -> dict[str, t.Any]: ...
+def load(fp: t.IO[bytes] | t.IO[str]) -> dict[str, t.Any]: ...
+def dumps(
+    obj: t.Any,
+    *,
+    default: t.Callable[[t.Any], t.Any] | None = None,
+    pretty: bool = False,
+) -> str: ...
+def dump(
+    obj: t.Any,
+    fp: t.IO[bytes],
+    *,
+    default: t.Callable[[t.Any], t.Any] | None = None,
+    pretty: bool = False,
+) -> None: ...
@@ -0,0 +1,11 @@
+"""ryo3-yaml types"""
+
+import typing as t
+
+def loads(s: str) -> t.Any: ...
+def loads_all(s: str) -> list[t.Any]: ...
+def dumps(
+    obj: t.Any,
+    *,
+    default: t.Callable[[t.Any], t.Any] | None = None,
+) -> str: ...
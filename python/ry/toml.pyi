@@ -0,0 +1,13 @@
+from ry.ryo3.toml import TomlDocument as TomlDocument
+from ry.ryo3.toml import dump as dump
+from ry.ryo3.toml import dumps as dumps
+from ry.ryo3.toml import load as load
+from ry.ryo3.toml import loads as loads
+
+__all__ = (
+    "TomlDocument",
+    "dump",
+    "dumps",
+    "load",
+    "loads",
+)
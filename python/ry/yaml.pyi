@@ -0,0 +1,9 @@
+from ry.ryo3.yaml import dumps as dumps
+from ry.ryo3.yaml import loads as loads
+from ry.ryo3.yaml import loads_all as loads_all
+
+__all__ = (
+    "dumps",
+    "loads",
+    "loads_all",
+)
@@ -17,12 +17,15 @@ uv run -- maturin develop --uv
 uv run -- pytest tests python/ry --doctest-modules --cov=ry --cov-report xml
 cargo llvm-cov report \
   --package ryo3 \
+  --package ryo3-aho-corasick \
   --package ryo3-aws-lc \
   --package ryo3-brotli \
   --package ryo3-bytes \
   --package ryo3-bzip2 \
+  --package ryo3-cbor \
   --package ryo3-cookie \
   --package ryo3-core \
+  --package ryo3-data-encoding \
   --package ryo3-dirs \
   --package ryo3-flate2 \
   --package ryo3-fnv \
@@ -37,6 +40,8 @@ cargo llvm-cov report \
   --package ryo3-lz4rip \
   --package ryo3-macro-rules \
   --package ryo3-memchr \
+  --package ryo3-memmap2 \
+  --package ryo3-msgpack \
   --package ryo3-pydantic \
   --package ryo3-regex \
   --package ryo3-reqwest \
@@ -49,6 +54,7 @@ cargo llvm-cov report \
   --package ryo3-tokio \
   --package ryo3-tokio-rt \
   --package ryo3-tokio-websockets \
+  --package ryo3-toml \
   --package ryo3-twox-hash \
   --package ryo3-ulid \
   --package ryo3-unindent \
@@ -56,17 +62,21 @@ cargo llvm-cov report \
   --package ryo3-uuid \
   --package ryo3-walkdir \
   --package ryo3-which \
+  --package ryo3-yaml \
   --package ryo3-zstd \
   --lcov \
   --output-path coverage.lcov
 cargo llvm-cov report \
   --package ryo3 \
+  --package ryo3-aho-corasick \
   --package ryo3-aws-lc \
   --package ryo3-brotli \
   --package ryo3-bytes \
   --package ryo3-bzip2 \
+  --package ryo3-cbor \
   --package ryo3-cookie \
   --package ryo3-core \
+  --package ryo3-data-encoding \
   --package ryo3-dirs \
   --package ryo3-flate2 \
   --package ryo3-fnv \
@@ -81,6 +91,8 @@ cargo llvm-cov report \
   --package ryo3-lz4rip \
   --package ryo3-macro-rules \
   --package ryo3-memchr \
+  --package ryo3-memmap2 \
+  --package ryo3-msgpack \
   --package ryo3-pydantic \
   --package ryo3-regex \
   --package ryo3-reqwest \
@@ -93,6 +105,7 @@ cargo llvm-cov report \
   --package ryo3-tokio \
   --package ryo3-tokio-rt \
   --package ryo3-tokio-websockets \
+  --package ryo3-toml \
   --package ryo3-twox-hash \
   --package ryo3-ulid \
   --package ryo3-unindent \
@@ -100,6 +113,7 @@ cargo llvm-cov report \
   --package ryo3-uuid \
   --package ryo3-walkdir \
   --package ryo3-which \
+  --package ryo3-yaml \
   --package ryo3-zstd \
   --html
 serve ./target/llvm-cov/html
@@ -0,0 +1,160 @@
+from __future__ import annotations
+
+import pickle
+import typing as t
+
+import pytest
+
+import ry
+
+if t.TYPE_CHECKING:
+    from pathlib import Path
+
+_PATTERNS = ["apple", "maple", "Snapple"]
+_HAYSTACK = "Nobody likes maple in their apple flavored Snapple."
+
+
+def _spans(matches: list[ry.AhoCorasickMatch]) -> list[tuple[int, int, int]]:
+    return [(m.pattern, m.start, m.end) for m in matches]
+
+
+def test_find_iter() -> None:
+    ac = ry.AhoCorasick(_PATTERNS)
+    assert _spans(ac.find_iter(_HAYSTACK)) == [(1, 13, 18), (0, 28, 33), (2, 43, 50)]
+    assert ac.count(_HAYSTACK) == 3
+    assert ac.is_match(_HAYSTACK)
+    assert not ac.is_match("no fruit here")
+    m = ac.find(_HAYSTACK)
+    assert m is not None
+    assert m == ac.find_iter(_HAYSTACK)[0]
+    assert m.span() == (13, 18)
+    assert len(m) == 5
+    assert _HAYSTACK[m.start : m.end] == "maple"
+    assert repr(m) == "AhoCorasickMatch(pattern=1, start=13, end=18)"
+    assert ac.find("nope") is None
+
+
+def test_match_kind() -> None:
+    patterns = ["Samwise", "Sam"]
+    assert _spans(ry.AhoCorasick(patterns).find_iter("Samwise")) == [(0, 0, 7)]
+    assert _spans(
+        ry.AhoCorasick(["Sam", "Samwise"], match_kind="leftmost-first").find_iter(
+            "Samwise"
+        )
+    ) == [(0, 0, 3)]
+    assert _spans(
+        ry.AhoCorasick(["Sam", "Samwise"], match_kind="leftmost-longest").find_iter(
+            "Samwise"
+        )
+    ) == [(1, 0, 7)]
+    assert _spans(
+        ry.AhoCorasick(["Samwise", "Sam"], match_kind="standard").find_iter("Samwise")
+    ) == [(1, 0, 3)]
+    assert ry.AhoCorasick(patterns).match_kind == "leftmost-first"
+    with pytest.raises(ValueError, match="match_kind"):
+        ry.AhoCorasick(patterns, match_kind="rightmost")  # type: ignore[arg-type]
+
+
+def test_ascii_case_insensitive() -> None:
+    ac = ry.AhoCorasick(["foo", "BAR"], ascii_case_insensitive=True)
+    assert ac.ascii_case_insensitive
+    assert _spans(ac.find_iter("FOO bar")) == [(0, 0, 3), (1, 4, 7)]
+    assert not ry.AhoCorasick(["foo"]).is_match("FOO")
+
+
+def test_str_offsets_are_code_points() -> None:
+    haystack = "💩 héllo wörld 💩 héllo"
+    ac = ry.AhoCorasick(["héllo", "wörld"])
+    matches = ac.find_iter(haystack)
+    assert [haystack[m.start : m.end] for m in matches] == [
+        "héllo",
+        "wörld",
+        "héllo",
+    ]
+    # bytes haystacks report byte offsets
+    encoded = haystack.encode()
+    assert [encoded[m.start : m.end] for m in ac.find_iter(encoded)] == [
+        b.encode() for b in ["héllo", "wörld", "héllo"]
+    ]
+
+
+@pytest.mark.parametrize(
+    "haystack",
+    [b"xx foo yy bar", bytearray(b"xx foo yy bar"), ry.Bytes(b"xx foo yy bar")],
+)
+def test_buffer_haystacks(haystack: bytes | bytearray | ry.Bytes) -> None:
+    ac = ry.AhoCorasick([b"foo", "bar"])
+    assert _spans(ac.find_iter(haystack)) == [(0, 3, 6), (1, 10, 13)]
+    assert ac.patterns == [b"foo", "bar"]
+
+
+def test_replace_all() -> None:
+    ac = ry.AhoCorasick(_PATTERNS)
+    assert (
+        ac.replace_all(_HAYSTACK, ["fruit", "syrup", "drink"])
+        == "Nobody likes syrup in their fruit flavored drink."
+    )
+    replaced = ac.replace_all(_HAYSTACK.encode(), [b"fruit", b"syrup", b"drink"])
+    assert isinstance(replaced, ry.Bytes)
+    assert replaced == b"Nobody likes syrup in their fruit flavored drink."
+
+
+def test_replace_all_wrong_number_of_replacements() -> None:
+    with pytest.raises(ValueError, match="expected 3 replacements"):
+        ry.AhoCorasick(_PATTERNS).replace_all(_HAYSTACK, ["fruit"])
+
+
+def test_invalid_types() -> None:
+    with pytest.raises(TypeError):
+        ry.AhoCorasick([1, 2])  # type: ignore[list-item]
+    with pytest.raises(TypeError):
+        ry.AhoCorasick(["a"]).find_iter(123)  # type: ignore[arg-type]
+
+
+def test_repr_len_pickle() -> None:
+    ac = ry.AhoCorasick(["a", b"b"], match_kind="leftmost-longest")
+    assert repr(ac) == "AhoCorasick(['a', b'b'], match_kind='leftmost-longest')"
+    assert len(ac) == ac.patterns_len == 2
+    assert (
+        repr(ry.AhoCorasick(["a"], ascii_case_insensitive=True))
+        == "AhoCorasick(['a'], ascii_case_insensitive=True)"
+    )
+    unpickled = pickle.loads(pickle.dumps(ac))
+    assert repr(unpickled) == repr(ac)
+    assert unpickled.find_iter("ab") == ac.find_iter("ab")
+
+
+@pytest.mark.parametrize("chunk_size", [1, 2, 3, 5, 8, 64])
+@pytest.mark.parametrize(
+    "match_kind", ["standard", "leftmost-first", "leftmost-longest"]
+)
+def test_stream_find_iter_chunk_boundaries(
+    chunk_size: int,
+    match_kind: t.Literal["standard", "leftmost-first", "leftmost-longest"],
+) -> None:
+    data = b"abcd cd abc abcdabcd xx bcd" * 3
+    ac = ry.AhoCorasick(["abcd", "bc", "cd", "abc"], match_kind=match_kind)
+    chunks = [data[i : i + chunk_size] for i in range(0, len(data), chunk_size)]
+    assert ac.stream_find_iter(chunks).collect() == ac.find_iter(data)
+
+
+def test_stream_find_iter_generator() -> None:
+    def gen() -> t.Iterator[bytes]:
+        yield b"xxfo"
+        yield b""
+        yield b"oyyb"
+        yield b"ar"
+
+    ac = ry.AhoCorasick(["foo", "bar"])
+    assert _spans(list(ac.stream_find_iter(gen()))) == [(0, 2, 5), (1, 7, 10)]
+    assert list(ac.stream_find_iter([])) == []
+
+
+def test_stream_find_iter_file_read_stream(tmp_path: Path) -> None:
+    data = b"".join(b"line %d: keyword-%d\n" % (i, i % 7) for i in range(1000))
+    p = tmp_path / "data.txt"
+    p.write_bytes(data)
+    ac = ry.AhoCorasick(["keyword-3", "keyword-5"])
+    matches = ac.stream_find_iter(ry.read_stream(p, read_size=13)).collect()
+    assert matches == ac.find_iter(data)
+    assert all(data[m.start : m.end] == b"keyword-3" for m in matches if m.pattern == 0)
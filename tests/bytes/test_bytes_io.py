@@ -0,0 +1,236 @@
+from __future__ import annotations
+
+import array
+import csv
+import gzip
+import io
+import os
+import pickle
+import zipfile
+
+import pytest
+
+import ry
+
+
+class TestBytesReader:
+    def test_read(self) -> None:
+        reader = ry.BytesReader(b"hello world")
+        assert reader.read(5) == b"hello"
+        assert isinstance(reader.read(0), ry.Bytes)
+        assert reader.tell() == 5
+        assert reader.read() == b" world"
+        assert reader.read() == b""
+        assert reader.read(10) == b""
+
+    def test_read_negative_and_none(self) -> None:
+        assert ry.BytesReader(b"abc").read(-1) == b"abc"
+        assert ry.BytesReader(b"abc").read(None) == b"abc"
+        assert ry.BytesReader(b"abc").read1() == b"abc"
+        assert ry.BytesReader(b"abc").readall() == b"abc"
+
+    def test_default_empty(self) -> None:
+        assert ry.BytesReader().read() == b""
+
+    def test_from_buffers(self) -> None:
+        for data in (b"abc", bytearray(b"abc"), memoryview(b"abc"), ry.Bytes(b"abc")):
+            assert ry.BytesReader(data).read() == b"abc"
+
+    def test_readline_and_iter(self) -> None:
+        data = b"one\ntwo\n\nthree"
+        reader = ry.BytesReader(data)
+        assert reader.readline() == b"one\n"
+        assert reader.readline(2) == b"tw"
+        assert reader.readline() == b"o\n"
+        assert list(reader) == [b"\n", b"three"]
+        assert list(ry.BytesReader(data)) == list(io.BytesIO(data))
+        assert ry.BytesReader(data).readlines() == io.BytesIO(data).readlines()
+        assert ry.BytesReader(data).readlines(5) == io.BytesIO(data).readlines(5)
+
+    def test_readinto(self) -> None:
+        reader = ry.BytesReader(b"abcdef")
+        buf = bytearray(4)
+        assert reader.readinto(buf) == 4
+        assert buf == b"abcd"
+        assert reader.readinto1(buf) == 2
+        assert buf == b"efcd"
+        assert reader.readinto(buf) == 0
+
+    def test_readinto_non_byte_format(self) -> None:
+        data = bytes(range(8))
+        ints = array.array("i", [0, 0])
+        assert ry.BytesReader(data).readinto(ints) == 8
+        expected = array.array("i", [0, 0])
+        assert io.BytesIO(data).readinto(expected) == 8
+        assert ints == expected
+        view = memoryview(bytearray(4)).cast("I")
+        assert ry.BytesReader(data).readinto(view) == 4
+        assert view.tobytes() == data[:4]
+
+    def test_readinto_readonly(self) -> None:
+        with pytest.raises(TypeError):
+            ry.BytesReader(b"abc").readinto(b"xyz")
+
+    def test_seek_tell(self) -> None:
+        reader = ry.BytesReader(b"0123456789")
+        assert reader.seek(3) == 3
+        assert reader.read(2) == b"34"
+        assert reader.seek(-2, os.SEEK_CUR) == 3
+        assert reader.seek(-3, os.SEEK_END) == 7
+        assert reader.read() == b"789"
+        assert reader.seek(100) == 100
+        assert reader.read() == b""
+        assert reader.seek(-100, os.SEEK_CUR) == 0
+        with pytest.raises(ValueError):
+            reader.seek(-1)
+        with pytest.raises(ValueError):
+            reader.seek(0, 3)
+
+    def test_peek_getvalue(self) -> None:
+        reader = ry.BytesReader(b"abcdef")
+        reader.read(2)
+        assert reader.peek() == b"cdef"
+        assert reader.peek(1) == b"c"
+        assert reader.tell() == 2
+        assert reader.getvalue() == b"abcdef"
+
+    def test_flags(self) -> None:
+        reader = ry.BytesReader(b"")
+        assert reader.readable()
+        assert not reader.writable()
+        assert reader.seekable()
+        assert not reader.isatty()
+        assert reader.mode == "rb"
+        with pytest.raises(io.UnsupportedOperation):
+            reader.write(b"nope")
+        with pytest.raises(io.UnsupportedOperation):
+            reader.fileno()
+
+    def test_close(self) -> None:
+        with ry.BytesReader(b"abc") as reader:
+            assert not reader.closed
+        assert reader.closed
+        with pytest.raises(ValueError, match="closed file"):
+            reader.read()
+        with pytest.raises(ValueError, match="closed file"):
+            reader.tell()
+        reader.close()  # idempotent
+
+    def test_isinstance_buffered_io_base(self) -> None:
+        assert isinstance(ry.BytesReader(b""), io.BufferedIOBase)
+        assert isinstance(ry.BytesWriter(), io.BufferedIOBase)
+
+    def test_gzip(self) -> None:
+        data = b"hello gzip " * 100
+        with gzip.GzipFile(fileobj=ry.BytesReader(gzip.compress(data))) as f:
+            assert f.read() == data
+
+    def test_text_wrapper_csv(self) -> None:
+        data = b"a,b\n1,2\n3,4\n"
+        text = io.TextIOWrapper(ry.BytesReader(data), encoding="utf-8", newline="")
+        assert list(csv.reader(text)) == [["a", "b"], ["1", "2"], ["3", "4"]]
+
+    def test_zipfile(self) -> None:
+        buf = io.BytesIO()
+        with zipfile.ZipFile(buf, "w") as zf:
+            zf.writestr("a.txt", b"aaa")
+        with zipfile.ZipFile(ry.BytesReader(buf.getvalue())) as zf:  # type: ignore[arg-type]
+            assert zf.read("a.txt") == b"aaa"
+
+    def test_not_picklable(self) -> None:
+        with pytest.raises(TypeError):
+            pickle.dumps(ry.BytesReader(b"abc"))
+
+
+class TestBytesWriter:
+    def test_write(self) -> None:
+        writer = ry.BytesWriter()
+        assert writer.write(b"hello") == 5
+        assert writer.write(bytearray(b" ")) == 1
+        writer.writelines([b"wor", memoryview(b"ld")])
+        assert len(writer) == 11
+        assert writer.tell() == 11
+        value = writer.getvalue()
+        assert isinstance(value, ry.Bytes)
+        assert value == b"hello world"
+        assert writer.freeze() == b"hello world"
+        assert writer.closed
+
+    def test_write_str_raises(self) -> None:
+        with pytest.raises(TypeError):
+            ry.BytesWriter().write("abc")  # type: ignore[arg-type]
+
+    def test_write_past_huge_seek_raises(self) -> None:
+        writer = ry.BytesWriter()
+        writer.write(b"abc")
+        assert writer.seek(2**62) == 2**62
+        with pytest.raises(MemoryError):
+            writer.write(b"x")
+        writer.seek(0, os.SEEK_END)
+        writer.write(b"d")
+        assert writer.getvalue() == b"abcd"
+
+    def test_seek_overwrite_matches_bytesio(self) -> None:
+        ops = [
+            ("write", b"0123456789"),
+            ("seek", 2),
+            ("write", b"ab"),
+            ("seek", 12),
+            ("write", b"xy"),
+            ("seek", -3),
+            ("write", b"Z"),
+            ("truncate", None),
+            ("seek", 0),
+            ("truncate", 5),
+            ("write", b"!"),
+        ]
+        expected = io.BytesIO()
+        writer = ry.BytesWriter()
+        for op, arg in ops:
+            if op == "seek":
+                assert isinstance(arg, int)
+                whence = os.SEEK_END if arg < 0 else os.SEEK_SET
+                assert writer.seek(arg, whence) == expected.seek(arg, whence)
+            elif op == "truncate":
+                assert writer.truncate(arg) == expected.truncate(arg)  # type: ignore[arg-type]
+            else:
+                assert writer.write(arg) == expected.write(arg)  # type: ignore[arg-type]
+            assert writer.getvalue() == expected.getvalue()
+            assert writer.tell() == expected.tell()
+
+    def test_flags(self) -> None:
+        writer = ry.BytesWriter(capacity=64)
+        assert not writer.readable()
+        assert writer.writable()
+        assert writer.seekable()
+        assert writer.mode == "wb"
+        with pytest.raises(io.UnsupportedOperation):
+            writer.read()
+
+    def test_close(self) -> None:
+        with ry.BytesWriter() as writer:
+            writer.write(b"abc")
+        assert writer.closed
+        with pytest.raises(ValueError, match="closed file"):
+            writer.write(b"abc")
+        with pytest.raises(ValueError, match="closed file"):
+            writer.getvalue()
+
+    def test_gzip(self) -> None:
+        data = b"hello gzip " * 100
+        writer = ry.BytesWriter()
+        with gzip.GzipFile(fileobj=writer, mode="wb") as f:
+            f.write(data)
+        assert gzip.decompress(writer.freeze()) == data
+
+    def test_text_wrapper_csv(self) -> None:
+        writer = ry.BytesWriter()
+        text = io.TextIOWrapper(writer, encoding="utf-8", newline="")
+        csv.writer(text).writerows([["a", "b"], ["1", "2"]])
+        text.flush()
+        assert writer.getvalue() == b"a,b\r\n1,2\r\n"
+
+    def test_round_trip_reader(self) -> None:
+        writer = ry.BytesWriter()
+        writer.write(b"line1\nline2\n")
+        assert list(ry.BytesReader(writer.freeze())) == [b"line1\n", b"line2\n"]
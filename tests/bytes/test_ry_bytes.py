@@ -867,19 +867,211 @@ class TestBytesSplit:
             ry.Bytes(b"abc").rsplit(b"")
 
 
+class TestBytesCount:
+    @given(
+        b=st.binary(max_size=32),
+        sub=st.one_of(st.binary(max_size=3), st.integers(min_value=0, max_value=255)),
+        start=st.one_of(st.none(), st.integers(min_value=-20, max_value=20)),
+        end=st.one_of(st.none(), st.integers(min_value=-20, max_value=20)),
+    )
+    def test_count_matches_python(
+        self,
+        b: bytes,
+        sub: bytes | int,
+        start: int | None,
+        end: int | None,
+    ) -> None:
+        assert ry.Bytes(b).count(sub, start, end) == b.count(sub, start, end)
+
+    @pytest.mark.parametrize(
+        ("b", "sub"),
+        [
+            (b"", b""),
+            (b"abc", b""),
+            (b"aaaa", b"aa"),
+            (b"aaaaa", b"aa"),
+            (b"abcabc", b"abc"),
+            (b"abcabc", 97),
+        ],
+    )
+    def test_count_edge_cases(self, b: bytes, sub: bytes | int) -> None:
+        assert ry.Bytes(b).count(sub) == b.count(sub)
+
+
+class TestBytesStartsEndsWith:
+    @given(
+        b=st.binary(max_size=16),
+        affix=st.binary(max_size=4),
+        start=st.one_of(st.none(), st.integers(min_value=-20, max_value=20)),
+        end=st.one_of(st.none(), st.integers(min_value=-20, max_value=20)),
+    )
+    def test_startswith_endswith_matches_python(
+        self,
+        b: bytes,
+        affix: bytes,
+        start: int | None,
+        end: int | None,
+    ) -> None:
+        ry_bytes = ry.Bytes(b)
+        assert ry_bytes.startswith(affix, start, end) == b.startswith(affix, start, end)
+        assert ry_bytes.endswith(affix, start, end) == b.endswith(affix, start, end)
+
+    @given(
+        b=st.binary(max_size=16),
+        affixes=st.lists(st.binary(max_size=4), max_size=4).map(tuple),
+        start=st.one_of(st.none(), st.integers(min_value=-20, max_value=20)),
+    )
+    def test_tuple_affixes_match_python(
+        self,
+        b: bytes,
+        affixes: tuple[bytes, ...],
+        start: int | None,
+    ) -> None:
+        ry_bytes = ry.Bytes(b)
+        assert ry_bytes.startswith(affixes, start) == b.startswith(affixes, start)
+        assert ry_bytes.endswith(affixes, start) == b.endswith(affixes, start)
+
+    @pytest.mark.parametrize("fnname", ["startswith", "endswith"])
+    def test_buffer_affixes(self, fnname: str) -> None:
+        ry_bytes = ry.Bytes(b"abcabc")
+        fn = getattr(ry_bytes, fnname)
+        assert fn(bytearray(b"abc"))
+        assert fn(memoryview(b"abc"))
+        assert fn(ry.Bytes(b"abc"))
+        assert fn((b"x", ry.Bytes(b"abc")))
+
+    @pytest.mark.parametrize("affix", ["abc", 1, ("abc",), (b"abc", 1)])
+    @pytest.mark.parametrize("fnname", ["startswith", "endswith"])
+    def test_rejects_non_buffer(self, fnname: str, affix: object) -> None:
+        with pytest.raises(TypeError):
+            getattr(ry.Bytes(b"abc"), fnname)(affix)
+
+
+class TestBytesSplitlines:
+    @given(st.binary(max_size=32))
+    def test_splitlines_matches_python(self, b: bytes) -> None:
+        ry_bytes = ry.Bytes(b)
+        assert ry_bytes.splitlines() == b.splitlines()
+        assert ry_bytes.splitlines(keepends=True) == b.splitlines(keepends=True)
+
+    @pytest.mark.parametrize(
+        "b",
+        [
+            b"",
+            b"\n",
+            b"\r\n",
+            b"\n\r",
+            b"a\rb\r\nc\nd",
+            b"trailing\n",
+            b"\x0b\x0c\x1c\x1d\x1e\x85",
+        ],
+    )
+    def test_splitlines_edge_cases(self, b: bytes) -> None:
+        ry_bytes = ry.Bytes(b)
+        assert ry_bytes.splitlines() == b.splitlines()
+        assert ry_bytes.splitlines(True) == b.splitlines(True)  # noqa: FBT003
+
+
+class TestBytesJoin:
+    @given(st.binary(max_size=4), st.lists(st.binary(max_size=8), max_size=8))
+    def test_join_matches_python(self, sep: bytes, parts: list[bytes]) -> None:
+        assert ry.Bytes(sep).join(parts) == sep.join(parts)
+
+    def test_join_buffers_and_iterables(self) -> None:
+        parts = (p for p in [b"a", bytearray(b"b"), memoryview(b"c"), ry.Bytes(b"d")])
+        assert ry.Bytes(b"-").join(parts) == b"a-b-c-d"
+
+    def test_join_rejects_non_buffer_items(self) -> None:
+        with pytest.raises(TypeError, match="sequence item 1"):
+            ry.Bytes(b"-").join([b"a", "b"])
+
+
+class TestBytesPadding:
+    @given(
+        b=st.binary(max_size=16),
+        width=st.integers(min_value=-4, max_value=32),
+        fillbyte=st.binary(min_size=1, max_size=1),
+    )
+    def test_center_ljust_rjust_match_python(
+        self,
+        b: bytes,
+        width: int,
+        fillbyte: bytes,
+    ) -> None:
+        ry_bytes = ry.Bytes(b)
+        assert ry_bytes.center(width) == b.center(width)
+        assert ry_bytes.ljust(width) == b.ljust(width)
+        assert ry_bytes.rjust(width) == b.rjust(width)
+        assert ry_bytes.center(width, fillbyte) == b.center(width, fillbyte)
+        assert ry_bytes.ljust(width, fillbyte) == b.ljust(width, fillbyte)
+        assert ry_bytes.rjust(width, fillbyte) == b.rjust(width, fillbyte)
+
+    @given(
+        b=st.one_of(
+            st.binary(max_size=16),
+            st.sampled_from([b"+1", b"-1", b"-", b"+"]),
+        ),
+        width=st.integers(min_value=-4, max_value=32),
+    )
+    def test_zfill_matches_python(self, b: bytes, width: int) -> None:
+        assert ry.Bytes(b).zfill(width) == b.zfill(width)
+
+    @pytest.mark.parametrize("fnname", ["center", "ljust", "rjust"])
+    @pytest.mark.parametrize("fillbyte", [b"", b"ab", "a", 1])
+    def test_rejects_bad_fillbyte(self, fnname: str, fillbyte: object) -> None:
+        with pytest.raises(TypeError):
+            getattr(ry.Bytes(b"abc"), fnname)(10, fillbyte)
+
+    @pytest.mark.parametrize("fnname", ["center", "ljust", "rjust", "zfill"])
+    def test_returns_same_object_when_unchanged(self, fnname: str) -> None:
+        ry_bytes = ry.Bytes(b"abc")
+        assert getattr(ry_bytes, fnname)(3) is ry_bytes
+        assert getattr(ry_bytes, fnname)(-1) is ry_bytes
+
+
+class TestBytesTranslate:
+    @given(
+        b=st.binary(max_size=32),
+        frm=st.binary(max_size=8),
+        delete=st.binary(max_size=4),
+    )
+    def test_translate_matches_python(
+        self,
+        b: bytes,
+        frm: bytes,
+        delete: bytes,
+    ) -> None:
+        to = bytes(reversed(frm))
+        ry_table = ry.Bytes.maketrans(frm, to)
+        py_table = bytes.maketrans(frm, to)
+        assert ry_table == py_table
+        ry_bytes = ry.Bytes(b)
+        assert ry_bytes.translate(ry_table) == b.translate(py_table)
+        assert ry_bytes.translate(ry_table, delete) == b.translate(py_table, delete)
+        assert ry_bytes.translate(None, delete) == b.translate(None, delete)
+        assert ry_bytes.translate(None, delete=delete) == b.translate(
+            None, delete=delete
+        )
+
+    def test_translate_returns_same_object_when_unchanged(self) -> None:
+        ry_bytes = ry.Bytes(b"abc")
+        assert ry_bytes.translate(None) is ry_bytes
+        assert ry_bytes.translate(ry.Bytes.maketrans(b"x", b"y")) is ry_bytes
+
+    def test_translate_rejects_bad_table(self) -> None:
+        with pytest.raises(ValueError, match="256"):
+            ry.Bytes(b"abc").translate(b"abc")
+
+    def test_maketrans_rejects_length_mismatch(self) -> None:
+        with pytest.raises(ValueError, match="same length"):
+            ry.Bytes.maketrans(b"ab", b"c")
+
+
 @pytest.mark.parametrize(
     "fn_name",
     [
         "__mod__",
         "__rmod__",
-        "center",
-        "count",
-        "join",
-        "ljust",
-        "maketrans",
-        "rjust",
-        "translate",
-        "zfill",
     ],
 )
 def test_bytes_not_impl(fn_name: str) -> None:
@@ -0,0 +1,273 @@
+from __future__ import annotations
+
+import dataclasses
+import datetime as pydt
+import pickle
+import typing as t
+import uuid as pyuuid
+
+import pytest
+
+import ry
+from ry import cbor
+
+
+@pytest.mark.parametrize(
+    "obj",
+    [
+        None,
+        True,
+        False,
+        0,
+        -1,
+        23,
+        24,
+        2**32,
+        -(2**63),
+        2**64 - 1,
+        1.5,
+        "",
+        "hello",
+        "ünïcödé",
+        b"",
+        b"\x00\x01\xff",
+        [],
+        [1, "two", 3.0, None],
+        {},
+        {"a": 1, "b": [1, 2, {"c": None}]},
+    ],
+)
+def test_roundtrip(obj: t.Any) -> None:
+    data = cbor.dumps(obj)
+    assert isinstance(data, ry.Bytes)
+    assert cbor.loads(data) == obj
+
+
+@pytest.mark.parametrize(
+    ("obj", "expected"),
+    [
+        # RFC 8949 appendix A
+        (0, "00"),
+        (23, "17"),
+        (24, "1818"),
+        (1000000, "1a000f4240"),
+        (18446744073709551615, "1bffffffffffffffff"),
+        (18446744073709551616, "c249010000000000000000"),
+        (-18446744073709551617, "c349010000000000000000"),
+        (-1000, "3903e7"),
+        (1.5, "f93e00"),
+        (100000.0, "fa47c35000"),
+        (1.1, "fb3ff199999999999a"),
+        (False, "f4"),
+        (None, "f6"),
+        ("IETF", "6449455446"),
+        (b"\x01\x02\x03\x04", "4401020304"),
+        ([1, [2, 3], [4, 5]], "8301820203820405"),
+        ({1: 2, 3: 4}, "a201020304"),
+        ({"a": 1, "b": [2, 3]}, "a26161016162820203"),
+    ],
+)
+def test_known_encodings(obj: t.Any, expected: str) -> None:
+    assert bytes(cbor.dumps(obj)).hex() == expected
+    assert cbor.loads(bytes.fromhex(expected)) == obj
+
+
+def test_tuple_is_array() -> None:
+    assert cbor.loads(cbor.dumps((1, 2, 3))) == [1, 2, 3]
+
+
+def test_bytes_likes_are_byte_strings() -> None:
+    for obj in (b"ab", bytearray(b"ab"), memoryview(b"ab"), ry.Bytes(b"ab")):
+        assert cbor.dumps(obj) == b"\x42ab"
+
+
+def test_non_str_keys() -> None:
+    obj = {1: "a", 1.5: "b", b"k": "c", (1, 2): "d", None: "e"}
+    assert cbor.loads(cbor.dumps(obj)) == obj
+
+
+def test_map_key_map_is_unhashable() -> None:
+    # {{1: 2}: 3}
+    with pytest.raises(TypeError):
+        cbor.loads(bytes.fromhex("a1a1010203"))
+
+
+def test_dataclass() -> None:
+    @dataclasses.dataclass
+    class Point:
+        x: int
+        y: int
+
+    assert cbor.loads(cbor.dumps(Point(1, 2))) == {"x": 1, "y": 2}
+
+
+class TestTags:
+    def test_datetime_tag_0(self) -> None:
+        dt = pydt.datetime(2013, 3, 21, 20, 4, tzinfo=pydt.timezone.utc)
+        data = cbor.dumps(dt)
+        assert data == b"\xc0\x78\x192013-03-21T20:04:00+00:00"
+        assert cbor.loads(data) == dt
+
+    def test_datetime_tag_0_offset(self) -> None:
+        tz = pydt.timezone(pydt.timedelta(hours=-5))
+        dt = pydt.datetime(2020, 1, 2, 3, 4, 5, 123456, tzinfo=tz)
+        res = cbor.loads(cbor.dumps(dt))
+        assert res == dt
+        assert res.utcoffset() == dt.utcoffset()
+
+    def test_datetime_tag_1(self) -> None:
+        dt = pydt.datetime(2013, 3, 21, 20, 4, tzinfo=pydt.timezone.utc)
+        data = cbor.dumps(dt, datetime_as_timestamp=True)
+        # RFC 8949 appendix A
+        assert bytes(data).hex() == "c11a514b67b0"
+        assert cbor.loads(data) == dt
+
+    def test_datetime_tag_1_float(self) -> None:
+        dt = pydt.datetime(2013, 3, 21, 20, 4, 0, 500000, tzinfo=pydt.timezone.utc)
+        data = cbor.dumps(dt, datetime_as_timestamp=True)
+        assert bytes(data).hex() == "c1fb41d452d9ec200000"
+        assert cbor.loads(data) == dt
+
+    def test_naive_datetime_is_str(self) -> None:
+        dt = pydt.datetime(2020, 1, 2, 3, 4, 5)
+        assert cbor.loads(cbor.dumps(dt)) == "2020-01-02T03:04:05"
+
+    def test_date(self) -> None:
+        d = pydt.date(2020, 1, 2)
+        data = cbor.dumps(d)
+        assert data == b"\xd9\x03\xec\x6a2020-01-02"
+        assert cbor.loads(data) == d
+
+    def test_date_epoch_days(self) -> None:
+        # tag 100 (days since 1970-01-01)
+        assert cbor.loads(bytes.fromhex("d864194756")) == pydt.date(2020, 1, 1)
+
+    def test_ry_datetimes(self) -> None:
+        zdt = ry.date(2020, 8, 26).at(6, 27, 0, 0).in_tz("America/New_York")
+        assert cbor.loads(cbor.dumps(zdt)) == zdt.to_py()
+        ts = ry.Timestamp.from_millisecond(0)
+        assert cbor.loads(cbor.dumps(ts)) == pydt.datetime(
+            1970, 1, 1, tzinfo=pydt.timezone.utc
+        )
+        assert cbor.loads(cbor.dumps(ry.date(2020, 8, 26))) == pydt.date(2020, 8, 26)
+
+    def test_uuid_tag_37(self) -> None:
+        u = pyuuid.UUID("12345678-1234-5678-1234-567812345678")
+        data = cbor.dumps(u)
+        assert data == b"\xd8\x25\x50" + u.bytes
+        assert cbor.loads(data) == u
+
+    def test_ry_uuid_tag_37(self) -> None:
+        u = ry.uuid4()
+        res = cbor.loads(cbor.dumps(u))
+        assert isinstance(res, pyuuid.UUID)
+        assert str(res) == str(u)
+
+    @pytest.mark.parametrize(
+        "n",
+        [2**64, 2**127, 2**128, 2**200, -(2**64) - 1, -(2**200), -(2**1000) + 7],
+    )
+    def test_bignums(self, n: int) -> None:
+        assert cbor.loads(cbor.dumps(n)) == n
+
+    def test_bignum_keys_and_nested(self) -> None:
+        obj = {2**100: [2**100, -(2**100)]}
+        assert cbor.loads(cbor.dumps(obj)) == obj
+
+    def test_unknown_tag(self) -> None:
+        # tag 32 (uri)
+        data = bytes.fromhex("d82076687474703a2f2f7777772e6578616d706c652e636f6d")
+        res = cbor.loads(data)
+        assert res == cbor.Tag(32, "http://www.example.com")
+        assert res.tag == 32
+        assert res.value == "http://www.example.com"
+        assert cbor.dumps(res) == data
+
+    def test_tag_hook(self) -> None:
+        data = cbor.dumps(cbor.Tag(32, "http://www.example.com"))
+        assert cbor.loads(data, tag_hook=lambda tag, value: (tag, value)) == (
+            32,
+            "http://www.example.com",
+        )
+
+    def test_tag_nested_values(self) -> None:
+        tag = cbor.Tag(1234, {"a": [b"x", pydt.date(2020, 1, 2)]})
+        assert cbor.loads(cbor.dumps(tag)) == tag
+
+    def test_tag_repr_eq_hash(self) -> None:
+        tag = cbor.Tag(42, b"x")
+        assert repr(tag) == "Tag(42, b'x')"
+        assert tag == cbor.Tag(42, b"x")
+        assert tag != cbor.Tag(43, b"x")
+        assert hash(tag) == hash(cbor.Tag(42, b"x"))
+
+    def test_tag_pickle(self) -> None:
+        tag = cbor.Tag(42, [1, 2])
+        assert pickle.loads(pickle.dumps(tag)) == tag
+
+
+class TestCanonical:
+    def test_sorts_keys_by_encoded_bytes(self) -> None:
+        obj = {"aa": 1, "b": 2, 10: 3, -1: 4, 100: 5, False: 6}
+        data = cbor.dumps(obj, canonical=True)
+        # 10, 100, -1, "b", "aa", false (RFC 8949 4.2.1)
+        assert list(cbor.loads(data)) == [10, 100, -1, "b", "aa", False]
+
+    def test_deterministic(self) -> None:
+        a = {"b": {"y": 1, "x": 2}, "a": [{"d": 1, "c": 2}]}
+        b = {"a": [{"c": 2, "d": 1}], "b": {"x": 2, "y": 1}}
+        assert cbor.dumps(a) != cbor.dumps(b)
+        assert cbor.dumps(a, canonical=True) == cbor.dumps(b, canonical=True)
+        assert cbor.loads(cbor.dumps(a, canonical=True)) == a
+
+    def test_tagged_and_bignum_values(self) -> None:
+        obj = {"b": 2**100, "a": cbor.Tag(42, {"z": 1, "y": 2})}
+        data = cbor.dumps(obj, canonical=True)
+        assert cbor.loads(data) == obj
+        assert list(cbor.loads(data)) == ["a", "b"]
+
+
+class TestErrors:
+    def test_default(self) -> None:
+        class Thing:
+            pass
+
+        assert cbor.loads(cbor.dumps([Thing()], default=lambda _: "thing")) == [
+            "thing"
+        ]
+
+    def test_default_not_callable(self) -> None:
+        with pytest.raises(TypeError, match="not callable"):
+            cbor.dumps({}, default=1)  # type: ignore[arg-type]
+
+    def test_unserializable(self) -> None:
+        with pytest.raises(TypeError, match="Failed to serialize"):
+            cbor.dumps(object())
+
+    def test_recursion(self) -> None:
+        obj: list[t.Any] = []
+        obj.append(obj)
+        with pytest.raises(RecursionError):
+            cbor.dumps(obj)
+
+    def test_recursion_counts_map_keys(self) -> None:
+        key: t.Any = ()
+        for _ in range(10):
+            key = (key,)
+        obj: t.Any = {key: 1}
+        for _ in range(250):
+            obj = {"a": obj}
+        with pytest.raises(RecursionError):
+            cbor.dumps(obj)
+
+    def test_truncated(self) -> None:
+        with pytest.raises(ValueError, match="Failed to deserialize cbor"):
+            cbor.loads(b"\x82\x01")
+
+    def test_trailing_data(self) -> None:
+        with pytest.raises(ValueError, match="1 bytes of trailing data"):
+            cbor.loads(b"\x01\x02")
+
+    def test_tag_hook_not_callable(self) -> None:
+        with pytest.raises(TypeError, match="not callable"):
+            cbor.loads(b"\x01", tag_hook=1)  # type: ignore[arg-type]
@@ -0,0 +1,174 @@
+from __future__ import annotations
+
+import base64
+import binascii
+import sys
+
+import pytest
+
+import ry
+
+_DATA = [
+    b"",
+    b"f",
+    b"fo",
+    b"foo",
+    b"foob",
+    b"fooba",
+    b"foobar",
+    b"\x00\x00\x00\x00",
+    bytes(range(256)),
+]
+
+
+@pytest.mark.parametrize("data", _DATA)
+def test_base64_matches_stdlib(data: bytes) -> None:
+    assert ry.base64_encode(data) == base64.b64encode(data)
+    assert ry.base64_encode(data, variant="urlsafe") == base64.urlsafe_b64encode(
+        data
+    )
+    assert ry.base64_encode(data, variant="standard-nopad") == (
+        base64.b64encode(data).rstrip(b"=")
+    )
+    assert ry.base64_encode_str(data) == base64.b64encode(data).decode()
+    assert ry.base64_decode(base64.b64encode(data)) == data
+    assert ry.base64_decode(base64.b64encode(data).decode()) == data
+    assert (
+        ry.base64_decode(base64.urlsafe_b64encode(data), variant="urlsafe") == data
+    )
+
+
+@pytest.mark.parametrize("data", _DATA)
+def test_base32_matches_stdlib(data: bytes) -> None:
+    assert ry.base32_encode(data) == base64.b32encode(data)
+    assert ry.base32_encode(data, variant="hex") == base64.b32hexencode(data)
+    assert ry.base32_decode(base64.b32encode(data)) == data
+    assert ry.base32_decode(base64.b32hexencode(data), variant="hex") == data
+    crockford = ry.base32_encode_str(data, variant="crockford")
+    assert ry.base32_decode(crockford, variant="crockford") == data
+
+
+@pytest.mark.parametrize("data", _DATA)
+def test_base85_matches_stdlib(data: bytes) -> None:
+    assert ry.base85_encode(data) == base64.b85encode(data)
+    assert ry.base85_encode(data, pad=True) == base64.b85encode(data, pad=True)
+    assert ry.base85_decode(base64.b85encode(data)) == data
+    z85 = ry.base85_encode(data, variant="z85")
+    assert ry.base85_decode(z85, variant="z85") == data
+    if sys.version_info >= (3, 13):
+        assert z85 == base64.z85encode(data)
+
+
+@pytest.mark.parametrize("data", _DATA)
+def test_hex_matches_stdlib(data: bytes) -> None:
+    assert ry.hex_encode(data) == binascii.hexlify(data)
+    assert ry.hex_encode_str(data, upper=True) == data.hex().upper()
+    assert ry.hex_decode(data.hex()) == data
+    assert ry.hex_decode(data.hex().upper()) == data
+
+
+@pytest.mark.parametrize("data", _DATA)
+@pytest.mark.parametrize("alphabet", ["bitcoin", "ripple", "flickr"])
+def test_base58_round_trip(data: bytes, alphabet: str) -> None:
+    encoded = ry.base58_encode(data, alphabet=alphabet)  # type: ignore[arg-type]
+    assert isinstance(encoded, ry.Bytes)
+    assert ry.base58_decode(encoded, alphabet=alphabet) == data  # type: ignore[arg-type]
+
+
+def test_base58_known() -> None:
+    assert ry.base58_encode_str(b"hello world") == "StV1DL6CwTryKyV"
+    assert ry.base58_encode_str(b"\x00\x00\x01") == "112"
+    assert ry.base58_decode("StV1DL6CwTryKyV") == b"hello world"
+
+
+def test_encode_returns_bytes() -> None:
+    assert isinstance(ry.base64_encode(b"abc"), ry.Bytes)
+    assert isinstance(ry.base64_encode_str(b"abc"), str)
+    assert isinstance(ry.base64_decode(b"YWJj"), ry.Bytes)
+
+
+def test_encode_accepts_buffers() -> None:
+    expected = base64.b64encode(b"abc")
+    assert ry.base64_encode(bytearray(b"abc")) == expected
+    assert ry.base64_encode(memoryview(b"abc")) == expected
+    assert ry.base64_encode(ry.Bytes(b"abc")) == expected
+
+
+def test_base64_mime() -> None:
+    data = bytes(range(256))
+    encoded = ry.base64_encode(data, variant="mime")
+    assert encoded == base64.encodebytes(data).replace(b"\n", b"\r\n")
+    assert ry.base64_decode(encoded, variant="mime") == data
+
+
+def test_base64_strict_vs_lenient() -> None:
+    # missing padding
+    with pytest.raises(ValueError, match="Invalid base64 data"):
+        ry.base64_decode("aGk")
+    assert ry.base64_decode("aGk", strict=False) == b"hi"
+    # whitespace
+    with pytest.raises(ValueError):
+        ry.base64_decode("aG k=")
+    assert ry.base64_decode(" aG\nk= ", strict=False) == b"hi"
+    # non-zero trailing bits
+    with pytest.raises(ValueError):
+        ry.base64_decode("aGl=")
+    assert ry.base64_decode("aGl=", strict=False) == b"hi"
+
+
+def test_base32_strict_vs_lenient() -> None:
+    with pytest.raises(ValueError):
+        ry.base32_decode("nbuq====")
+    assert ry.base32_decode("nbuq", strict=False) == b"hi"
+    assert ry.base32_decode("D1MG", variant="crockford") == b"hi"
+    assert ry.base32_decode("d1-mg", variant="crockford", strict=False) == b"hi"
+    # crockford aliases: I/L -> 1, O -> 0
+    assert ry.base32_decode(
+        "DIMG", variant="crockford", strict=False
+    ) == ry.base32_decode("D1MG", variant="crockford")
+
+
+def test_base85_errors() -> None:
+    with pytest.raises(ValueError, match="Invalid base85 data"):
+        ry.base85_decode(b"\x00\x00")
+    with pytest.raises(ValueError, match="Invalid base85 data"):
+        ry.base85_decode(b"~~~~~")
+    with pytest.raises(ValueError):
+        ry.base85_decode(b"Xk~0{Z")  # dangling single char
+    assert ry.base85_decode(b"Xk~0{Zv\n", strict=False) == b"hello"
+    with pytest.raises(ValueError, match="invalid length"):
+        ry.base85_decode(b"Xk~0{ Z\n", strict=False)
+
+
+def test_hex_errors() -> None:
+    with pytest.raises(ValueError, match="Invalid hex data"):
+        ry.hex_decode("abc")
+    with pytest.raises(ValueError, match="Invalid hex data"):
+        ry.hex_decode("zz")
+    assert ry.hex_decode("01 ab\n", strict=False) == b"\x01\xab"
+
+
+def test_decode_non_ascii_str() -> None:
+    with pytest.raises(ValueError, match="only ASCII"):
+        ry.base64_decode("aGké")
+
+
+def test_decode_str_subclass() -> None:
+    class S(str):
+        pass
+
+    assert ry.base64_decode(S("aGk=")) == b"hi"
+
+
+def test_decode_bad_type() -> None:
+    with pytest.raises(TypeError):
+        ry.base64_decode(123)  # type: ignore[arg-type]
+
+
+def test_invalid_variant() -> None:
+    with pytest.raises(ValueError, match="standard"):
+        ry.base64_encode(b"", variant="nope")  # type: ignore[arg-type]
+    with pytest.raises(TypeError):
+        ry.base64_encode(b"", variant=1)  # type: ignore[arg-type]
+    # case-insensitive and `_` == `-`
+    assert ry.base64_encode(b"?", variant="URLSAFE_NOPAD") == b"Pw"  # type: ignore[arg-type]
@@ -0,0 +1,204 @@
+from __future__ import annotations
+
+import json
+import typing as t
+from decimal import Decimal
+
+import pytest
+
+import ry
+
+if t.TYPE_CHECKING:
+    from pathlib import Path
+
+MONEY = b'{"amount": 0.1, "total": 1234567.891011121314, "count": 3}'
+BIG_INT = 123456789012345678901234567890
+BIG_INTS = f"[1, -9223372036854775808, 9223372036854775808, {BIG_INT}, -{BIG_INT}]"
+
+
+class TestFloatMode:
+    def test_float_default(self) -> None:
+        data = ry.parse_json(MONEY)
+        assert isinstance(data["amount"], float)
+        assert data == json.loads(MONEY)
+
+    def test_decimal(self) -> None:
+        data = ry.parse_json(MONEY, float_mode="decimal")
+        assert data == {
+            "amount": Decimal("0.1"),
+            "total": Decimal("1234567.891011121314"),
+            "count": 3,
+        }
+        assert isinstance(data["amount"], Decimal)
+        assert isinstance(data["count"], int)
+
+    def test_lossless_float(self) -> None:
+        data = ry.parse_json(MONEY, float_mode="lossless-float")
+        total = data["total"]
+        assert not isinstance(total, float)
+        assert bytes(total) == b"1234567.891011121314"
+        assert float(total) == 1234567.891011121314
+        assert isinstance(data["count"], int)
+
+    def test_invalid_float_mode(self) -> None:
+        with pytest.raises(ValueError):
+            ry.parse_json(MONEY, float_mode="double")  # type: ignore[arg-type]
+
+    @pytest.mark.parametrize("float_mode", ["float", "decimal", "lossless-float"])
+    def test_big_ints_are_exact(self, float_mode: t.Any) -> None:
+        assert ry.parse_json(BIG_INTS, float_mode=float_mode) == json.loads(BIG_INTS)
+
+
+class TestBigIntMode:
+    def test_int_default(self) -> None:
+        data = ry.parse_json(BIG_INTS)
+        assert data[3] == BIG_INT
+        assert data[4] == -BIG_INT
+
+    def test_str(self) -> None:
+        data = ry.parse_json(BIG_INTS, big_int_mode="str")
+        assert data == [
+            1,
+            -9223372036854775808,
+            "9223372036854775808",
+            str(BIG_INT),
+            f"-{BIG_INT}",
+        ]
+
+    def test_str_floats_unchanged(self) -> None:
+        data = ry.parse_json(b"[1.5, 1e400, 2]", big_int_mode="str")
+        assert data == [1.5, float("inf"), 2]
+
+    @pytest.mark.parametrize(
+        ("float_mode", "cls"), [("decimal", Decimal), ("float", float)]
+    )
+    def test_str_with_float_mode(self, float_mode: t.Any, cls: type) -> None:
+        data = ry.parse_json(
+            b'{"a": 0.1, "b": 99999999999999999999}',
+            big_int_mode="str",
+            float_mode=float_mode,
+        )
+        assert isinstance(data["a"], cls)
+        assert data["b"] == "99999999999999999999"
+
+    def test_invalid_big_int_mode(self) -> None:
+        with pytest.raises(ValueError, match="Invalid big int mode"):
+            ry.parse_json(BIG_INTS, big_int_mode="float")  # type: ignore[arg-type]
+
+
+class TestLazyNumbers:
+    def test_lazy_numbers(self) -> None:
+        data = ry.parse_json(
+            b'{"a": 1, "b": -0.10, "c": 1E+3, "d": [99999999999999999999]}',
+            lazy_numbers=True,
+        )
+        assert data == {
+            "a": "1",
+            "b": "-0.10",
+            "c": "1E+3",
+            "d": ["99999999999999999999"],
+        }
+
+    def test_lazy_numbers_round_trip_decimal(self) -> None:
+        data = ry.parse_json(MONEY, lazy_numbers=True)
+        assert Decimal(data["total"]) == Decimal("1234567.891011121314")
+
+    def test_non_numbers_unchanged(self) -> None:
+        doc = {
+            "s": 'str "with" escapes \\ \n ü \U0001f600',
+            "b": [True, False, None],
+            "o": {"nested": {"empty": {}, "list": []}},
+        }
+        data = ry.parse_json(json.dumps(doc), lazy_numbers=True)
+        assert data == doc
+
+    def test_inf_nan(self) -> None:
+        data = ry.parse_json(
+            b"[NaN, Infinity, -Infinity]", allow_inf_nan=True, lazy_numbers=True
+        )
+        assert data == ["NaN", "Infinity", "-Infinity"]
+
+    def test_inf_nan_not_allowed(self) -> None:
+        with pytest.raises(ValueError, match="expected value at line 1 column 2"):
+            ry.parse_json(b"[NaN]", lazy_numbers=True)
+
+    def test_catch_duplicate_keys(self) -> None:
+        with pytest.raises(ValueError, match='Detected duplicate key "a"'):
+            ry.parse_json(
+                b'{"a": 1, "a": 2}', lazy_numbers=True, catch_duplicate_keys=True
+            )
+
+    @pytest.mark.parametrize(
+        ("data", "expected"),
+        [
+            (b"[1, 2", ["1", "2"]),
+            (b'{"a": 1.5, "b": [3', {"a": "1.5", "b": ["3"]}),
+            (b'{"a": 1, "b', {"a": "1"}),
+            (b'["x", "y', ["x"]),
+        ],
+    )
+    def test_partial_mode(self, data: bytes, expected: t.Any) -> None:
+        assert ry.parse_json(data, lazy_numbers=True, partial_mode=True) == expected
+
+    def test_partial_mode_trailing_strings(self) -> None:
+        data = ry.parse_json(
+            b'["x", 123456789012345678901234567890, "y',
+            big_int_mode="str",
+            partial_mode="trailing-strings",
+        )
+        assert data == ["x", "123456789012345678901234567890", "y"]
+
+    def test_partial_mode_off_errors(self) -> None:
+        with pytest.raises(ValueError, match="EOF while parsing a list"):
+            ry.parse_json(b"[1, 2", lazy_numbers=True)
+
+    @pytest.mark.parametrize(
+        ("data", "msg"),
+        [
+            (b"", "EOF while parsing a value at line 1 column 1"),
+            (b"[1,]", "trailing comma at line 1 column 4"),
+            (b'{"a": 1', "EOF while parsing an object at line 1 column 8"),
+            (b"[1] 2", "trailing characters at line 1 column 5"),
+            (b"[01]", "expected `,` or `]` at line 1 column 3"),
+            (b"[1.]", "invalid number at line 1 column 4"),
+            (b'"\\x"', "invalid escape at line 1 column 3"),
+            (b"[1,\n x]", "expected value at line 2 column 2"),
+        ],
+    )
+    def test_errors(self, data: bytes, msg: str) -> None:
+        with pytest.raises(ValueError, match=msg.replace("[", r"\[")):
+            ry.parse_json(data, lazy_numbers=True)
+
+
+class TestOtherEntryPoints:
+    def test_parse_jsonl(self) -> None:
+        data = b'{"a": 0.1}\n{"a": 99999999999999999999}\n'
+        assert ry.parse_jsonl(data, float_mode="decimal") == [
+            {"a": Decimal("0.1")},
+            {"a": 99999999999999999999},
+        ]
+        assert ry.parse_jsonl(data, big_int_mode="str") == [
+            {"a": 0.1},
+            {"a": "99999999999999999999"},
+        ]
+        assert ry.parse_jsonl(data, lazy_numbers=True) == [
+            {"a": "0.1"},
+            {"a": "99999999999999999999"},
+        ]
+
+    def test_read_json(self, tmp_path: Path) -> None:
+        path = tmp_path / "money.json"
+        path.write_bytes(MONEY)
+        data = ry.read_json(path, float_mode="decimal")
+        assert data["amount"] == Decimal("0.1")
+        assert ry.read_json(path, lazy_numbers=True)["total"] == "1234567.891011121314"
+
+    def test_read_jsonl(self, tmp_path: Path) -> None:
+        path = tmp_path / "money.jsonl"
+        path.write_bytes(b"0.1\n2.5\n")
+        data = ry.read_json(path, lines=True, float_mode="decimal")
+        assert data == [Decimal("0.1"), Decimal("2.5")]
+
+    def test_json_module(self) -> None:
+        assert ry.JSON.parse(MONEY, float_mode="decimal")["amount"] == Decimal("0.1")
+        assert ry.JSON.loads(MONEY, lazy_numbers=True)["count"] == "3"
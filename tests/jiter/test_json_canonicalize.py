@@ -0,0 +1,123 @@
+from __future__ import annotations
+
+import dataclasses
+import hashlib
+import json
+import math
+
+import pytest
+
+from ry import JSON
+
+
+def _jcs(obj: object) -> bytes:
+    return bytes(JSON.canonicalize(obj))
+
+
+class TestRfc8785:
+    def test_sample(self) -> None:
+        # RFC 8785 section 3.2.2
+        data = {
+            "numbers": [333333333.33333329, 1e30, 4.50, 2e-3, 1e-27],
+            "string": "\u20ac$\u000f\u000aA'\u0042\u0022\u005c\\\"/",
+            "literals": [None, True, False],
+        }
+        assert _jcs(data) == (
+            b'{"literals":[null,true,false],'
+            b'"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],'
+            b'"string":"\xe2\x82\xac$\\u000f\\nA\'B\\"\\\\\\\\\\"/"}'
+        )
+
+    def test_sorting(self) -> None:
+        # RFC 8785 section 3.2.3 (sorted by utf-16 code units, not code points)
+        data = {
+            "€": "Euro Sign",
+            "\r": "Carriage Return",
+            "דּ": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\U0001f600": "Emoji: Grinning Face",
+            "\u0080": "Control",
+            "ö": "Latin Small Letter O With Diaeresis",
+        }
+        keys = list(json.loads(_jcs(data)))
+        assert keys == [
+            "\r",
+            "1",
+            "\u0080",
+            "ö",
+            "€",
+            "\U0001f600",
+            "דּ",
+        ]
+
+    @pytest.mark.parametrize(
+        ("value", "expected"),
+        [
+            # RFC 8785 appendix B
+            (0.0, b"0"),
+            (-0.0, b"0"),
+            (5e-324, b"5e-324"),
+            (-5e-324, b"-5e-324"),
+            (1.7976931348623157e308, b"1.7976931348623157e+308"),
+            (9007199254740992.0, b"9007199254740992"),
+            (295147905179352830000.0, b"295147905179352830000"),
+            (1e21, b"1e+21"),
+            (9.999999999999997e22, b"9.999999999999997e+22"),
+            (1e23, b"1e+23"),
+            (0.000001, b"0.000001"),
+            (1e-7, b"1e-7"),
+            (4.5, b"4.5"),
+            (2e-3, b"0.002"),
+            (100.0, b"100"),
+            (123, b"123"),
+            (-(2**53), b"-9007199254740992"),
+        ],
+    )
+    def test_numbers(self, value: float, expected: bytes) -> None:
+        assert _jcs(value) == expected
+
+
+class TestCanonicalize:
+    def test_no_whitespace_and_sorted(self) -> None:
+        data = {"b": [1, {"d": None, "c": True}], "a": "x"}
+        assert _jcs(data) == b'{"a":"x","b":[1,{"c":true,"d":null}]}'
+
+    def test_key_order_independent(self) -> None:
+        a = {"x": 1, "y": {"p": 1.0, "q": [1, 2]}}
+        b = {"y": {"q": [1, 2], "p": 1}, "x": 1.0}
+        assert _jcs(a) == _jcs(b)
+
+    def test_non_ascii_not_escaped(self) -> None:
+        assert _jcs("üñí   \U0001f600") == '"üñí   \U0001f600"'.encode()
+
+    def test_hashable(self) -> None:
+        data = {"b": 2, "a": 1}
+        digest = hashlib.sha256(JSON.canonicalize(data)).hexdigest()
+        assert digest == hashlib.sha256(b'{"a":1,"b":2}').hexdigest()
+
+    def test_python_objects(self) -> None:
+        @dataclasses.dataclass
+        class Point:
+            y: int
+            x: int
+
+        data = {"p": Point(2, 1), "t": (1, 2)}
+        assert _jcs(data) == b'{"p":{"x":1,"y":2},"t":[1,2]}'
+
+    def test_default(self) -> None:
+        out = JSON.canonicalize({"c": complex(1, 2)}, default=str)
+        assert bytes(out) == b'{"c":"(1+2j)"}'
+
+    @pytest.mark.parametrize("value", [math.nan, math.inf, -math.inf])
+    def test_non_finite(self, value: float) -> None:
+        with pytest.raises(ValueError, match="not a valid JSON number"):
+            JSON.canonicalize({"a": [value]})
+
+    @pytest.mark.parametrize("value", [2**53 + 1, -(2**53) - 1, 2**63 - 1])
+    def test_unsafe_integers(self, value: int) -> None:
+        with pytest.raises(ValueError, match="cannot be represented exactly"):
+            JSON.canonicalize(value)
+
+    def test_unserializable(self) -> None:
+        with pytest.raises(TypeError):
+            JSON.canonicalize(object())
@@ -0,0 +1,370 @@
+from __future__ import annotations
+
+import dataclasses
+import enum
+import typing as t
+
+import pytest
+
+import ry
+from ry import JSON
+
+
+class Color(enum.Enum):
+    RED = "red"
+    GREEN = "green"
+
+
+class Level(enum.IntEnum):
+    LOW = 1
+    HIGH = 2
+
+
+@dataclasses.dataclass
+class Item:
+    id: int
+    name: str
+    tags: list[str] = dataclasses.field(default_factory=list)
+
+
+@dataclasses.dataclass
+class Order:
+    id: ry.UUID
+    created: ry.ZonedDateTime
+    items: list[Item]
+    note: str | None = None
+
+
+@dataclasses.dataclass
+class Node:
+    value: int
+    children: list[Node] = dataclasses.field(default_factory=list)
+
+
+@dataclasses.dataclass
+class Positive:
+    n: int
+
+    def __post_init__(self) -> None:
+        if self.n <= 0:
+            msg = "n must be positive"
+            raise ValueError(msg)
+
+
+class Movie(t.TypedDict):
+    title: str
+    year: int
+
+
+class PartialMovie(t.TypedDict, total=False):
+    title: str
+    year: int
+
+
+@dataclasses.dataclass
+class Cat:
+    kind: t.Literal["cat"]
+    lives: int
+
+
+@dataclasses.dataclass
+class Dog:
+    kind: t.Literal["dog"]
+    good: bool
+
+
+class TestScalars:
+    @pytest.mark.parametrize(
+        ("tp", "data", "expected"),
+        [
+            (int, b"1", 1),
+            (float, b"1.5", 1.5),
+            (str, b'"a"', "a"),
+            (bool, b"true", True),
+            (type(None), b"null", None),
+            (t.Any, b'{"a": [1]}', {"a": [1]}),
+            (object, b"[1]", [1]),
+        ],
+    )
+    def test_scalars(self, tp: t.Any, data: bytes, expected: t.Any) -> None:
+        assert JSON.parse(data, type=tp) == expected
+
+    def test_int_to_float(self) -> None:
+        value = JSON.parse(b"1", type=float)
+        assert isinstance(value, float)
+        assert value == 1.0
+
+    @pytest.mark.parametrize(
+        ("tp", "data", "msg"),
+        [
+            (int, b'"1"', "Expected `int`, got `str`"),
+            (int, b"1.0", "Expected `int`, got `float`"),
+            (int, b"true", "Expected `int`, got `bool`"),
+            (bool, b"1", "Expected `bool`, got `int`"),
+            (str, b"null", "Expected `str`, got `null`"),
+            (type(None), b"0", "Expected `null`, got `int`"),
+        ],
+    )
+    def test_mismatch(self, tp: t.Any, data: bytes, msg: str) -> None:
+        with pytest.raises(JSON.JsonValidationError, match=msg) as exc_info:
+            JSON.parse(data, type=tp)
+        assert exc_info.value.path == "$"
+        assert isinstance(exc_info.value, ValueError)
+
+    def test_no_type_is_plain_parse(self) -> None:
+        assert JSON.parse(b'{"a": 1}') == {"a": 1}
+        assert JSON.loads(b"[1, 2]", type=list[int]) == [1, 2]
+
+
+class TestContainers:
+    def test_list(self) -> None:
+        assert JSON.parse(b"[1, 2, 3]", type=list[int]) == [1, 2, 3]
+
+    def test_tuple(self) -> None:
+        assert JSON.parse(b'[1, "a"]', type=tuple[int, str]) == (1, "a")
+        assert JSON.parse(b"[1, 2, 3]", type=tuple[int, ...]) == (1, 2, 3)
+
+    def test_tuple_length(self) -> None:
+        with pytest.raises(
+            JSON.JsonValidationError, match="Expected `array` of length 2, got 3"
+        ):
+            JSON.parse(b"[1, 2, 3]", type=tuple[int, int])
+
+    def test_sets(self) -> None:
+        assert JSON.parse(b"[1, 2, 2]", type=set[int]) == {1, 2}
+        value = JSON.parse(b'["a"]', type=frozenset[str])
+        assert isinstance(value, frozenset)
+        assert value == frozenset({"a"})
+
+    def test_dict(self) -> None:
+        data = b'{"a": [1], "b": []}'
+        assert JSON.parse(data, type=dict[str, list[int]]) == {"a": [1], "b": []}
+
+    def test_dict_non_str_keys(self) -> None:
+        data = b'{"1": "a", "-2": "b", "123456789012345678901234567890": "c"}'
+        assert JSON.parse(data, type=dict[int, str]) == {
+            1: "a",
+            -2: "b",
+            123456789012345678901234567890: "c",
+        }
+        assert JSON.parse(b'{"1.5": 1, "2": 2}', type=dict[float, int]) == {
+            1.5: 1,
+            2.0: 2,
+        }
+        assert JSON.parse(b'{"1": "x", "2": "y"}', type=dict[Level, str]) == {
+            Level.LOW: "x",
+            Level.HIGH: "y",
+        }
+        assert JSON.parse(b'{"red": 1}', type=dict[Color, int]) == {Color.RED: 1}
+        assert JSON.parse(
+            b'{"1": 1, "red": 2}', type=dict[t.Union[int, Color], int]
+        ) == {1: 1, Color.RED: 2}
+
+    def test_dict_non_str_keys_invalid(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'{"1": 1, "x": 2}', type=dict[int, int])
+        assert exc_info.value.path == "$.x"
+        with pytest.raises(JSON.JsonValidationError, match="Invalid enum value"):
+            JSON.parse(b'{"3": 1}', type=dict[Level, int])
+
+    def test_abc_types(self) -> None:
+        assert JSON.parse(b"[1]", type=t.Sequence[int]) == [1]
+        assert JSON.parse(b'{"a": 1}', type=t.Mapping[str, int]) == {"a": 1}
+
+    def test_bare_containers(self) -> None:
+        assert JSON.parse(b"[1, [2]]", type=list) == [1, [2]]
+        assert JSON.parse(b'{"a": null}', type=dict) == {"a": None}
+        assert JSON.parse(b"[1, 2]", type=tuple) == (1, 2)
+
+    def test_nested_path(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'{"a": [1, 2], "b c": [3, "x"]}', type=dict[str, list[int]])
+        err = exc_info.value
+        assert err.path == "$['b c'][1]"
+        assert str(err) == "Expected `int`, got `str` - at `$['b c'][1]`"
+
+
+class TestUnionsAndLiterals:
+    def test_optional(self) -> None:
+        assert JSON.parse(b"null", type=t.Optional[int]) is None  # noqa: UP045
+        assert JSON.parse(b"1", type=int | None) == 1
+
+    def test_union_mismatch(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'"a"', type=int | None)
+        assert str(exc_info.value) == "Expected `int | null`, got `str`"
+
+    def test_union_any(self) -> None:
+        assert JSON.parse(b'"a"', type=int | t.Any) == "a"
+
+    def test_literal(self) -> None:
+        tp = t.Literal["a", "b", 1]
+        assert JSON.parse(b'"b"', type=tp) == "b"
+        assert JSON.parse(b"1", type=tp) == 1
+        with pytest.raises(JSON.JsonValidationError, match="Invalid enum value 'c'"):
+            JSON.parse(b'"c"', type=tp)
+
+    def test_literal_bool_is_not_int(self) -> None:
+        with pytest.raises(JSON.JsonValidationError):
+            JSON.parse(b"true", type=t.Literal[1])
+
+    def test_tagged_union(self) -> None:
+        decoder = JSON.JsonDecoder(list[Cat | Dog])
+        pets = decoder.decode(
+            b'[{"kind": "dog", "good": true}, {"kind": "cat", "lives": 9}]'
+        )
+        assert pets == [Dog(kind="dog", good=True), Cat(kind="cat", lives=9)]
+
+    def test_union_reports_member_error(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'[{"value": "x"}]', type=list[Node] | None)
+        assert exc_info.value.path == "$[0].value"
+
+    def test_enum(self) -> None:
+        assert JSON.parse(b'["red", "green"]', type=list[Color]) == [
+            Color.RED,
+            Color.GREEN,
+        ]
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'["blue"]', type=list[Color])
+        assert str(exc_info.value) == "Invalid enum value 'blue' - at `$[0]`"
+
+
+class TestDataclasses:
+    def test_dataclass(self) -> None:
+        item = JSON.parse(b'{"id": 1, "name": "a", "extra": true}', type=Item)
+        assert item == Item(id=1, name="a", tags=[])
+
+    def test_nested(self) -> None:
+        data = b"""{
+            "id": "a8098c1a-f86e-11da-bd1a-00112444be1e",
+            "created": "2024-03-10T12:00:00-04:00[America/New_York]",
+            "items": [{"id": 1, "name": "x", "tags": ["t"]}]
+        }"""
+        order = JSON.parse(data, type=Order)
+        assert isinstance(order, Order)
+        assert isinstance(order.id, ry.UUID)
+        assert str(order.id) == "a8098c1a-f86e-11da-bd1a-00112444be1e"
+        assert isinstance(order.created, ry.ZonedDateTime)
+        assert str(order.created) == "2024-03-10T12:00:00-04:00[America/New_York]"
+        assert order.items == [Item(id=1, name="x", tags=["t"])]
+        assert order.note is None
+
+    def test_missing_field(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'{"items": [{"id": 1}]}', type=dict[str, list[Item]])
+        err = exc_info.value
+        assert str(err) == "Object missing required field `name` - at `$.items[0]`"
+        assert err.path == "$.items[0]"
+
+    def test_expected_object(self) -> None:
+        with pytest.raises(
+            JSON.JsonValidationError, match="Expected `object`, got `array`"
+        ):
+            JSON.parse(b"[]", type=Item)
+
+    def test_recursive(self) -> None:
+        data = b'{"value": 1, "children": [{"value": 2, "children": [{"value": 3}]}]}'
+        node = JSON.parse(data, type=Node)
+        assert node == Node(1, [Node(2, [Node(3)])])
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'{"value": 1, "children": [{"value": null}]}', type=Node)
+        assert exc_info.value.path == "$.children[0].value"
+
+    def test_post_init_error(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'[{"n": 1}, {"n": 0}]', type=list[Positive])
+        assert str(exc_info.value) == "n must be positive - at `$[1]`"
+
+
+class TestTypedDict:
+    def test_typed_dict(self) -> None:
+        movie = JSON.parse(b'{"title": "Heat", "year": 1995}', type=Movie)
+        assert movie == {"title": "Heat", "year": 1995}
+
+    def test_required(self) -> None:
+        with pytest.raises(
+            JSON.JsonValidationError, match="Object missing required field `year`"
+        ):
+            JSON.parse(b'{"title": "Heat"}', type=Movie)
+        assert JSON.parse(b'{"title": "Heat"}', type=PartialMovie) == {
+            "title": "Heat"
+        }
+
+    def test_field_type(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'{"title": "Heat", "year": "1995"}', type=Movie)
+        assert exc_info.value.path == "$.year"
+
+
+class TestRyTypes:
+    @pytest.mark.parametrize(
+        ("tp", "value"),
+        [
+            (ry.Date, "2024-03-10"),
+            (ry.DateTime, "2024-03-10T12:30:00"),
+            (ry.Time, "12:30:00"),
+            (ry.Timestamp, "2024-03-10T12:30:00Z"),
+            (ry.ZonedDateTime, "2024-03-10T12:30:00+00:00[UTC]"),
+            (ry.SignedDuration, "PT1H"),
+            (ry.TimeSpan, "P1D"),
+            (ry.UUID, "a8098c1a-f86e-11da-bd1a-00112444be1e"),
+            (ry.ULID, "01ARZ3NDEKTSV4RRFFQ69G5FAV"),
+            (ry.URL, "https://example.com/"),
+            (ry.IpAddr, "::1"),
+            (ry.Ipv4Addr, "127.0.0.1"),
+            (ry.Ipv6Addr, "::1"),
+        ],
+    )
+    def test_ry_types(self, tp: type, value: str) -> None:
+        decoded = JSON.parse(f'["{value}"]', type=list[tp])  # type: ignore[valid-type]
+        assert len(decoded) == 1
+        assert isinstance(decoded[0], tp)
+
+    def test_invalid(self) -> None:
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            JSON.parse(b'{"a": "not-a-uuid"}', type=dict[str, ry.UUID])
+        assert str(exc_info.value).startswith("Invalid `UUID`: ")
+        assert exc_info.value.path == "$.a"
+
+    def test_expected_str(self) -> None:
+        with pytest.raises(JSON.JsonValidationError, match="Expected `str`, got `int`"):
+            JSON.parse(b"1", type=ry.Date)
+
+
+class TestJsonDecoder:
+    def test_decode(self) -> None:
+        decoder = JSON.JsonDecoder(list[Item])
+        assert decoder.type == list[Item]
+        assert decoder.decode('[{"id": 1, "name": "a"}]') == [Item(1, "a")]
+        assert decoder.decode(bytearray(b"[]")) == []
+
+    def test_repr(self) -> None:
+        assert repr(JSON.JsonDecoder(int)) == "JsonDecoder(<class 'int'>)"
+
+    def test_decode_lines(self) -> None:
+        decoder = JSON.JsonDecoder(Item)
+        data = b'{"id": 1, "name": "a"}\n{"id": 2, "name": "b"}\n'
+        assert decoder.decode_lines(data) == [Item(1, "a"), Item(2, "b")]
+        with pytest.raises(JSON.JsonValidationError) as exc_info:
+            decoder.decode_lines(b'{"id": 1, "name": "a"}\n{"id": "2"}\n')
+        assert exc_info.value.path == "$[1].id"
+
+    def test_convert(self) -> None:
+        decoder = JSON.JsonDecoder(dict[str, Item])
+        assert decoder.convert({"x": {"id": 1, "name": "a"}}) == {"x": Item(1, "a")}
+
+    def test_jsonc_options(self) -> None:
+        decoder = JSON.JsonDecoder(
+            list[int], allow_comments=True, allow_trailing_commas=True
+        )
+        assert decoder.decode(b"[1, 2, // two\n]") == [1, 2]
+
+    def test_invalid_json(self) -> None:
+        with pytest.raises(ValueError) as exc_info:
+            JSON.JsonDecoder(int).decode(b"[1,")
+        assert not isinstance(exc_info.value, JSON.JsonValidationError)
+
+    def test_unsupported_type(self) -> None:
+        with pytest.raises(TypeError, match="is not supported"):
+            JSON.JsonDecoder(complex)
@@ -0,0 +1,268 @@
+from __future__ import annotations
+
+import copy
+import dataclasses
+import typing as t
+
+import pytest
+
+from ry import JSON
+
+# RFC 6902 appendix A (subset)
+RFC6902_CASES: list[tuple[t.Any, list[dict[str, t.Any]], t.Any]] = [
+    # A.1 adding an object member
+    (
+        {"foo": "bar"},
+        [{"op": "add", "path": "/baz", "value": "qux"}],
+        {"baz": "qux", "foo": "bar"},
+    ),
+    # A.2 adding an array element
+    (
+        {"foo": ["bar", "baz"]},
+        [{"op": "add", "path": "/foo/1", "value": "qux"}],
+        {"foo": ["bar", "qux", "baz"]},
+    ),
+    # A.3 removing an object member
+    (
+        {"baz": "qux", "foo": "bar"},
+        [{"op": "remove", "path": "/baz"}],
+        {"foo": "bar"},
+    ),
+    # A.4 removing an array element
+    (
+        {"foo": ["bar", "qux", "baz"]},
+        [{"op": "remove", "path": "/foo/1"}],
+        {"foo": ["bar", "baz"]},
+    ),
+    # A.5 replacing a value
+    (
+        {"baz": "qux", "foo": "bar"},
+        [{"op": "replace", "path": "/baz", "value": "boo"}],
+        {"baz": "boo", "foo": "bar"},
+    ),
+    # A.6 moving a value
+    (
+        {"foo": {"bar": "baz", "waldo": "fred"}, "qux": {"corge": "grault"}},
+        [{"op": "move", "from": "/foo/waldo", "path": "/qux/thud"}],
+        {"foo": {"bar": "baz"}, "qux": {"corge": "grault", "thud": "fred"}},
+    ),
+    # A.7 moving an array element
+    (
+        {"foo": ["all", "grass", "cows", "eat"]},
+        [{"op": "move", "from": "/foo/1", "path": "/foo/3"}],
+        {"foo": ["all", "cows", "eat", "grass"]},
+    ),
+    # A.10 adding a nested member object
+    (
+        {"foo": "bar"},
+        [{"op": "add", "path": "/child", "value": {"grandchild": {}}}],
+        {"foo": "bar", "child": {"grandchild": {}}},
+    ),
+    # A.14 ~ escape ordering
+    (
+        {"/": 9, "~1": 10},
+        [{"op": "test", "path": "/~01", "value": 10}],
+        {"/": 9, "~1": 10},
+    ),
+    # A.16 adding an array value
+    (
+        {"foo": ["bar"]},
+        [{"op": "add", "path": "/foo/-", "value": ["abc", "def"]}],
+        {"foo": ["bar", ["abc", "def"]]},
+    ),
+    # copy
+    (
+        {"a": {"b": [1]}},
+        [{"op": "copy", "from": "/a", "path": "/c"}],
+        {"a": {"b": [1]}, "c": {"b": [1]}},
+    ),
+    # replace the whole document
+    ({"a": 1}, [{"op": "replace", "path": "", "value": [1]}], [1]),
+]
+
+
+class TestPatch:
+    @pytest.mark.parametrize(("doc", "ops", "expected"), RFC6902_CASES)
+    def test_rfc6902(
+        self, doc: t.Any, ops: list[dict[str, t.Any]], expected: t.Any
+    ) -> None:
+        assert JSON.patch(doc, ops) == expected
+
+    def test_returns_new_objects(self) -> None:
+        doc = {"a": {"b": [1, 2]}}
+        original = copy.deepcopy(doc)
+        result = JSON.patch(doc, [{"op": "add", "path": "/a/b/-", "value": 3}])
+        assert result == {"a": {"b": [1, 2, 3]}}
+        assert doc == original
+
+    def test_copy_is_deep(self) -> None:
+        result = JSON.patch(
+            {"a": {"b": 1}},
+            [
+                {"op": "copy", "from": "/a", "path": "/c"},
+                {"op": "replace", "path": "/c/b", "value": 2},
+            ],
+        )
+        assert result == {"a": {"b": 1}, "c": {"b": 2}}
+
+    def test_key_order_preserved(self) -> None:
+        result = JSON.patch({"z": 1, "a": 2}, [{"op": "add", "path": "/m", "value": 3}])
+        assert list(result) == ["z", "a", "m"]
+
+    def test_serializes_python_objects(self) -> None:
+        @dataclasses.dataclass
+        class Point:
+            x: int
+            y: int
+
+        result = JSON.patch(
+            {"p": Point(1, 2), "t": (1, 2)},
+            [{"op": "replace", "path": "/p/x", "value": 10}],
+        )
+        assert result == {"p": {"x": 10, "y": 2}, "t": [1, 2]}
+
+    @pytest.mark.parametrize(
+        ("doc", "op", "match"),
+        [
+            # A.12 adding to a nonexistent target
+            (
+                {"foo": "bar"},
+                {"op": "add", "path": "/baz/bat", "value": "qux"},
+                "does not exist",
+            ),
+            ({"a": 1}, {"op": "remove", "path": "/b"}, "does not exist"),
+            ({"a": [1]}, {"op": "remove", "path": "/a/1"}, "does not exist"),
+            ({"a": 1}, {"op": "replace", "path": "/b", "value": 1}, "does not exist"),
+            ({"a": [1]}, {"op": "add", "path": "/a/5", "value": 1}, "out of range"),
+            ({"a": [1]}, {"op": "add", "path": "/a/01", "value": 1}, "invalid array"),
+            ({"a": {}}, {"op": "move", "from": "/a", "path": "/a/b"}, "children"),
+            # A.13 invalid operation
+            ({"a": 1}, {"op": "invalid", "path": "/a"}, "unknown operation"),
+            ({"a": 1}, {"op": "add", "path": "/b"}, 'missing member "value"'),
+            ({"a": 1}, {"op": "copy", "path": "/b"}, 'missing member "from"'),
+            ({"a": 1}, {"op": "add", "path": "b", "value": 1}, "json pointer"),
+        ],
+    )
+    def test_errors(self, doc: t.Any, op: dict[str, t.Any], match: str) -> None:
+        ops = [{"op": "test", "path": "", "value": doc}, op]
+        with pytest.raises(JSON.JsonPatchError, match=match) as exc_info:
+            JSON.patch(doc, ops)
+        assert exc_info.value.index == 1  # type: ignore[attr-defined]
+        assert exc_info.value.op == op  # type: ignore[attr-defined]
+        assert isinstance(exc_info.value, ValueError)
+
+    def test_invalid_ops(self) -> None:
+        with pytest.raises(JSON.JsonPatchError, match="list of operations"):
+            JSON.patch({}, {"op": "add"})
+        with pytest.raises(JSON.JsonPatchError, match="must be an object"):
+            JSON.patch({}, [1])
+        with pytest.raises(JSON.JsonPatchError, match='"op"'):
+            JSON.patch({}, [{"path": "/a"}])
+
+    def test_test_error(self) -> None:
+        # A.9 testing a value: error
+        doc = {"baz": "qux"}
+        ops = [{"op": "test", "path": "/baz", "value": "bar"}]
+        with pytest.raises(JSON.JsonPatchTestError) as exc_info:
+            JSON.patch(doc, ops)
+        err = exc_info.value
+        assert isinstance(err, JSON.JsonPatchError)
+        assert err.index == 0  # type: ignore[attr-defined]
+        assert err.op == ops[0]  # type: ignore[attr-defined]
+        assert err.path == "/baz"  # type: ignore[attr-defined]
+        assert err.expected == "bar"  # type: ignore[attr-defined]
+        assert err.actual == "qux"  # type: ignore[attr-defined]
+
+    def test_test_json_equality(self) -> None:
+        doc = {"n": 1, "b": True, "o": {"x": 1, "y": [1.0, None]}}
+        ok = [
+            {"op": "test", "path": "/n", "value": 1.0},
+            {"op": "test", "path": "/o", "value": {"y": [1, None], "x": 1}},
+        ]
+        assert JSON.patch(doc, ok) == doc
+        # booleans are not numbers in json
+        with pytest.raises(JSON.JsonPatchTestError):
+            JSON.patch(doc, [{"op": "test", "path": "/b", "value": 1}])
+        with pytest.raises(JSON.JsonPatchTestError):
+            JSON.patch(doc, [{"op": "test", "path": "/n", "value": True}])
+
+    def test_test_missing_path(self) -> None:
+        with pytest.raises(JSON.JsonPatchError, match="does not exist"):
+            JSON.patch({}, [{"op": "test", "path": "/a", "value": 1}])
+
+
+DIFF_CASES: list[tuple[t.Any, t.Any]] = [
+    ({}, {}),
+    ({"a": 1}, {"a": 2}),
+    ({"a": 1, "b": 2}, {"b": 2, "c": 3}),
+    ({"a": [1, 2, 3]}, {"a": [1, 4]}),
+    ({"a": [1]}, {"a": [1, 2, [3]]}),
+    ({"a": {"b": {"c": 1}}}, {"a": {"b": {"c": 1, "d": None}}}),
+    ({"a/b": {"~": 1}}, {"a/b": {"~": 2}}),
+    ({"a": 1}, [1, 2]),
+    ([1, {"x": True}], [1, {"x": 1}]),
+    ("a", "b"),
+]
+
+
+class TestDiff:
+    @pytest.mark.parametrize(("a", "b"), DIFF_CASES)
+    def test_round_trip(self, a: t.Any, b: t.Any) -> None:
+        assert JSON.patch(a, JSON.diff(a, b)) == b
+
+    def test_no_changes(self) -> None:
+        assert JSON.diff({"a": [1, {"b": 2}]}, {"a": [1, {"b": 2}]}) == []
+        assert JSON.diff({"n": 1}, {"n": 1.0}) == []
+
+    def test_ops(self) -> None:
+        assert JSON.diff(
+            {"a": 1, "b": [1, 2, 3], "c": {"d": 1}},
+            {"a": 2, "b": [1], "c": {}, "e/f": None},
+        ) == [
+            {"op": "replace", "path": "/a", "value": 2},
+            {"op": "remove", "path": "/b/2"},
+            {"op": "remove", "path": "/b/1"},
+            {"op": "remove", "path": "/c/d"},
+            {"op": "add", "path": "/e~1f", "value": None},
+        ]
+
+    def test_bool_is_not_int(self) -> None:
+        assert JSON.diff({"a": 1}, {"a": True}) == [
+            {"op": "replace", "path": "/a", "value": True}
+        ]
+
+
+# RFC 7386 appendix A
+RFC7386_CASES: list[tuple[t.Any, t.Any, t.Any]] = [
+    ({"a": "b"}, {"a": "c"}, {"a": "c"}),
+    ({"a": "b"}, {"b": "c"}, {"a": "b", "b": "c"}),
+    ({"a": "b"}, {"a": None}, {}),
+    ({"a": "b", "b": "c"}, {"a": None}, {"b": "c"}),
+    ({"a": ["b"]}, {"a": "c"}, {"a": "c"}),
+    ({"a": "c"}, {"a": ["b"]}, {"a": ["b"]}),
+    ({"a": {"b": "c"}}, {"a": {"b": "d", "c": None}}, {"a": {"b": "d"}}),
+    ({"a": [{"b": "c"}]}, {"a": [1]}, {"a": [1]}),
+    (["a", "b"], ["c", "d"], ["c", "d"]),
+    ({"a": "b"}, ["c"], ["c"]),
+    ({"a": "foo"}, None, None),
+    ({"a": "foo"}, "bar", "bar"),
+    ({"e": None}, {"a": 1}, {"e": None, "a": 1}),
+    ([1, 2], {"a": "b", "c": None}, {"a": "b"}),
+    ({}, {"a": {"bb": {"ccc": None}}}, {"a": {"bb": {}}}),
+]
+
+
+class TestMergePatch:
+    @pytest.mark.parametrize(("doc", "patch", "expected"), RFC7386_CASES)
+    def test_rfc7386(self, doc: t.Any, patch: t.Any, expected: t.Any) -> None:
+        assert JSON.merge_patch(doc, patch) == expected
+
+    def test_returns_new_objects(self) -> None:
+        doc = {"a": {"b": 1}, "c": [1]}
+        patch = {"a": {"d": 2}}
+        result = JSON.merge_patch(doc, patch)
+        assert result == {"a": {"b": 1, "d": 2}, "c": [1]}
+        assert doc == {"a": {"b": 1}, "c": [1]}
+        assert patch == {"a": {"d": 2}}
+        result["c"].append(2)
+        assert doc["c"] == [1]
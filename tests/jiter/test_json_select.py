@@ -0,0 +1,177 @@
+from __future__ import annotations
+
+import json
+import typing as t
+
+import pytest
+
+import ry
+from ry import JSON
+
+DOC: dict[str, t.Any] = {
+    "items": [
+        {"id": 1, "name": "one", "tags": ["a"]},
+        {"id": 2, "name": "two", "tags": []},
+        {"id": 3, "name": "three", "tags": ["b", "c"], "price": 12.5},
+    ],
+    "meta": {"total": 3, "a/b": "slash", "m~n": "tilde", "": "empty"},
+    "esc\"aped": {"ü": True},
+}
+DOC_BYTES = json.dumps(DOC, indent=2).encode()
+
+
+class TestGet:
+    @pytest.mark.parametrize(
+        ("pointer", "expected"),
+        [
+            # RFC 6901 style
+            ("", DOC),
+            ("/items", DOC["items"]),
+            ("/items/0", DOC["items"][0]),
+            ("/items/0/id", 1),
+            ("/items/2/price", 12.5),
+            ("/items/2/tags/1", "c"),
+            ("/meta/total", 3),
+            ("/meta/a~1b", "slash"),
+            ("/meta/m~0n", "tilde"),
+            ("/meta/", "empty"),
+            ('/esc"aped/ü', True),
+        ],
+    )
+    def test_get(self, pointer: str, expected: t.Any) -> None:
+        assert JSON.get(DOC_BYTES, pointer) == expected
+        assert JSON.get(DOC_BYTES.decode(), pointer) == expected
+
+    @pytest.mark.parametrize(
+        "pointer",
+        [
+            "/nope",
+            "/items/3",
+            "/items/-",
+            "/items/01",
+            "/items/x",
+            "/items/0/id/deeper",
+            "/meta/total/0",
+        ],
+    )
+    def test_missing(self, pointer: str) -> None:
+        assert JSON.get(DOC_BYTES, pointer) is None
+        sentinel = object()
+        assert JSON.get(DOC_BYTES, pointer, sentinel) is sentinel
+        assert JSON.get(DOC_BYTES, pointer, default=0) == 0
+
+    def test_buffers(self) -> None:
+        for buf in (bytearray(DOC_BYTES), memoryview(DOC_BYTES), ry.Bytes(DOC_BYTES)):
+            assert JSON.get(buf, "/items/1/name") == "two"
+
+    def test_null_value_vs_missing(self) -> None:
+        data = b'{"a": null}'
+        assert JSON.get(data, "/a", default=1) is None
+        assert JSON.get(data, "/b", default=1) == 1
+
+    def test_first_duplicate_key(self) -> None:
+        assert JSON.get(b'{"a": 1, "a": 2}', "/a") == 1
+
+    def test_only_scans_to_target(self) -> None:
+        # the broken tail is never reached
+        assert JSON.get(b'{"a": [1, 2], "b": oops', "/a/1") == 2
+
+    @pytest.mark.parametrize("pointer", ["items", "#/items", "/items/~2"])
+    def test_invalid_pointer(self, pointer: str) -> None:
+        with pytest.raises(ValueError, match="json pointer"):
+            JSON.get(DOC_BYTES, pointer)
+
+    @pytest.mark.parametrize(
+        "data",
+        [b'{"a": [1, 2', b'{"a" 1}', b'{"b": [1, 2}', b'{"b": "unterminated'],
+    )
+    def test_invalid_json(self, data: bytes) -> None:
+        with pytest.raises(ValueError, match="invalid json"):
+            JSON.get(data, "/a")
+
+    def test_invalid_target(self) -> None:
+        with pytest.raises(ValueError):
+            JSON.get(b'{"a": tru}', "/a")
+
+    def test_bad_type(self) -> None:
+        with pytest.raises(TypeError):
+            JSON.get(123, "/a")  # type: ignore[arg-type]
+
+
+class TestQuery:
+    @pytest.mark.parametrize(
+        ("path", "expected"),
+        [
+            ("$", [DOC]),
+            ("$.items[*].id", [1, 2, 3]),
+            ("$.items[0].name", ["one"]),
+            ("$.items[-1].name", ["three"]),
+            ("$.items[0:2].id", [1, 2]),
+            ("$.items[::2].id", [1, 3]),
+            ("$.items[0,2].id", [1, 3]),
+            ("$..tags[*]", ["a", "b", "c"]),
+            ("$.items[?@.price].name", ["three"]),
+            ("$.items[?@.id > 1].name", ["two", "three"]),
+            ("$.items[?length(@.tags) == 0].id", [2]),
+            ("$.items[?match(@.name, 't.*')].id", [2, 3]),
+            ("$.meta['a/b']", ["slash"]),
+            ("$.meta.*", [3, "slash", "tilde", "empty"]),
+            ("$['items'][1][\"id\"]", [2]),
+            ("$.items[*].tags[*]", ["a", "b", "c"]),
+            ("$.items[-3].id", [1]),
+            ("$.items[3].id", []),
+            ("$.items[-4].id", []),
+            ("$.items.id", []),
+            ("$.items[0][0]", []),
+            ("$.nope", []),
+        ],
+    )
+    def test_query(self, path: str, expected: list[t.Any]) -> None:
+        assert JSON.query(DOC_BYTES, path) == expected
+        assert JSON.query(DOC_BYTES.decode(), path) == expected
+
+    def test_invalid_path(self) -> None:
+        with pytest.raises(ValueError, match="invalid JSONPath"):
+            JSON.query(DOC_BYTES, "items[*]")
+
+    def test_invalid_json(self) -> None:
+        with pytest.raises(ValueError, match="Failed to parse JSON"):
+            JSON.query(b"[1, 2", "$[0]")
+
+    def test_key_order_is_preserved(self) -> None:
+        data = b'{"items": [{"z": 1, "a": {"y": 2, "b": 3}, "m": [{"c": 4, "b": 5}]}]}'
+        (item,) = JSON.query(data, "$.items[0]")
+        assert list(item) == ["z", "a", "m"]
+        assert list(item["a"]) == ["y", "b"]
+        assert list(item["m"][0]) == ["c", "b"]
+        assert JSON.query(data, "$.items[0]") == JSON.query(data, "$.items[*]")
+
+    def test_big_ints(self) -> None:
+        big = 2**64 + 1
+        huge = 10**40
+        data = f'{{"a": [{big}, {{"n": {huge}}}], "b": {-big}}}'.encode()
+        assert JSON.query(data, "$.a[0]") == [big]
+        assert JSON.query(data, "$.a[1]") == [{"n": huge}]
+        assert JSON.query(data, "$.b") == [-big]
+        assert JSON.query(data, "$..n") == [huge]
+
+    def test_duplicate_keys_last_wins(self) -> None:
+        data = b'{"a": {"x": 1, "y": 2}, "a": {"y": 3, "x": 4}}'
+        assert JSON.query(data, "$.a") == [{"y": 3, "x": 4}]
+        assert list(JSON.query(data, "$.a")[0]) == ["y", "x"]
+        assert JSON.query(data, "$.*") == [{"y": 3, "x": 4}]
+        assert JSON.query(data, "$[?@.x].x") == [4]
+
+    @pytest.mark.parametrize(
+        "path",
+        ["$.items[*]", "$.items[1].name", "$.items[-1].tags[0]", "$['meta']['']"],
+    )
+    def test_scanned_matches_parsed(self, path: str) -> None:
+        # blank space is not handled by the raw scan, so the second query
+        # is evaluated on the parsed document
+        parsed = path.replace("$", "$ ", 1)
+        assert JSON.query(DOC_BYTES, path) == JSON.query(DOC_BYTES, parsed)
+
+    def test_invalid_json_in_scanned_path(self) -> None:
+        with pytest.raises(ValueError, match="Failed to parse JSON"):
+            JSON.query(b'{"a": [1, 2', "$.a[0]")
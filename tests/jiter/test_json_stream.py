@@ -0,0 +1,198 @@
+from __future__ import annotations
+
+import json
+import typing as t
+from typing import TYPE_CHECKING
+
+import pytest
+
+import ry
+from ry import JSON
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+DOC = {
+    "meta": {"count": 3, "tags": ["a", "b"]},
+    "data": [
+        {"id": 1, "name": "one", "nested": {"x": [1, 2, {"y": None}]}},
+        {"id": 2, "name": "tw\"o \\ ü \U0001f600", "nested": {}},
+        {"id": 3, "name": "three", "nested": {"x": []}},
+    ],
+    "tail": [1.5, -2e10, True, False, None, "s"],
+}
+DOC_BYTES = json.dumps(DOC, indent=2).encode()
+
+
+def _chunks(data: bytes, size: int) -> list[bytes]:
+    return [data[i : i + size] for i in range(0, len(data), size)]
+
+
+class TestIterItems:
+    def test_top_level_array(self) -> None:
+        data = [1, "two", [3], {"four": 4}, None]
+        assert list(JSON.iter_items(json.dumps(data).encode())) == data
+
+    def test_prefix(self) -> None:
+        assert list(JSON.iter_items(DOC_BYTES, "data.item")) == DOC["data"]
+        assert list(JSON.iter_items(DOC_BYTES, "tail.item")) == DOC["tail"]
+        assert list(JSON.iter_items(DOC_BYTES, "data.item.name")) == [
+            d["name"] for d in DOC["data"]
+        ]
+
+    def test_prefix_non_array(self) -> None:
+        assert list(JSON.iter_items(DOC_BYTES, "meta")) == [DOC["meta"]]
+        assert list(JSON.iter_items(DOC_BYTES, "meta.count")) == [3]
+        assert list(JSON.iter_items(DOC_BYTES, "")) == [DOC]
+
+    def test_no_match(self) -> None:
+        assert list(JSON.iter_items(DOC_BYTES, "nope.item")) == []
+
+    @pytest.mark.parametrize("read_size", [1, 2, 3, 7, 64, 65536])
+    def test_read_size(self, read_size: int) -> None:
+        res = JSON.iter_items(DOC_BYTES, "data.item", read_size=read_size)
+        assert list(res) == DOC["data"]
+
+    @pytest.mark.parametrize("chunk_size", [1, 5, 13, 1024])
+    def test_iterable_of_chunks(self, chunk_size: int) -> None:
+        chunks = _chunks(DOC_BYTES, chunk_size)
+        assert list(JSON.iter_items(iter(chunks), "data.item")) == DOC["data"]
+
+    def test_empty_chunks_are_skipped(self) -> None:
+        chunks = [b"", b"[1,", b"", b"", b"2]", b""]
+        assert list(JSON.iter_items(chunks)) == [1, 2]
+
+    def test_buffer_types(self) -> None:
+        for buf in (
+            DOC_BYTES,
+            bytearray(DOC_BYTES),
+            memoryview(DOC_BYTES),
+            ry.Bytes(DOC_BYTES),
+        ):
+            assert list(JSON.iter_items(buf, "tail.item")) == DOC["tail"]
+
+    def test_path(self, tmp_path: Path) -> None:
+        fpath = tmp_path / "data.json"
+        fpath.write_bytes(DOC_BYTES)
+        assert list(JSON.iter_items(fpath, "data.item", read_size=8)) == DOC["data"]
+        assert list(JSON.iter_items(str(fpath), "tail.item")) == DOC["tail"]
+
+    def test_file_read_stream(self, tmp_path: Path) -> None:
+        fpath = tmp_path / "data.json"
+        fpath.write_bytes(DOC_BYTES)
+        stream = ry.FileReadStream(fpath, read_size=16)
+        assert list(JSON.iter_items(stream, "data.item")) == DOC["data"]
+
+    def test_big_array(self) -> None:
+        data = [{"i": i, "s": str(i) * (i % 7)} for i in range(5000)]
+        buf = json.dumps(data).encode()
+        it = JSON.iter_items(_chunks(buf, 1000))
+        for expected, item in zip(data, it, strict=True):
+            assert item == expected
+
+    def test_collect(self) -> None:
+        it = JSON.iter_items(b"[1, 2, 3, 4]")
+        assert next(it) == 1
+        assert it.collect() == [2, 3, 4]
+        assert it.collect() == []
+
+    def test_keys_with_escapes(self) -> None:
+        data = {"a\"b": [1, 2], "ü": {"k": [3]}}
+        buf = json.dumps(data).encode()
+        assert list(JSON.iter_items(buf, 'a"b.item')) == [1, 2]
+        assert list(JSON.iter_items(buf, "ü.k.item")) == [3]
+
+
+class TestJsonEventReader:
+    def test_events(self) -> None:
+        buf = b'{"a": [1, {"b": null}], "c": "d"}'
+        assert list(JSON.JsonEventReader(buf)) == [
+            ("", "start_map", None),
+            ("", "key", "a"),
+            ("a", "start_array", None),
+            ("a.item", "value", 1),
+            ("a.item", "start_map", None),
+            ("a.item", "key", "b"),
+            ("a.item.b", "value", None),
+            ("a.item", "end_map", None),
+            ("a", "end_array", None),
+            ("", "key", "c"),
+            ("c", "value", "d"),
+            ("", "end_map", None),
+        ]
+
+    def test_scalar_document(self) -> None:
+        assert list(JSON.JsonEventReader(b" 123 ")) == [("", "value", 123)]
+
+    @pytest.mark.parametrize("read_size", [1, 3, 65536])
+    def test_read_sizes_agree(self, read_size: int) -> None:
+        expected = list(JSON.JsonEventReader(DOC_BYTES))
+        assert list(JSON.JsonEventReader(DOC_BYTES, read_size=read_size)) == expected
+
+    def test_depth(self) -> None:
+        reader = JSON.JsonEventReader(b"[[1]]")
+        assert reader.depth == 0
+        next(reader)
+        next(reader)
+        assert reader.depth == 2
+
+    def test_path(self, tmp_path: Path) -> None:
+        fpath = tmp_path / "data.json"
+        fpath.write_bytes(b"[true]")
+        assert list(JSON.JsonEventReader(fpath)) == [
+            ("", "start_array", None),
+            ("item", "value", True),
+            ("", "end_array", None),
+        ]
+
+
+class TestErrors:
+    @pytest.mark.parametrize(
+        ("buf", "match"),
+        [
+            (b"[1, 2", "unexpected end of input"),
+            (b'["abc', "unterminated string"),
+            (b"[1] 2", "trailing characters"),
+            (b"[1 2]", "unexpected token"),
+            (b'{"a" 1}', "unexpected token"),
+            (b"{1: 2}", "unexpected token"),
+            (b"[1, }", "unexpected token"),
+            (b"[1, @]", "unexpected character at byte 4"),
+            (b"", "unexpected end of input"),
+        ],
+    )
+    def test_invalid(self, buf: bytes, match: str) -> None:
+        with pytest.raises(ValueError, match=match):
+            list(JSON.iter_items(buf, ""))
+        with pytest.raises(ValueError, match=match):
+            list(JSON.JsonEventReader(buf))
+
+    def test_invalid_scalar(self) -> None:
+        with pytest.raises(ValueError):
+            list(JSON.iter_items(b"[nul]"))
+
+    def test_items_before_error_are_yielded(self) -> None:
+        it = JSON.iter_items(b"[1, 2, oops]")
+        assert next(it) == 1
+        assert next(it) == 2
+        with pytest.raises(ValueError):
+            next(it)
+
+    def test_bad_source(self) -> None:
+        with pytest.raises(TypeError):
+            JSON.iter_items(123)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            list(JSON.iter_items([1, 2]))  # type: ignore[list-item]
+
+    def test_missing_file(self, tmp_path: Path) -> None:
+        with pytest.raises(FileNotFoundError):
+            JSON.iter_items(tmp_path / "nope.json")
+
+    def test_read_size_zero(self) -> None:
+        with pytest.raises(ValueError, match="read_size"):
+            JSON.iter_items(b"[]", read_size=0)
+
+
+def test_types() -> None:
+    it: t.Any = JSON.iter_items(b"[]")
+    assert isinstance(it, JSON.JsonItemsIter)
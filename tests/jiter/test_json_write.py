@@ -0,0 +1,275 @@
+from __future__ import annotations
+
+import dataclasses
+import gzip
+import json
+import typing as t
+
+import pytest
+
+import ry
+from ry import JSON
+
+if t.TYPE_CHECKING:
+    from pathlib import Path
+
+_Compression: t.TypeAlias = t.Literal["gzip", "gz", "zstd", "zst"] | None
+
+ROWS: list[t.Any] = [
+    {"id": 1, "name": "one", "tags": ["a"]},
+    {"id": 2, "name": "two", "tags": []},
+    {"id": 3, "name": "üñí", "price": 12.5},
+    None,
+    [1, 2, 3],
+    "str",
+]
+
+
+def _read_lines(path: Path, compression: str | None = None) -> list[t.Any]:
+    data = path.read_bytes()
+    if compression in ("gzip", "gz"):
+        data = gzip.decompress(data)
+    elif compression in ("zstd", "zst"):
+        data = bytes(ry.zstd.decode(data))
+    assert data.endswith(b"\n") or data == b""
+    return [json.loads(line) for line in data.splitlines()]
+
+
+class TestWrite:
+    def test_write(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.json"
+        n = JSON.write(path, {"a": [1, 2]})
+        assert path.read_bytes() == b'{"a":[1,2]}'
+        assert n == len(b'{"a":[1,2]}')
+
+    def test_write_str_path(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.json"
+        JSON.write(str(path), [1])
+        assert path.read_bytes() == b"[1]"
+
+    def test_write_options(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.json"
+        obj = {"b": "ü", "a": 1}
+        JSON.write(
+            path,
+            obj,
+            indent=4,
+            sort_keys=True,
+            ensure_ascii=True,
+            append_newline=True,
+        )
+        expected = json.dumps(obj, indent=4, sort_keys=True, ensure_ascii=True)
+        assert path.read_text() == expected + "\n"
+
+    def test_write_matches_stringify(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.json"
+        JSON.write(path, ROWS, fmt=True)
+        assert path.read_bytes() == bytes(JSON.stringify(ROWS, fmt=True))
+
+    def test_write_default(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.json"
+        JSON.write(path, {"s": {1, 2}}, default=sorted)
+        assert json.loads(path.read_bytes()) == {"s": [1, 2]}
+
+    def test_write_truncates(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.json"
+        path.write_bytes(b"x" * 100)
+        JSON.write(path, 1)
+        assert path.read_bytes() == b"1"
+
+    def test_write_unserializable(self, tmp_path: Path) -> None:
+        with pytest.raises(TypeError):
+            JSON.write(tmp_path / "data.json", object())
+
+
+class TestJsonLinesWriter:
+    @pytest.mark.parametrize("compression", [None, "gzip", "gz", "zstd", "zst"])
+    def test_round_trip(self, tmp_path: Path, compression: _Compression) -> None:
+        path = tmp_path / "data.jsonl"
+        with JSON.JsonLinesWriter(path, compression=compression) as w:
+            for row in ROWS[:3]:
+                w.write(row)
+            w.writelines(ROWS[3:])
+        assert w.closed
+        assert _read_lines(path, compression) == ROWS
+
+    def test_write_returns_uncompressed_len(self, tmp_path: Path) -> None:
+        with JSON.JsonLinesWriter(tmp_path / "a.jsonl.gz", compression="gzip") as w:
+            assert w.write({"a": 1}) == len(b'{"a":1}\n')
+            assert w.writelines([1, 22]) == len(b"1\n22\n")
+
+    def test_writelines_many(self, tmp_path: Path) -> None:
+        # more than one 64KiB batch
+        rows = [{"i": i, "pad": "x" * 100} for i in range(2000)]
+        path = tmp_path / "data.jsonl"
+        with JSON.JsonLinesWriter(path) as w:
+            w.writelines(iter(rows))
+        assert _read_lines(path) == rows
+
+    def test_append(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl"
+        with JSON.JsonLinesWriter(path) as w:
+            w.write(1)
+        with JSON.JsonLinesWriter(path, append=True) as w:
+            w.write(2)
+        assert _read_lines(path) == [1, 2]
+        with JSON.JsonLinesWriter(path) as w:
+            w.write(3)
+        assert _read_lines(path) == [3]
+
+    def test_append_gzip_members(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl.gz"
+        for i in range(3):
+            with JSON.JsonLinesWriter(path, append=True, compression="gzip") as w:
+                w.write(i)
+        assert _read_lines(path, "gzip") == [0, 1, 2]
+
+    def test_options(self, tmp_path: Path) -> None:
+        @dataclasses.dataclass
+        class Point:
+            x: int
+            y: int
+
+        path = tmp_path / "data.jsonl"
+        with JSON.JsonLinesWriter(
+            path, sort_keys=True, ensure_ascii=True, non_str_keys=True
+        ) as w:
+            w.write({"b": "ü", "a": Point(1, 2), 1: None})
+        assert path.read_bytes() == (
+            b'{"1":null,"a":{"x":1,"y":2},"b":"\\u00fc"}\n'
+        )
+
+    def test_default(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl"
+        with JSON.JsonLinesWriter(path, default=str) as w:
+            w.write({"c": complex(1, 2)})
+        assert _read_lines(path) == [{"c": "(1+2j)"}]
+
+    @pytest.mark.parametrize("compression", [None, "gzip", "zstd"])
+    def test_finished_on_drop(self, tmp_path: Path, compression: _Compression) -> None:
+        path = tmp_path / "data.jsonl"
+        w = JSON.JsonLinesWriter(path, compression=compression)
+        w.writelines(ROWS)
+        del w
+        assert _read_lines(path, compression) == ROWS
+
+    def test_empty_gzip_is_valid(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl.gz"
+        JSON.JsonLinesWriter(path, compression="gzip").close()
+        assert gzip.decompress(path.read_bytes()) == b""
+
+    def test_level(self, tmp_path: Path) -> None:
+        rows = [{"i": i, "pad": "abc" * 50} for i in range(500)]
+        sizes = {}
+        for level in (0, 9):
+            path = tmp_path / f"data-{level}.jsonl.gz"
+            with JSON.JsonLinesWriter(path, compression="gzip", level=level) as w:
+                w.writelines(rows)
+            assert _read_lines(path, "gzip") == rows
+            sizes[level] = path.stat().st_size
+        assert sizes[9] < sizes[0]
+
+    def test_invalid_level(self, tmp_path: Path) -> None:
+        with pytest.raises(ValueError):
+            JSON.JsonLinesWriter(tmp_path / "x.gz", compression="gzip", level=42)
+
+    def test_invalid_compression(self, tmp_path: Path) -> None:
+        with pytest.raises(ValueError):
+            JSON.JsonLinesWriter(
+                tmp_path / "x",
+                compression="brotli",  # type: ignore[arg-type]
+            )
+
+    def test_closed(self, tmp_path: Path) -> None:
+        w = JSON.JsonLinesWriter(tmp_path / "data.jsonl")
+        assert not w.closed
+        w.close()
+        assert w.closed
+        w.close()  # idempotent
+        with pytest.raises(ValueError, match="closed"):
+            w.write(1)
+        with pytest.raises(ValueError, match="closed"):
+            w.writelines([1])
+
+    def test_flush(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl"
+        w = JSON.JsonLinesWriter(path)
+        w.write(1)
+        w.flush()
+        assert path.read_bytes() == b"1\n"
+        w.close()
+
+    def test_unserializable_does_not_write(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl"
+        with JSON.JsonLinesWriter(path) as w:
+            w.write(1)
+            with pytest.raises(TypeError):
+                w.write(object())
+        assert _read_lines(path) == [1]
+
+    def test_path_and_repr(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl"
+        with JSON.JsonLinesWriter(str(path)) as w:
+            assert str(w.path) == str(path)
+            assert "JsonLinesWriter" in repr(w)
+
+
+class TestAsyncJsonLinesWriter:
+    @pytest.mark.anyio
+    @pytest.mark.parametrize("compression", [None, "gzip", "zstd"])
+    async def test_round_trip(
+        self, tmp_path: Path, compression: _Compression
+    ) -> None:
+        path = tmp_path / "data.jsonl"
+        async with JSON.AsyncJsonLinesWriter(path, compression=compression) as w:
+            for row in ROWS[:3]:
+                assert await w.write(row) == len(JSON.stringify(row)) + 1
+            await w.writelines(ROWS[3:])
+        assert w.closed
+        assert _read_lines(path, compression) == ROWS
+
+    @pytest.mark.anyio
+    async def test_append(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl"
+        async with JSON.AsyncJsonLinesWriter(path) as w:
+            await w.write(1)
+        async with JSON.AsyncJsonLinesWriter(path, append=True) as w:
+            await w.write(2)
+        assert _read_lines(path) == [1, 2]
+
+    @pytest.mark.anyio
+    async def test_without_context_manager(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl.gz"
+        w = JSON.AsyncJsonLinesWriter(path, compression="gzip")
+        await w.write({"a": 1})
+        await w.flush()
+        await w.close()
+        await w.close()  # idempotent
+        assert w.closed
+        assert _read_lines(path, "gzip") == [{"a": 1}]
+        with pytest.raises(ValueError, match="closed"):
+            await w.write(1)
+
+    @pytest.mark.anyio
+    @pytest.mark.parametrize("compression", [None, "gzip", "zstd"])
+    async def test_finished_on_drop(
+        self, tmp_path: Path, compression: _Compression
+    ) -> None:
+        path = tmp_path / "data.jsonl"
+        w = JSON.AsyncJsonLinesWriter(path, compression=compression)
+        await w.writelines(ROWS)
+        assert not w.closed
+        del w
+        assert _read_lines(path, compression) == ROWS
+
+    @pytest.mark.anyio
+    async def test_close_without_writes(self, tmp_path: Path) -> None:
+        path = tmp_path / "data.jsonl"
+        await JSON.AsyncJsonLinesWriter(path).close()
+        assert path.read_bytes() == b""
+
+    @pytest.mark.anyio
+    async def test_serialization_error_is_eager(self, tmp_path: Path) -> None:
+        async with JSON.AsyncJsonLinesWriter(tmp_path / "data.jsonl") as w:
+            with pytest.raises(TypeError):
+                w.write(object())  # raises before awaiting
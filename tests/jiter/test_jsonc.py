@@ -0,0 +1,150 @@
+from __future__ import annotations
+
+import json
+import typing as t
+
+import pytest
+
+import ry
+from ry import JSON
+
+if t.TYPE_CHECKING:
+    from pathlib import Path
+
+TSCONFIG = b"""// tsconfig.json
+{
+  /* compiler options */
+  "compilerOptions": {
+    "target": "es2022", // trailing comment
+    "paths": {"@/*": ["src/*",],},
+    "strict": true,
+  },
+  "include": ["src/**/*.ts", "http://not-a-comment", "/* nor this */"],
+}
+"""
+
+EXPECTED = {
+    "compilerOptions": {
+        "target": "es2022",
+        "paths": {"@/*": ["src/*"]},
+        "strict": True,
+    },
+    "include": ["src/**/*.ts", "http://not-a-comment", "/* nor this */"],
+}
+
+
+class TestParse:
+    def test_jsonc(self) -> None:
+        data = JSON.parse(TSCONFIG, allow_comments=True, allow_trailing_commas=True)
+        assert data == EXPECTED
+
+    def test_str(self) -> None:
+        data = ry.parse_json(
+            TSCONFIG.decode(), allow_comments=True, allow_trailing_commas=True
+        )
+        assert data == EXPECTED
+
+    def test_rejected_by_default(self) -> None:
+        with pytest.raises(ValueError):
+            JSON.parse(TSCONFIG)
+        with pytest.raises(ValueError):
+            JSON.parse(b"[1,]")
+        with pytest.raises(ValueError):
+            JSON.parse(b"[1] // comment")
+
+    def test_comments_only(self) -> None:
+        assert JSON.parse(b"/* a */ [1, // b\n 2] // c", allow_comments=True) == [1, 2]
+        with pytest.raises(ValueError):
+            JSON.parse(b"[1, 2,] // c", allow_comments=True)
+
+    def test_trailing_commas_only(self) -> None:
+        assert JSON.parse(b'{"a": [1,],}', allow_trailing_commas=True) == {"a": [1]}
+        with pytest.raises(ValueError):
+            JSON.parse(b"[1,] // c", allow_trailing_commas=True)
+
+    def test_comment_between_comma_and_close(self) -> None:
+        data = b"[1, /* last */\n]"
+        assert JSON.parse(data, allow_comments=True, allow_trailing_commas=True) == [1]
+
+    @pytest.mark.parametrize("data", [b"[,]", b"{,}", b"[1,,]", b'{"a":,}'])
+    def test_not_a_trailing_comma(self, data: bytes) -> None:
+        with pytest.raises(ValueError):
+            JSON.parse(data, allow_trailing_commas=True)
+
+    def test_escaped_quote_in_string(self) -> None:
+        data = b'["a \\" // not a comment", 1,]'
+        assert JSON.parse(
+            data, allow_comments=True, allow_trailing_commas=True
+        ) == ['a " // not a comment', 1]
+
+    def test_unterminated_block_comment(self) -> None:
+        with pytest.raises(
+            ValueError, match="EOF while parsing a comment at line 2 column 3"
+        ):
+            JSON.parse(b"[1,\n  /* oops", allow_comments=True)
+
+    def test_error_position_is_preserved(self) -> None:
+        with pytest.raises(ValueError, match="line 3 column"):
+            JSON.parse(b"// c\n/* c */ [\n x]", allow_comments=True)
+
+    def test_with_number_options(self) -> None:
+        data = b"[1.5, // big\n 99999999999999999999,]"
+        assert JSON.parse(
+            data,
+            allow_comments=True,
+            allow_trailing_commas=True,
+            big_int_mode="str",
+        ) == [1.5, "99999999999999999999"]
+        assert JSON.parse(
+            data, allow_comments=True, allow_trailing_commas=True, lazy_numbers=True
+        ) == ["1.5", "99999999999999999999"]
+
+
+class TestOtherEntryPoints:
+    def test_parse_jsonl(self) -> None:
+        data = b'// header\n{"a": 1,} // one\n/* two */ [2,]\n'
+        assert ry.parse_jsonl(
+            data, allow_comments=True, allow_trailing_commas=True
+        ) == [{"a": 1}, [2]]
+
+    def test_parse_jsonl_multiline_comment(self) -> None:
+        data = b'{"a": 1}\n/* a comment\n   over lines */\n[2] /* more\n*/\n'
+        assert ry.parse_jsonl(data, allow_comments=True) == [{"a": 1}, [2]]
+
+    def test_read_json(self, tmp_path: Path) -> None:
+        path = tmp_path / "tsconfig.json"
+        path.write_bytes(TSCONFIG)
+        data = ry.read_json(path, allow_comments=True, allow_trailing_commas=True)
+        assert data == EXPECTED
+
+
+class TestMinify:
+    def test_strip_comments(self) -> None:
+        minified = JSON.minify(
+            TSCONFIG, strip_comments=True, strip_trailing_commas=True
+        )
+        assert json.loads(bytes(minified)) == EXPECTED
+        assert bytes(minified).startswith(b'{"compilerOptions":{"target":"es2022"')
+
+    def test_strip_comments_str(self) -> None:
+        minified = JSON.minify(
+            '{"a": 1, /* c */ "b": [2,],}',
+            strip_comments=True,
+            strip_trailing_commas=True,
+        )
+        assert bytes(minified) == b'{"a":1,"b":[2]}'
+
+    def test_strip_comments_keeps_trailing_commas(self) -> None:
+        assert bytes(JSON.minify('[1, /* c */ 2]', strip_comments=True)) == b"[1,2]"
+        with pytest.raises(ValueError):
+            JSON.minify("[1, /* c */ 2,]", strip_comments=True)
+
+    def test_strip_trailing_commas(self) -> None:
+        minified = JSON.minify('{"a": [1, 2,],}', strip_trailing_commas=True)
+        assert bytes(minified) == b'{"a":[1,2]}'
+        with pytest.raises(ValueError):
+            JSON.minify('{"a": 1, // c\n}', strip_trailing_commas=True)
+
+    def test_comments_rejected_by_default(self) -> None:
+        with pytest.raises(ValueError):
+            JSON.minify(TSCONFIG)
@@ -0,0 +1,48 @@
+from __future__ import annotations
+
+import pytest
+
+import ry
+
+_HAYSTACKS = (
+    b"a,b;c\nd,,e\n",
+    b"",
+    b"no delimiters",
+)
+
+
+def _positions(haystack: bytes, needles: bytes) -> list[int]:
+    return [i for i, b in enumerate(haystack) if b in needles]
+
+
+@pytest.mark.parametrize("haystack", _HAYSTACKS)
+def test_memchr_iter(haystack: bytes) -> None:
+    expected = _positions(haystack, b"\n")
+    assert ry.memchr_iter(b"\n", haystack) == expected
+    assert ry.memchr_iter(ord("\n"), bytearray(haystack)) == expected
+    assert ry.memchr_iter(b"\n", ry.Bytes(haystack)) == expected
+    assert ry.memrchr_iter(b"\n", memoryview(haystack)) == expected[::-1]
+
+
+@pytest.mark.parametrize("haystack", _HAYSTACKS)
+def test_memchr2_iter(haystack: bytes) -> None:
+    assert ry.memchr2_iter(b",", b";", haystack) == _positions(haystack, b",;")
+
+
+@pytest.mark.parametrize("haystack", _HAYSTACKS)
+def test_memchr3_iter(haystack: bytes) -> None:
+    assert ry.memchr3_iter(b",", b";", b"\n", haystack) == _positions(
+        haystack, b",;\n"
+    )
+
+
+def test_memchr_iter_line_splitting() -> None:
+    data = b"line one\nline two\nline three"
+    starts = [0, *(i + 1 for i in ry.memchr_iter(b"\n", data))]
+    ends = [*ry.memchr_iter(b"\n", data), len(data)]
+    assert [data[s:e] for s, e in zip(starts, ends, strict=True)] == data.split(b"\n")
+
+
+def test_memchr_iter_invalid_needle() -> None:
+    with pytest.raises((TypeError, ValueError)):
+        ry.memchr_iter(b"ab", b"abc")
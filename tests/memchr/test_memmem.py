@@ -0,0 +1,81 @@
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+import ry
+
+_CASES = [
+    (b"foo bar baz bar", b"bar"),
+    (b"aaaa", b"aa"),
+    (b"\r\n\r\nx\r\n", b"\r\n"),
+    (b"nope", b"zzz"),
+    (b"abc", b""),
+    (b"", b"a"),
+]
+
+
+def _find_iter(haystack: bytes, needle: bytes) -> list[int]:
+    """Non-overlapping occurrences (same as the `memchr::memmem` iterators)"""
+    positions = []
+    i = haystack.find(needle)
+    while i != -1:
+        positions.append(i)
+        i = haystack.find(needle, i + max(len(needle), 1))
+    return positions
+
+
+def _none_if_neg(i: int) -> int | None:
+    return None if i == -1 else i
+
+
+@pytest.mark.parametrize(("haystack", "needle"), _CASES)
+def test_memmem_find(haystack: bytes, needle: bytes) -> None:
+    assert ry.memmem_find(haystack, needle) == _none_if_neg(haystack.find(needle))
+    assert ry.memmem_rfind(haystack, needle) == _none_if_neg(haystack.rfind(needle))
+    assert ry.memmem_find(ry.Bytes(haystack), bytearray(needle)) == _none_if_neg(
+        haystack.find(needle)
+    )
+
+
+@pytest.mark.parametrize(("haystack", "needle"), _CASES[:4])
+def test_memmem_find_iter(haystack: bytes, needle: bytes) -> None:
+    expected = _find_iter(haystack, needle)
+    assert ry.memmem_find_iter(haystack, needle) == expected
+    assert ry.memmem_find_iter(memoryview(haystack), needle) == expected
+
+
+def test_memmem_rfind_iter() -> None:
+    assert ry.memmem_rfind_iter(b"foo bar baz bar", b"bar") == [12, 4]
+    # non-overlapping from the end
+    assert ry.memmem_rfind_iter(b"aaaaa", b"aa") == [3, 1]
+
+
+@pytest.mark.parametrize(("haystack", "needle"), _CASES[:4])
+def test_finder(haystack: bytes, needle: bytes) -> None:
+    finder = ry.Finder(needle)
+    assert finder.needle == needle
+    assert len(finder) == len(needle)
+    assert finder.find(haystack) == ry.memmem_find(haystack, needle)
+    assert finder.find_iter(haystack) == ry.memmem_find_iter(haystack, needle)
+    finder_rev = ry.FinderRev(needle)
+    assert finder_rev.rfind(haystack) == ry.memmem_rfind(haystack, needle)
+    assert finder_rev.rfind_iter(haystack) == ry.memmem_rfind_iter(haystack, needle)
+
+
+def test_finder_reuse() -> None:
+    finder = ry.Finder(b"\r\n")
+    lines = [b"a\r\nb", b"no newline", bytearray(b"\r\n\r\n")]
+    assert [finder.find_iter(line) for line in lines] == [[1], [], [0, 2]]
+
+
+def test_finder_dunders() -> None:
+    finder = ry.Finder(bytearray(b"ab"))
+    assert repr(finder) == "Finder(b'ab')"
+    assert repr(ry.FinderRev(b"ab")) == "FinderRev(b'ab')"
+    assert finder == ry.Finder(b"ab")
+    assert finder != ry.Finder(b"ba")
+    assert hash(finder) == hash(ry.Finder(b"ab"))
+    assert pickle.loads(pickle.dumps(finder)) == finder
+    assert pickle.loads(pickle.dumps(ry.FinderRev(b"ab"))) == ry.FinderRev(b"ab")
@@ -0,0 +1,193 @@
+from __future__ import annotations
+
+import hashlib
+from pathlib import Path
+
+import pytest
+
+import ry
+
+_DATA = b"".join(f"{i:08d}\n".encode() for i in range(10_000))
+
+
+@pytest.fixture
+def data_file(tmp_path: Path) -> Path:
+    p = tmp_path / "data.bin"
+    p.write_bytes(_DATA)
+    return p
+
+
+class TestMmap:
+    def test_mmap_read(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            assert isinstance(mm, ry.Mmap)
+            assert len(mm) == len(_DATA)
+            assert bytes(mm) == _DATA
+            assert memoryview(mm).readonly
+            assert memoryview(mm)[:9] == b"00000000\n"
+        assert mm.closed
+
+    def test_mmap_offset_length(self, data_file: Path) -> None:
+        with ry.Mmap(data_file, offset=9, length=18) as mm:
+            assert bytes(mm) == b"00000001\n00000002\n"
+            assert mm.offset == 9
+            assert mm.path == data_file
+
+    def test_mmap_unaligned_offset(self, data_file: Path) -> None:
+        with ry.mmap(data_file, offset=4099) as mm:
+            assert bytes(mm) == _DATA[4099:]
+
+    def test_mmap_empty_file(self, tmp_path: Path) -> None:
+        p = tmp_path / "empty.bin"
+        p.write_bytes(b"")
+        with ry.mmap(p) as mm:
+            assert len(mm) == 0
+            assert bytes(mm) == b""
+
+    def test_mmap_file_not_found(self, tmp_path: Path) -> None:
+        with pytest.raises(FileNotFoundError):
+            ry.mmap(tmp_path / "nope.bin")
+
+    def test_mmap_repr(self, data_file: Path) -> None:
+        mm = ry.mmap(data_file)
+        assert repr(mm) == f"Mmap(path='{data_file}', length={len(_DATA)})"
+        mm.close()
+        assert repr(mm) == f"Mmap(path='{data_file}', closed=True)"
+
+    def test_mmap_not_writable(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            mv = memoryview(mm)
+            with pytest.raises(TypeError):
+                mv[0] = 0
+            mv.release()
+
+
+class TestMmapBufferApis:
+    """Mmap should work with anything that takes a `Buffer`"""
+
+    def test_bytes_zero_copy(self, data_file: Path) -> None:
+        mm = ry.mmap(data_file)
+        b = ry.Bytes(mm)
+        assert b == _DATA
+        # the `ry.Bytes` holds an exported buffer, so the map can't be closed
+        with pytest.raises(BufferError):
+            mm.close()
+        del b
+        mm.close()
+        assert mm.closed
+
+    def test_memchr(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            assert ry.memchr(b"\n", mm) == 8
+            assert ry.memrchr(b"9", mm) == len(_DATA) - 2
+
+    def test_xxhash(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            assert ry.xxhash.xxh3_64_intdigest(mm) == ry.xxhash.xxh3_64_intdigest(_DATA)
+
+    def test_hashlib(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            assert hashlib.sha256(mm).digest() == hashlib.sha256(_DATA).digest()
+
+    def test_zstd(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            assert ry.zstd_decode(ry.zstd_encode(mm)) == _DATA
+
+    def test_json_parse(self, tmp_path: Path) -> None:
+        p = tmp_path / "data.json"
+        p.write_bytes(b'{"a": [1, 2, 3], "b": null}')
+        with ry.mmap(p) as mm:
+            assert ry.JSON.parse(mm) == {"a": [1, 2, 3], "b": None}
+
+
+class TestMmapClose:
+    def test_close_idempotent(self, data_file: Path) -> None:
+        mm = ry.mmap(data_file)
+        mm.close()
+        mm.close()
+        assert mm.closed
+
+    def test_closed_errors(self, data_file: Path) -> None:
+        mm = ry.mmap(data_file)
+        mm.close()
+        with pytest.raises(ValueError, match="mmap closed or invalid"):
+            len(mm)
+        with pytest.raises(ValueError, match="mmap closed or invalid"):
+            bytes(mm)
+        with pytest.raises(ValueError, match="mmap closed or invalid"):
+            memoryview(mm)
+
+    def test_close_with_memoryview(self, data_file: Path) -> None:
+        mm = ry.mmap(data_file)
+        mv = memoryview(mm)
+        with pytest.raises(BufferError):
+            mm.close()
+        mv.release()
+        mm.close()
+
+
+class TestMadvise:
+    @pytest.mark.parametrize("advice", ["normal", "random", "sequential", "willneed"])
+    def test_madvise(self, data_file: Path, advice: str) -> None:
+        with ry.mmap(data_file) as mm:
+            mm.madvise(advice)  # type: ignore[arg-type]
+            mm.madvise(advice, 4096, 4096)  # type: ignore[arg-type]
+
+    def test_madvise_invalid(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            with pytest.raises(ValueError):
+                mm.madvise("dont-care")  # type: ignore[arg-type]
+            with pytest.raises(TypeError):
+                mm.madvise(1)  # type: ignore[arg-type]
+
+    def test_madvise_out_of_range(self, data_file: Path) -> None:
+        with ry.mmap(data_file) as mm:
+            with pytest.raises(ValueError):
+                mm.madvise("normal", len(_DATA) + 1)
+            with pytest.raises(ValueError):
+                mm.madvise("normal", 10, len(_DATA))
+
+
+class TestMmapMut:
+    def test_mmap_mut_write(self, data_file: Path) -> None:
+        with ry.mmap(data_file, writable=True) as mm:
+            assert isinstance(mm, ry.MmapMut)
+            mv = memoryview(mm)
+            assert not mv.readonly
+            mv[:8] = b"abcdefgh"
+            mv.release()
+            mm.flush()
+        assert data_file.read_bytes() == b"abcdefgh" + _DATA[8:]
+
+    def test_mmap_mut_flush_range(self, data_file: Path) -> None:
+        mm = ry.MmapMut(data_file, offset=9, length=9)
+        with memoryview(mm) as mv:
+            mv[:] = b"ZZZZZZZZ\n"
+        mm.flush(0, 9)
+        mm.flush(sync=False)
+        with pytest.raises(ValueError):
+            mm.flush(0, 10)
+        mm.close()
+        assert data_file.read_bytes()[:27] == b"00000000\nZZZZZZZZ\n00000002\n"
+
+    def test_mmap_mut_close_flushes(self, data_file: Path) -> None:
+        mm = ry.mmap(data_file, writable=True)
+        with memoryview(mm) as mv:
+            mv[-2:] = b"!\n"
+        mm.close()
+        assert data_file.read_bytes() == _DATA[:-2] + b"!\n"
+
+    def test_mmap_mut_repr(self, data_file: Path) -> None:
+        with ry.mmap(data_file, writable=True, offset=9) as mm:
+            assert (
+                repr(mm)
+                == f"MmapMut(path='{data_file}', offset=9, length={len(_DATA) - 9})"
+            )
+
+    def test_mmap_mut_bytes(self, data_file: Path) -> None:
+        with ry.mmap(data_file, writable=True) as mm:
+            b = ry.Bytes(mm)
+            assert b == _DATA
+            with pytest.raises(BufferError):
+                mm.close()
+            del b
@@ -0,0 +1,220 @@
+from __future__ import annotations
+
+import dataclasses
+import datetime as pydt
+import pickle
+import typing as t
+import uuid as pyuuid
+
+import pytest
+
+import ry
+from ry import msgpack
+
+
+@pytest.mark.parametrize(
+    "obj",
+    [
+        None,
+        True,
+        False,
+        0,
+        -1,
+        127,
+        -32,
+        2**32,
+        -(2**63),
+        1.5,
+        "",
+        "hello",
+        "ünïcödé",
+        b"",
+        b"\x00\x01\xff",
+        [],
+        [1, "two", 3.0, None],
+        {},
+        {"a": 1, "b": [1, 2, {"c": None}]},
+    ],
+)
+def test_roundtrip(obj: t.Any) -> None:
+    packed = msgpack.packb(obj)
+    assert isinstance(packed, ry.Bytes)
+    assert msgpack.unpackb(packed) == obj
+
+
+def test_pack_known_encodings() -> None:
+    assert msgpack.packb(None) == b"\xc0"
+    assert msgpack.packb(True) == b"\xc3"
+    assert msgpack.packb(1) == b"\x01"
+    assert msgpack.packb(-1) == b"\xff"
+    assert msgpack.packb("a") == b"\xa1a"
+    assert msgpack.packb(b"a") == b"\xc4\x01a"
+    assert msgpack.packb([1, 2]) == b"\x92\x01\x02"
+    assert msgpack.packb({"a": 1}) == b"\x81\xa1a\x01"
+
+
+def test_tuple_packs_as_array() -> None:
+    assert msgpack.unpackb(msgpack.packb((1, 2, 3))) == [1, 2, 3]
+
+
+def test_bytes_likes_pack_as_bin() -> None:
+    expected = b"\xc4\x03abc"
+    assert msgpack.packb(b"abc") == expected
+    assert msgpack.packb(bytearray(b"abc")) == expected
+    assert msgpack.packb(memoryview(b"abc")) == expected
+    assert msgpack.packb(ry.Bytes(b"abc")) == expected
+
+
+def test_unpack_buffer_types() -> None:
+    packed = msgpack.packb({"a": [1, 2]})
+    assert msgpack.unpackb(bytes(packed)) == {"a": [1, 2]}
+    assert msgpack.unpackb(bytearray(packed)) == {"a": [1, 2]}
+    assert msgpack.unpackb(memoryview(bytes(packed))) == {"a": [1, 2]}
+
+
+def test_unpack_bin_is_bytes() -> None:
+    res = msgpack.unpackb(b"\xc4\x01a")
+    assert isinstance(res, bytes)
+    assert res == b"a"
+
+
+def test_unpack_u64() -> None:
+    assert msgpack.unpackb(b"\xcf\xff\xff\xff\xff\xff\xff\xff\xff") == 2**64 - 1
+
+
+def test_unpack_array_keys_are_tuples() -> None:
+    # {[1, 2]: "a"}
+    assert msgpack.unpackb(b"\x81\x92\x01\x02\xa1a") == {(1, 2): "a"}
+
+
+def test_unpack_map_key_unhashable() -> None:
+    # {{}: 1}
+    with pytest.raises(TypeError):
+        msgpack.unpackb(b"\x81\x80\x01")
+
+
+def test_unpack_errors() -> None:
+    with pytest.raises(ValueError, match="Failed to deserialize msgpack"):
+        msgpack.unpackb(b"")
+    with pytest.raises(ValueError, match="Failed to deserialize msgpack"):
+        msgpack.unpackb(b"\x92\x01")
+    with pytest.raises(ValueError, match="trailing data"):
+        msgpack.unpackb(b"\x01\x02")
+    with pytest.raises(ValueError, match="utf-8"):
+        msgpack.unpackb(b"\xa1\xff")
+
+
+def test_pack_datetime_and_uuid() -> None:
+    dt = pydt.datetime(2025, 1, 2, 3, 4, 5)
+    u = pyuuid.UUID("12345678-1234-5678-1234-567812345678")
+    obj = {
+        "date": pydt.date(2025, 1, 2),
+        "dt": dt,
+        "uuid": u,
+    }
+    assert msgpack.unpackb(msgpack.packb(obj)) == {
+        "date": "2025-01-02",
+        "dt": "2025-01-02T03:04:05",
+        "uuid": "12345678-1234-5678-1234-567812345678",
+    }
+
+
+def test_pack_ry_types_same_as_json() -> None:
+    obj = {
+        "date": ry.date(2025, 1, 2),
+        "ts": ry.Timestamp.from_millisecond(0),
+        "ulid": ry.ULID.from_str("01ARZ3NDEKTSV4RRFFQ69G5FAV"),
+        "url": ry.URL("https://example.com/path"),
+    }
+    assert msgpack.unpackb(msgpack.packb(obj)) == ry.parse_json(ry.stringify(obj))
+
+
+@dataclasses.dataclass
+class _Point:
+    x: int
+    y: int
+
+
+def test_pack_dataclass() -> None:
+    assert msgpack.unpackb(msgpack.packb(_Point(1, 2))) == {"x": 1, "y": 2}
+
+
+class _Custom:
+    def __init__(self, value: int) -> None:
+        self.value = value
+
+
+def test_pack_default() -> None:
+    def _default(obj: t.Any) -> t.Any:
+        if isinstance(obj, _Custom):
+            return {"custom": obj.value}
+        raise TypeError
+
+    packed = msgpack.packb([_Custom(1)], default=_default)
+    assert msgpack.unpackb(packed) == [{"custom": 1}]
+
+
+def test_pack_unserializable() -> None:
+    with pytest.raises(TypeError, match="Failed to serialize"):
+        msgpack.packb(_Custom(1))
+
+
+def test_pack_default_not_callable() -> None:
+    with pytest.raises(TypeError, match="'int' is not callable"):
+        msgpack.packb(1, default=1)  # type: ignore[arg-type]
+
+
+def test_pack_recursion() -> None:
+    a: dict[str, t.Any] = {}
+    a["a"] = a
+    with pytest.raises(RecursionError):
+        msgpack.packb(a)
+
+
+class TestExt:
+    def test_ext_attrs(self) -> None:
+        ext = msgpack.Ext(5, b"abc")
+        assert ext.code == 5
+        assert ext.data == b"abc"
+        assert isinstance(ext.data, ry.Bytes)
+        assert ext == msgpack.Ext(5, b"abc")
+        assert ext != msgpack.Ext(6, b"abc")
+        assert hash(ext) == hash(msgpack.Ext(5, b"abc"))
+        assert repr(ext) == "Ext(code=5, data=b'abc')"
+
+    def test_ext_code_range(self) -> None:
+        assert msgpack.Ext(-128, b"").code == -128
+        with pytest.raises(ValueError, match="ext code"):
+            msgpack.Ext(128, b"")
+
+    def test_ext_pack(self) -> None:
+        # fixext 1
+        assert msgpack.packb(msgpack.Ext(5, b"a")) == b"\xd4\x05a"
+        # ext 8
+        assert msgpack.packb(msgpack.Ext(1, b"abc")) == b"\xc7\x03\x01abc"
+
+    def test_ext_roundtrip(self) -> None:
+        obj = {"ext": msgpack.Ext(42, b"\x00\x01\x02\x03")}
+        assert msgpack.unpackb(msgpack.packb(obj)) == obj
+
+    def test_ext_hook(self) -> None:
+        packed = msgpack.packb([msgpack.Ext(1, b"abc"), msgpack.Ext(2, b"xyz")])
+
+        def _hook(code: int, data: bytes) -> t.Any:
+            assert isinstance(data, bytes)
+            if code == 1:
+                return data.decode()
+            return msgpack.Ext(code, data)
+
+        assert msgpack.unpackb(packed, ext_hook=_hook) == [
+            "abc",
+            msgpack.Ext(2, b"xyz"),
+        ]
+
+    def test_ext_hook_not_callable(self) -> None:
+        with pytest.raises(TypeError, match="not callable"):
+            msgpack.unpackb(b"\xc0", ext_hook="nope")  # type: ignore[arg-type]
+
+    def test_ext_pickle(self) -> None:
+        ext = msgpack.Ext(7, b"data")
+        assert pickle.loads(pickle.dumps(ext)) == ext
@@ -0,0 +1,145 @@
+from __future__ import annotations
+
+import pickle
+import re
+
+import pytest
+
+import ry
+
+_KV_PATTERN = r"(?<key>\w+)=(?<value>\w+)?"
+_PY_KV_PATTERN = rb"(?P<key>\w+)=(?P<value>\w+)?"
+
+
+@pytest.mark.parametrize(
+    "haystack",
+    [
+        b"a=1 b= c=3",
+        bytearray(b"a=1 b= c=3"),
+        memoryview(b"a=1 b= c=3"),
+        ry.Bytes(b"a=1 b= c=3"),
+    ],
+)
+def test_accepts_any_buffer(haystack: bytes | bytearray | memoryview | ry.Bytes) -> None:
+    rx = ry.BytesRegex(_KV_PATTERN)
+    assert rx.is_match(haystack)
+    assert rx.test(haystack)
+    assert rx.find(haystack) == (0, 3)
+    assert rx.find_all(haystack) == [(0, 3), (4, 6), (7, 10)]
+    assert rx.find_all_bytes(haystack) == [b"a=1", b"b=", b"c=3"]
+
+
+def test_find_no_match() -> None:
+    rx = ry.BytesRegex(r"\d+")
+    assert not rx.is_match(b"abc")
+    assert rx.find(b"abc") is None
+    assert rx.find_all(b"abc") == []
+
+
+def test_non_utf8_haystack() -> None:
+    rx = ry.BytesRegex(r"\xff+", unicode=False)
+    haystack = b"a\xff\xffb\xffc"
+    assert rx.find_all(haystack) == [(1, 3), (4, 5)]
+    assert rx.split(haystack) == [b"a", b"b", b"c"]
+    assert rx.replace_all(haystack, b"-") == b"a-b-c"
+
+
+def test_captures_matches_stdlib() -> None:
+    haystack = b"junk a=1 b= c=3"
+    caps = ry.BytesRegex(_KV_PATTERN).captures(haystack)
+    expected = re.search(_PY_KV_PATTERN, haystack)
+    assert caps is not None
+    assert expected is not None
+    assert caps.group() == expected.group()
+    assert isinstance(caps.group(), ry.Bytes)
+    assert caps.groups() == expected.groups()
+    assert caps.groupdict() == expected.groupdict()
+    assert caps.span() == expected.span()
+    assert caps.span("value") == expected.span("value")
+    assert caps["key"] == b"a"
+    assert caps.string == haystack
+    assert len(caps) == 3
+    assert repr(caps) == "BytesCaptures(span=(5, 8), match=b'a=1')"
+
+
+def test_captures_unmatched_group() -> None:
+    caps = ry.BytesRegex(_KV_PATTERN).captures(b"b=")
+    assert caps is not None
+    assert caps["value"] is None
+    assert caps.span("value") == (-1, -1)
+    assert caps.groups(b"") == (b"b", b"")
+    with pytest.raises(IndexError):
+        caps.group("nope")
+
+
+def test_captures_iter_matches_stdlib() -> None:
+    haystack = b"a=1 b= c=3"
+    rx = ry.BytesRegex(_KV_PATTERN)
+    assert [c.span() for c in rx.captures_iter(haystack)] == [
+        m.span() for m in re.finditer(_PY_KV_PATTERN, haystack)
+    ]
+    it = rx.captures_iter(haystack)
+    first = next(it)
+    assert first.group() == b"a=1"
+    assert [c.group("key") for c in it.collect()] == [b"b", b"c"]
+    assert rx.capture_names() == [None, "key", "value"]
+    assert rx.captures_len() == 3
+
+
+def test_empty_matches() -> None:
+    rx = ry.BytesRegex(r"x*")
+    assert rx.find_all(b"axb") == [(0, 0), (1, 2), (3, 3)]
+
+
+def test_replace() -> None:
+    rx = ry.BytesRegex(_KV_PATTERN)
+    assert rx.replace(b"a=1 b=2", b"$value=$key") == b"1=a b=2"
+    assert rx.replace_all(b"a=1 b=2", b"${value}:$key") == b"1:a 2:b"
+    unchanged = rx.replace_all(b"nothing here", b"x")
+    assert isinstance(unchanged, ry.Bytes)
+    assert unchanged == b"nothing here"
+
+
+def test_split() -> None:
+    rx = ry.BytesRegex(r"[,;]\s*")
+    assert rx.split(b"a, b;c") == [b"a", b"b", b"c"]
+    assert rx.splitn(b"a, b;c", 2) == [b"a", b"b;c"]
+    assert all(isinstance(part, ry.Bytes) for part in rx.split(b"a,b"))
+
+
+def test_options() -> None:
+    assert ry.BytesRegex(r"abc", case_insensitive=True).is_match(b"ABC")
+    assert not ry.BytesRegex(r"abc").is_match(b"ABC")
+    assert ry.BytesRegex(r"^b$", multi_line=True).find(b"a\nb\nc") == (2, 3)
+
+
+def test_invalid_pattern() -> None:
+    with pytest.raises(ValueError):
+        ry.BytesRegex(r"(unclosed")
+
+
+def test_repr_and_pickle() -> None:
+    rx = ry.BytesRegex(r"\d+", case_insensitive=True)
+    assert repr(rx) == "BytesRegex(r'\\d+', case_insensitive=True)"
+    assert pickle.loads(pickle.dumps(rx)) == rx
+    assert rx != ry.BytesRegex(r"\d+")
+
+
+class TestBytesRegexSet:
+    def test_is_match(self) -> None:
+        rs = ry.BytesRegexSet([r"\d+", r"foo"])
+        assert rs.is_match(b"abc 123")
+        assert rs.test(bytearray(b"foo"))
+        assert not rs.is_match(ry.Bytes(b"bar"))
+
+    def test_options(self) -> None:
+        rs = ry.BytesRegexSet([r"foo", r"bar"], case_insensitive=True)
+        assert rs.is_match(b"FOO")
+
+    def test_pickle(self) -> None:
+        rs = ry.BytesRegexSet([r"\d+", r"foo"], multi_line=True)
+        assert pickle.loads(pickle.dumps(rs)) == rs
+
+    def test_invalid_pattern(self) -> None:
+        with pytest.raises(ValueError):
+            ry.BytesRegexSet([r"ok", r"(unclosed"])
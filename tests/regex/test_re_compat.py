@@ -0,0 +1,257 @@
+from __future__ import annotations
+
+import re
+import typing as t
+
+import pytest
+
+from ry import re as ryre
+
+_CASES: list[tuple[str, str]] = [
+    (r"\d+", "abc 123 def 4567"),
+    (r"(\w+)@(\w+)\.com", "mail foo@bar.com and baz@qux.com"),
+    (r"(?P<key>\w+)=(?P<value>\w*)", "a=1, b=, c=three"),
+    (r"(a)|(b)", "xaybz"),
+    (r"héllo|wörld", "héllo wörld 💩 héllo"),
+    (r"[^\]]+", "a]b]]c"),
+    (r"x", "no match here"),
+]
+
+
+@pytest.mark.parametrize(("pattern", "string"), _CASES)
+def test_search_match_fullmatch(pattern: str, string: str) -> None:
+    for fn in ("search", "match", "fullmatch"):
+        expected = getattr(re, fn)(pattern, string)
+        actual = getattr(ryre, fn)(pattern, string)
+        if expected is None:
+            assert actual is None, fn
+            continue
+        assert actual is not None, fn
+        assert actual.group() == expected.group()
+        assert actual.groups() == expected.groups()
+        assert actual.groupdict() == expected.groupdict()
+        assert actual.span() == expected.span()
+        assert actual.lastindex == expected.lastindex
+        assert actual.lastgroup == expected.lastgroup
+        assert actual.string is string
+
+
+@pytest.mark.parametrize(("pattern", "string"), _CASES)
+def test_findall_finditer(pattern: str, string: str) -> None:
+    assert ryre.findall(pattern, string) == re.findall(pattern, string)
+    assert [m.span() for m in ryre.finditer(pattern, string)] == [
+        m.span() for m in re.finditer(pattern, string)
+    ]
+
+
+@pytest.mark.parametrize(("pattern", "string"), _CASES)
+def test_split(pattern: str, string: str) -> None:
+    assert ryre.split(pattern, string) == re.split(pattern, string)
+    assert ryre.split(pattern, string, maxsplit=1) == re.split(
+        pattern, string, maxsplit=1
+    )
+
+
+@pytest.mark.parametrize(
+    ("pattern", "repl", "string"),
+    [
+        (r"(\w+)=(\d+)", r"\2=\1", "a=1 b=22"),
+        (r"(?P<k>\w+)=(?P<v>\d+)", r"\g<v>:\g<k>\n", "a=1 b=22"),
+        (r"(?P<k>\w+)=(?P<v>\d+)", r"\g<0>!", "a=1 b=22"),
+        (r"(a)|(b)", r"[\1\2]", "ab"),
+        (r"\s+", " ", "a   b \t c"),
+        (r"x", "y", "no match"),
+    ],
+)
+def test_sub_subn(pattern: str, repl: str, string: str) -> None:
+    assert ryre.sub(pattern, repl, string) == re.sub(pattern, repl, string)
+    assert ryre.subn(pattern, repl, string) == re.subn(pattern, repl, string)
+    assert ryre.sub(pattern, repl, string, count=1) == re.sub(
+        pattern, repl, string, count=1
+    )
+
+
+def test_sub_callable() -> None:
+    def upper(m: t.Any) -> str:
+        return str(m.group(1)).upper()
+
+    assert ryre.sub(r"-(\w)", upper, "snake-case-words") == "snakeCaseWords"
+
+
+def test_match_expand() -> None:
+    m = ryre.search(r"(?P<first>\w+) (?P<last>\w+)", "Jane Doe")
+    assert m is not None
+    assert m.expand(r"\g<last>, \1") == "Doe, Jane"
+    assert m["first"] == "Jane"
+    assert m.group(1, "last") == ("Jane", "Doe")
+    assert m.regs == ((0, 8), (0, 4), (5, 8))
+
+
+def test_flags() -> None:
+    assert ryre.search(r"abc", "ABC", ryre.I) is not None
+    assert ryre.search(r"abc", "ABC", re.IGNORECASE) is not None
+    assert ryre.findall(r"^\w", "a\nb", ryre.M) == ["a", "b"]
+    assert ryre.search(r"a.b", "a\nb", ryre.S) is not None
+    assert ryre.search(r"a.b", "a\nb") is None
+    pattern = r"""
+        (\d+)  # number
+        \s*    # optional space
+        (\w+)  # unit
+    """
+    assert ryre.fullmatch(pattern, "10 kg", ryre.X) is not None
+    assert ryre.findall(pattern, "10 kg, 5m", ryre.X) == [("10", "kg"), ("5", "m")]
+    assert ryre.I | ryre.M == re.I | re.M
+
+
+def test_unsupported_flag() -> None:
+    with pytest.raises(ValueError, match="unsupported"):
+        ryre.compile(r"a", re.ASCII)
+
+
+def test_pattern_attributes() -> None:
+    p = ryre.compile(r"(?P<a>x)(y)", ryre.I)
+    assert p.pattern == r"(?P<a>x)(y)"
+    assert p.groups == 2
+    assert dict(p.groupindex) == {"a": 1}
+    assert p.flags & ryre.I
+    assert p == ryre.compile(r"(?P<a>x)(y)", ryre.I)
+    assert ryre.compile(p) is p
+    assert repr(p) == "ry.re.compile('(?P<a>x)(y)', ry.re.IGNORECASE)"
+    with pytest.raises(ValueError):
+        ryre.compile(p, ryre.M)
+
+
+def test_pos_endpos() -> None:
+    p = ryre.compile(r"\d+")
+    m = p.search("ab12cd34", 4)
+    assert m is not None
+    assert m.span() == (6, 8)
+    assert m.pos == 4
+    assert m.endpos == 8
+    assert p.match("ab12", 2) is not None
+    assert p.search("ab12cd34", 0, 3) is not None
+    assert p.search("ab12cd34", 0, 3).group() == "1"  # type: ignore[union-attr]
+    assert p.findall("1 2 3 4", 2, 5) == ["2", "3"]
+
+
+@pytest.mark.parametrize(
+    "pattern",
+    [
+        r"foo(?=bar)",
+        r"foo(?!bar)",
+        r"(?<=foo)bar",
+        r"(?<!foo)bar",
+    ],
+)
+def test_lookaround_raises(pattern: str) -> None:
+    with pytest.raises(re.error, match="look-around is not supported"):
+        ryre.compile(pattern)
+
+
+@pytest.mark.parametrize("pattern", [r"(a)\1", r"(?P<x>a)(?P=x)"])
+def test_backreferences_raise(pattern: str) -> None:
+    with pytest.raises(ryre.error, match="backreferences are not supported"):
+        ryre.compile(pattern)
+
+
+def test_not_lookaround() -> None:
+    # escaped parens and char classes are not look-around
+    assert ryre.findall(r"\(?=", "(= =") == ["(=", "="]
+    assert ryre.findall(r"[(?=]+", "a(?=b") == ["(?="]
+
+
+def test_invalid_pattern() -> None:
+    with pytest.raises(ryre.error):
+        ryre.compile(r"(unclosed")
+
+
+def test_escape() -> None:
+    special = "a.b*c+d?e(f)g[h]i{j}k|l^m$n\\o p\tq\nr-s&t~u#v"
+    escaped = ryre.escape(special)
+    assert ryre.fullmatch(escaped, special) is not None
+    assert re.fullmatch(escaped, special) is not None
+    assert ryre.escape(b"a.b") == b"a\\.b"
+
+
+def test_bytes_patterns() -> None:
+    data = b"key=\xff\xfe value=abc"
+    p = ryre.compile(rb"(\w+)=(\S+)")
+    assert p.findall(data) == re.findall(rb"(\w+)=(\S+)", data)
+    m = p.search(data)
+    assert m is not None
+    assert m.group(2) == b"\xff\xfe"
+    assert isinstance(m.group(2), bytes)
+    assert ryre.sub(rb"\xff", b"!", data) == re.sub(rb"\xff", b"!", data)
+    assert ryre.split(rb"\s", data) == [b"key=\xff\xfe", b"value=abc"]
+
+
+@pytest.mark.filterwarnings("ignore::FutureWarning")  # "possible set ..."
+@pytest.mark.parametrize(
+    ("pattern", "string"),
+    [
+        # rust class set operators / nested classes are literals in the stdlib
+        (r"[&&]+", "a&&b&c"),
+        (r"[\w&&\d]+", "ab&&12 x"),
+        (r"[~~x]+", "a~~xb~"),
+        (r"[+--]+", "a+,-.b"),
+        (r"[--/]+", "a-./0"),
+        (r"[a-]+", "ba-c-"),
+        (r"[-a]+", "b-a-c"),
+        (r"[[a]+", "b[a[c"),
+        # `{` that is not a repetition is a literal
+        (r"a{", "a{b"),
+        (r"{x}", "{x} x"),
+        (r"a{}", "a{} a"),
+        (r"a{1,b}", "a{1,b}"),
+        (r"a{1,2}", "aaa"),
+        (r"ba{,2}", "baaab"),
+        (r"ba{,}", "baaab"),
+        (r"x{2,}", "xxxxx x"),
+        (r"x{2}?", "xxxxx"),
+    ],
+)
+def test_translation(pattern: str, string: str) -> None:
+    assert ryre.findall(pattern, string) == re.findall(pattern, string)
+
+
+@pytest.mark.parametrize(
+    ("pattern", "string"),
+    [
+        # backslash + non-ascii byte escapes the byte
+        (b"\\\xe9+", b"a\xe9\xe9b"),
+        (b"[\\\xe9]+", b"a\xe9\xe9b"),
+        # escaped backslash followed by a non-ascii byte
+        (b"\\\\\xe9", b"a\\\xe9b"),
+    ],
+)
+def test_bytes_escaped_non_ascii(pattern: bytes, string: bytes) -> None:
+    assert ryre.findall(pattern, string) == re.findall(pattern, string)
+
+
+@pytest.mark.parametrize(
+    ("pattern", "string"),
+    [
+        (r"(a)(b*)", "a"),
+        (r"(a(b*))", "a"),
+        (r"(a)(b)?", "a"),
+        (r"(a)(?:x|(y*))", "a"),
+        (r"(?P<x>a)(?P<y>)", "a"),
+        (r"((a)|b)+", "ab"),
+        (r"(a)(b)(c)", "abc"),
+        (r"x", "x"),
+    ],
+)
+def test_lastindex(pattern: str, string: str) -> None:
+    expected = re.search(pattern, string)
+    actual = ryre.search(pattern, string)
+    assert expected is not None
+    assert actual is not None
+    assert actual.lastindex == expected.lastindex
+    assert actual.lastgroup == expected.lastgroup
+
+
+def test_purge() -> None:
+    p = ryre.compile(r"purge-me")
+    assert ryre.compile(r"purge-me") is p
+    ryre.purge()
+    assert ryre.compile(r"purge-me") is not p
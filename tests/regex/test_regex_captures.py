@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+import re
+
+import pytest
+
+import ry
+
+_DATE_PATTERN = r"(?<y>\d{4})-(?<m>\d{2})(-(?<d>\d{2}))?"
+_PY_DATE_PATTERN = r"(?P<y>\d{4})-(?P<m>\d{2})(-(?P<d>\d{2}))?"
+
+
+def test_capture_names_and_len() -> None:
+    rx = ry.Regex(_DATE_PATTERN)
+    assert rx.capture_names() == [None, "y", "m", None, "d"]
+    assert rx.captures_len() == 5
+    assert ry.Regex(r"\w+").capture_names() == [None]
+    assert ry.Regex(r"\w+").captures_len() == 1
+
+
+def test_captures_no_match() -> None:
+    assert ry.Regex(_DATE_PATTERN).captures("no dates here") is None
+
+
+@pytest.mark.parametrize(
+    "haystack",
+    [
+        "released on 2024-03-17!",
+        "released on 2024-03!",
+        "2024-03-17",
+        "héllo wörld 💩 2024-03-17 🐍",
+        "ünïcödé 1999-12",
+    ],
+)
+def test_captures_matches_stdlib(haystack: str) -> None:
+    caps = ry.Regex(_DATE_PATTERN).captures(haystack)
+    expected = re.search(_PY_DATE_PATTERN, haystack)
+    assert caps is not None
+    assert expected is not None
+    assert caps.group() == expected.group()
+    assert caps.group(0) == expected.group(0)
+    assert caps.group("y", "m", 4) == expected.group("y", "m", 4)
+    assert caps.groups() == expected.groups()
+    assert caps.groups("") == expected.groups("")
+    assert caps.groupdict() == expected.groupdict()
+    assert caps.groupdict("?") == expected.groupdict("?")
+    for group in (0, 1, 2, 3, 4, "y", "m", "d"):
+        assert caps.span(group) == expected.span(group)
+        assert caps.start(group) == expected.start(group)
+        assert caps.end(group) == expected.end(group)
+        assert caps[group] == expected[group]
+    assert caps.span() == expected.span()
+    assert caps.string == haystack
+    assert haystack[caps.start() : caps.end()] == caps.group()
+
+
+def test_captures_len_and_repr() -> None:
+    caps = ry.Regex(_DATE_PATTERN).captures("on 2024-03-17")
+    assert caps is not None
+    assert len(caps) == 5
+    assert repr(caps) == "Captures(span=(3, 13), match='2024-03-17')"
+
+
+def test_captures_bad_group() -> None:
+    caps = ry.Regex(_DATE_PATTERN).captures("2024-03-17")
+    assert caps is not None
+    with pytest.raises(IndexError):
+        caps.group(5)
+    with pytest.raises(IndexError):
+        caps.group(-1)
+    with pytest.raises(IndexError):
+        caps.group("nope")
+    with pytest.raises(IndexError):
+        caps["nope"]
+    with pytest.raises(TypeError):
+        caps.group(1.5)  # type: ignore[call-overload]
+
+
+def test_captures_iter() -> None:
+    haystack = "é 2024-03-17, ü 1999-12 and 💩 2000-01-01"
+    rx = ry.Regex(_DATE_PATTERN)
+    caps = list(rx.captures_iter(haystack))
+    expected = list(re.finditer(_PY_DATE_PATTERN, haystack))
+    assert [c.span() for c in caps] == [m.span() for m in expected]
+    assert [c.groups() for c in caps] == [m.groups() for m in expected]
+    assert [c.groupdict() for c in caps] == [m.groupdict() for m in expected]
+
+
+def test_captures_iter_is_lazy() -> None:
+    it = ry.Regex(r"\d+").captures_iter("1 22 333")
+    first = next(it)
+    assert first.group() == "1"
+    assert [c.group() for c in it.collect()] == ["22", "333"]
+    with pytest.raises(StopIteration):
+        next(it)
+
+
+def test_captures_iter_empty_matches() -> None:
+    # same semantics as the regex crate (an empty match directly after a
+    # match is skipped)
+    caps = list(ry.Regex(r"a*").captures_iter("baaa"))
+    assert [c.span() for c in caps] == [(0, 0), (1, 4)]
+    caps = list(ry.Regex(r"").captures_iter("éa"))
+    assert [c.span() for c in caps] == [(0, 0), (1, 1), (2, 2)]
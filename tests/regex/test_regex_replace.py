@@ -0,0 +1,102 @@
+from __future__ import annotations
+
+import re
+
+import pytest
+
+import ry
+
+_NAME = r"(?<last>[^,\s]+),\s+(?<first>\S+)"
+
+
+def test_replace_template() -> None:
+    rx = ry.Regex(_NAME)
+    assert rx.replace("Springsteen, Bruce", "$first $last") == "Bruce Springsteen"
+    assert rx.replace("Springsteen, Bruce", "${2} ${1}") == "Bruce Springsteen"
+    assert rx.replace("Springsteen, Bruce", "$$first") == "$first"
+
+
+def test_replace_all_and_replacen() -> None:
+    rx = ry.Regex(r"\d+")
+    assert rx.replace_all("a1b22c333", "#") == "a#b#c#"
+    assert rx.replacen("a1b22c333", 2, "#") == "a#b#c333"
+    assert rx.replacen("a1b22c333", 0, "#") == "a#b#c#"
+    assert rx.replacen("a1b22c333", 1, "#") == rx.replace("a1b22c333", "#")
+
+
+def test_replace_no_match_returns_haystack() -> None:
+    haystack = "no digits here"
+    assert ry.Regex(r"\d+").replace_all(haystack, "#") == haystack
+    assert ry.Regex(r"\d+").replace_all(haystack, lambda _c: "#") == haystack
+
+
+def test_replace_callable() -> None:
+    rx = ry.Regex(r"(?<word>\w+)@(?<domain>\w+)\.com")
+
+    def redact(caps: ry.Captures) -> str:
+        assert isinstance(caps, ry.Captures)
+        return f"{caps['word'][0]}***@{caps['domain']}.com"
+
+    haystack = "mail foo@bar.com or baz@qux.com"
+    assert rx.replace_all(haystack, redact) == "mail f***@bar.com or b***@qux.com"
+    assert rx.replace(haystack, redact) == "mail f***@bar.com or baz@qux.com"
+    assert rx.replacen(haystack, 1, redact) == rx.replace(haystack, redact)
+
+
+def test_replace_callable_matches_stdlib_sub() -> None:
+    haystack = "héllo wörld 💩 snake_case_word"
+    rx = ry.Regex(r"_(\w)")
+    assert rx.replace_all(haystack, lambda c: str(c.group(1)).upper()) == re.sub(
+        r"_(\w)", lambda m: m.group(1).upper(), haystack
+    )
+
+
+def test_replace_callable_spans_are_str_indices() -> None:
+    spans: list[tuple[int, int]] = []
+
+    def record(caps: ry.Captures) -> str:
+        spans.append(caps.span())
+        return "!"
+
+    haystack = "💩a💩bb💩"
+    assert ry.Regex(r"[ab]+").replace_all(haystack, record) == "💩!💩!💩"
+    assert spans == [m.span() for m in re.finditer(r"[ab]+", haystack)]
+
+
+def test_replace_callable_errors() -> None:
+    rx = ry.Regex(r"\d")
+
+    def boom(_caps: ry.Captures) -> str:
+        msg = "boom"
+        raise RuntimeError(msg)
+
+    with pytest.raises(RuntimeError, match="boom"):
+        rx.replace_all("a1", boom)
+    with pytest.raises(TypeError):
+        rx.replace_all("a1", lambda _c: 1)  # type: ignore[arg-type,return-value]
+    with pytest.raises(TypeError):
+        rx.replace_all("a1", 1)  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    ("template", "expected"),
+    [
+        ("$last", "Springsteen"),
+        ("${first} ${last}", "Bruce Springsteen"),
+        ("$2 $1", "Bruce Springsteen"),
+        ("${2}x", "Brucex"),
+        # `$2x` refers to a group named `2x` which doesn't exist
+        ("$2x", ""),
+        ("$$ $0", "$ Springsteen, Bruce"),
+        ("$nope!", "!"),
+        ("$", "$"),
+        ("${unclosed", "${unclosed"),
+        ("no refs", "no refs"),
+    ],
+)
+def test_captures_expand(template: str, expected: str) -> None:
+    caps = ry.Regex(_NAME).captures("Springsteen, Bruce")
+    assert caps is not None
+    assert caps.expand(template) == expected
+    # same semantics as the template replacement
+    assert ry.Regex(_NAME).replace("Springsteen, Bruce", template) == expected
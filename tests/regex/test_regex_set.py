@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+import pickle
+
+import pytest
+
+import ry
+
+_PATTERNS = [r"\w+", r"\d+", r"\pL+", r"foo", r"bar", r"barfoo", r"foobar"]
+
+
+def test_matches() -> None:
+    rs = ry.RegexSet(_PATTERNS)
+    matches = rs.matches("foobar")
+    assert list(matches) == [0, 2, 3, 4, 6]
+    assert matches.to_list() == [0, 2, 3, 4, 6]
+    assert len(matches) == 5
+    assert matches.patterns_len == len(_PATTERNS)
+    assert matches.matched_any()
+    assert not matches.matched_all()
+    assert matches.matched(3)
+    assert not matches.matched(1)
+    assert 6 in matches
+    assert 5 not in matches
+    assert -1 not in matches
+    assert "6" not in matches
+    assert None not in matches
+    assert repr(matches) == "SetMatches([0, 2, 3, 4, 6])"
+
+
+def test_matches_none() -> None:
+    matches = ry.RegexSet([r"\d+", r"foo"]).matches("bar")
+    assert not matches
+    assert not matches.matched_any()
+    assert list(matches) == []
+
+
+def test_matches_all() -> None:
+    matches = ry.RegexSet([r"a", r"b"]).matches("ab")
+    assert matches.matched_all()
+    assert matches == ry.RegexSet([r"b", r"a"]).matches("ab")
+
+
+def test_matched_out_of_range() -> None:
+    matches = ry.RegexSet([r"a"]).matches("a")
+    with pytest.raises(IndexError):
+        matches.matched(1)
+
+
+def test_patterns_len_iter() -> None:
+    rs = ry.RegexSet(_PATTERNS)
+    assert rs.patterns == _PATTERNS
+    assert rs.len() == len(_PATTERNS)
+    assert len(rs) == len(_PATTERNS)
+    assert list(rs) == _PATTERNS
+    assert not rs.is_empty()
+    assert ry.RegexSet([]).is_empty()
+
+
+def test_is_match() -> None:
+    rs = ry.RegexSet([r"\d+", r"foo"])
+    assert rs.is_match("abc 123")
+    assert rs.test("foo")
+    assert not rs.is_match("bar")
+
+
+def test_matches_many() -> None:
+    rules = ry.RegexSet([r"(?i)error", r"(?i)warn", r"timeout"])
+    lines = [
+        "ERROR: connection timeout",
+        "warning: disk almost full",
+        "info: all good",
+    ]
+    results = rules.matches_many(lines)
+    assert [list(m) for m in results] == [[0, 2], [1], []]
+    assert results == [rules.matches(line) for line in lines]
+    assert rules.matches_many([]) == []
+
+
+def test_matches_many_rejects_str() -> None:
+    with pytest.raises(TypeError):
+        ry.RegexSet([r"a"]).matches_many("abc")  # type: ignore[arg-type]
+
+
+def test_options_and_pickle() -> None:
+    rs = ry.RegexSet([r"foo", r"^bar$"], case_insensitive=True, multi_line=True)
+    assert list(rs.matches("FOO\nBAR\n")) == [0, 1]
+    assert pickle.loads(pickle.dumps(rs)) == rs
+
+
+def test_invalid_pattern() -> None:
+    with pytest.raises(ValueError):
+        ry.RegexSet([r"ok", r"(unclosed"])
+
+
+def test_bytes_regex_set() -> None:
+    rs = ry.BytesRegexSet([r"\xff", r"foo"], unicode=False)
+    assert rs.patterns == [r"\xff", r"foo"]
+    assert len(rs) == 2
+    assert list(rs.matches(b"foo\xff")) == [0, 1]
+    assert [
+        list(m)
+        for m in rs.matches_many([b"\xff", bytearray(b"foo"), ry.Bytes(b"nope")])
+    ] == [[0], [1], []]
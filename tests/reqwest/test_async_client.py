@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import asyncio
 import typing as t
+from decimal import Decimal
 from typing import TYPE_CHECKING
 
 import pytest
@@ -279,6 +280,27 @@ class TestResponseJson:
         }
         assert data == expected
 
+    @pytest.mark.anyio
+    async def test_get_json_float_mode_decimal(
+        self, server: ReqtestServer, client: TClient
+    ) -> None:
+        url = server.url / "broken-json"
+        response = await client.get(url)
+        data = await response.json(partial_mode=True, float_mode="decimal")
+        assert data["fraction-red-heelers"] == Decimal("0.5")
+        assert isinstance(data["fraction-red-heelers"], Decimal)
+        assert data["bluey-fam-size"] == 4
+
+    @pytest.mark.anyio
+    async def test_get_json_lazy_numbers_partial(
+        self, server: ReqtestServer, client: TClient
+    ) -> None:
+        url = server.url / "broken-json"
+        response = await client.get(url)
+        data = await response.json(partial_mode=True, lazy_numbers=True)
+        assert data["fraction-red-heelers"] == "0.5"
+        assert data["bluey-fam-size"] == "4"
+
 
 class TestStream:
     @pytest.mark.anyio
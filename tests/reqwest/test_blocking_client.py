@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import typing as t
+from decimal import Decimal
 from typing import TYPE_CHECKING
 
 import pytest
@@ -255,6 +256,15 @@ class TestResponseJson:
         }
         assert data == expected
 
+    def test_get_json_float_mode_decimal(self, server: ReqtestServer) -> None:
+        url = server.url / "broken-json"
+        client = ry.BlockingClient()
+        response = client.get(url)
+        data = response.json(partial_mode=True, float_mode="decimal")
+        assert data["fraction-red-heelers"] == Decimal("0.5")
+        assert isinstance(data["fraction-red-heelers"], Decimal)
+        assert data["bluey-fam-size"] == 4
+
 
 class TestStream:
     @staticmethod
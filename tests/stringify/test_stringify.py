@@ -194,7 +194,7 @@ def test_typed_dict() -> None:
 
 
 def test_namedtuples() -> None:
-    """Test that `ry.stringify` handles namedtuples correctly."""
+    """Test that `ry.stringify` serializes namedtuples as objects"""
 
     class Point(t.NamedTuple):
         x: int
@@ -207,8 +207,8 @@ def test_namedtuples() -> None:
     json_bytes = ry.stringify(data)
     parsed = ry.parse_json(json_bytes)
     assert parsed == {
-        "point": [1, 2],
-        "point2": [3, 4],
+        "point": {"x": 1, "y": 2},
+        "point2": {"x": 3, "y": 4},
     }
 
 
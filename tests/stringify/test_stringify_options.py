@@ -0,0 +1,211 @@
+from __future__ import annotations
+
+import dataclasses
+import datetime as pydt
+import enum
+import json as pyjson
+import typing as t
+import uuid as pyuuid
+
+import pytest
+
+import ry
+
+UTC = pydt.timezone.utc
+PLUS_TWO = pydt.timezone(pydt.timedelta(hours=2))
+
+
+class TestIndent:
+    data: t.ClassVar[dict[str, t.Any]] = {"a": [1, 2], "b": {"c": None}}
+
+    @pytest.mark.parametrize("indent", [0, 1, 2, 4, 8])
+    def test_indent_int(self, indent: int) -> None:
+        res = ry.stringify(self.data, indent=indent)
+        assert res == pyjson.dumps(self.data, indent=indent).encode()
+
+    @pytest.mark.parametrize("indent", ["\t", "  ", "--"])
+    def test_indent_str(self, indent: str) -> None:
+        res = ry.stringify(self.data, indent=indent)
+        assert res == pyjson.dumps(self.data, indent=indent).encode()
+
+    def test_fmt_is_two_spaces(self) -> None:
+        assert ry.stringify(self.data, fmt=True) == ry.stringify(self.data, indent=2)
+
+    def test_indent_overrides_fmt(self) -> None:
+        assert ry.stringify(self.data, fmt=True, indent=4) == ry.stringify(
+            self.data, indent=4
+        )
+
+    def test_indent_with_sort_keys(self) -> None:
+        data = {"b": 1, "a": {"d": 2, "c": 3}}
+        res = ry.stringify(data, indent=3, sort_keys=True)
+        assert res == pyjson.dumps(data, indent=3, sort_keys=True).encode()
+
+    @pytest.mark.parametrize("indent", [-1, 1.5, b"  "])
+    def test_bad_indent(self, indent: t.Any) -> None:
+        with pytest.raises(TypeError, match="indent"):
+            ry.stringify(self.data, indent=indent)
+
+
+class TestEnsureAscii:
+    @pytest.mark.parametrize(
+        "obj",
+        [
+            "plain",
+            "ü",
+            "\U0001f600",
+            "mixed ascii and ünïcödé \U0001f600\n\"quoted\"",
+            {"ключ": ["значение", " "]},
+        ],
+    )
+    def test_matches_python(self, obj: t.Any) -> None:
+        res = ry.stringify(obj, ensure_ascii=True)
+        assert res == pyjson.dumps(obj, separators=(",", ":")).encode()
+        assert res.decode("ascii")
+        assert ry.parse_json(res) == obj
+
+    def test_with_indent(self) -> None:
+        obj = {"ü": ["é"]}
+        res = ry.stringify(obj, ensure_ascii=True, indent=2)
+        assert res == pyjson.dumps(obj, indent=2).encode()
+
+    def test_default_is_utf8(self) -> None:
+        assert ry.stringify("ü") == '"ü"'.encode()
+
+
+class Color(enum.Enum):
+    RED = "red"
+
+
+class TestNonStrKeys:
+    def test_non_str_keys(self) -> None:
+        u = pyuuid.UUID("12345678-1234-5678-1234-567812345678")
+        data = {
+            1: 3,
+            1.5: 2,
+            None: 4,
+            u: 5,
+            pydt.date(2020, 1, 1): 6,
+            Color.RED: 7,
+        }
+        res = ry.parse_json(ry.stringify(data, non_str_keys=True))
+        assert res == {
+            "1": 3,
+            "1.5": 2,
+            "null": 4,
+            str(u): 5,
+            "2020-01-01": 6,
+            "red": 7,
+        }
+
+    def test_bool_keys(self) -> None:
+        res = ry.stringify({True: 1, False: 0}, non_str_keys=True)
+        assert ry.parse_json(res) == {"true": 1, "false": 0}
+
+    @pytest.mark.parametrize(
+        "key",
+        [None, pyuuid.UUID(int=1), pydt.date(2020, 1, 1)],
+    )
+    def test_requires_flag(self, key: t.Any) -> None:
+        with pytest.raises(TypeError):
+            ry.stringify({key: 1})
+
+    def test_unsupported_key(self) -> None:
+        with pytest.raises(TypeError):
+            ry.stringify({(1, 2): 1}, non_str_keys=True)
+
+
+class TestDatetimeOptions:
+    naive = pydt.datetime(2020, 1, 1, 1, 2, 3)
+    aware_utc = pydt.datetime(2020, 1, 1, 1, 2, 3, tzinfo=UTC)
+    aware_plus_two = pydt.datetime(2020, 1, 1, 1, 2, 3, tzinfo=PLUS_TWO)
+
+    def test_default(self) -> None:
+        assert ry.stringify(self.naive) == b'"2020-01-01T01:02:03"'
+
+    def test_naive_utc(self) -> None:
+        assert (
+            ry.stringify(self.naive, naive_utc=True) == b'"2020-01-01T01:02:03+00:00"'
+        )
+
+    def test_utc_z(self) -> None:
+        assert ry.stringify(self.aware_utc, utc_z=True) == b'"2020-01-01T01:02:03Z"'
+        # naive datetimes are unaffected without `naive_utc`
+        assert ry.stringify(self.naive, utc_z=True) == b'"2020-01-01T01:02:03"'
+
+    def test_naive_utc_and_utc_z(self) -> None:
+        for dt in (self.naive, self.aware_utc):
+            assert (
+                ry.stringify(dt, naive_utc=True, utc_z=True)
+                == b'"2020-01-01T01:02:03Z"'
+            )
+
+    def test_non_utc_offset_unaffected(self) -> None:
+        expected = ry.stringify(self.aware_plus_two)
+        assert (
+            ry.stringify(self.aware_plus_two, naive_utc=True, utc_z=True) == expected
+        )
+
+    def test_nested(self) -> None:
+        res = ry.stringify(
+            {"dts": [self.naive, self.aware_utc]}, naive_utc=True, utc_z=True
+        )
+        assert ry.parse_json(res) == {
+            "dts": ["2020-01-01T01:02:03Z", "2020-01-01T01:02:03Z"]
+        }
+
+
+@dataclasses.dataclass
+class Point:
+    x: int
+    y: int
+
+
+class TestPassthrough:
+    def test_dataclass_default(self) -> None:
+        assert ry.parse_json(ry.stringify(Point(1, 2))) == {"x": 1, "y": 2}
+
+    def test_passthrough_dataclass(self) -> None:
+        res = ry.stringify(
+            [Point(1, 2)],
+            passthrough_dataclass=True,
+            default=lambda p: [p.x, p.y],
+        )
+        assert ry.parse_json(res) == [[1, 2]]
+
+    def test_passthrough_dataclass_no_default(self) -> None:
+        with pytest.raises(TypeError):
+            ry.stringify(Point(1, 2), passthrough_dataclass=True)
+
+    @pytest.mark.parametrize(
+        "obj",
+        [
+            pydt.datetime(2020, 1, 1, 1, 2, 3),
+            pydt.date(2020, 1, 1),
+            pydt.time(1, 2, 3),
+        ],
+    )
+    def test_passthrough_datetime(
+        self, obj: pydt.datetime | pydt.date | pydt.time
+    ) -> None:
+        res = ry.stringify(
+            {"v": obj},
+            passthrough_datetime=True,
+            default=lambda o: f"custom:{o.isoformat()}",
+        )
+        assert ry.parse_json(res) == {"v": f"custom:{obj.isoformat()}"}
+
+    def test_passthrough_datetime_no_default(self) -> None:
+        with pytest.raises(TypeError, match="not json-serializable"):
+            ry.stringify(pydt.datetime(2020, 1, 1), passthrough_datetime=True)
+
+    def test_passthrough_datetime_keys_unaffected(self) -> None:
+        data = {pydt.date(2020, 1, 1): 1}
+        res = ry.stringify(data, non_str_keys=True, passthrough_datetime=True)
+        assert ry.parse_json(res) == {"2020-01-01": 1}
+
+
+def test_dumps_accepts_options() -> None:
+    data = {"ü": pydt.datetime(2020, 1, 1)}
+    res = ry.JSON.dumps(data, indent=2, ensure_ascii=True, naive_utc=True)
+    assert res == pyjson.dumps({"ü": "2020-01-01T00:00:00+00:00"}, indent=2).encode()
@@ -0,0 +1,201 @@
+"""Serialization of stdlib/3rd-party objects (namedtuple, Decimal, pathlib,
+ipaddress, enum.Flag, attrs, pydantic) for the json and serde targets"""
+
+from __future__ import annotations
+
+import collections
+import dataclasses
+import enum
+import ipaddress
+import pathlib
+import typing as t
+from decimal import Decimal
+
+import pydantic
+import pytest
+
+import ry
+
+
+def _json_roundtrip(data: t.Any) -> t.Any:
+    return ry.parse_json(ry.stringify(data))
+
+
+def _yaml_roundtrip(data: t.Any) -> t.Any:
+    return ry.yaml.loads(ry.yaml.dumps(data))
+
+
+def _msgpack_roundtrip(data: t.Any) -> t.Any:
+    return ry.msgpack.unpackb(ry.msgpack.packb(data))
+
+
+pytest_mark_roundtrips = pytest.mark.parametrize(
+    "roundtrip",
+    [_json_roundtrip, _yaml_roundtrip, _msgpack_roundtrip],
+    ids=["json", "yaml", "msgpack"],
+)
+
+
+PointTuple = collections.namedtuple("PointTuple", ["x", "y"])
+
+
+class Point(t.NamedTuple):
+    x: int
+    y: int = 0
+
+
+class Perm(enum.Flag):
+    READ = 1
+    WRITE = 2
+    EXEC = 4
+
+
+class Item(pydantic.BaseModel):
+    name: str
+    price: Decimal
+    path: pathlib.Path
+
+
+class Order(pydantic.BaseModel):
+    id: int
+    items: list[Item]
+
+
+@pytest_mark_roundtrips
+def test_namedtuple(roundtrip: t.Callable[[t.Any], t.Any]) -> None:
+    assert roundtrip(PointTuple(1, 2)) == {"x": 1, "y": 2}
+    assert roundtrip([Point(3), Point(4, 5)]) == [
+        {"x": 3, "y": 0},
+        {"x": 4, "y": 5},
+    ]
+    assert roundtrip({"p": Point(1, Point(2, 3))}) == {
+        "p": {"x": 1, "y": {"x": 2, "y": 3}}
+    }
+
+
+def test_many_dynamic_classes() -> None:
+    # more classes than the per-type cache holds
+    for i in range(1100):
+        cls = collections.namedtuple(f"T{i}", ["a", f"f{i}"])  # type: ignore[misc]
+        assert _json_roundtrip(cls(1, i)) == {"a": 1, f"f{i}": i}
+        assert _json_roundtrip(tuple(cls(1, i))) == [1, i]
+
+
+@pytest_mark_roundtrips
+def test_plain_tuple_subclass_is_a_sequence(
+    roundtrip: t.Callable[[t.Any], t.Any],
+) -> None:
+    class MyTuple(tuple):  # type: ignore[type-arg]
+        __slots__ = ()
+
+    assert roundtrip(MyTuple((1, 2))) == [1, 2]
+
+
+@pytest_mark_roundtrips
+@pytest.mark.parametrize(
+    "value", [Decimal("1.10"), Decimal("-0"), Decimal("1E+3"), Decimal("NaN")]
+)
+def test_decimal(roundtrip: t.Callable[[t.Any], t.Any], value: Decimal) -> None:
+    assert roundtrip({"d": value, "l": [value]}) == {
+        "d": str(value),
+        "l": [str(value)],
+    }
+
+
+@pytest_mark_roundtrips
+@pytest.mark.parametrize(
+    "value",
+    [
+        pathlib.Path("a/b.txt"),
+        pathlib.PurePosixPath("/tmp/x"),
+        pathlib.PureWindowsPath("C:/Users"),
+    ],
+)
+def test_path(
+    roundtrip: t.Callable[[t.Any], t.Any], value: pathlib.PurePath
+) -> None:
+    assert roundtrip({"p": value, "l": [value]}) == {
+        "p": str(value),
+        "l": [str(value)],
+    }
+
+
+@pytest_mark_roundtrips
+@pytest.mark.parametrize(
+    "value",
+    [
+        ipaddress.IPv4Address("127.0.0.1"),
+        ipaddress.IPv6Address("::1"),
+        ipaddress.IPv4Network("10.0.0.0/8"),
+        ipaddress.IPv6Network("fe80::/64"),
+        ipaddress.IPv4Interface("192.168.0.1/24"),
+        ipaddress.IPv6Interface("fe80::1/64"),
+    ],
+)
+def test_ipaddress(roundtrip: t.Callable[[t.Any], t.Any], value: t.Any) -> None:
+    assert roundtrip({"ip": value, "l": [value]}) == {
+        "ip": str(value),
+        "l": [str(value)],
+    }
+
+
+@pytest_mark_roundtrips
+def test_enum_flag(roundtrip: t.Callable[[t.Any], t.Any]) -> None:
+    assert roundtrip(
+        {"one": Perm.READ, "many": Perm.READ | Perm.EXEC, "none": Perm(0)}
+    ) == {"one": 1, "many": 5, "none": 0}
+
+
+@pytest_mark_roundtrips
+def test_attrs(roundtrip: t.Callable[[t.Any], t.Any]) -> None:
+    attrs = pytest.importorskip("attrs")
+
+    @attrs.define
+    class Inner:
+        path: pathlib.Path
+
+    @attrs.frozen
+    class Outer:
+        id: int
+        inner: Inner
+        tags: list[str] = attrs.field(factory=list)
+
+    assert roundtrip(Outer(1, Inner(pathlib.Path("a")), ["x"])) == {
+        "id": 1,
+        "inner": {"path": "a"},
+        "tags": ["x"],
+    }
+
+
+def test_attrs_class_is_not_an_instance() -> None:
+    attrs = pytest.importorskip("attrs")
+
+    @attrs.define
+    class Thing:
+        x: int
+
+    with pytest.raises(TypeError):
+        ry.stringify(Thing)
+
+
+@pytest_mark_roundtrips
+def test_pydantic_model(roundtrip: t.Callable[[t.Any], t.Any]) -> None:
+    order = Order(
+        id=1,
+        items=[Item(name="a", price=Decimal("9.99"), path=pathlib.Path("x/y"))],
+    )
+    assert roundtrip([order]) == [
+        {"id": 1, "items": [{"name": "a", "price": "9.99", "path": "x/y"}]}
+    ]
+
+
+@pytest_mark_roundtrips
+def test_pydantic_model_in_dataclass(roundtrip: t.Callable[[t.Any], t.Any]) -> None:
+    @dataclasses.dataclass
+    class Wrapper:
+        item: Item
+
+    item = Item(name="b", price=Decimal(1), path=pathlib.Path("z"))
+    assert roundtrip(Wrapper(item)) == {
+        "item": {"name": "b", "price": "1", "path": "z"}
+    }
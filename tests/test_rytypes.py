@@ -7,10 +7,19 @@ import pytest
 import ry
 
 _RY_TYPES: list[type] = [
+    ry.AhoCorasick,
+    ry.AhoCorasickMatch,
+    ry.AhoCorasickStreamIter,
     ry.AsyncFile,
     ry.AsyncFileReadStream,
     ry.BlockingClient,
     ry.Bytes,
+    ry.BytesCaptures,
+    ry.BytesReader,
+    ry.BytesRegex,
+    ry.BytesRegexSet,
+    ry.BytesWriter,
+    ry.Captures,
     ry.Certificate,
     ry.CertificateRevocationList,
     ry.Client,
@@ -24,6 +33,8 @@ _RY_TYPES: list[type] = [
     ry.Duration,
     ry.FileReadStream,
     ry.FileType,
+    ry.Finder,
+    ry.FinderRev,
     ry.FsPath,
     ry.Glob,
     ry.GlobPattern,
@@ -41,13 +52,17 @@ _RY_TYPES: list[type] = [
     ry.Lz4BlockDecompressor,
     ry.Lz4FrameCompressor,
     ry.Metadata,
+    ry.Mmap,
+    ry.MmapMut,
     ry.Offset,
     ry.OffsetRound,
     ry.Proxy,
     ry.ReadDir,
     ry.Regex,
+    ry.RegexSet,
     ry.ReqwestError,
     ry.Response,
+    ry.SetMatches,
     ry.SignedDuration,
     ry.SignedDurationRound,
     ry.Size,
@@ -87,6 +102,13 @@ _RY_TYPES: list[type] = [
     ry.sha512,
     ry.sha512_256,
     # submodules
+    ry.JSON.AsyncJsonLinesWriter,
+    ry.JSON.JsonEventReader,
+    ry.JSON.JsonItemsIter,
+    ry.JSON.JsonLinesWriter,
+    ry.cbor.Tag,
+    ry.msgpack.Ext,
+    ry.toml.TomlDocument,
     ry.xxhash.xxh3_64,
     ry.xxhash.xxh3_128,
     ry.xxhash.xxh32,
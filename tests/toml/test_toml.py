@@ -0,0 +1,292 @@
+from __future__ import annotations
+
+import dataclasses
+import datetime as pydt
+import io
+import pickle
+import tomllib
+import typing as t
+
+import pytest
+
+import ry
+from ry import toml
+
+_DOC = """\
+# This is a TOML document
+title = "TOML Example" # the title
+
+[owner]
+name = "Tom Preston-Werner"
+dob = 1979-05-27T07:32:00-08:00
+
+[database]
+enabled = true
+ports = [ 8000, 8001, 8002 ]
+data = [ ["delta", "phi"], [3.14] ]
+temp_targets = { cpu = 79.5, case = 72.0 }
+
+[servers]
+
+[servers.alpha]
+ip = "10.0.0.1"
+role = "frontend"
+
+[[products]]
+name = "Hammer"
+sku = 738594937
+
+[[products]]
+name = "Nail"
+sku = 284758393
+"""
+
+
+class TestLoads:
+    def test_matches_tomllib(self) -> None:
+        assert toml.loads(_DOC) == tomllib.loads(_DOC)
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "odt = 1979-05-27T07:32:00Z",
+            "odt = 1979-05-27T00:32:00.999999-07:00",
+            "ldt = 1979-05-27T07:32:00",
+            "ldt = 1979-05-27 07:32:00",
+            "ld = 1979-05-27",
+            "lt = 00:32:00.999999",
+            "lt = 07:32:00.123456789",
+        ],
+    )
+    def test_datetimes_match_tomllib(self, s: str) -> None:
+        assert toml.loads(s) == tomllib.loads(s)
+
+    def test_preserves_order(self) -> None:
+        assert list(toml.loads("b = 1\na = 2\nc = 3")) == ["b", "a", "c"]
+
+    def test_invalid_has_position(self) -> None:
+        with pytest.raises(ValueError, match="line 2, column 5"):
+            toml.loads("a = 1\nb = \n")
+
+    def test_duplicate_key(self) -> None:
+        with pytest.raises(ValueError):
+            toml.loads("a = 1\na = 2")
+
+    def test_load_bytes(self) -> None:
+        assert toml.load(io.BytesIO(_DOC.encode())) == tomllib.loads(_DOC)
+
+    def test_load_str(self) -> None:
+        assert toml.load(io.StringIO(_DOC)) == tomllib.loads(_DOC)
+
+
+class TestDumps:
+    def test_roundtrip(self) -> None:
+        data = tomllib.loads(_DOC)
+        assert tomllib.loads(toml.dumps(data)) == data
+
+    def test_tables(self) -> None:
+        assert toml.dumps({"a": 1, "b": {"c": "d"}}) == 'a = 1\n\n[b]\nc = "d"\n'
+
+    def test_array_of_tables(self) -> None:
+        assert toml.dumps({"p": [{"n": 1}, {"n": 2}]}) == (
+            "[[p]]\nn = 1\n\n[[p]]\nn = 2\n"
+        )
+
+    def test_pretty(self) -> None:
+        assert toml.dumps({"a": [1, 2]}) == "a = [1, 2]\n"
+        assert toml.dumps({"a": [1, 2]}, pretty=True) == "a = [\n    1,\n    2,\n]\n"
+
+    def test_preserves_order(self) -> None:
+        assert toml.dumps({"b": 1, "a": 2}) == "b = 1\na = 2\n"
+
+    def test_none_values_are_skipped(self) -> None:
+        assert toml.dumps({"a": None, "b": 1}) == "b = 1\n"
+
+    def test_dataclass(self) -> None:
+        @dataclasses.dataclass
+        class Config:
+            name: str
+            debug: bool
+
+        assert toml.dumps(Config("x", True)) == 'name = "x"\ndebug = true\n'
+
+    def test_pydatetimes_are_native(self) -> None:
+        data = {
+            "odt": pydt.datetime(1979, 5, 27, 7, 32, tzinfo=pydt.timezone.utc),
+            "ldt": pydt.datetime(1979, 5, 27, 7, 32, 0, 999999),
+            "ld": pydt.date(1979, 5, 27),
+            "lt": pydt.time(7, 32),
+        }
+        s = toml.dumps(data)
+        assert s == (
+            "odt = 1979-05-27T07:32:00+00:00\n"
+            "ldt = 1979-05-27T07:32:00.999999\n"
+            "ld = 1979-05-27\n"
+            "lt = 07:32:00\n"
+        )
+        assert tomllib.loads(s) == data
+
+    def test_ry_datetimes_are_native(self) -> None:
+        s = toml.dumps({
+            "zdt": ry.date(2020, 8, 26).at(6, 27, 0, 0).in_tz("America/New_York"),
+            "ts": ry.Timestamp.from_millisecond(0),
+            "dt": ry.date(2020, 8, 26).at(6, 27, 0, 0),
+            "d": ry.date(2020, 8, 26),
+            "t": ry.time(6, 27),
+        })
+        assert s == (
+            "zdt = 2020-08-26T06:27:00-04:00\n"
+            "ts = 1970-01-01T00:00:00Z\n"
+            "dt = 2020-08-26T06:27:00\n"
+            "d = 2020-08-26\n"
+            "t = 06:27:00\n"
+        )
+
+    def test_datetimes_in_arrays_and_tables(self) -> None:
+        d = pydt.date(2020, 1, 2)
+        assert toml.dumps({"a": [d, d], "t": {"d": d}}) == (
+            "a = [2020-01-02, 2020-01-02]\n\n[t]\nd = 2020-01-02\n"
+        )
+
+    def test_aware_time_is_string(self) -> None:
+        # toml has no offset-time type
+        s = toml.dumps({"t": pydt.time(1, 2, tzinfo=pydt.timezone.utc)})
+        assert isinstance(tomllib.loads(s)["t"], str)
+
+    def test_default(self) -> None:
+        class Thing:
+            pass
+
+        assert toml.dumps({"a": Thing()}, default=lambda _: "thing") == (
+            'a = "thing"\n'
+        )
+
+    def test_default_not_callable(self) -> None:
+        with pytest.raises(TypeError, match="not callable"):
+            toml.dumps({}, default=1)  # type: ignore[arg-type]
+
+    @pytest.mark.parametrize("obj", [[1, 2], 1, "a"])
+    def test_top_level_must_be_table(self, obj: t.Any) -> None:
+        with pytest.raises(TypeError):
+            toml.dumps(obj)
+
+    def test_non_str_keys(self) -> None:
+        with pytest.raises(TypeError):
+            toml.dumps({1: 2})
+
+    def test_dump(self) -> None:
+        buf = io.BytesIO()
+        toml.dump({"a": "ü"}, buf)
+        assert buf.getvalue() == 'a = "ü"\n'.encode()
+
+
+class TestTomlDocument:
+    def test_str_roundtrip(self) -> None:
+        assert str(toml.TomlDocument(_DOC)) == _DOC
+
+    def test_empty(self) -> None:
+        doc = toml.TomlDocument()
+        assert str(doc) == ""
+        assert len(doc) == 0
+
+    def test_invalid(self) -> None:
+        with pytest.raises(ValueError, match="line 1"):
+            toml.TomlDocument("a = ")
+
+    def test_getitem(self) -> None:
+        doc = toml.TomlDocument(_DOC)
+        assert doc["title"] == "TOML Example"
+        assert doc["owner", "name"] == "Tom Preston-Werner"
+        assert doc[["database", "temp_targets", "cpu"]] == 79.5
+        assert doc["products"] == [
+            {"name": "Hammer", "sku": 738594937},
+            {"name": "Nail", "sku": 284758393},
+        ]
+        with pytest.raises(KeyError):
+            doc["nope"]
+        with pytest.raises(KeyError):
+            doc["owner", "nope"]
+
+    def test_dotted_str_is_a_single_key(self) -> None:
+        doc = toml.TomlDocument('"a.b" = 1\n[a]\nb = 2\n')
+        assert doc["a.b"] == 1
+        assert doc["a", "b"] == 2
+
+    def test_get_contains_len_keys(self) -> None:
+        doc = toml.TomlDocument(_DOC)
+        assert doc.get("nope") is None
+        assert doc.get("nope", 1) == 1
+        assert doc.get(("servers", "alpha", "ip")) == "10.0.0.1"
+        assert "title" in doc
+        assert ("servers", "alpha") in doc
+        assert ("servers", "beta") not in doc
+        assert len(doc) == 5
+        keys = ["title", "owner", "database", "servers", "products"]
+        assert doc.keys() == keys
+        assert list(doc) == keys
+
+    def test_to_dict(self) -> None:
+        assert toml.TomlDocument(_DOC).to_dict() == tomllib.loads(_DOC)
+
+    def test_set_keeps_formatting(self) -> None:
+        doc = toml.TomlDocument(_DOC)
+        doc["title"] = "New Title"
+        doc["database", "ports"] = [9000]
+        assert str(doc) == (
+            _DOC.replace('"TOML Example"', '"New Title"').replace(
+                "[ 8000, 8001, 8002 ]", "[9000]"
+            )
+        )
+
+    def test_set_new_key_in_table(self) -> None:
+        doc = toml.TomlDocument("[a]\nx = 1 # x\n\n[b]\ny = 2\n")
+        doc["a", "z"] = pydt.date(2020, 1, 2)
+        assert str(doc) == "[a]\nx = 1 # x\nz = 2020-01-02\n\n[b]\ny = 2\n"
+
+    def test_set_creates_tables(self) -> None:
+        doc = toml.TomlDocument("a = 1\n")
+        doc["tool", "ry", "debug"] = True
+        assert str(doc) == "a = 1\n\n[tool.ry]\ndebug = true\n"
+        assert doc["tool"] == {"ry": {"debug": True}}
+
+    def test_set_table(self) -> None:
+        doc = toml.TomlDocument("a = 1\n")
+        doc["t"] = {"b": 2, "c": {"d": 3}}
+        assert toml.loads(str(doc)) == {"a": 1, "t": {"b": 2, "c": {"d": 3}}}
+
+    def test_set_inline_table(self) -> None:
+        doc = toml.TomlDocument("t = { a = 1 }\n")
+        doc["t"] = {"b": 2}
+        doc["t", "c"] = 3
+        assert str(doc) == "t = { b = 2, c = 3 }\n"
+
+    def test_set_not_a_table(self) -> None:
+        doc = toml.TomlDocument("a = 1\n")
+        with pytest.raises(TypeError, match="'a' is not a table"):
+            doc["a", "b"] = 1
+
+    def test_set_none(self) -> None:
+        doc = toml.TomlDocument("a = 1\n")
+        with pytest.raises(TypeError):
+            doc["a"] = None
+
+    def test_delitem(self) -> None:
+        doc = toml.TomlDocument("a = 1 # a\nb = 2\n[t]\nc = 3\nd = 4\n")
+        del doc["a"]
+        del doc["t", "c"]
+        assert str(doc) == "b = 2\n[t]\nd = 4\n"
+        with pytest.raises(KeyError):
+            del doc["a"]
+        with pytest.raises(KeyError):
+            del doc["nope", "c"]
+
+    def test_bad_key(self) -> None:
+        doc = toml.TomlDocument()
+        with pytest.raises(TypeError):
+            doc[1]  # type: ignore[index]
+        with pytest.raises(KeyError):
+            doc[()]
+
+    def test_pickle(self) -> None:
+        doc = toml.TomlDocument(_DOC)
+        assert str(pickle.loads(pickle.dumps(doc))) == _DOC
@@ -0,0 +1,188 @@
+from __future__ import annotations
+
+import dataclasses
+import datetime as pydt
+import typing as t
+import uuid as pyuuid
+
+import pytest
+
+import ry
+from ry import yaml
+
+_MANIFEST = """\
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  labels: &labels
+    app: web
+spec:
+  replicas: 3
+  selector:
+    matchLabels: *labels
+  template:
+    spec:
+      containers:
+        - name: web
+          image: "nginx:1.27"
+          ports:
+            - containerPort: 80
+          env: []
+"""
+
+
+class TestLoads:
+    def test_manifest(self) -> None:
+        data = yaml.loads(_MANIFEST)
+        assert data["kind"] == "Deployment"
+        assert data["spec"]["replicas"] == 3
+        assert data["spec"]["selector"]["matchLabels"] == {"app": "web"}
+        container = data["spec"]["template"]["spec"]["containers"][0]
+        assert container == {
+            "name": "web",
+            "image": "nginx:1.27",
+            "ports": [{"containerPort": 80}],
+            "env": [],
+        }
+
+    @pytest.mark.parametrize(
+        ("s", "expected"),
+        [
+            ("null", None),
+            ("~", None),
+            ("", None),
+            ("true", True),
+            ("false", False),
+            ("1", 1),
+            ("-1", -1),
+            ("18446744073709551615", 18446744073709551615),
+            ("1.5", 1.5),
+            (".inf", float("inf")),
+            ("hello", "hello"),
+            ("'1'", "1"),
+            ("[1, two]", [1, "two"]),
+            ("{a: 1}", {"a": 1}),
+            ("a: |\n  x\n  y\n", {"a": "x\ny\n"}),
+        ],
+    )
+    def test_scalars_and_collections(self, s: str, expected: t.Any) -> None:
+        assert yaml.loads(s) == expected
+
+    def test_preserves_order(self) -> None:
+        assert list(yaml.loads("b: 1\na: 2\nc: 3\n")) == ["b", "a", "c"]
+
+    def test_merge_keys(self) -> None:
+        s = "base: &b {x: 1, y: 1}\nderived:\n  <<: *b\n  y: 2\n"
+        assert yaml.loads(s)["derived"] == {"x": 1, "y": 2}
+
+    def test_non_str_keys(self) -> None:
+        assert yaml.loads("1: one\n? [1, 2]\n: pair\n") == {1: "one", (1, 2): "pair"}
+
+    def test_tags_are_dropped(self) -> None:
+        assert yaml.loads("a: !Ref thing\n") == {"a": "thing"}
+
+    @pytest.mark.parametrize(
+        ("s", "line", "column"),
+        [
+            ("a: b: c\n", 1, 5),
+            ("a:\n  - 1\n - 2\n", 3, 2),
+            ("{a: 1\n", 2, 1),
+        ],
+    )
+    def test_error_has_line_and_column(self, s: str, line: int, column: int) -> None:
+        with pytest.raises(ValueError, match=f"at line {line} column {column}"):
+            yaml.loads(s)
+
+    def test_multiple_documents(self) -> None:
+        with pytest.raises(ValueError, match="more than one document"):
+            yaml.loads("a: 1\n---\nb: 2\n")
+
+
+class TestLoadsAll:
+    def test_documents(self) -> None:
+        s = f"{_MANIFEST}---\napiVersion: v1\nkind: Service\n"
+        docs = yaml.loads_all(s)
+        assert [d["kind"] for d in docs] == ["Deployment", "Service"]
+
+    def test_empty_documents(self) -> None:
+        assert yaml.loads_all("---\na: 1\n---\n---\nb: 2\n") == [
+            {"a": 1},
+            None,
+            {"b": 2},
+        ]
+
+    def test_error_has_line_and_column(self) -> None:
+        with pytest.raises(ValueError, match="at line 3 column 5"):
+            yaml.loads_all("a: 1\n---\nb: c: d\n")
+
+
+class TestDumps:
+    def test_roundtrip(self) -> None:
+        data = yaml.loads(_MANIFEST)
+        assert yaml.loads(yaml.dumps(data)) == data
+
+    def test_block_style(self) -> None:
+        assert yaml.dumps({"a": 1, "b": [1, "two"], "c": {"d": None}}) == (
+            "a: 1\nb:\n- 1\n- two\nc:\n  d: null\n"
+        )
+
+    @pytest.mark.parametrize(
+        ("obj", "expected"),
+        [
+            (None, "null\n"),
+            (1, "1\n"),
+            ("s", "s\n"),
+            ("1", "'1'\n"),
+            ("true", "'true'\n"),
+            ([], "[]\n"),
+            ({}, "{}\n"),
+            ("a\nb", "|-\n  a\n  b\n"),
+        ],
+    )
+    def test_scalars(self, obj: t.Any, expected: str) -> None:
+        assert yaml.dumps(obj) == expected
+        assert yaml.loads(yaml.dumps(obj)) == obj
+
+    def test_ry_and_std_types(self) -> None:
+        u = pyuuid.UUID("88475448-f91d-49a2-8fa0-3f1a9c5d2b3a")
+        s = yaml.dumps({
+            "uuid": u,
+            "date": pydt.date(2020, 1, 2),
+            "ry_date": ry.date(2020, 1, 2),
+            "dt": pydt.datetime(2020, 1, 2, 3, 4, 5),
+        })
+        assert yaml.loads(s) == {
+            "uuid": str(u),
+            "date": "2020-01-02",
+            "ry_date": "2020-01-02",
+            "dt": "2020-01-02T03:04:05",
+        }
+
+    def test_dataclass(self) -> None:
+        @dataclasses.dataclass
+        class Container:
+            name: str
+            ports: list[int]
+
+        assert yaml.dumps(Container("web", [80])) == "name: web\nports:\n- 80\n"
+
+    def test_default(self) -> None:
+        class Thing:
+            pass
+
+        assert yaml.dumps({"a": Thing()}, default=lambda _: "thing") == "a: thing\n"
+
+    def test_default_not_callable(self) -> None:
+        with pytest.raises(TypeError, match="not callable"):
+            yaml.dumps({}, default=1)  # type: ignore[arg-type]
+
+    def test_unserializable(self) -> None:
+        with pytest.raises(TypeError):
+            yaml.dumps({"a": object()})
+
+    def test_recursion(self) -> None:
+        data: list[t.Any] = []
+        data.append(data)
+        with pytest.raises(RecursionError):
+            yaml.dumps(data)